@@ -1,11 +1,12 @@
 use std::collections::BTreeMap;
 pub use tree_sitter::Tree as SyntaxTree;
 
-use crate::source::Spanned;
+use crate::{diagnostics::Diagnostics, source::Spanned};
 
 pub mod arbitrary;
 pub mod builder;
 pub mod from_cst;
+pub mod imports;
 pub mod queries;
 
 pub type SyntaxNode<'a> = Spanned<tree_sitter::Node<'a>>;
@@ -14,14 +15,88 @@ pub type SyntaxNode<'a> = Spanned<tree_sitter::Node<'a>>;
 pub struct ExprId(pub usize);
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd, Default, Debug)]
-pub struct InternId(usize);
+pub struct InternId(pub(crate) usize);
 
 #[derive(Debug, PartialEq)]
 pub enum Expr<'a> {
+    Unit {
+        node: Option<SyntaxNode<'a>>,
+    }, // unit
     Bool {
         value: bool,
         node: Option<SyntaxNode<'a>>,
     },
+    Nat {
+        value: u64,
+        node: Option<SyntaxNode<'a>>,
+    }, // 0, 1, 2
+    String {
+        value: String,
+        node: Option<SyntaxNode<'a>>,
+    }, // "hello\n", escapes are already replaced
+    Succ {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // succ x
+    Pred {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // pred x
+    IsZero {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // iszero x
+    Concat {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // concat "a" "b"
+    Length {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // length "abc"
+    StringEq {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // eq "a" "b"
+    Not {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // not a
+    And {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // a && b
+    Or {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // a || b
+    Equal {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // a == b
+    Tuple {
+        elements: Vec<Option<ExprId>>,
+        node: Option<SyntaxNode<'a>>,
+    }, // {x, y}
+    Projection {
+        expr: Option<ExprId>,
+        index: usize,
+        node: Option<SyntaxNode<'a>>,
+    }, // x.1
+    Record {
+        fields: Vec<(InternId, Option<ExprId>)>,
+        node: Option<SyntaxNode<'a>>,
+    }, // {x = a, y = b}
+    Field {
+        expr: Option<ExprId>,
+        label: InternId,
+        node: Option<SyntaxNode<'a>>,
+    }, // x.y
     Var {
         name: InternId,
         node: Option<SyntaxNode<'a>>,
@@ -30,16 +105,139 @@ pub enum Expr<'a> {
         name: InternId,
         node: Option<SyntaxNode<'a>>,
     },
+    PatternTuple {
+        elements: Vec<Option<ExprId>>,
+        node: Option<SyntaxNode<'a>>,
+    }, // {a, b} in `let {a, b} = p; a`
+    PatternRecord {
+        fields: Vec<(InternId, Option<ExprId>)>,
+        node: Option<SyntaxNode<'a>>,
+    }, // {x = a, y = b} in `let {x = a, y = b} = p; a`
+    Wildcard {
+        node: Option<SyntaxNode<'a>>,
+    }, // _ in `_: 0`, binds nothing
+    Inl {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // inl x
+    Inr {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // inr x
+    Case {
+        expr: Option<ExprId>,
+        inl: Option<ExprId>,
+        inr: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // case x of inl a => a | inr b => b
+    CaseArm {
+        binder: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // inl a => a
+    Variant {
+        label: InternId,
+        value: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // <some = x>
+    VariantCase {
+        expr: Option<ExprId>,
+        arms: Vec<Option<ExprId>>,
+        node: Option<SyntaxNode<'a>>,
+    }, // case x of <some = a> => a | <none = b> => b
+    VariantArm {
+        label: InternId,
+        binder: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // <some = a> => a
+    Nil {
+        node: Option<SyntaxNode<'a>>,
+    }, // nil
+    Cons {
+        head: Option<ExprId>,
+        tail: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // cons x xs
+    IsNil {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // isnil xs
+    Head {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // head xs
+    Tail {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // tail xs
+    List {
+        elements: Vec<Option<ExprId>>,
+        node: Option<SyntaxNode<'a>>,
+    }, // [x, y]
+    Ref {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // ref x
+    Deref {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // !x
+    Assign {
+        target: Option<ExprId>,
+        value: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // x := y
+    Error {
+        node: Option<SyntaxNode<'a>>,
+    }, // error
+    Raise {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // raise x
+    Try {
+        body: Option<ExprId>,
+        handler: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // try x with y
+    TypeAbs {
+        name: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // /\X. x: X => x
+    TypeApp {
+        expr: Option<ExprId>,
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // id [Nat]
+    Pack {
+        ty: Option<ExprId>,
+        value: Option<ExprId>,
+        existential: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // {*Nat, 0} as {Some X, X}
+    Unpack {
+        ty_name: Option<ExprId>,
+        name: Option<ExprId>,
+        value: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // let {X, x} = p in x
     Def {
         arg: Option<ExprId>,
+        ty: Option<ExprId>,
         body: Option<ExprId>,
         node: Option<SyntaxNode<'a>>,
-    }, // "fn x: x"
+    }, // "fn x: x" or "fn x: Bool => x"
     Call {
         func: Option<ExprId>,
         arg: Option<ExprId>,
         node: Option<SyntaxNode<'a>>,
     }, // x(y)
+    Fix {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // fix f
     IfElse {
         cond: Option<ExprId>,
         then: Option<ExprId>,
@@ -52,6 +250,84 @@ pub enum Expr<'a> {
         body: Option<ExprId>,
         node: Option<SyntaxNode<'a>>,
     }, // let x = 0; x
+    Letrec {
+        name: Option<ExprId>,
+        ty: Option<ExprId>,
+        value: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // letrec x: Nat = 0 in x
+    TypeAlias {
+        name: Option<ExprId>,
+        ty: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // type Point = {x: Nat, y: Nat}; p
+    Import {
+        path: String,
+        name: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // import "other.lbd" as m; m.x
+    Sequence {
+        first: Option<ExprId>,
+        then: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // x; y
+    Ascribe {
+        expr: Option<ExprId>,
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // x as Bool
+    TypeName {
+        name: InternId,
+        node: Option<SyntaxNode<'a>>,
+    }, // Bool
+    TypeFn {
+        from: Option<ExprId>,
+        to: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // Bool -> Bool
+    TypeProduct {
+        elements: Vec<Option<ExprId>>,
+        node: Option<SyntaxNode<'a>>,
+    }, // {Bool, Nat}
+    TypeRecord {
+        fields: Vec<(InternId, Option<ExprId>)>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeVariant {
+        fields: Vec<(InternId, Option<ExprId>)>,
+        node: Option<SyntaxNode<'a>>,
+    }, // <some: Nat, none: Unit>
+    TypeSum {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // Bool + Nat // {x: Bool, y: Nat}
+    TypeList {
+        element: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // List Nat
+    TypeRef {
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // Ref Nat
+    TypeRec {
+        name: InternId,
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // Rec X. <nil: Unit, cons: {Nat, X}>
+    TypeForAll {
+        name: InternId,
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // All X. X -> X
+    TypeSome {
+        name: InternId,
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    }, // {Some X, X}
 }
 
 pub fn var_def_to_str<'a>(e: &'a Exprs<'a>, id: ExprId) -> &'a str {
@@ -73,6 +349,11 @@ pub struct Exprs<'a> {
     pub i_to_s: BTreeMap<InternId, String>,
     pub s_to_i: BTreeMap<String, InternId>,
     pub intern_counter: InternId,
+    /// Root expression of every file, by filename
+    pub modules: BTreeMap<String, ExprId>,
+    /// Errors in the source which still parses, like a number that doesn't fit,
+    /// reported with the diagnostics of the lowering to IR, see `Exprs::report_errors`
+    pub errors: Vec<(SyntaxNode<'a>, String)>,
 }
 
 impl<'a> Exprs<'a> {
@@ -104,6 +385,13 @@ impl<'a> Exprs<'a> {
         let root = root?;
         Some(self.get(root).debug(self))
     }
+
+    /// Also needed without a root, a program of only a bad number literal has none
+    pub fn report_errors(&self, diagnostics: &mut Diagnostics) {
+        for (node, error) in &self.errors {
+            diagnostics.push(&Some(node.clone()), error);
+        }
+    }
 }
 
 #[derive(PartialEq)]
@@ -124,18 +412,274 @@ impl std::fmt::Debug for ExprId {
 impl<'a> std::fmt::Debug for DebugExpr<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.e {
+            Expr::Unit { node: _ } => write!(f, "Unit"),
             Expr::Bool { value: b, node: _ } => f.debug_tuple("Bool").field(b).finish(),
+            Expr::Nat { value: n, node: _ } => f.debug_tuple("Nat").field(n).finish(),
+            Expr::String { value, node: _ } => f.debug_tuple("String").field(value).finish(),
+            Expr::Succ { arg, node: _ } => {
+                f.debug_tuple("Succ").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Pred { arg, node: _ } => {
+                f.debug_tuple("Pred").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::IsZero { arg, node: _ } => {
+                f.debug_tuple("IsZero").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Concat {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("Concat")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::Length { arg, node: _ } => {
+                f.debug_tuple("Length").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::StringEq {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("StringEq")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::Not { arg, node: _ } => f.debug_tuple("Not").field(&self.ex.debug(*arg)).finish(),
+            Expr::And {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("And")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::Or {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("Or")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::Equal {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("Equal")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::Tuple { elements, node: _ } => {
+                let mut t = f.debug_tuple("Tuple");
+                for el in elements {
+                    t.field(&self.ex.debug(*el));
+                }
+                t.finish()
+            }
+            Expr::Projection {
+                expr,
+                index,
+                node: _,
+            } => f
+                .debug_tuple("Projection")
+                .field(&self.ex.debug(*expr))
+                .field(index)
+                .finish(),
+            Expr::Record { fields, node: _ } => {
+                let mut t = f.debug_struct("Record");
+                for (label, value) in fields {
+                    t.field(self.ex.get_str(*label), &self.ex.debug(*value));
+                }
+                t.finish()
+            }
+            Expr::Field {
+                expr,
+                label,
+                node: _,
+            } => f
+                .debug_tuple("Field")
+                .field(&self.ex.debug(*expr))
+                .field(&format_args!("{}", self.ex.get_str(*label)))
+                .finish(),
             Expr::Var { name: v, node: _ } => write!(f, "{}", self.ex.get_str(*v)),
             Expr::VarDef { name, node: _ } => write!(f, "Var({})", self.ex.get_str(*name)),
+            Expr::PatternTuple { elements, node: _ } => {
+                let mut t = f.debug_tuple("PatternTuple");
+                for el in elements {
+                    t.field(&self.ex.debug(*el));
+                }
+                t.finish()
+            }
+            Expr::PatternRecord { fields, node: _ } => {
+                let mut t = f.debug_struct("PatternRecord");
+                for (label, pattern) in fields {
+                    t.field(self.ex.get_str(*label), &self.ex.debug(*pattern));
+                }
+                t.finish()
+            }
+            Expr::Wildcard { node: _ } => write!(f, "Wildcard"),
+            Expr::Inl { arg, node: _ } => f.debug_tuple("Inl").field(&self.ex.debug(*arg)).finish(),
+            Expr::Inr { arg, node: _ } => f.debug_tuple("Inr").field(&self.ex.debug(*arg)).finish(),
+            Expr::Case {
+                expr,
+                inl,
+                inr,
+                node: _,
+            } => f
+                .debug_tuple("Case")
+                .field(&self.ex.debug(*expr))
+                .field(&self.ex.debug(*inl))
+                .field(&self.ex.debug(*inr))
+                .finish(),
+            Expr::CaseArm {
+                binder,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("CaseArm")
+                .field(&self.ex.debug(*binder))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::Variant {
+                label,
+                value,
+                node: _,
+            } => f
+                .debug_tuple("Variant")
+                .field(&format_args!("{}", self.ex.get_str(*label)))
+                .field(&self.ex.debug(*value))
+                .finish(),
+            Expr::VariantCase {
+                expr,
+                arms,
+                node: _,
+            } => {
+                let mut t = f.debug_tuple("VariantCase");
+                t.field(&self.ex.debug(*expr));
+                for arm in arms {
+                    t.field(&self.ex.debug(*arm));
+                }
+                t.finish()
+            }
+            Expr::VariantArm {
+                label,
+                binder,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("VariantArm")
+                .field(&format_args!("{}", self.ex.get_str(*label)))
+                .field(&self.ex.debug(*binder))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::Nil { node: _ } => write!(f, "Nil"),
+            Expr::Cons {
+                head,
+                tail,
+                node: _,
+            } => f
+                .debug_tuple("Cons")
+                .field(&self.ex.debug(*head))
+                .field(&self.ex.debug(*tail))
+                .finish(),
+            Expr::IsNil { arg, node: _ } => {
+                f.debug_tuple("IsNil").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Head { arg, node: _ } => {
+                f.debug_tuple("Head").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Tail { arg, node: _ } => {
+                f.debug_tuple("Tail").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::List { elements, node: _ } => {
+                let mut t = f.debug_tuple("List");
+                for el in elements {
+                    t.field(&self.ex.debug(*el));
+                }
+                t.finish()
+            }
+            Expr::Ref { arg, node: _ } => f.debug_tuple("Ref").field(&self.ex.debug(*arg)).finish(),
+            Expr::Deref { arg, node: _ } => {
+                f.debug_tuple("Deref").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Assign {
+                target,
+                value,
+                node: _,
+            } => f
+                .debug_tuple("Assign")
+                .field(&self.ex.debug(*target))
+                .field(&self.ex.debug(*value))
+                .finish(),
+            Expr::Error { node: _ } => write!(f, "Error"),
+            Expr::Raise { arg, node: _ } => {
+                f.debug_tuple("Raise").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Try {
+                body,
+                handler,
+                node: _,
+            } => f
+                .debug_tuple("Try")
+                .field(&self.ex.debug(*body))
+                .field(&self.ex.debug(*handler))
+                .finish(),
+            Expr::TypeAbs {
+                name,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("TypeAbs")
+                .field(&self.ex.debug(*name))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::TypeApp { expr, ty, node: _ } => f
+                .debug_tuple("TypeApp")
+                .field(&self.ex.debug(*expr))
+                .field(&self.ex.debug(*ty))
+                .finish(),
+            Expr::Pack {
+                ty,
+                value,
+                existential,
+                node: _,
+            } => f
+                .debug_tuple("Pack")
+                .field(&self.ex.debug(*ty))
+                .field(&self.ex.debug(*value))
+                .field(&self.ex.debug(*existential))
+                .finish(),
+            Expr::Unpack {
+                ty_name,
+                name,
+                value,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("Unpack")
+                .field(&self.ex.debug(*ty_name))
+                .field(&self.ex.debug(*name))
+                .field(&self.ex.debug(*value))
+                .field(&self.ex.debug(*body))
+                .finish(),
             Expr::Def {
                 arg,
+                ty,
                 body: ret,
                 node: _,
-            } => f
-                .debug_tuple("Def")
-                .field(&self.ex.debug(*arg))
-                .field(&self.ex.debug(*ret))
-                .finish(),
+            } => {
+                let mut t = f.debug_tuple("Def");
+                t.field(&self.ex.debug(*arg));
+                if ty.is_some() {
+                    t.field(&self.ex.debug(*ty));
+                }
+                t.field(&self.ex.debug(*ret)).finish()
+            }
             Expr::Call {
                 func: fun,
                 arg,
@@ -145,6 +689,7 @@ impl<'a> std::fmt::Debug for DebugExpr<'a> {
                 .field(&self.ex.debug(*fun))
                 .field(&self.ex.debug(*arg))
                 .finish(),
+            Expr::Fix { arg, node: _ } => f.debug_tuple("Fix").field(&self.ex.debug(*arg)).finish(),
             Expr::IfElse {
                 cond,
                 then,
@@ -167,6 +712,113 @@ impl<'a> std::fmt::Debug for DebugExpr<'a> {
                 .field(&self.ex.debug(*value))
                 .field(&self.ex.debug(*then))
                 .finish(),
+            Expr::Letrec {
+                name,
+                ty,
+                value,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("Letrec")
+                .field(&self.ex.debug(*name))
+                .field(&self.ex.debug(*ty))
+                .field(&self.ex.debug(*value))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::TypeAlias {
+                name,
+                ty,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("TypeAlias")
+                .field(&self.ex.debug(*name))
+                .field(&self.ex.debug(*ty))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::Import {
+                path,
+                name,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("Import")
+                .field(path)
+                .field(&self.ex.debug(*name))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::Sequence {
+                first,
+                then,
+                node: _,
+            } => f
+                .debug_tuple("Sequence")
+                .field(&self.ex.debug(*first))
+                .field(&self.ex.debug(*then))
+                .finish(),
+            Expr::Ascribe { expr, ty, node: _ } => f
+                .debug_tuple("Ascribe")
+                .field(&self.ex.debug(*expr))
+                .field(&self.ex.debug(*ty))
+                .finish(),
+            Expr::TypeName { name, node: _ } => write!(f, "TypeName({})", self.ex.get_str(*name)),
+            Expr::TypeFn { from, to, node: _ } => f
+                .debug_tuple("TypeFn")
+                .field(&self.ex.debug(*from))
+                .field(&self.ex.debug(*to))
+                .finish(),
+            Expr::TypeProduct { elements, node: _ } => {
+                let mut t = f.debug_tuple("TypeProduct");
+                for el in elements {
+                    t.field(&self.ex.debug(*el));
+                }
+                t.finish()
+            }
+            Expr::TypeSum {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("TypeSum")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::TypeVariant { fields, node: _ } => {
+                let mut t = f.debug_struct("TypeVariant");
+                for (label, ty) in fields {
+                    t.field(self.ex.get_str(*label), &self.ex.debug(*ty));
+                }
+                t.finish()
+            }
+            Expr::TypeRecord { fields, node: _ } => {
+                let mut t = f.debug_struct("TypeRecord");
+                for (label, ty) in fields {
+                    t.field(self.ex.get_str(*label), &self.ex.debug(*ty));
+                }
+                t.finish()
+            }
+            Expr::TypeList { element, node: _ } => f
+                .debug_tuple("TypeList")
+                .field(&self.ex.debug(*element))
+                .finish(),
+            Expr::TypeRef { ty, node: _ } => {
+                f.debug_tuple("TypeRef").field(&self.ex.debug(*ty)).finish()
+            }
+            Expr::TypeRec { name, ty, node: _ } => f
+                .debug_tuple("TypeRec")
+                .field(&format_args!("{}", self.ex.get_str(*name)))
+                .field(&self.ex.debug(*ty))
+                .finish(),
+            Expr::TypeForAll { name, ty, node: _ } => f
+                .debug_tuple("TypeForAll")
+                .field(&format_args!("{}", self.ex.get_str(*name)))
+                .field(&self.ex.debug(*ty))
+                .finish(),
+            Expr::TypeSome { name, ty, node: _ } => f
+                .debug_tuple("TypeSome")
+                .field(&format_args!("{}", self.ex.get_str(*name)))
+                .field(&self.ex.debug(*ty))
+                .finish(),
         }
     }
 }
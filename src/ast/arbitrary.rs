@@ -16,10 +16,275 @@ pub fn arbitrary_expr_id(e: &mut Exprs, u: &mut Unstructured) -> Result<ExprId>
 fn arbitrary_expr<'a>(e: &mut Exprs<'a>, u: &mut Unstructured) -> Result<Expr<'a>> {
     let kind = u.arbitrary::<ExprKind>()?;
     Ok(match kind {
+        ExprKind::Unit => Expr::Unit { node: None },
         ExprKind::Bool => Expr::Bool {
             value: u.arbitrary()?,
             node: None,
         },
+        ExprKind::Nat => Expr::Nat {
+            value: u.int_in_range(0..=16)?,
+            node: None,
+        },
+        ExprKind::String => Expr::String {
+            value: u.choose(&["", "a", "b c", "\"\\\n\t"])?.to_string(),
+            node: None,
+        },
+        ExprKind::Concat => Expr::Concat {
+            left: Some(arbitrary_expr_id(e, u)?),
+            right: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Length => Expr::Length {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::StringEq => Expr::StringEq {
+            left: Some(arbitrary_expr_id(e, u)?),
+            right: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Not => Expr::Not {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::And => Expr::And {
+            left: Some(arbitrary_expr_id(e, u)?),
+            right: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Or => Expr::Or {
+            left: Some(arbitrary_expr_id(e, u)?),
+            right: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Equal => Expr::Equal {
+            left: Some(arbitrary_expr_id(e, u)?),
+            right: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Succ => Expr::Succ {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Pred => Expr::Pred {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::IsZero => Expr::IsZero {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Tuple => {
+            let len = u.int_in_range(1..=3)?;
+            let elements = (0..len)
+                .map(|_| arbitrary_expr_id(e, u).map(Some))
+                .collect::<Result<_>>()?;
+            Expr::Tuple {
+                elements,
+                node: None,
+            }
+        }
+        ExprKind::Projection => Expr::Projection {
+            expr: Some(arbitrary_expr_id(e, u)?),
+            index: u.int_in_range(1..=3)?,
+            node: None,
+        },
+        ExprKind::Record => {
+            let len = u.int_in_range(1..=3)?;
+            let fields = (0..len)
+                .map(|_| {
+                    let label = e.push_str(u.choose(NAMES)?);
+                    Ok((label, Some(arbitrary_expr_id(e, u)?)))
+                })
+                .collect::<Result<_>>()?;
+            Expr::Record { fields, node: None }
+        }
+        ExprKind::Field => Expr::Field {
+            expr: Some(arbitrary_expr_id(e, u)?),
+            label: e.push_str(u.choose(NAMES)?),
+            node: None,
+        },
+        ExprKind::Inl => Expr::Inl {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Inr => Expr::Inr {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Case => {
+            let expr = Some(arbitrary_expr_id(e, u)?);
+            let mut arm = |e: &mut Exprs<'a>| -> Result<ExprId> {
+                let name = e.push_str(u.choose(NAMES)?);
+                let binder = Some(e.push(Expr::VarDef { name, node: None }));
+                let body = Some(arbitrary_expr_id(e, u)?);
+                Ok(e.push(Expr::CaseArm {
+                    binder,
+                    body,
+                    node: None,
+                }))
+            };
+            let inl = Some(arm(e)?);
+            let inr = Some(arm(e)?);
+            Expr::Case {
+                expr,
+                inl,
+                inr,
+                node: None,
+            }
+        }
+        ExprKind::Variant => Expr::Variant {
+            label: e.push_str(u.choose(NAMES)?),
+            value: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::VariantCase => {
+            let expr = Some(arbitrary_expr_id(e, u)?);
+            let len = u.int_in_range(1..=3)?;
+            let arms = (0..len)
+                .map(|_| {
+                    let label = e.push_str(u.choose(NAMES)?);
+                    let name = e.push_str(u.choose(NAMES)?);
+                    let binder = Some(e.push(Expr::VarDef { name, node: None }));
+                    let body = Some(arbitrary_expr_id(e, u)?);
+                    Ok(Some(e.push(Expr::VariantArm {
+                        label,
+                        binder,
+                        body,
+                        node: None,
+                    })))
+                })
+                .collect::<Result<_>>()?;
+            Expr::VariantCase {
+                expr,
+                arms,
+                node: None,
+            }
+        }
+        ExprKind::Nil => Expr::Nil { node: None },
+        ExprKind::Cons => Expr::Cons {
+            head: Some(arbitrary_expr_id(e, u)?),
+            tail: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::IsNil => Expr::IsNil {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Head => Expr::Head {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Tail => Expr::Tail {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Ref => Expr::Ref {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Deref => Expr::Deref {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Error => Expr::Error { node: None },
+        ExprKind::Raise => Expr::Raise {
+            arg: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::Try => Expr::Try {
+            body: Some(arbitrary_expr_id(e, u)?),
+            handler: Some(arbitrary_expr_id(e, u)?),
+            node: None,
+        },
+        ExprKind::TypeAbs => {
+            let name = e.push_str("X");
+            let name = Some(e.push(Expr::VarDef { name, node: None }));
+            Expr::TypeAbs {
+                name,
+                body: Some(arbitrary_expr_id(e, u)?),
+                node: None,
+            }
+        }
+        ExprKind::TypeApp => {
+            let expr = Some(arbitrary_expr_id(e, u)?);
+            let name = e.push_str(u.choose(&["Unit", "Bool", "Nat"])?);
+            let ty = Some(e.push(Expr::TypeName { name, node: None }));
+            Expr::TypeApp {
+                expr,
+                ty,
+                node: None,
+            }
+        }
+        // `{*Nat, e} as {Some X, X}`
+        ExprKind::Pack => {
+            let nat = e.push_str("Nat");
+            let ty = Some(e.push(Expr::TypeName {
+                name: nat,
+                node: None,
+            }));
+            let value = Some(arbitrary_expr_id(e, u)?);
+            let x = e.push_str("X");
+            let body = Some(e.push(Expr::TypeName {
+                name: x,
+                node: None,
+            }));
+            let existential = Some(e.push(Expr::TypeSome {
+                name: x,
+                ty: body,
+                node: None,
+            }));
+            Expr::Pack {
+                ty,
+                value,
+                existential,
+                node: None,
+            }
+        }
+        ExprKind::Unpack => {
+            let ty_name = e.push_str("X");
+            let ty_name = Some(e.push(Expr::VarDef {
+                name: ty_name,
+                node: None,
+            }));
+            let name = e.push_str(u.choose(NAMES)?);
+            let name = Some(e.push(Expr::VarDef { name, node: None }));
+            let value = Some(arbitrary_expr_id(e, u)?);
+            let body = Some(arbitrary_expr_id(e, u)?);
+            Expr::Unpack {
+                ty_name,
+                name,
+                value,
+                body,
+                node: None,
+            }
+        }
+        ExprKind::TypeAlias => {
+            let name = e.push_str("X");
+            let name = Some(e.push(Expr::VarDef { name, node: None }));
+            let nat = e.push_str("Nat");
+            let ty = Some(e.push(Expr::TypeName {
+                name: nat,
+                node: None,
+            }));
+            let body = Some(arbitrary_expr_id(e, u)?);
+            Expr::TypeAlias {
+                name,
+                ty,
+                body,
+                node: None,
+            }
+        }
+        ExprKind::List => {
+            let len = u.int_in_range(0..=3)?;
+            let elements = (0..len)
+                .map(|_| arbitrary_expr_id(e, u).map(Some))
+                .collect::<Result<_>>()?;
+            Expr::List {
+                elements,
+                node: None,
+            }
+        }
         ExprKind::Var => var(u.choose(NAMES)?).build(e).expect("Var"),
         ExprKind::Def => {
             let ret = arbitrary_expr_id(e, u)?;
@@ -27,6 +292,7 @@ fn arbitrary_expr<'a>(e: &mut Exprs<'a>, u: &mut Unstructured) -> Result<Expr<'a
             let name = e.push(Expr::VarDef { name, node: None });
             Expr::Def {
                 arg: Some(name),
+                ty: None,
                 body: Some(ret),
                 node: None,
             }
@@ -40,6 +306,15 @@ fn arbitrary_expr<'a>(e: &mut Exprs<'a>, u: &mut Unstructured) -> Result<Expr<'a
                 node: None,
             }
         }
+        ExprKind::Sequence => {
+            let first = Some(arbitrary_expr_id(e, u)?);
+            let then = Some(arbitrary_expr_id(e, u)?);
+            Expr::Sequence {
+                first,
+                then,
+                node: None,
+            }
+        }
         ExprKind::Let => {
             let name = e.push_str(u.choose(NAMES)?);
             let name = Some(e.push(Expr::VarDef { name, node: None }));
@@ -52,20 +327,95 @@ fn arbitrary_expr<'a>(e: &mut Exprs<'a>, u: &mut Unstructured) -> Result<Expr<'a
                 node: None,
             }
         }
+        ExprKind::LetPattern => {
+            let len = u.int_in_range(1..=3)?;
+            let mut names = (0..len)
+                .map(|_| {
+                    let name = e.push_str(u.choose(NAMES)?);
+                    Ok(Some(e.push(Expr::VarDef { name, node: None })))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let pattern = match u.arbitrary()? {
+                true => Expr::PatternTuple {
+                    elements: names,
+                    node: None,
+                },
+                false => Expr::PatternRecord {
+                    fields: names
+                        .drain(..)
+                        .map(|name| Ok((e.push_str(u.choose(NAMES)?), name)))
+                        .collect::<Result<_>>()?,
+                    node: None,
+                },
+            };
+            let name = Some(e.push(pattern));
+            let value = Some(arbitrary_expr_id(e, u)?);
+            let then = Some(arbitrary_expr_id(e, u)?);
+            Expr::Let {
+                name,
+                value,
+                body: then,
+                node: None,
+            }
+        }
     })
 }
 
+// No `Fix` or `Letrec`, well typed programs using them may not terminate.
+// No `Assign`, a function stored in a reference can call itself through it
 #[derive(Arbitrary)]
 enum ExprKind {
+    Unit,
     Bool,
+    Nat,
+    Succ,
+    Pred,
+    IsZero,
+    String,
+    Concat,
+    Length,
+    StringEq,
+    Not,
+    And,
+    Or,
+    Equal,
+    Tuple,
+    Projection,
+    Record,
+    Field,
+    Inl,
+    Inr,
+    Case,
+    Variant,
+    VariantCase,
+    Nil,
+    Cons,
+    IsNil,
+    Head,
+    Tail,
+    List,
+    Ref,
+    Deref,
+    Error,
+    Raise,
+    Try,
+    TypeAbs,
+    TypeApp,
+    Pack,
+    Unpack,
+    TypeAlias,
     Var,
     Def,
     Call,
     Let,
+    LetPattern,
+    Sequence,
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::{Duration, Instant};
+
     use crate::{diagnostics::Diagnostics, runtime::RunEnv, types::TypeEnv};
 
     use super::*;
@@ -84,9 +434,92 @@ mod tests {
             if diagnostics.has_errors() {
                 return Ok(());
             }
-            crate::runtime::eval(&exprs, &mut rt, root);
+            _ = crate::runtime::eval(&ir, &mut rt, root);
             Ok(())
         });
         // .budget_ms(5_000);
     }
+
+    /// Tuple of `let` chains binding `size` generated values in total, wide rather than deep,
+    /// so that nothing recurses much more than a small expression does.
+    /// Each chain binds every name of `NAMES` and ends with a tuple of them,
+    /// so values are generalized and the variables in later values use them
+    fn large_program(e: &mut Exprs, size: usize) -> ExprId {
+        // Same programs on every run, from a linear congruential generator
+        let mut state: u64 = 42;
+        let mut generate = |e: &mut Exprs| {
+            let data: Vec<u8> = (0..32)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    (state >> 56) as u8
+                })
+                .collect();
+            arbitrary_expr_id(e, &mut Unstructured::new(&data)).ok()
+        };
+        let elements = (0..size / NAMES.len())
+            .map(|_| {
+                let values: Vec<_> = NAMES.iter().map(|_| generate(e)).collect();
+                let names = NAMES
+                    .iter()
+                    .map(|name| var(*name).build(e).map(|var| e.push(var)))
+                    .collect();
+                let uses = e.push(Expr::Tuple {
+                    elements: names,
+                    node: None,
+                });
+                let chain = NAMES
+                    .iter()
+                    .zip(values)
+                    .rev()
+                    .fold(uses, |body, (name, value)| {
+                        let name = e.push_str(name);
+                        let name = e.push(Expr::VarDef { name, node: None });
+                        e.push(Expr::Let {
+                            name: Some(name),
+                            value,
+                            body: Some(body),
+                            node: None,
+                        })
+                    });
+                Some(chain)
+            })
+            .collect();
+        e.push(Expr::Tuple {
+            elements,
+            node: None,
+        })
+    }
+
+    /// Inference time per expression stays about the same as programs get larger.
+    /// Ignored as it measures time, which isn't reliable on a busy machine
+    #[test]
+    #[ignore]
+    fn inference_is_linear() {
+        let sizes = [1_000, 8_000];
+        let per_expr: Vec<Duration> = sizes
+            .iter()
+            .map(|size| {
+                let mut exprs = Exprs::default();
+                let root = large_program(&mut exprs, *size);
+                let mut diagnostics = Diagnostics::default();
+                let ir = crate::ir::Exprs::from_ast(&exprs, root, &mut diagnostics);
+
+                // Best of a few runs, to leave out the noise
+                let elapsed = (0..3).fold(Duration::MAX, |best, _| {
+                    let start = Instant::now();
+                    _ = TypeEnv::infer(&ir, root, &mut Diagnostics::default());
+                    best.min(start.elapsed())
+                });
+                elapsed / *size as u32
+            })
+            .collect();
+
+        // Eight times the expressions, a quadratic checker would take eight times as long for each
+        assert!(
+            per_expr[1] < per_expr[0] * 3,
+            "Inference is not linear: {per_expr:?} per expression"
+        );
+    }
 }
@@ -7,24 +7,287 @@ pub trait BuilderFn<'a> {
         Self: Sized,
     {
         Some(match self.build(exprs)? {
+            Expr::Unit { node: _ } => Expr::Unit { node: Some(node) },
             Expr::Bool { value: b, node: _ } => Expr::Bool {
                 value: b,
                 node: Some(node),
             },
+            Expr::Nat { value: n, node: _ } => Expr::Nat {
+                value: n,
+                node: Some(node),
+            },
+            Expr::Succ { arg, node: _ } => Expr::Succ {
+                arg,
+                node: Some(node),
+            },
+            Expr::Pred { arg, node: _ } => Expr::Pred {
+                arg,
+                node: Some(node),
+            },
+            Expr::IsZero { arg, node: _ } => Expr::IsZero {
+                arg,
+                node: Some(node),
+            },
+            Expr::String { value, node: _ } => Expr::String {
+                value,
+                node: Some(node),
+            },
+            Expr::Concat {
+                left,
+                right,
+                node: _,
+            } => Expr::Concat {
+                left,
+                right,
+                node: Some(node),
+            },
+            Expr::Length { arg, node: _ } => Expr::Length {
+                arg,
+                node: Some(node),
+            },
+            Expr::StringEq {
+                left,
+                right,
+                node: _,
+            } => Expr::StringEq {
+                left,
+                right,
+                node: Some(node),
+            },
+            Expr::Not { arg, node: _ } => Expr::Not {
+                arg,
+                node: Some(node),
+            },
+            Expr::And {
+                left,
+                right,
+                node: _,
+            } => Expr::And {
+                left,
+                right,
+                node: Some(node),
+            },
+            Expr::Or {
+                left,
+                right,
+                node: _,
+            } => Expr::Or {
+                left,
+                right,
+                node: Some(node),
+            },
+            Expr::Equal {
+                left,
+                right,
+                node: _,
+            } => Expr::Equal {
+                left,
+                right,
+                node: Some(node),
+            },
+            Expr::Tuple { elements, node: _ } => Expr::Tuple {
+                elements,
+                node: Some(node),
+            },
+            Expr::Projection {
+                expr,
+                index,
+                node: _,
+            } => Expr::Projection {
+                expr,
+                index,
+                node: Some(node),
+            },
+            Expr::Record { fields, node: _ } => Expr::Record {
+                fields,
+                node: Some(node),
+            },
+            Expr::Field {
+                expr,
+                label,
+                node: _,
+            } => Expr::Field {
+                expr,
+                label,
+                node: Some(node),
+            },
+            Expr::Inl { arg, node: _ } => Expr::Inl {
+                arg,
+                node: Some(node),
+            },
+            Expr::Inr { arg, node: _ } => Expr::Inr {
+                arg,
+                node: Some(node),
+            },
+            Expr::Case {
+                expr,
+                inl,
+                inr,
+                node: _,
+            } => Expr::Case {
+                expr,
+                inl,
+                inr,
+                node: Some(node),
+            },
+            Expr::CaseArm {
+                binder,
+                body,
+                node: _,
+            } => Expr::CaseArm {
+                binder,
+                body,
+                node: Some(node),
+            },
+            Expr::Variant {
+                label,
+                value,
+                node: _,
+            } => Expr::Variant {
+                label,
+                value,
+                node: Some(node),
+            },
+            Expr::VariantCase {
+                expr,
+                arms,
+                node: _,
+            } => Expr::VariantCase {
+                expr,
+                arms,
+                node: Some(node),
+            },
+            Expr::VariantArm {
+                label,
+                binder,
+                body,
+                node: _,
+            } => Expr::VariantArm {
+                label,
+                binder,
+                body,
+                node: Some(node),
+            },
+            Expr::Nil { node: _ } => Expr::Nil { node: Some(node) },
+            Expr::Cons {
+                head,
+                tail,
+                node: _,
+            } => Expr::Cons {
+                head,
+                tail,
+                node: Some(node),
+            },
+            Expr::IsNil { arg, node: _ } => Expr::IsNil {
+                arg,
+                node: Some(node),
+            },
+            Expr::Head { arg, node: _ } => Expr::Head {
+                arg,
+                node: Some(node),
+            },
+            Expr::Tail { arg, node: _ } => Expr::Tail {
+                arg,
+                node: Some(node),
+            },
+            Expr::Ref { arg, node: _ } => Expr::Ref {
+                arg,
+                node: Some(node),
+            },
+            Expr::Deref { arg, node: _ } => Expr::Deref {
+                arg,
+                node: Some(node),
+            },
+            Expr::Assign {
+                target,
+                value,
+                node: _,
+            } => Expr::Assign {
+                target,
+                value,
+                node: Some(node),
+            },
+            Expr::Error { node: _ } => Expr::Error { node: Some(node) },
+            Expr::Raise { arg, node: _ } => Expr::Raise {
+                arg,
+                node: Some(node),
+            },
+            Expr::Try {
+                body,
+                handler,
+                node: _,
+            } => Expr::Try {
+                body,
+                handler,
+                node: Some(node),
+            },
+            Expr::TypeAbs {
+                name,
+                body,
+                node: _,
+            } => Expr::TypeAbs {
+                name,
+                body,
+                node: Some(node),
+            },
+            Expr::TypeApp { expr, ty, node: _ } => Expr::TypeApp {
+                expr,
+                ty,
+                node: Some(node),
+            },
+            Expr::Pack {
+                ty,
+                value,
+                existential,
+                node: _,
+            } => Expr::Pack {
+                ty,
+                value,
+                existential,
+                node: Some(node),
+            },
+            Expr::Unpack {
+                ty_name,
+                name,
+                value,
+                body,
+                node: _,
+            } => Expr::Unpack {
+                ty_name,
+                name,
+                value,
+                body,
+                node: Some(node),
+            },
+            Expr::List { elements, node: _ } => Expr::List {
+                elements,
+                node: Some(node),
+            },
             Expr::VarDef { name, node: _ } => Expr::VarDef {
                 name,
                 node: Some(node),
             },
+            Expr::PatternTuple { elements, node: _ } => Expr::PatternTuple {
+                elements,
+                node: Some(node),
+            },
+            Expr::PatternRecord { fields, node: _ } => Expr::PatternRecord {
+                fields,
+                node: Some(node),
+            },
+            Expr::Wildcard { node: _ } => Expr::Wildcard { node: Some(node) },
             Expr::Var { name: id, node: _ } => Expr::Var {
                 name: id,
                 node: Some(node),
             },
             Expr::Def {
                 arg: id,
+                ty,
                 body: ret,
                 node: _,
             } => Expr::Def {
                 arg: id,
+                ty,
                 body: ret,
                 node: Some(node),
             },
@@ -39,6 +302,41 @@ pub trait BuilderFn<'a> {
                 body: then,
                 node: Some(node),
             },
+            Expr::Letrec {
+                name,
+                ty,
+                value,
+                body,
+                node: _,
+            } => Expr::Letrec {
+                name,
+                ty,
+                value,
+                body,
+                node: Some(node),
+            },
+            Expr::TypeAlias {
+                name,
+                ty,
+                body,
+                node: _,
+            } => Expr::TypeAlias {
+                name,
+                ty,
+                body,
+                node: Some(node),
+            },
+            Expr::Import {
+                path,
+                name,
+                body,
+                node: _,
+            } => Expr::Import {
+                path,
+                name,
+                body,
+                node: Some(node),
+            },
             Expr::IfElse {
                 cond,
                 then,
@@ -55,6 +353,77 @@ pub trait BuilderFn<'a> {
                 arg,
                 node: Some(node),
             },
+            Expr::Fix { arg, node: _ } => Expr::Fix {
+                arg,
+                node: Some(node),
+            },
+            Expr::Sequence {
+                first,
+                then,
+                node: _,
+            } => Expr::Sequence {
+                first,
+                then,
+                node: Some(node),
+            },
+            Expr::Ascribe { expr, ty, node: _ } => Expr::Ascribe {
+                expr,
+                ty,
+                node: Some(node),
+            },
+            Expr::TypeName { name, node: _ } => Expr::TypeName {
+                name,
+                node: Some(node),
+            },
+            Expr::TypeFn { from, to, node: _ } => Expr::TypeFn {
+                from,
+                to,
+                node: Some(node),
+            },
+            Expr::TypeProduct { elements, node: _ } => Expr::TypeProduct {
+                elements,
+                node: Some(node),
+            },
+            Expr::TypeRecord { fields, node: _ } => Expr::TypeRecord {
+                fields,
+                node: Some(node),
+            },
+            Expr::TypeVariant { fields, node: _ } => Expr::TypeVariant {
+                fields,
+                node: Some(node),
+            },
+            Expr::TypeSum {
+                left,
+                right,
+                node: _,
+            } => Expr::TypeSum {
+                left,
+                right,
+                node: Some(node),
+            },
+            Expr::TypeRef { ty, node: _ } => Expr::TypeRef {
+                ty,
+                node: Some(node),
+            },
+            Expr::TypeRec { name, ty, node: _ } => Expr::TypeRec {
+                name,
+                ty,
+                node: Some(node),
+            },
+            Expr::TypeForAll { name, ty, node: _ } => Expr::TypeForAll {
+                name,
+                ty,
+                node: Some(node),
+            },
+            Expr::TypeSome { name, ty, node: _ } => Expr::TypeSome {
+                name,
+                ty,
+                node: Some(node),
+            },
+            Expr::TypeList { element, node: _ } => Expr::TypeList {
+                element,
+                node: Some(node),
+            },
         })
     }
 
@@ -99,6 +468,10 @@ pub fn var<'t>(name: impl ToString) -> impl BuilderFn<'t> {
     }
 }
 
+pub fn unit<'t>() -> impl BuilderFn<'t> {
+    atom(Expr::Unit { node: None })
+}
+
 pub fn boolean<'t>(b: bool) -> impl BuilderFn<'t> {
     atom(Expr::Bool {
         value: b,
@@ -106,100 +479,800 @@ pub fn boolean<'t>(b: bool) -> impl BuilderFn<'t> {
     })
 }
 
-fn var_def<'t>(arg: impl ToString) -> impl BuilderFn<'t> {
-    let arg = arg.to_string();
+pub fn nat<'t>(n: u64) -> impl BuilderFn<'t> {
+    atom(Expr::Nat {
+        value: n,
+        node: None,
+    })
+}
+
+pub fn string<'t>(s: impl ToString) -> impl BuilderFn<'t> {
+    atom(Expr::String {
+        value: s.to_string(),
+        node: None,
+    })
+}
+
+pub fn succ<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
     move |e: &mut Exprs<'t>| {
-        Some(Expr::VarDef {
-            name: e.push_str(arg),
+        Some(Expr::Succ {
+            arg: arg.dependency(e),
             node: None,
         })
     }
 }
 
-pub fn def<'t>(arg: impl VarDefLike<'t>, ret: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+pub fn pred<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
     move |e: &mut Exprs<'t>| {
-        Some(Expr::Def {
-            arg: arg.var_def_dep(e),
-            body: ret.dependency(e),
+        Some(Expr::Pred {
+            arg: arg.dependency(e),
             node: None,
         })
     }
 }
 
-pub struct VarDef<'t> {
-    pub arg: String,
-    pub node: Option<SyntaxNode<'t>>,
-}
-impl<'t> BuilderFn<'t> for VarDef<'t> {
-    fn build(self, exprs: &mut Exprs<'t>) -> Option<Expr<'t>> {
-        match self.node {
-            None => var_def(self.arg).build(exprs),
-            Some(node) => var_def(self.arg).build_with_node(exprs, node),
-        }
+pub fn iszero<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::IsZero {
+            arg: arg.dependency(e),
+            node: None,
+        })
     }
 }
 
-pub trait VarDefLike<'t>: BuilderFn<'t> + Sized {
-    fn build_var_def(self, exprs: &mut Exprs<'t>) -> Option<Expr<'t>> {
-        Some(match self.build(exprs)? {
-            Expr::Var { name, node } => Expr::VarDef { name, node },
-            Expr::VarDef { name, node } => Expr::VarDef { name, node },
-            e => unreachable!("{:?} is not Var", e),
+pub fn concat<'t>(left: impl BuilderFn<'t>, right: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Concat {
+            left: left.dependency(e),
+            right: right.dependency(e),
+            node: None,
         })
     }
-    fn var_def_dep(self, expr: &mut Exprs<'t>) -> Option<ExprId> {
-        let e = self.build_var_def(expr)?;
-        Some(expr.push(e))
-    }
 }
 
-impl<'t> VarDefLike<'t> for &'t str {}
-impl<'t> VarDefLike<'t> for VarDef<'t> {}
+pub fn length<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Length {
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
 
-pub fn _let<'t>(
-    name: impl VarDefLike<'t>,
-    value: impl BuilderFn<'t>,
-    then: impl BuilderFn<'t>,
-) -> impl BuilderFn<'t> {
+pub fn string_eq<'t>(left: impl BuilderFn<'t>, right: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
     move |e: &mut Exprs<'t>| {
-        Some(Expr::Let {
-            name: name.var_def_dep(e),
-            value: value.dependency(e),
-            body: then.dependency(e),
+        Some(Expr::StringEq {
+            left: left.dependency(e),
+            right: right.dependency(e),
             node: None,
         })
     }
 }
 
-pub fn call<'t>(fun: impl BuilderFn<'t>, arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+pub fn not<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
     move |e: &mut Exprs<'t>| {
-        Some(Expr::Call {
-            func: fun.dependency(e),
+        Some(Expr::Not {
             arg: arg.dependency(e),
             node: None,
         })
     }
 }
 
-pub fn if_else<'t>(
-    cond: impl BuilderFn<'t>,
-    then: impl BuilderFn<'t>,
-    else_: impl BuilderFn<'t>,
-) -> impl BuilderFn<'t> {
+pub fn and<'t>(left: impl BuilderFn<'t>, right: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
     move |e: &mut Exprs<'t>| {
-        Some(Expr::IfElse {
-            cond: cond.dependency(e),
-            then: then.dependency(e),
-            else_: else_.dependency(e),
+        Some(Expr::And {
+            left: left.dependency(e),
+            right: right.dependency(e),
             node: None,
         })
     }
 }
 
-// Syntax Sugar
-impl<'t> BuilderFn<'t> for bool {
-    fn build(self, _: &mut Exprs<'t>) -> Option<Expr<'t>> {
-        Some(Expr::Bool {
+pub fn or<'t>(left: impl BuilderFn<'t>, right: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Or {
+            left: left.dependency(e),
+            right: right.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn equal<'t>(left: impl BuilderFn<'t>, right: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Equal {
+            left: left.dependency(e),
+            right: right.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn tuple<'t>(elements: impl Elements<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Tuple {
+            elements: elements.dependencies(e),
+            node: None,
+        })
+    }
+}
+
+pub fn projection<'t>(expr: impl BuilderFn<'t>, index: usize) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Projection {
+            expr: expr.dependency(e),
+            index,
+            node: None,
+        })
+    }
+}
+
+pub fn record<'t>(fields: impl Fields<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Record {
+            fields: fields.dependencies(e),
+            node: None,
+        })
+    }
+}
+
+pub fn field<'t>(expr: impl BuilderFn<'t>, label: impl ToString) -> impl BuilderFn<'t> {
+    let label = label.to_string();
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Field {
+            expr: expr.dependency(e),
+            label: e.push_str(label),
+            node: None,
+        })
+    }
+}
+
+pub fn inl<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Inl {
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn inr<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Inr {
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn case<'t>(
+    expr: impl BuilderFn<'t>,
+    inl: impl BuilderFn<'t>,
+    inr: impl BuilderFn<'t>,
+) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Case {
+            expr: expr.dependency(e),
+            inl: inl.dependency(e),
+            inr: inr.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn case_arm<'t>(binder: impl VarDefLike<'t>, body: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::CaseArm {
+            binder: binder.var_def_dep(e),
+            body: body.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn variant<'t>(label: impl ToString, value: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    let label = label.to_string();
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Variant {
+            label: e.push_str(label),
+            value: value.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn variant_case<'t>(expr: impl BuilderFn<'t>, arms: impl Elements<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::VariantCase {
+            expr: expr.dependency(e),
+            arms: arms.dependencies(e),
+            node: None,
+        })
+    }
+}
+
+pub fn variant_arm<'t>(
+    label: impl ToString,
+    binder: impl VarDefLike<'t>,
+    body: impl BuilderFn<'t>,
+) -> impl BuilderFn<'t> {
+    let label = label.to_string();
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::VariantArm {
+            label: e.push_str(label),
+            binder: binder.var_def_dep(e),
+            body: body.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn nil<'t>() -> impl BuilderFn<'t> {
+    atom(Expr::Nil { node: None })
+}
+
+pub fn cons<'t>(head: impl BuilderFn<'t>, tail: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Cons {
+            head: head.dependency(e),
+            tail: tail.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn isnil<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::IsNil {
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn head<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Head {
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn tail<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Tail {
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn list<'t>(elements: impl Elements<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::List {
+            elements: elements.dependencies(e),
+            node: None,
+        })
+    }
+}
+
+pub fn _ref<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Ref {
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn deref<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Deref {
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn assign<'t>(target: impl BuilderFn<'t>, value: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Assign {
+            target: target.dependency(e),
+            value: value.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn error<'t>() -> impl BuilderFn<'t> {
+    atom(Expr::Error { node: None })
+}
+
+pub fn raise<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Raise {
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn _try<'t>(body: impl BuilderFn<'t>, handler: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Try {
+            body: body.dependency(e),
+            handler: handler.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_abs<'t>(name: impl VarDefLike<'t>, body: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeAbs {
+            name: name.var_def_dep(e),
+            body: body.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_app<'t>(expr: impl BuilderFn<'t>, ty: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeApp {
+            expr: expr.dependency(e),
+            ty: ty.dependency(e),
+            node: None,
+        })
+    }
+}
+
+fn var_def<'t>(arg: impl ToString) -> impl BuilderFn<'t> {
+    let arg = arg.to_string();
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::VarDef {
+            name: e.push_str(arg),
+            node: None,
+        })
+    }
+}
+
+pub fn def<'t>(arg: impl VarDefLike<'t>, ret: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    typed_def(arg, |_: &mut Exprs<'t>| None, ret)
+}
+
+/// When `ty` builds nothing, parameter is not annotated
+pub fn typed_def<'t>(
+    arg: impl VarDefLike<'t>,
+    ty: impl BuilderFn<'t>,
+    ret: impl BuilderFn<'t>,
+) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Def {
+            arg: arg.var_def_dep(e),
+            ty: ty.dependency(e),
+            body: ret.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub struct VarDef<'t> {
+    pub arg: String,
+    pub node: Option<SyntaxNode<'t>>,
+}
+impl<'t> BuilderFn<'t> for VarDef<'t> {
+    fn build(self, exprs: &mut Exprs<'t>) -> Option<Expr<'t>> {
+        match self.node {
+            None => var_def(self.arg).build(exprs),
+            Some(node) => var_def(self.arg).build_with_node(exprs, node),
+        }
+    }
+}
+
+pub trait VarDefLike<'t>: BuilderFn<'t> + Sized {
+    fn build_var_def(self, exprs: &mut Exprs<'t>) -> Option<Expr<'t>> {
+        Some(match self.build(exprs)? {
+            Expr::Var { name, node } | Expr::VarDef { name, node }
+                if exprs.get_str(name) == "_" =>
+            {
+                Expr::Wildcard { node }
+            }
+            Expr::Var { name, node } => Expr::VarDef { name, node },
+            Expr::VarDef { name, node } => Expr::VarDef { name, node },
+            e
+            @ (Expr::PatternTuple { .. } | Expr::PatternRecord { .. } | Expr::Wildcard { .. }) => e,
+            e => unreachable!("{:?} is not Var", e),
+        })
+    }
+    fn var_def_dep(self, expr: &mut Exprs<'t>) -> Option<ExprId> {
+        let e = self.build_var_def(expr)?;
+        Some(expr.push(e))
+    }
+}
+
+impl<'t> VarDefLike<'t> for &'t str {}
+impl<'t> VarDefLike<'t> for VarDef<'t> {}
+
+/// Binds several names at once in a `let`, like `{a, b}` or `{x = a, y = b}`
+pub struct Pattern<F>(pub F);
+impl<'t, F: BuilderFn<'t>> BuilderFn<'t> for Pattern<F> {
+    fn build(self, exprs: &mut Exprs<'t>) -> Option<Expr<'t>> {
+        self.0.build(exprs)
+    }
+}
+impl<'t, F: BuilderFn<'t>> VarDefLike<'t> for Pattern<F> {}
+
+pub fn pattern_tuple<'t>(elements: Vec<impl VarDefLike<'t>>) -> Pattern<impl BuilderFn<'t>> {
+    Pattern(move |e: &mut Exprs<'t>| {
+        Some(Expr::PatternTuple {
+            elements: elements.into_iter().map(|el| el.var_def_dep(e)).collect(),
+            node: None,
+        })
+    })
+}
+
+pub fn pattern_record<'t>(
+    fields: Vec<(impl ToString, impl VarDefLike<'t>)>,
+) -> Pattern<impl BuilderFn<'t>> {
+    Pattern(move |e: &mut Exprs<'t>| {
+        Some(Expr::PatternRecord {
+            fields: fields
+                .into_iter()
+                .map(|(label, pattern)| (e.push_str(label), pattern.var_def_dep(e)))
+                .collect(),
+            node: None,
+        })
+    })
+}
+
+pub fn _let<'t>(
+    name: impl VarDefLike<'t>,
+    value: impl BuilderFn<'t>,
+    then: impl BuilderFn<'t>,
+) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Let {
+            name: name.var_def_dep(e),
+            value: value.dependency(e),
+            body: then.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_alias<'t>(
+    name: impl VarDefLike<'t>,
+    ty: impl BuilderFn<'t>,
+    then: impl BuilderFn<'t>,
+) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeAlias {
+            name: name.var_def_dep(e),
+            ty: ty.dependency(e),
+            body: then.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn pack<'t>(
+    ty: impl BuilderFn<'t>,
+    value: impl BuilderFn<'t>,
+    existential: impl BuilderFn<'t>,
+) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Pack {
+            ty: ty.dependency(e),
+            value: value.dependency(e),
+            existential: existential.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn unpack<'t>(
+    ty_name: impl VarDefLike<'t>,
+    name: impl VarDefLike<'t>,
+    value: impl BuilderFn<'t>,
+    then: impl BuilderFn<'t>,
+) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Unpack {
+            ty_name: ty_name.var_def_dep(e),
+            name: name.var_def_dep(e),
+            value: value.dependency(e),
+            body: then.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn letrec<'t>(
+    name: impl VarDefLike<'t>,
+    ty: impl BuilderFn<'t>,
+    value: impl BuilderFn<'t>,
+    then: impl BuilderFn<'t>,
+) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Letrec {
+            name: name.var_def_dep(e),
+            ty: ty.dependency(e),
+            value: value.dependency(e),
+            body: then.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn import<'t>(
+    path: impl ToString,
+    name: impl VarDefLike<'t>,
+    then: impl BuilderFn<'t>,
+) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Import {
+            path: path.to_string(),
+            name: name.var_def_dep(e),
+            body: then.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn fix<'t>(arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Fix {
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn call<'t>(fun: impl BuilderFn<'t>, arg: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Call {
+            func: fun.dependency(e),
+            arg: arg.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn if_else<'t>(
+    cond: impl BuilderFn<'t>,
+    then: impl BuilderFn<'t>,
+    else_: impl BuilderFn<'t>,
+) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::IfElse {
+            cond: cond.dependency(e),
+            then: then.dependency(e),
+            else_: else_.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn sequence<'t>(first: impl BuilderFn<'t>, then: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Sequence {
+            first: first.dependency(e),
+            then: then.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn ascribe<'t>(expr: impl BuilderFn<'t>, ty: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::Ascribe {
+            expr: expr.dependency(e),
+            ty: ty.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_name<'t>(name: impl ToString) -> impl BuilderFn<'t> {
+    let name = name.to_string();
+    move |e: &mut Exprs| {
+        Some(Expr::TypeName {
+            name: e.push_str(name),
+            node: None,
+        })
+    }
+}
+
+pub fn type_fn<'t>(from: impl BuilderFn<'t>, to: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeFn {
+            from: from.dependency(e),
+            to: to.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_product<'t>(elements: impl Elements<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeProduct {
+            elements: elements.dependencies(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_record<'t>(fields: impl Fields<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeRecord {
+            fields: fields.dependencies(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_sum<'t>(left: impl BuilderFn<'t>, right: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeSum {
+            left: left.dependency(e),
+            right: right.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_variant<'t>(fields: impl Fields<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeVariant {
+            fields: fields.dependencies(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_list<'t>(element: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeList {
+            element: element.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_ref<'t>(ty: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeRef {
+            ty: ty.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_rec<'t>(name: impl ToString, ty: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    let name = name.to_string();
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeRec {
+            name: e.push_str(name),
+            ty: ty.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_forall<'t>(name: impl ToString, ty: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    let name = name.to_string();
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeForAll {
+            name: e.push_str(name),
+            ty: ty.dependency(e),
+            node: None,
+        })
+    }
+}
+
+pub fn type_some<'t>(name: impl ToString, ty: impl BuilderFn<'t>) -> impl BuilderFn<'t> {
+    let name = name.to_string();
+    move |e: &mut Exprs<'t>| {
+        Some(Expr::TypeSome {
+            name: e.push_str(name),
+            ty: ty.dependency(e),
+            node: None,
+        })
+    }
+}
+
+/// Labelled builders for the fields of a record, in order
+pub trait Fields<'t> {
+    fn dependencies(self, exprs: &mut Exprs<'t>) -> Vec<(InternId, Option<ExprId>)>;
+}
+
+impl<'t, S: ToString, T: BuilderFn<'t>> Fields<'t> for Vec<(S, T)> {
+    fn dependencies(self, exprs: &mut Exprs<'t>) -> Vec<(InternId, Option<ExprId>)> {
+        self.into_iter()
+            .map(|(label, value)| (exprs.push_str(label), value.dependency(exprs)))
+            .collect()
+    }
+}
+
+impl<'t, S: ToString, T: BuilderFn<'t>> Fields<'t> for ((S, T),) {
+    fn dependencies(self, exprs: &mut Exprs<'t>) -> Vec<(InternId, Option<ExprId>)> {
+        vec![self.0].dependencies(exprs)
+    }
+}
+
+impl<'t, S1, T1, S2, T2> Fields<'t> for ((S1, T1), (S2, T2))
+where
+    S1: ToString,
+    T1: BuilderFn<'t>,
+    S2: ToString,
+    T2: BuilderFn<'t>,
+{
+    fn dependencies(self, exprs: &mut Exprs<'t>) -> Vec<(InternId, Option<ExprId>)> {
+        let ((l1, v1), (l2, v2)) = self;
+        vec![
+            (exprs.push_str(l1), v1.dependency(exprs)),
+            (exprs.push_str(l2), v2.dependency(exprs)),
+        ]
+    }
+}
+
+/// Builders for the elements of a tuple, in order
+pub trait Elements<'t> {
+    fn dependencies(self, exprs: &mut Exprs<'t>) -> Vec<Option<ExprId>>;
+}
+
+impl<'t, T: BuilderFn<'t>> Elements<'t> for Vec<T> {
+    fn dependencies(self, exprs: &mut Exprs<'t>) -> Vec<Option<ExprId>> {
+        self.into_iter().map(|el| el.dependency(exprs)).collect()
+    }
+}
+
+impl<'t, T: BuilderFn<'t>> Elements<'t> for (T,) {
+    fn dependencies(self, exprs: &mut Exprs<'t>) -> Vec<Option<ExprId>> {
+        vec![self.0.dependency(exprs)]
+    }
+}
+
+impl<'t, T1, T2> Elements<'t> for (T1, T2)
+where
+    T1: BuilderFn<'t>,
+    T2: BuilderFn<'t>,
+{
+    fn dependencies(self, exprs: &mut Exprs<'t>) -> Vec<Option<ExprId>> {
+        vec![self.0.dependency(exprs), self.1.dependency(exprs)]
+    }
+}
+
+impl<'t, T1, T2, T3> Elements<'t> for (T1, T2, T3)
+where
+    T1: BuilderFn<'t>,
+    T2: BuilderFn<'t>,
+    T3: BuilderFn<'t>,
+{
+    fn dependencies(self, exprs: &mut Exprs<'t>) -> Vec<Option<ExprId>> {
+        vec![
+            self.0.dependency(exprs),
+            self.1.dependency(exprs),
+            self.2.dependency(exprs),
+        ]
+    }
+}
+
+// Syntax Sugar
+impl<'t> BuilderFn<'t> for bool {
+    fn build(self, _: &mut Exprs<'t>) -> Option<Expr<'t>> {
+        Some(Expr::Bool {
+            value: self,
+            node: None,
+        })
+    }
+}
+
+impl<'t> BuilderFn<'t> for u64 {
+    fn build(self, _: &mut Exprs<'t>) -> Option<Expr<'t>> {
+        Some(Expr::Nat {
             value: self,
             node: None,
         })
@@ -3,6 +3,8 @@ use std::sync::Arc;
 use crate::source::Spanned;
 
 use super::builder::*;
+use super::imports::{resolve_import, SourceFile};
+use super::Expr;
 use super::ExprId;
 use super::Exprs;
 use super::{SyntaxNode, SyntaxTree};
@@ -45,6 +47,27 @@ pub fn from_tree<'t>(
     code: &'t str,
     filename: &'t str,
 ) -> (Option<ExprId>, Exprs<'t>) {
+    from_root(tree, code, filename).root()
+}
+
+/// All the files share the same `Exprs`, the root of each one is in `Exprs::modules`.
+/// Returns the root of the first file
+pub fn from_files(files: &[SourceFile]) -> (Option<ExprId>, Exprs<'_>) {
+    let mut exprs = Exprs::default();
+    let roots: Vec<_> = files
+        .iter()
+        .map(|file| {
+            let root = from_root(&file.tree, &file.source, &file.filename).dependency(&mut exprs);
+            if let Some(root) = root {
+                exprs.modules.insert(file.filename.clone(), root);
+            }
+            root
+        })
+        .collect();
+    (roots.first().copied().flatten(), exprs)
+}
+
+fn from_root<'t>(tree: &'t SyntaxTree, code: &'t str, filename: &'t str) -> impl BuilderFn<'t> {
     let root = tree.root_node();
 
     let mut cursor = root.walk();
@@ -56,7 +79,7 @@ pub fn from_tree<'t>(
         node: root,
     });
 
-    from_maybe_node(root).root()
+    from_maybe_node(root)
 }
 
 // pub fn from_source(code: &str) -> (ExprId, Exprs) {
@@ -68,6 +91,46 @@ fn from_field<'t>(node: SyntaxNode<'t>, field: &str) -> impl BuilderFn<'t> + 't
     from_maybe_node(node.map(|t| t.child_by_field_name(field)).transpose())
 }
 
+fn from_elements(node: SyntaxNode<'_>) -> Vec<impl BuilderFn<'_> + '_> {
+    from_children(node, "element")
+}
+
+fn from_children<'t>(node: SyntaxNode<'t>, field: &str) -> Vec<impl BuilderFn<'t> + 't> {
+    let mut cursor = node.node.walk();
+    // Parentheses around an element are tagged with the field as well
+    let elements: Vec<_> = node
+        .node
+        .children_by_field_name(field, &mut cursor)
+        .filter(|el| !matches!(el.kind(), "(" | ")"))
+        .collect();
+    elements
+        .into_iter()
+        .map(|el| from_maybe_node(Some(node.clone().map(|_| el))))
+        .collect()
+}
+
+/// Fields of a record, or of a record type, where `value` is the field name of their content
+fn from_fields<'t>(node: SyntaxNode<'t>, value: &str) -> Vec<(String, impl BuilderFn<'t> + 't)> {
+    let mut cursor = node.node.walk();
+    let fields: Vec<_> = node
+        .node
+        .children_by_field_name("field", &mut cursor)
+        .collect();
+    fields
+        .into_iter()
+        .map(|f| {
+            let f = node.clone().map(|_| f);
+            let label = f
+                .clone()
+                .map(|f| f.child_by_field_name("label"))
+                .transpose()
+                .map(from_str)
+                .unwrap_or_default();
+            (label, from_field(f, value))
+        })
+        .collect()
+}
+
 fn from_maybe_node<'t>(node: Option<SyntaxNode<'t>>) -> impl BuilderFn<'t> + 't {
     move |e: &mut Exprs<'t>| match node {
         Some(node) => from_node(node).build(e),
@@ -78,23 +141,206 @@ fn from_maybe_node<'t>(node: Option<SyntaxNode<'t>>) -> impl BuilderFn<'t> + 't
 fn from_node<'t>(node: SyntaxNode<'t>) -> impl BuilderFn<'t> + 't {
     move |e: &mut Exprs<'t>| match node.node.kind() {
         "(" => from_maybe_node(node.map(|node| node.next_sibling()).transpose()).build(e),
+        "unit" => unit().build_with_node(e, node),
         "bool" => match node.node.child(0).map(|n| n.kind()) {
             Some("true") => true.build_with_node(e, node),
             Some("false") => false.build_with_node(e, node),
             kind => todo!("{kind:?}"),
         },
+        "nat" => match from_str(node.clone()).parse::<u64>() {
+            Ok(n) => n.build_with_node(e, node),
+            Err(_) => {
+                let error = format!("Number literal is too large, the largest is {}", u64::MAX);
+                e.errors.push((node, error));
+                None
+            }
+        },
+        "string" => {
+            let text = unescape(e, node.clone());
+            string(text).build_with_node(e, node)
+        }
+        "succ" => succ(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "pred" => pred(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "iszero" => iszero(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "concat" => concat(
+            from_field(node.clone(), "left"),
+            from_field(node.clone(), "right"),
+        )
+        .build_with_node(e, node),
+        "length" => length(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "eq" => string_eq(
+            from_field(node.clone(), "left"),
+            from_field(node.clone(), "right"),
+        )
+        .build_with_node(e, node),
+        "not" => not(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "and" => and(
+            from_field(node.clone(), "left"),
+            from_field(node.clone(), "right"),
+        )
+        .build_with_node(e, node),
+        "or" => or(
+            from_field(node.clone(), "left"),
+            from_field(node.clone(), "right"),
+        )
+        .build_with_node(e, node),
+        "equal" => equal(
+            from_field(node.clone(), "left"),
+            from_field(node.clone(), "right"),
+        )
+        .build_with_node(e, node),
         "let" => _let(
+            from_pattern(
+                node.clone()
+                    .map(|n| n.child_by_field_name("key"))
+                    .transpose(),
+            ),
+            from_field(node.clone(), "value"),
+            from_field(node.clone(), "in"),
+        )
+        .build_with_node(e, node),
+        "type_alias" => type_alias(
+            from_var_def(node.clone(), "name"),
+            from_field(node.clone(), "type"),
+            from_field(node.clone(), "in"),
+        )
+        .build_with_node(e, node),
+        "unpack" => unpack(
+            from_var_def(node.clone(), "type"),
             from_var_def(node.clone(), "key"),
             from_field(node.clone(), "value"),
             from_field(node.clone(), "in"),
         )
         .build_with_node(e, node),
-        "def" => def(
-            from_var_def(node.clone(), "arg"),
+        "letrec" => letrec(
+            from_var_def(node.clone(), "key"),
+            from_field(node.clone(), "type"),
+            from_field(node.clone(), "value"),
+            from_field(node.clone(), "in"),
+        )
+        .build_with_node(e, node),
+        "import" => import(
+            resolve_import(
+                &node.filename,
+                &from_str(
+                    node.clone()
+                        .map(|n| n.child_by_field_name("path"))
+                        .transpose()?,
+                ),
+            ),
+            from_var_def(node.clone(), "name"),
+            from_field(node.clone(), "in"),
+        )
+        .build_with_node(e, node),
+        "fix" => fix(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "tuple" => tuple(from_elements(node.clone())).build_with_node(e, node),
+        "record" => record(from_fields(node.clone(), "value")).build_with_node(e, node),
+        "projection" if node.node.child_by_field_name("label").is_some() => field(
+            from_field(node.clone(), "expr"),
+            from_str(
+                node.clone()
+                    .map(|n| n.child_by_field_name("label"))
+                    .transpose()?,
+            ),
+        )
+        .build_with_node(e, node),
+        "projection" => {
+            let index = node
+                .clone()
+                .map(|n| n.child_by_field_name("index"))
+                .transpose()
+                .map(from_str)
+                .and_then(|index| index.parse::<usize>().ok())?;
+            projection(from_field(node.clone(), "expr"), index).build_with_node(e, node)
+        }
+        "inl" => inl(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "inr" => inr(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "variant" => variant(
+            from_str(
+                node.clone()
+                    .map(|n| n.child_by_field_name("label"))
+                    .transpose()?,
+            ),
+            from_field(node.clone(), "value"),
+        )
+        .build_with_node(e, node),
+        "case" if node.node.child_by_field_name("arm").is_some() => variant_case(
+            from_field(node.clone(), "expr"),
+            from_children(node.clone(), "arm"),
+        )
+        .build_with_node(e, node),
+        "variant_arm" => variant_arm(
+            from_str(
+                node.clone()
+                    .map(|n| n.child_by_field_name("label"))
+                    .transpose()?,
+            ),
+            from_var_def(node.clone(), "binder"),
+            from_field(node.clone(), "body"),
+        )
+        .build_with_node(e, node),
+        "case" => case(
+            from_field(node.clone(), "expr"),
+            from_field(node.clone(), "inl"),
+            from_field(node.clone(), "inr"),
+        )
+        .build_with_node(e, node),
+        "inl_arm" | "inr_arm" => case_arm(
+            from_var_def(node.clone(), "binder"),
+            from_field(node.clone(), "body"),
+        )
+        .build_with_node(e, node),
+        "nil" => nil().build_with_node(e, node),
+        "cons" => cons(
+            from_field(node.clone(), "head"),
+            from_field(node.clone(), "tail"),
+        )
+        .build_with_node(e, node),
+        "isnil" => isnil(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "head" => head(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "tail" => tail(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "list" => list(from_elements(node.clone())).build_with_node(e, node),
+        "ref" => _ref(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "deref" => deref(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "assign" => assign(
+            from_field(node.clone(), "target"),
+            from_field(node.clone(), "value"),
+        )
+        .build_with_node(e, node),
+        "error" => error().build_with_node(e, node),
+        "raise" => raise(from_field(node.clone(), "arg")).build_with_node(e, node),
+        "try" => _try(
+            from_field(node.clone(), "body"),
+            from_field(node.clone(), "handler"),
+        )
+        .build_with_node(e, node),
+        "type_abs" => type_abs(
+            from_var_def(node.clone(), "name"),
             from_field(node.clone(), "body"),
         )
         .build_with_node(e, node),
+        "type_app" => type_app(
+            from_field(node.clone(), "expr"),
+            from_field(node.clone(), "type"),
+        )
+        .build_with_node(e, node),
+        "pack" => pack(
+            from_field(node.clone(), "type"),
+            from_field(node.clone(), "value"),
+            from_field(node.clone(), "as"),
+        )
+        .build_with_node(e, node),
+        "def" => {
+            let mut cursor = node.node.walk();
+            let args: Vec<_> = node
+                .node
+                .children_by_field_name("arg", &mut cursor)
+                .collect();
+            from_def(e, node, &args)
+        }
         "ident" => var(from_str(node.clone())).build_with_node(e, node),
+        // Reported when scopes are resolved, `_` is never defined
+        "wildcard" => var("_").build_with_node(e, node),
         "call" => call(
             from_field(node.clone(), "func"),
             from_field(node.clone(), "arg"),
@@ -106,10 +352,91 @@ fn from_node<'t>(node: SyntaxNode<'t>) -> impl BuilderFn<'t> + 't {
             from_field(node.clone(), "else"),
         )
         .build_with_node(e, node),
+        "sequence" => sequence(
+            from_field(node.clone(), "first"),
+            from_field(node.clone(), "then"),
+        )
+        .build_with_node(e, node),
+        "ascribe" => ascribe(
+            from_field(node.clone(), "expr"),
+            from_field(node.clone(), "type"),
+        )
+        .build_with_node(e, node),
+        "type_name" => type_name(from_str(node.clone())).build_with_node(e, node),
+        "fn_type" => type_fn(
+            from_field(node.clone(), "from"),
+            from_field(node.clone(), "to"),
+        )
+        .build_with_node(e, node),
+        "product_type" => type_product(from_elements(node.clone())).build_with_node(e, node),
+        "sum_type" => type_sum(
+            from_field(node.clone(), "left"),
+            from_field(node.clone(), "right"),
+        )
+        .build_with_node(e, node),
+        "variant_type" => type_variant(from_fields(node.clone(), "type")).build_with_node(e, node),
+        "record_type" => type_record(from_fields(node.clone(), "type")).build_with_node(e, node),
+        "list_type" => type_list(from_field(node.clone(), "element")).build_with_node(e, node),
+        "ref_type" => type_ref(from_field(node.clone(), "type")).build_with_node(e, node),
+        "rec_type" => type_rec(
+            from_str(
+                node.clone()
+                    .map(|n| n.child_by_field_name("name"))
+                    .transpose()?,
+            ),
+            from_field(node.clone(), "type"),
+        )
+        .build_with_node(e, node),
+        "forall_type" => type_forall(
+            from_str(
+                node.clone()
+                    .map(|n| n.child_by_field_name("name"))
+                    .transpose()?,
+            ),
+            from_field(node.clone(), "type"),
+        )
+        .build_with_node(e, node),
+        "some_type" => type_some(
+            from_str(
+                node.clone()
+                    .map(|n| n.child_by_field_name("name"))
+                    .transpose()?,
+            ),
+            from_field(node.clone(), "type"),
+        )
+        .build_with_node(e, node),
         kind => todo!("{kind}"),
     }
 }
 
+/// `a b c: body` is `a: b: c: body`. The `def` node stands for the nested `Def`s too,
+/// with a range starting at their parameter, so errors point to what the user wrote
+fn from_def<'t>(
+    e: &mut Exprs<'t>,
+    node: SyntaxNode<'t>,
+    args: &[tree_sitter::Node<'t>],
+) -> Option<Expr<'t>> {
+    let arg = args.first().map(|arg| node.clone().map(|_| *arg));
+    let arg = VarDef {
+        arg: arg.clone().map(from_str).unwrap_or_default(),
+        node: arg,
+    };
+    match args {
+        [_, rest @ ..] if !rest.is_empty() => {
+            let mut inner = node.clone();
+            inner.range.start_byte = rest[0].start_byte();
+            inner.range.start_point = rest[0].start_position();
+            def(arg, move |e: &mut Exprs<'t>| from_def(e, inner, rest)).build_with_node(e, node)
+        }
+        _ => typed_def(
+            arg,
+            from_field(node.clone(), "type"),
+            from_field(node.clone(), "body"),
+        )
+        .build_with_node(e, node),
+    }
+}
+
 fn from_var_def<'t>(node: SyntaxNode<'t>, field: &str) -> impl VarDefLike<'t> {
     let node = node.map(|node| node.child_by_field_name(field)).transpose();
     VarDef {
@@ -118,6 +445,47 @@ fn from_var_def<'t>(node: SyntaxNode<'t>, field: &str) -> impl VarDefLike<'t> {
     }
 }
 
+/// Names bound by `let`, `{a, {x = b}}` binds `a` and `b`
+fn from_pattern<'t>(node: Option<SyntaxNode<'t>>) -> Pattern<PatternFn<'t>> {
+    Pattern(Box::new(move |e: &mut Exprs<'t>| {
+        let kind = node.as_ref().map(|node| node.node.kind());
+        match (kind, node) {
+            (Some("tuple_pattern"), Some(node)) => {
+                let mut cursor = node.node.walk();
+                let elements: Vec<_> = node
+                    .node
+                    .children_by_field_name("element", &mut cursor)
+                    .map(|el| from_pattern(Some(node.clone().map(|_| el))))
+                    .collect();
+                pattern_tuple(elements).build_with_node(e, node)
+            }
+            (Some("record_pattern"), Some(node)) => {
+                let mut cursor = node.node.walk();
+                let fields: Vec<_> = node
+                    .node
+                    .children_by_field_name("field", &mut cursor)
+                    .map(|f| {
+                        let f = node.clone().map(|_| f);
+                        let child =
+                            |name| f.clone().map(|f| f.child_by_field_name(name)).transpose();
+                        let label = child("label").map(from_str).unwrap_or_default();
+                        (label, from_pattern(child("pattern")))
+                    })
+                    .collect();
+                pattern_record(fields).build_with_node(e, node)
+            }
+            (_, node) => VarDef {
+                arg: node.clone().map(from_str).unwrap_or_default(),
+                node,
+            }
+            .build_var_def(e),
+        }
+    }))
+}
+
+/// Patterns nest, so their builder is boxed
+type PatternFn<'t> = Box<dyn FnOnce(&mut Exprs<'t>) -> Option<Expr<'t>> + 't>;
+
 #[allow(clippy::expect_used)]
 /// PANICS: Our lambda language always expects utf8
 fn from_str(node: SyntaxNode) -> String {
@@ -127,6 +495,30 @@ fn from_str(node: SyntaxNode) -> String {
         .into()
 }
 
+/// Content of a string literal, the grammar allows only the escapes handled here
+/// and parses any other escape as an error
+fn unescape<'t>(e: &mut Exprs<'t>, node: SyntaxNode<'t>) -> String {
+    let mut cursor = node.node.walk();
+    let parts: Vec<_> = node.node.named_children(&mut cursor).collect();
+    parts
+        .into_iter()
+        .map(|part| {
+            let text = from_str(node.clone().map(|_| part));
+            match (part.kind(), text.as_str()) {
+                ("escape_sequence", "\\n") => "\n".into(),
+                ("escape_sequence", "\\t") => "\t".into(),
+                ("escape_sequence", escaped) => escaped[1..].into(),
+                ("ERROR", escaped) => {
+                    let error = format!("Invalid escape sequence `{escaped}`");
+                    e.errors.push((node.clone().map(|_| part), error));
+                    text
+                }
+                _ => text,
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ast::builder::*;
@@ -173,6 +565,82 @@ mod tests {
     #[test_case("a: a", def("a", "a"))]
     #[test_case("a b", "a".call("b"))]
     #[test_case("a b c", "a".call_n(("b", "c")))]
+    #[test_case("0", nat(0))]
+    #[test_case("42", nat(42))]
+    #[test_case("succ 0", succ(nat(0)))]
+    #[test_case(r#""""#, string(""))]
+    #[test_case(r#""a\tb\n\"c\\""#, string("a\tb\n\"c\\") ; "Escapes")]
+    #[test_case(r#"concat "a" "b""#, concat(string("a"), string("b")))]
+    #[test_case(r#"length concat x "b""#, length(concat("x", string("b"))))]
+    #[test_case(r#"eq x "a""#, string_eq("x", string("a")))]
+    #[test_case("iszero pred 1", iszero(pred(nat(1))))]
+    #[test_case("f succ 0", "f".call(succ(nat(0))))]
+    #[test_case("unit", unit())]
+    #[test_case("a; b; c", sequence("a", sequence("b", "c")))]
+    #[test_case("let x = a; b; c", _let("x", "a", sequence("b", "c")))]
+    #[test_case("let {a, b} = p; a", _let(pattern_tuple(vec!["a", "b"]), "p", "a"))]
+    #[test_case(
+        "let {x = a, y = b} = p; a",
+        _let(pattern_record(vec![("x", "a"), ("y", "b")]), "p", "a")
+    )]
+    #[test_case("x: a; b", sequence(def("x", "a"), "b"))]
+    #[test_case("_ b: b", ("_", "b").ret("b") ; "Wildcard parameter")]
+    #[test_case("let {_, b} = p; b", _let(pattern_tuple(vec!["_", "b"]), "p", "b"))]
+    #[test_case("x: Bool => x", typed_def("x", type_name("Bool"), "x"))]
+    #[test_case("x: (Bool) => x", typed_def("x", type_name("Bool"), "x") ; "Parenthesized type")]
+    #[test_case("x: (a)", def("x", "a"))]
+    #[test_case("a b c: a", ("a", "b").ret(def("c", "a")))]
+    #[test_case("f a b", "f".call_n(("a", "b")) ; "Call without colon")]
+    #[test_case("f (a b: a) x", "f".call_n((("a", "b").ret("a"), "x")) ; "Multiple parameters as argument")]
+    #[test_case("x as Bool", ascribe("x", type_name("Bool")))]
+    #[test_case("f x as Bool", ascribe("f".call("x"), type_name("Bool")))]
+    #[test_case(
+        "f as (Bool -> Nat) -> Unit",
+        ascribe(
+            "f",
+            type_fn(type_fn(type_name("Bool"), type_name("Nat")), type_name("Unit"))
+        )
+    )]
+    #[test_case("{a, b}", tuple(("a", "b")))]
+    #[test_case("(a, b, c)", tuple(("a", "b", "c")))]
+    #[test_case("{(a), b}", tuple(("a", "b")) ; "Parenthesized element")]
+    #[test_case("{((a)), (b; c)}", tuple(("a", sequence("b", "c"))) ; "Nested parentheses")]
+    #[test_case("{a}", tuple(("a",)))]
+    #[test_case("{x = a, y = (b)}", record((("x", "a"), ("y", "b"))))]
+    #[test_case("r.x.1", projection(field("r", "x"), 1))]
+    #[test_case(
+        "r: {x: Bool} => r.x",
+        typed_def("r", type_record((("x", type_name("Bool")),)), field("r", "x"))
+    )]
+    #[test_case("x.1.2", projection(projection("x", 1), 2))]
+    #[test_case("f x.1", "f".call(projection("x", 1)))]
+    #[test_case(
+        "x: {Bool, Nat} => x",
+        typed_def("x", type_product((type_name("Bool"), type_name("Nat"))), "x")
+    )]
+    #[test_case(
+        "case inl 0 of inl a => a | inr b => f b",
+        case(inl(nat(0)), case_arm("a", "a"), case_arm("b", "f".call("b")))
+    )]
+    #[test_case(
+        "x as Nat + Bool -> Unit",
+        ascribe(
+            "x",
+            type_fn(type_sum(type_name("Nat"), type_name("Bool")), type_name("Unit"))
+        )
+    )]
+    #[test_case("<some = 0>", variant("some", nat(0)))]
+    #[test_case(
+        "case x of <some = a> => a | <none = b> => b",
+        variant_case(
+            "x",
+            (variant_arm("some", "a", "a"), variant_arm("none", "b", "b"))
+        )
+    )]
+    #[test_case(
+        "x as <some: Nat>",
+        ascribe("x", type_variant((("some", type_name("Nat")),)))
+    )]
     fn test_cst<'t>(source: &'t str, expected: impl BuilderFn<'t>) {
         let tree = get_tree(source);
         let (r, exprs) = from_tree(&tree, source, "test");
@@ -0,0 +1,114 @@
+use std::path::{Component, Path, PathBuf};
+
+use super::from_cst::get_tree;
+use super::SyntaxTree;
+
+/// A parsed file of the program
+pub struct SourceFile {
+    pub filename: String,
+    pub source: String,
+    pub tree: SyntaxTree,
+}
+
+impl SourceFile {
+    pub fn new(filename: impl ToString, source: impl ToString) -> Self {
+        let source = source.to_string();
+        SourceFile {
+            filename: filename.to_string(),
+            tree: get_tree(&source),
+            source,
+        }
+    }
+}
+
+/// `root` followed by every file it imports, directly or not.
+/// `read` returns the source of a file, files it can't read are left out
+/// and reported when resolving the scopes.
+pub fn load_files(
+    root: SourceFile,
+    mut read: impl FnMut(&str) -> Option<String>,
+) -> Vec<SourceFile> {
+    let mut files = vec![root];
+    let mut i = 0;
+    while i < files.len() {
+        for path in imports(&files[i]) {
+            if files.iter().any(|f| f.filename == path) {
+                continue;
+            }
+            if let Some(source) = read(&path) {
+                files.push(SourceFile::new(path, source));
+            }
+        }
+        i += 1;
+    }
+    files
+}
+
+/// Resolved paths of the files imported by `file`
+fn imports(file: &SourceFile) -> Vec<String> {
+    let mut paths = vec![];
+    let mut cursor = file.tree.walk();
+    'walk: loop {
+        let node = cursor.node();
+        if node.kind() == "import" {
+            if let Some(path) = node
+                .child_by_field_name("path")
+                .and_then(|path| path.utf8_text(file.source.as_bytes()).ok())
+            {
+                paths.push(resolve_import(&file.filename, path));
+            }
+        }
+        if cursor.goto_first_child() || cursor.goto_next_sibling() {
+            continue;
+        }
+        while cursor.goto_parent() {
+            if cursor.goto_next_sibling() {
+                continue 'walk;
+            }
+        }
+        return paths;
+    }
+}
+
+/// Path of `path` as written in an `import` of `importer`, relative to the directory of `importer`
+pub fn resolve_import(importer: &str, path: &str) -> String {
+    let path = path.trim_matches('"');
+    let joined = match Path::new(importer).parent() {
+        Some(dir) => dir.join(path),
+        None => PathBuf::from(path),
+    };
+
+    // `a/../b.lbd` and `b.lbd` are the same module
+    let mut resolved = PathBuf::new();
+    for component in joined.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    resolved.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                resolved.pop();
+            }
+            component => resolved.push(component),
+        }
+    }
+    resolved.display().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("test", "\"other.lbd\"", "other.lbd")]
+    #[test_case("src/main.lbd", "\"lib/nat.lbd\"", "src/lib/nat.lbd")]
+    #[test_case("src/main.lbd", "\"../other.lbd\"", "other.lbd")]
+    #[test_case("/src/lib/a.lbd", "\"./../b.lbd\"", "/src/b.lbd")]
+    #[test_case("main.lbd", "\"../other.lbd\"", "../other.lbd")]
+    fn resolve(importer: &str, path: &str, expected: &str) {
+        assert_eq!(resolve_import(importer, path), expected);
+    }
+}
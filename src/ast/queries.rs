@@ -3,25 +3,145 @@ use super::{Expr, ExprId, Exprs, SyntaxNode};
 impl<'a> Expr<'a> {
     pub fn node(&self) -> Option<SyntaxNode<'a>> {
         match self {
+            Expr::Unit { node } => node.clone(),
             Expr::Bool { node, .. } => node.clone(),
+            Expr::Nat { node, .. } => node.clone(),
+            Expr::Succ { node, .. } => node.clone(),
+            Expr::Pred { node, .. } => node.clone(),
+            Expr::IsZero { node, .. } => node.clone(),
+            Expr::String { node, .. } => node.clone(),
+            Expr::Concat { node, .. } => node.clone(),
+            Expr::Length { node, .. } => node.clone(),
+            Expr::StringEq { node, .. } => node.clone(),
+            Expr::Not { node, .. } => node.clone(),
+            Expr::And { node, .. } => node.clone(),
+            Expr::Or { node, .. } => node.clone(),
+            Expr::Equal { node, .. } => node.clone(),
+            Expr::Tuple { node, .. } => node.clone(),
+            Expr::Projection { node, .. } => node.clone(),
+            Expr::Record { node, .. } => node.clone(),
+            Expr::Field { node, .. } => node.clone(),
             Expr::Var { node, .. } => node.clone(),
             Expr::VarDef { node, .. } => node.clone(),
+            Expr::PatternTuple { node, .. } => node.clone(),
+            Expr::PatternRecord { node, .. } => node.clone(),
+            Expr::Wildcard { node } => node.clone(),
+            Expr::Inl { node, .. } => node.clone(),
+            Expr::Inr { node, .. } => node.clone(),
+            Expr::Case { node, .. } => node.clone(),
+            Expr::CaseArm { node, .. } => node.clone(),
+            Expr::Variant { node, .. } => node.clone(),
+            Expr::VariantCase { node, .. } => node.clone(),
+            Expr::VariantArm { node, .. } => node.clone(),
+            Expr::Nil { node } => node.clone(),
+            Expr::Cons { node, .. } => node.clone(),
+            Expr::IsNil { node, .. } => node.clone(),
+            Expr::Ref { node, .. } => node.clone(),
+            Expr::Deref { node, .. } => node.clone(),
+            Expr::Assign { node, .. } => node.clone(),
+            Expr::Error { node } => node.clone(),
+            Expr::Raise { node, .. } => node.clone(),
+            Expr::Try { node, .. } => node.clone(),
+            Expr::TypeAbs { node, .. } => node.clone(),
+            Expr::TypeApp { node, .. } => node.clone(),
+            Expr::Pack { node, .. } => node.clone(),
+            Expr::Unpack { node, .. } => node.clone(),
+            Expr::Head { node, .. } => node.clone(),
+            Expr::Tail { node, .. } => node.clone(),
+            Expr::List { node, .. } => node.clone(),
             Expr::Def { node, .. } => node.clone(),
             Expr::Call { node, .. } => node.clone(),
             Expr::IfElse { node, .. } => node.clone(),
             Expr::Let { node, .. } => node.clone(),
+            Expr::Letrec { node, .. } => node.clone(),
+            Expr::TypeAlias { node, .. } => node.clone(),
+            Expr::Import { node, .. } => node.clone(),
+            Expr::Fix { node, .. } => node.clone(),
+            Expr::Sequence { node, .. } => node.clone(),
+            Expr::Ascribe { node, .. } => node.clone(),
+            Expr::TypeName { node, .. } => node.clone(),
+            Expr::TypeFn { node, .. } => node.clone(),
+            Expr::TypeProduct { node, .. } => node.clone(),
+            Expr::TypeRecord { node, .. } => node.clone(),
+            Expr::TypeSum { node, .. } => node.clone(),
+            Expr::TypeVariant { node, .. } => node.clone(),
+            Expr::TypeList { node, .. } => node.clone(),
+            Expr::TypeRef { node, .. } => node.clone(),
+            Expr::TypeRec { node, .. } => node.clone(),
+            Expr::TypeForAll { node, .. } => node.clone(),
+            Expr::TypeSome { node, .. } => node.clone(),
         }
     }
 
     pub fn is_literal(&self) -> bool {
         match self {
+            Expr::Unit { .. } => true,
             Expr::Bool { .. } => true,
+            Expr::Nat { .. } => true,
+            Expr::Succ { .. } => false,
+            Expr::Pred { .. } => false,
+            Expr::IsZero { .. } => false,
+            Expr::String { .. } => true,
+            Expr::Concat { .. } => false,
+            Expr::Length { .. } => false,
+            Expr::StringEq { .. } => false,
+            Expr::Not { .. } => false,
+            Expr::And { .. } => false,
+            Expr::Or { .. } => false,
+            Expr::Equal { .. } => false,
+            Expr::Tuple { .. } => false,
+            Expr::Projection { .. } => false,
+            Expr::Record { .. } => false,
+            Expr::Field { .. } => false,
             Expr::Var { .. } => false,
             Expr::VarDef { .. } => false,
+            Expr::PatternTuple { .. } => false,
+            Expr::PatternRecord { .. } => false,
+            Expr::Wildcard { .. } => false,
+            Expr::Inl { .. } => false,
+            Expr::Inr { .. } => false,
+            Expr::Case { .. } => false,
+            Expr::CaseArm { .. } => false,
+            Expr::Variant { .. } => false,
+            Expr::VariantCase { .. } => false,
+            Expr::VariantArm { .. } => false,
+            Expr::Nil { .. } => true,
+            Expr::Cons { .. } => false,
+            Expr::IsNil { .. } => false,
+            Expr::Ref { .. } => false,
+            Expr::Deref { .. } => false,
+            Expr::Assign { .. } => false,
+            Expr::Error { .. } => false,
+            Expr::Raise { .. } => false,
+            Expr::Try { .. } => false,
+            Expr::TypeAbs { .. } => false,
+            Expr::TypeApp { .. } => false,
+            Expr::Pack { .. } => false,
+            Expr::Unpack { .. } => false,
+            Expr::Head { .. } => false,
+            Expr::Tail { .. } => false,
+            Expr::List { .. } => false,
             Expr::Def { .. } => false,
             Expr::Call { .. } => false,
             Expr::IfElse { .. } => false,
             Expr::Let { .. } => false,
+            Expr::Letrec { .. } => false,
+            Expr::TypeAlias { .. } => false,
+            Expr::Import { .. } => false,
+            Expr::Fix { .. } => false,
+            Expr::Sequence { .. } => false,
+            Expr::Ascribe { .. } => false,
+            Expr::TypeName { .. } => false,
+            Expr::TypeFn { .. } => false,
+            Expr::TypeProduct { .. } => false,
+            Expr::TypeRecord { .. } => false,
+            Expr::TypeSum { .. } => false,
+            Expr::TypeVariant { .. } => false,
+            Expr::TypeList { .. } => false,
+            Expr::TypeRef { .. } => false,
+            Expr::TypeRec { .. } => false,
+            Expr::TypeForAll { .. } => false,
+            Expr::TypeSome { .. } => false,
         }
     }
 }
@@ -31,6 +31,20 @@ fn default_filename() -> Arc<str> {
 
 impl Diagnostics {
     pub fn push(&mut self, node: &Option<crate::ast::SyntaxNode>, error: impl ToString) {
+        self.push_with_severity(node, error, Severity::Error);
+    }
+
+    /// Warnings do not prevent the program from being evaluated
+    pub fn push_warning(&mut self, node: &Option<crate::ast::SyntaxNode>, warning: impl ToString) {
+        self.push_with_severity(node, warning, Severity::Warning);
+    }
+
+    fn push_with_severity(
+        &mut self,
+        node: &Option<crate::ast::SyntaxNode>,
+        error: impl ToString,
+        severity: Severity,
+    ) {
         let range = node.as_ref().map(|n| n.range).unwrap_or_else(default_range);
         let source = node
             .as_ref()
@@ -52,12 +66,13 @@ impl Diagnostics {
                 filename,
                 node: error.to_string(),
             },
+            severity,
         };
         self.errors.push(diag);
     }
 
     pub fn has_errors(&self) -> bool {
-        !self.errors.is_empty()
+        self.errors.iter().any(|d| d.severity == Severity::Error)
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
@@ -65,29 +80,35 @@ impl Diagnostics {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
 #[derive(Debug)]
 pub struct Diagnostic {
-    pub message: Spanned<String>, // pub span: tree_sitter::Range,
-                                  // pub message: String,
+    pub message: Spanned<String>,
+    pub severity: Severity,
 }
 
 impl Diagnostic {
     pub fn to_report(&self) -> Report<'_, (Arc<str>, std::ops::Range<usize>)> {
         let range = self.message.range;
-        Report::build(
-            ariadne::ReportKind::Error,
-            self.message.filename.clone(),
-            range.start_byte,
-        )
-        .with_message(&self.message.node)
-        .with_label(
-            Label::new((
-                self.message.filename.clone(),
-                range.start_byte..range.end_byte,
-            ))
-            .with_color(Color::Red),
-        )
-        .finish()
+        let (kind, color) = match self.severity {
+            Severity::Error => (ariadne::ReportKind::Error, Color::Red),
+            Severity::Warning => (ariadne::ReportKind::Warning, Color::Yellow),
+        };
+        Report::build(kind, self.message.filename.clone(), range.start_byte)
+            .with_message(&self.message.node)
+            .with_label(
+                Label::new((
+                    self.message.filename.clone(),
+                    range.start_byte..range.end_byte,
+                ))
+                .with_color(color),
+            )
+            .finish()
     }
 }
 
@@ -111,7 +132,10 @@ impl Diagnostics {
 #[cfg(test)]
 mod tests {
     use crate::{
-        ast::from_cst::{from_tree, get_tree},
+        ast::{
+            from_cst::from_files,
+            imports::{load_files, SourceFile},
+        },
         ir::Exprs,
         types::TypeEnv,
     };
@@ -120,13 +144,18 @@ mod tests {
 
     #[test]
     fn types_tests() -> test_runner::Result {
-        test_runner::test_snapshots("tests/", "diagnostics", |input, _deps| {
-            let tree = get_tree(input);
-            let (r, exprs) = from_tree(&tree, input, "test");
-            let r = r.expect("Root node");
+        test_runner::test_snapshots("tests/", "diagnostics", |input, deps| {
+            let root = SourceFile::new("test", input);
+            let files = load_files(root, |path| deps.get(path).map(|dep| dep.to_string()));
+            let (r, exprs) = from_files(&files);
             let mut diagnostics = Diagnostics::default();
-            let ir = Exprs::from_ast(&exprs, r, &mut diagnostics);
-            _ = TypeEnv::infer(&ir, r, &mut diagnostics);
+            match r {
+                Some(r) => {
+                    let ir = Exprs::from_ast(&exprs, r, &mut diagnostics);
+                    _ = TypeEnv::infer(&ir, r, &mut diagnostics);
+                }
+                None => exprs.report_errors(&mut diagnostics),
+            }
             diagnostics.to_pretty_string().expect("Pretty print")
         })
     }
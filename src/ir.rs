@@ -1,4 +1,5 @@
-use std::collections::{BTreeMap, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::Arc;
 
 use tree_sitter::Point;
 
@@ -14,10 +15,84 @@ pub struct VarId(usize);
 
 #[derive(Debug, Clone)]
 pub enum Expr<'a> {
+    Unit {
+        node: Option<SyntaxNode<'a>>,
+    },
     Bool {
         value: bool,
         node: Option<SyntaxNode<'a>>,
     },
+    Nat {
+        value: u64,
+        node: Option<SyntaxNode<'a>>,
+    },
+    String {
+        value: String,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Succ {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Pred {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    IsZero {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Concat {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Length {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    StringEq {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Not {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    And {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Or {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Equal {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Tuple {
+        elements: Vec<Option<ExprId>>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Projection {
+        expr: Option<ExprId>,
+        index: usize,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Record {
+        fields: Vec<(InternId, Option<ExprId>)>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Field {
+        expr: Option<ExprId>,
+        label: InternId,
+        node: Option<SyntaxNode<'a>>,
+    },
     Var {
         name: InternId,
         id: Option<VarId>,
@@ -28,8 +103,123 @@ pub enum Expr<'a> {
         id: VarId,
         node: Option<SyntaxNode<'a>>,
     },
+    PatternTuple {
+        elements: Vec<Option<ExprId>>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    PatternRecord {
+        fields: Vec<(InternId, Option<ExprId>)>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Wildcard {
+        node: Option<SyntaxNode<'a>>,
+    },
+    Inl {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Inr {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Case {
+        expr: Option<ExprId>,
+        inl: Option<ExprId>,
+        inr: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    CaseArm {
+        binder: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Variant {
+        label: InternId,
+        value: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    VariantCase {
+        expr: Option<ExprId>,
+        arms: Vec<Option<ExprId>>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    VariantArm {
+        label: InternId,
+        binder: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Nil {
+        node: Option<SyntaxNode<'a>>,
+    },
+    Cons {
+        head: Option<ExprId>,
+        tail: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    IsNil {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Ref {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Deref {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Assign {
+        target: Option<ExprId>,
+        value: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Error {
+        node: Option<SyntaxNode<'a>>,
+    },
+    Raise {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Try {
+        body: Option<ExprId>,
+        handler: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeAbs {
+        name: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeApp {
+        expr: Option<ExprId>,
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Pack {
+        ty: Option<ExprId>,
+        value: Option<ExprId>,
+        existential: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Unpack {
+        ty_name: Option<ExprId>,
+        name: Option<ExprId>,
+        value: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Head {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Tail {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
     Def {
         arg: Option<ExprId>,
+        ty: Option<ExprId>,
         body: Option<ExprId>,
         node: Option<SyntaxNode<'a>>,
     },
@@ -38,6 +228,10 @@ pub enum Expr<'a> {
         arg: Option<ExprId>,
         node: Option<SyntaxNode<'a>>,
     },
+    Fix {
+        arg: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
     IfElse {
         cond: Option<ExprId>,
         then: Option<ExprId>,
@@ -50,6 +244,97 @@ pub enum Expr<'a> {
         body: Option<ExprId>,
         node: Option<SyntaxNode<'a>>,
     },
+    Letrec {
+        name: Option<ExprId>,
+        ty: Option<ExprId>,
+        value: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeAlias {
+        name: Option<ExprId>,
+        ty: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Import {
+        path: String,
+        name: Option<ExprId>,
+        /// Root of the imported file, once it is found
+        module: Option<ExprId>,
+        body: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    Ascribe {
+        expr: Option<ExprId>,
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeName {
+        name: InternId,
+        /// Type alias the name refers to
+        id: Option<VarId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeFn {
+        from: Option<ExprId>,
+        to: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeProduct {
+        elements: Vec<Option<ExprId>>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeRecord {
+        fields: Vec<(InternId, Option<ExprId>)>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeVariant {
+        fields: Vec<(InternId, Option<ExprId>)>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeSum {
+        left: Option<ExprId>,
+        right: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeList {
+        element: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeRef {
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeRec {
+        name: InternId,
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeForAll {
+        name: InternId,
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+    TypeSome {
+        name: InternId,
+        ty: Option<ExprId>,
+        node: Option<SyntaxNode<'a>>,
+    },
+}
+
+pub fn var_def_to_str<'a>(e: &'a Exprs<'a>, id: ExprId) -> &'a str {
+    match e.get(id) {
+        Expr::VarDef { name, .. } => e.get_str(*name),
+        Expr::Wildcard { .. } => "_",
+        e => unreachable!("{e:?} is not VarDef"),
+    }
+}
+pub fn var_def_to_intern(e: &Exprs, id: ExprId) -> InternId {
+    match e.get(id) {
+        Expr::VarDef { name, .. } => *name,
+        e => unreachable!("{e:?} is not VarDef"),
+    }
 }
 
 impl<'a> Expr<'a> {
@@ -63,6 +348,15 @@ impl<'a> Expr<'a> {
             e => unreachable!("{:?} is not VarDef", e),
         }
     }
+
+    /// Variable of a parameter or another binder, the wildcard `_` binds none
+    pub fn binder_var(&self) -> Option<VarId> {
+        match self {
+            Expr::VarDef { id, .. } => Some(*id),
+            Expr::Wildcard { .. } => None,
+            e => unreachable!("{:?} is not VarDef", e),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -73,6 +367,10 @@ pub struct Exprs<'a> {
     pub intern_counter: InternId,
     pub vars: Vec<Variable>,
     pub scopes: Vec<Scope>,
+    /// Root expression of every file, by filename
+    pub modules: BTreeMap<String, ExprId>,
+    /// Variables of imported modules referenced by `m.x`, by the `Field` expression
+    pub module_fields: BTreeMap<ExprId, VarId>,
 }
 
 #[derive(Debug)]
@@ -93,20 +391,44 @@ impl<'a> Exprs<'a> {
         root: ExprId,
         diagnostics: &mut Diagnostics,
     ) -> Exprs<'a> {
-        let ir = Exprs {
-            e: e.e.iter().map(Expr::from_ast).collect(),
+        let mut ir = Exprs {
+            e: vec![],
             i_to_s: e.i_to_s.clone(),
             s_to_i: e.s_to_i.clone(),
             intern_counter: e.intern_counter,
             vars: vec![],
             scopes: vec![],
+            modules: e.modules.clone(),
+            module_fields: BTreeMap::new(),
+        };
+        let mut lowering = Lowering {
+            offset: e.e.len(),
+            synthesized: vec![],
+            unit: ir.push_str("Unit"),
         };
+        let lowered =
+            e.e.iter()
+                .map(|expr| Expr::from_ast(expr, &mut lowering))
+                .collect();
+        ir.e = lowered;
+        ir.e.extend(lowering.synthesized);
+        e.report_errors(diagnostics);
 
         let ir = fix_scope(ir, root, diagnostics);
 
         ir
     }
 
+    pub fn push_str(&mut self, s: impl ToString) -> InternId {
+        let id = *self.s_to_i.entry(s.to_string()).or_insert_with(|| {
+            let id = self.intern_counter;
+            self.intern_counter.0 += 1;
+            id
+        });
+        self.i_to_s.insert(id, s.to_string());
+        id
+    }
+
     pub fn get_mut(&mut self, id: ExprId) -> &mut Expr<'a> {
         &mut self.e[id.0]
     }
@@ -123,16 +445,38 @@ impl<'a> Exprs<'a> {
         &self.vars[id.0]
     }
 
+    /// Variable defined or referenced by the expression.
+    /// `m.x` references `x` from the module imported as `m`
+    pub fn var_of(&self, id: ExprId) -> Option<VarId> {
+        match self.get(id) {
+            Expr::Var { id, .. } => *id,
+            Expr::VarDef { id, .. } => Some(*id),
+            Expr::TypeName { id, .. } => *id,
+            Expr::Field { .. } => self.module_fields.get(&id).copied(),
+            _ => None,
+        }
+    }
+
+    /// Variables defined at the top level of the module starting at `root`
+    pub fn exports(&self, root: ExprId) -> BTreeMap<InternId, VarId> {
+        exports(&self.e, root)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (ExprId, &Expr<'a>)> {
         self.e.iter().enumerate().map(|(id, e)| (ExprId(id), e))
     }
 
-    pub fn scopes_in_point(&self, point: Point) -> impl Iterator<Item = &Scope> {
+    pub fn scopes_in_point<'s>(
+        &'s self,
+        filename: &'s str,
+        point: Point,
+    ) -> impl Iterator<Item = &'s Scope> {
         tracing::info!("Point: {point:?}");
         self.scopes.iter().filter(move |s| {
-            s.range
-                .map(|r| r.start_point <= point && point <= r.end_point)
-                .unwrap_or(false)
+            s.filename.as_deref() == Some(filename)
+                && s.range
+                    .map(|r| r.start_point <= point && point <= r.end_point)
+                    .unwrap_or(false)
         })
     }
 
@@ -142,18 +486,40 @@ impl<'a> Exprs<'a> {
     }
 }
 
+/// Syntax sugar is lowered into expressions that do not exist in the AST.
+/// They are appended after all AST expressions, so `ExprId`s stay the same in AST and IR.
+struct Lowering<'a> {
+    offset: usize,
+    synthesized: Vec<Expr<'a>>,
+    /// Name of the `Unit` type, for the parameter of a sequence
+    unit: InternId,
+}
+
+impl<'a> Lowering<'a> {
+    fn push(&mut self, e: Expr<'a>) -> ExprId {
+        let id = ExprId(self.offset + self.synthesized.len());
+        self.synthesized.push(e);
+        id
+    }
+}
+
 #[derive(Debug)]
 pub struct Scope {
     pub vars: BTreeMap<InternId, VarId>,
+    /// Type aliases, they don't clash with variables of the same name
+    pub types: BTreeMap<InternId, VarId>,
     pub range: Option<tree_sitter::Range>,
+    pub filename: Option<Arc<str>>,
     pub depth: usize,
 }
 
 impl Scope {
-    fn new(range: Option<tree_sitter::Range>) -> Self {
+    fn new(node: Option<&SyntaxNode>) -> Self {
         Self {
             vars: BTreeMap::new(),
-            range,
+            types: BTreeMap::new(),
+            range: node.map(|node| node.range),
+            filename: node.map(|node| node.filename.clone()),
             depth: 0,
         }
     }
@@ -163,6 +529,54 @@ impl Scope {
 enum StackItem {
     Expr(Option<ExprId>),
     ScopePop,
+    /// An imported file is resolved on its own, without the scopes of the importer
+    ModuleStart(ExprId),
+    ModuleEnd,
+    /// Name of a type alias
+    TypeDef(Option<ExprId>),
+}
+
+fn exports(exprs: &[Expr], root: ExprId) -> BTreeMap<InternId, VarId> {
+    let mut exports = BTreeMap::new();
+    let mut next = Some(root);
+    while let Some(e) = next {
+        next = match &exprs[e.0] {
+            Expr::Let { name, body, .. } | Expr::Letrec { name, body, .. } => {
+                pattern_vars(exprs, *name, &mut exports);
+                *body
+            }
+            Expr::TypeAlias { body, .. } => *body,
+            // Modules imported by a module are not exported
+            Expr::Import { name, body, .. } => {
+                if let Some(Expr::VarDef { name, .. }) = name.map(|name| &exprs[name.0]) {
+                    exports.remove(name);
+                }
+                *body
+            }
+            _ => None,
+        };
+    }
+    exports
+}
+
+/// Names bound by a `let`, one for `let a = ..`, all of them for `let {a, {x = b}} = ..`
+fn pattern_vars(exprs: &[Expr], pattern: Option<ExprId>, vars: &mut BTreeMap<InternId, VarId>) {
+    match pattern.map(|pattern| &exprs[pattern.0]) {
+        Some(Expr::VarDef { name, id, .. }) => {
+            vars.insert(*name, *id);
+        }
+        Some(Expr::PatternTuple { elements, .. }) => {
+            for el in elements {
+                pattern_vars(exprs, *el, vars);
+            }
+        }
+        Some(Expr::PatternRecord { fields, .. }) => {
+            for (_label, pattern) in fields {
+                pattern_vars(exprs, *pattern, vars);
+            }
+        }
+        _ => (),
+    }
 }
 
 struct ScopeStack {
@@ -198,6 +612,8 @@ fn fix_scope<'a>(exprs: Exprs<'a>, e: ExprId, diagnostics: &mut Diagnostics) ->
         intern_counter,
         mut vars,
         scopes,
+        modules,
+        mut module_fields,
     } = exprs;
     let mut var_counter = VarId(0);
     let mut scope_stack: ScopeStack = ScopeStack {
@@ -209,6 +625,12 @@ fn fix_scope<'a>(exprs: Exprs<'a>, e: ExprId, diagnostics: &mut Diagnostics) ->
         v.push_front(StackItem::Expr(Some(e)));
         v
     };
+    let mut visited_modules = BTreeSet::from([e]);
+    let mut modules_in_progress = vec![e];
+    let mut saved_scopes = vec![];
+    // Definitions of `m` in `import "..." as m` and the root of the imported file
+    let mut module_vars: BTreeMap<ExprId, ExprId> = BTreeMap::new();
+    let mut module_exports: BTreeMap<ExprId, BTreeMap<InternId, VarId>> = BTreeMap::new();
     while let Some(e) = stack.pop_back() {
         let e = match e {
             StackItem::Expr(Some(e)) => e,
@@ -217,110 +639,738 @@ fn fix_scope<'a>(exprs: Exprs<'a>, e: ExprId, diagnostics: &mut Diagnostics) ->
                 scope_stack.pop();
                 continue;
             }
-        };
-
-        match &mut exprs[e.0] {
-            Expr::Def { arg, body, node } => {
-                stack.push_back(StackItem::ScopePop);
-                stack.push_back(StackItem::Expr(*body));
-                stack.push_back(StackItem::Expr(*arg));
-                let range = node.as_ref().map(|node| node.range);
-                scope_stack.push(Scope::new(range));
+            StackItem::ModuleStart(root) => {
+                visited_modules.insert(root);
+                modules_in_progress.push(root);
+                saved_scopes.push(std::mem::take(&mut scope_stack.stack));
+                continue;
             }
-            Expr::Bool { value: _, node: _ } => (),
-            Expr::Var { name, id, node } => {
-                let mut scope_stack = scope_stack.iter().rev();
-
-                let var = scope_stack.find_map(|s| s.vars.get(name).copied());
-                match var {
-                    Some(var) => {
-                        let var = &mut vars[var.0];
-                        var.references.push(e);
-                    }
-                    None => {
-                        diagnostics.push(
-                            node,
-                            format!("Variable `{}` is not defined anywhere", i_to_s[name]),
-                        );
-                    }
+            StackItem::ModuleEnd => {
+                if let Some(root) = modules_in_progress.pop() {
+                    module_exports.insert(root, exports(&exprs, root));
                 }
-                *id = var;
+                scope_stack.stack = saved_scopes.pop().unwrap_or_default();
+                continue;
             }
-            Expr::VarDef { name, id, node: _ } => {
-                if let Some(scope) = scope_stack.last_mut() {
+            StackItem::TypeDef(Some(def)) => {
+                if let (Expr::VarDef { name, id, node: _ }, Some(scope)) =
+                    (&mut exprs[def.0], scope_stack.last_mut())
+                {
                     let var = var_counter;
                     var_counter.0 += 1;
 
-                    scope.vars.insert(*name, var);
+                    scope.types.insert(*name, var);
                     vars.push(Variable {
-                        defined: e,
+                        defined: def,
                         references: vec![],
                     });
                     *id = var;
                 }
+                continue;
             }
-            Expr::Call { func, arg, node: _ } => {
-                stack.push_back(StackItem::Expr(*arg));
-                stack.push_back(StackItem::Expr(*func));
-            }
-            Expr::IfElse {
-                cond,
-                then,
-                else_,
-                node: _,
-            } => {
-                stack.push_back(StackItem::Expr(*else_));
-                stack.push_back(StackItem::Expr(*then));
-                stack.push_back(StackItem::Expr(*cond));
+            StackItem::TypeDef(None) => continue,
+        };
+
+        // `m.x` where `m` is an imported module references `x` from the other file
+        if let Expr::Field {
+            expr: Some(expr),
+            label,
+            ..
+        } = &exprs[e.0]
+        {
+            if let Expr::Var { name, .. } = &exprs[expr.0] {
+                let export = scope_stack
+                    .iter()
+                    .rev()
+                    .find_map(|s| s.vars.get(name))
+                    .and_then(|var| module_vars.get(&vars[var.0].defined))
+                    .and_then(|module| module_exports.get(module)?.get(label));
+                if let Some(export) = export {
+                    vars[export.0].references.push(e);
+                    module_fields.insert(e, *export);
+                }
             }
-            Expr::Let {
-                name,
-                value,
+        }
+
+        match &mut exprs[e.0] {
+            Expr::Def {
+                arg,
+                ty,
                 body,
                 node,
             } => {
                 stack.push_back(StackItem::ScopePop);
                 stack.push_back(StackItem::Expr(*body));
-                stack.push_back(StackItem::Expr(*value));
-                stack.push_back(StackItem::Expr(*name));
-                let range = node.as_ref().map(|node| node.range);
-                scope_stack.push(Scope::new(range));
+                stack.push_back(StackItem::Expr(*arg));
+                stack.push_back(StackItem::Expr(*ty));
+                scope_stack.push(Scope::new(node.as_ref()));
             }
-        }
-    }
-    Exprs {
-        e: exprs,
-        i_to_s,
-        s_to_i,
-        intern_counter,
-        vars,
+            Expr::Unit { node: _ } | Expr::Wildcard { node: _ } => (),
+            Expr::Bool { value: _, node: _ } => (),
+            Expr::Nat { value: _, node: _ } => (),
+            Expr::String { value: _, node: _ } => (),
+            Expr::Succ { arg, node: _ }
+            | Expr::Pred { arg, node: _ }
+            | Expr::IsZero { arg, node: _ }
+            | Expr::Length { arg, node: _ }
+            | Expr::Not { arg, node: _ } => {
+                stack.push_back(StackItem::Expr(*arg));
+            }
+            Expr::Tuple { elements, node: _ }
+            | Expr::PatternTuple { elements, node: _ }
+            | Expr::TypeProduct { elements, node: _ } => {
+                for el in elements.iter().rev() {
+                    stack.push_back(StackItem::Expr(*el));
+                }
+            }
+            Expr::Record { fields, node: _ }
+            | Expr::PatternRecord { fields, node: _ }
+            | Expr::TypeRecord { fields, node: _ }
+            | Expr::TypeVariant { fields, node: _ } => {
+                for (_label, value) in fields.iter().rev() {
+                    stack.push_back(StackItem::Expr(*value));
+                }
+            }
+            Expr::Projection {
+                expr,
+                index: _,
+                node: _,
+            }
+            | Expr::Field {
+                expr,
+                label: _,
+                node: _,
+            } => {
+                stack.push_back(StackItem::Expr(*expr));
+            }
+            Expr::Inl { arg, node: _ }
+            | Expr::Inr { arg, node: _ }
+            | Expr::IsNil { arg, node: _ }
+            | Expr::Ref { arg, node: _ }
+            | Expr::Deref { arg, node: _ }
+            | Expr::Raise { arg, node: _ }
+            | Expr::Head { arg, node: _ }
+            | Expr::Tail { arg, node: _ } => {
+                stack.push_back(StackItem::Expr(*arg));
+            }
+            Expr::Nil { node: _ } | Expr::Error { node: _ } => (),
+            Expr::Cons {
+                head: left,
+                tail: right,
+                node: _,
+            }
+            | Expr::Concat {
+                left,
+                right,
+                node: _,
+            }
+            | Expr::StringEq {
+                left,
+                right,
+                node: _,
+            }
+            | Expr::And {
+                left,
+                right,
+                node: _,
+            }
+            | Expr::Or {
+                left,
+                right,
+                node: _,
+            }
+            | Expr::Equal {
+                left,
+                right,
+                node: _,
+            } => {
+                stack.push_back(StackItem::Expr(*right));
+                stack.push_back(StackItem::Expr(*left));
+            }
+            Expr::Case {
+                expr,
+                inl,
+                inr,
+                node: _,
+            } => {
+                stack.push_back(StackItem::Expr(*inr));
+                stack.push_back(StackItem::Expr(*inl));
+                stack.push_back(StackItem::Expr(*expr));
+            }
+            Expr::Variant {
+                label: _,
+                value,
+                node: _,
+            } => {
+                stack.push_back(StackItem::Expr(*value));
+            }
+            Expr::VariantCase {
+                expr,
+                arms,
+                node: _,
+            } => {
+                for arm in arms.iter().rev() {
+                    stack.push_back(StackItem::Expr(*arm));
+                }
+                stack.push_back(StackItem::Expr(*expr));
+            }
+            // Each arm has its own scope, with the binder visible only in its body
+            Expr::CaseArm { binder, body, node }
+            | Expr::VariantArm {
+                label: _,
+                binder,
+                body,
+                node,
+            } => {
+                stack.push_back(StackItem::ScopePop);
+                stack.push_back(StackItem::Expr(*body));
+                stack.push_back(StackItem::Expr(*binder));
+                scope_stack.push(Scope::new(node.as_ref()));
+            }
+            Expr::Var { name, id, node } => {
+                let mut scope_stack = scope_stack.iter().rev();
+
+                let var = scope_stack.find_map(|s| s.vars.get(name).copied());
+                match var {
+                    Some(var) => {
+                        let var = &mut vars[var.0];
+                        var.references.push(e);
+                    }
+                    None if i_to_s[name] == "_" => {
+                        diagnostics.push(node, "`_` only ignores a value, it can't be used as one");
+                    }
+                    None => {
+                        diagnostics.push(
+                            node,
+                            format!("Variable `{}` is not defined anywhere", i_to_s[name]),
+                        );
+                    }
+                }
+                *id = var;
+            }
+            Expr::VarDef { name, id, node: _ } => {
+                if let Some(scope) = scope_stack.last_mut() {
+                    let var = var_counter;
+                    var_counter.0 += 1;
+
+                    scope.vars.insert(*name, var);
+                    vars.push(Variable {
+                        defined: e,
+                        references: vec![],
+                    });
+                    *id = var;
+                }
+            }
+            Expr::Call { func, arg, node: _ } => {
+                stack.push_back(StackItem::Expr(*arg));
+                stack.push_back(StackItem::Expr(*func));
+            }
+            Expr::Fix { arg, node: _ } => {
+                stack.push_back(StackItem::Expr(*arg));
+            }
+            Expr::IfElse {
+                cond,
+                then,
+                else_,
+                node: _,
+            } => {
+                stack.push_back(StackItem::Expr(*else_));
+                stack.push_back(StackItem::Expr(*then));
+                stack.push_back(StackItem::Expr(*cond));
+            }
+            Expr::Let {
+                name,
+                value,
+                body,
+                node,
+            } => {
+                // The value is resolved before `name` is defined, only `letrec` is recursive
+                stack.push_back(StackItem::ScopePop);
+                stack.push_back(StackItem::Expr(*body));
+                stack.push_back(StackItem::Expr(*name));
+                stack.push_back(StackItem::Expr(*value));
+                scope_stack.push(Scope::new(node.as_ref()));
+            }
+            // The abstract type is defined after the value, as the name is
+            Expr::Unpack {
+                ty_name,
+                name,
+                value,
+                body,
+                node,
+            } => {
+                stack.push_back(StackItem::ScopePop);
+                stack.push_back(StackItem::Expr(*body));
+                stack.push_back(StackItem::Expr(*name));
+                stack.push_back(StackItem::TypeDef(*ty_name));
+                stack.push_back(StackItem::Expr(*value));
+                scope_stack.push(Scope::new(node.as_ref()));
+            }
+            Expr::Letrec {
+                name,
+                ty,
+                value,
+                body,
+                node,
+            } => {
+                stack.push_back(StackItem::ScopePop);
+                stack.push_back(StackItem::Expr(*body));
+                stack.push_back(StackItem::Expr(*value));
+                stack.push_back(StackItem::Expr(*name));
+                stack.push_back(StackItem::Expr(*ty));
+                scope_stack.push(Scope::new(node.as_ref()));
+            }
+            // Alias is defined already in its own type, the type checker reports it as recursive
+            Expr::TypeAlias {
+                name,
+                ty,
+                body,
+                node,
+            } => {
+                stack.push_back(StackItem::ScopePop);
+                stack.push_back(StackItem::Expr(*body));
+                stack.push_back(StackItem::Expr(*ty));
+                stack.push_back(StackItem::TypeDef(*name));
+                scope_stack.push(Scope::new(node.as_ref()));
+            }
+            Expr::Import {
+                path,
+                name,
+                module,
+                body,
+                node,
+            } => {
+                stack.push_back(StackItem::ScopePop);
+                stack.push_back(StackItem::Expr(*body));
+                stack.push_back(StackItem::Expr(*name));
+                let path_node = node
+                    .clone()
+                    .and_then(|node| node.map(|n| n.child_by_field_name("path")).transpose());
+                match modules.get(path) {
+                    None => {
+                        diagnostics.push(&path_node, format!("Module `{path}` could not be found"))
+                    }
+                    Some(root) if modules_in_progress.contains(root) => {
+                        let cycle = modules_in_progress
+                            .iter()
+                            .skip_while(|m| *m != root)
+                            .chain([root])
+                            .filter_map(|m| modules.iter().find(|(_, r)| *r == m))
+                            .map(|(filename, _)| format!("`{filename}`"))
+                            .collect::<Vec<_>>()
+                            .join(" -> ");
+                        diagnostics.push(&path_node, format!("Import cycle: {cycle}"));
+                    }
+                    Some(root) => {
+                        *module = Some(*root);
+                        if let Some(name) = name {
+                            module_vars.insert(*name, *root);
+                        }
+                        // Each file is resolved once, even if it is imported many times
+                        if !visited_modules.contains(root) {
+                            stack.push_back(StackItem::ModuleEnd);
+                            stack.push_back(StackItem::Expr(Some(*root)));
+                            stack.push_back(StackItem::ModuleStart(*root));
+                        }
+                    }
+                }
+                scope_stack.push(Scope::new(node.as_ref()));
+            }
+            Expr::Ascribe { expr, ty, node: _ } => {
+                stack.push_back(StackItem::Expr(*ty));
+                stack.push_back(StackItem::Expr(*expr));
+            }
+            // Names which are not aliases are built-in types, or bound like `X` in `Rec X. T`
+            Expr::TypeName { name, id, node: _ } => {
+                *id = scope_stack
+                    .iter()
+                    .rev()
+                    .find_map(|s| s.types.get(name).copied());
+                if let Some(var) = id {
+                    vars[var.0].references.push(e);
+                }
+            }
+            Expr::TypeSum {
+                left,
+                right,
+                node: _,
+            } => {
+                stack.push_back(StackItem::Expr(*right));
+                stack.push_back(StackItem::Expr(*left));
+            }
+            Expr::TypeFn { from, to, node: _ } => {
+                stack.push_back(StackItem::Expr(*to));
+                stack.push_back(StackItem::Expr(*from));
+            }
+            Expr::TypeList { element, node: _ }
+            | Expr::TypeRef {
+                ty: element,
+                node: _,
+            }
+            | Expr::TypeRec {
+                name: _,
+                ty: element,
+                node: _,
+            }
+            | Expr::TypeForAll {
+                name: _,
+                ty: element,
+                node: _,
+            }
+            | Expr::TypeSome {
+                name: _,
+                ty: element,
+                node: _,
+            } => {
+                stack.push_back(StackItem::Expr(*element));
+            }
+            Expr::TypeAbs { name, body, node } => {
+                stack.push_back(StackItem::ScopePop);
+                stack.push_back(StackItem::Expr(*body));
+                stack.push_back(StackItem::TypeDef(*name));
+                scope_stack.push(Scope::new(node.as_ref()));
+            }
+            Expr::TypeApp { expr, ty, node: _ } => {
+                stack.push_back(StackItem::Expr(*ty));
+                stack.push_back(StackItem::Expr(*expr));
+            }
+            Expr::Pack {
+                ty,
+                value,
+                existential,
+                node: _,
+            } => {
+                stack.push_back(StackItem::Expr(*existential));
+                stack.push_back(StackItem::Expr(*value));
+                stack.push_back(StackItem::Expr(*ty));
+            }
+            Expr::Assign {
+                target,
+                value,
+                node: _,
+            } => {
+                stack.push_back(StackItem::Expr(*value));
+                stack.push_back(StackItem::Expr(*target));
+            }
+            Expr::Try {
+                body,
+                handler,
+                node: _,
+            } => {
+                stack.push_back(StackItem::Expr(*handler));
+                stack.push_back(StackItem::Expr(*body));
+            }
+        }
+    }
+    Exprs {
+        e: exprs,
+        i_to_s,
+        s_to_i,
+        intern_counter,
+        vars,
         scopes: scope_stack.scopes,
+        modules,
+        module_fields,
     }
 }
 
 impl<'a> Expr<'a> {
-    fn from_ast(e: &'a crate::ast::Expr<'a>) -> Expr<'a> {
+    fn from_ast(e: &'a crate::ast::Expr<'a>, lowering: &mut Lowering<'a>) -> Expr<'a> {
         match *e {
+            crate::ast::Expr::Unit { ref node } => Expr::Unit { node: node.clone() },
             crate::ast::Expr::Bool { value, ref node } => Expr::Bool {
                 value,
                 node: node.clone(),
             },
-            crate::ast::Expr::Var { name, ref node } => Expr::Var {
+            crate::ast::Expr::Nat { value, ref node } => Expr::Nat {
+                value,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Succ { arg, ref node } => Expr::Succ {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Pred { arg, ref node } => Expr::Pred {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::IsZero { arg, ref node } => Expr::IsZero {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::String {
+                ref value,
+                ref node,
+            } => Expr::String {
+                value: value.clone(),
+                node: node.clone(),
+            },
+            crate::ast::Expr::Concat {
+                left,
+                right,
+                ref node,
+            } => Expr::Concat {
+                left,
+                right,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Length { arg, ref node } => Expr::Length {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::StringEq {
+                left,
+                right,
+                ref node,
+            } => Expr::StringEq {
+                left,
+                right,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Not { arg, ref node } => Expr::Not {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::And {
+                left,
+                right,
+                ref node,
+            } => Expr::And {
+                left,
+                right,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Or {
+                left,
+                right,
+                ref node,
+            } => Expr::Or {
+                left,
+                right,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Equal {
+                left,
+                right,
+                ref node,
+            } => Expr::Equal {
+                left,
+                right,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Tuple {
+                ref elements,
+                ref node,
+            } => Expr::Tuple {
+                elements: elements.clone(),
+                node: node.clone(),
+            },
+            crate::ast::Expr::Projection {
+                expr,
+                index,
+                ref node,
+            } => Expr::Projection {
+                expr,
+                index,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Record {
+                ref fields,
+                ref node,
+            } => Expr::Record {
+                fields: fields.clone(),
+                node: node.clone(),
+            },
+            crate::ast::Expr::Field {
+                expr,
+                label,
+                ref node,
+            } => Expr::Field {
+                expr,
+                label,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Inl { arg, ref node } => Expr::Inl {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Inr { arg, ref node } => Expr::Inr {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Case {
+                expr,
+                inl,
+                inr,
+                ref node,
+            } => Expr::Case {
+                expr,
+                inl,
+                inr,
+                node: node.clone(),
+            },
+            crate::ast::Expr::CaseArm {
+                binder,
+                body,
+                ref node,
+            } => Expr::CaseArm {
+                binder,
+                body,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Variant {
+                label,
+                value,
+                ref node,
+            } => Expr::Variant {
+                label,
+                value,
+                node: node.clone(),
+            },
+            crate::ast::Expr::VariantCase {
+                expr,
+                ref arms,
+                ref node,
+            } => Expr::VariantCase {
+                expr,
+                arms: arms.clone(),
+                node: node.clone(),
+            },
+            crate::ast::Expr::VariantArm {
+                label,
+                binder,
+                body,
+                ref node,
+            } => Expr::VariantArm {
+                label,
+                binder,
+                body,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Var { name, ref node } => Expr::Var {
+                name,
+                node: node.clone(),
+                id: None,
+            },
+            crate::ast::Expr::VarDef { name, ref node } => Expr::VarDef {
+                name,
+                node: node.clone(),
+                id: VarId(0),
+            },
+            crate::ast::Expr::PatternTuple {
+                ref elements,
+                ref node,
+            } => Expr::PatternTuple {
+                elements: elements.clone(),
+                node: node.clone(),
+            },
+            crate::ast::Expr::PatternRecord {
+                ref fields,
+                ref node,
+            } => Expr::PatternRecord {
+                fields: fields.clone(),
+                node: node.clone(),
+            },
+            crate::ast::Expr::Wildcard { ref node } => Expr::Wildcard { node: node.clone() },
+            crate::ast::Expr::Nil { ref node } => Expr::Nil { node: node.clone() },
+            crate::ast::Expr::Cons {
+                head,
+                tail,
+                ref node,
+            } => Expr::Cons {
+                head,
+                tail,
+                node: node.clone(),
+            },
+            crate::ast::Expr::IsNil { arg, ref node } => Expr::IsNil {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Ref { arg, ref node } => Expr::Ref {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Deref { arg, ref node } => Expr::Deref {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Assign {
+                target,
+                value,
+                ref node,
+            } => Expr::Assign {
+                target,
+                value,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Error { ref node } => Expr::Error { node: node.clone() },
+            crate::ast::Expr::Raise { arg, ref node } => Expr::Raise {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Try {
+                body,
+                handler,
+                ref node,
+            } => Expr::Try {
+                body,
+                handler,
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeAbs {
                 name,
+                body,
+                ref node,
+            } => Expr::TypeAbs {
+                name,
+                body,
                 node: node.clone(),
-                id: None,
             },
-            crate::ast::Expr::VarDef { name, ref node } => Expr::VarDef {
+            crate::ast::Expr::TypeApp { expr, ty, ref node } => Expr::TypeApp {
+                expr,
+                ty,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Pack {
+                ty,
+                value,
+                existential,
+                ref node,
+            } => Expr::Pack {
+                ty,
+                value,
+                existential,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Unpack {
+                ty_name,
+                name,
+                value,
+                body,
+                ref node,
+            } => Expr::Unpack {
+                ty_name,
                 name,
+                value,
+                body,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Head { arg, ref node } => Expr::Head {
+                arg,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Tail { arg, ref node } => Expr::Tail {
+                arg,
                 node: node.clone(),
-                id: VarId(0),
             },
             crate::ast::Expr::Def {
                 arg,
+                ty,
                 body,
                 ref node,
             } => Expr::Def {
                 arg,
+                ty,
                 body,
                 node: node.clone(),
             },
@@ -333,6 +1383,10 @@ impl<'a> Expr<'a> {
                 arg,
                 node: node.clone(),
             },
+            crate::ast::Expr::Fix { arg, ref node } => Expr::Fix {
+                arg,
+                node: node.clone(),
+            },
             crate::ast::Expr::IfElse {
                 cond,
                 then,
@@ -355,6 +1409,156 @@ impl<'a> Expr<'a> {
                 body,
                 node: node.clone(),
             },
+            crate::ast::Expr::Letrec {
+                name,
+                ty,
+                value,
+                body,
+                ref node,
+            } => Expr::Letrec {
+                name,
+                ty,
+                value,
+                body,
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeAlias {
+                name,
+                ty,
+                body,
+                ref node,
+            } => Expr::TypeAlias {
+                name,
+                ty,
+                body,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Import {
+                ref path,
+                name,
+                body,
+                ref node,
+            } => Expr::Import {
+                path: path.clone(),
+                name,
+                module: None,
+                body,
+                node: node.clone(),
+            },
+            crate::ast::Expr::Ascribe { expr, ty, ref node } => Expr::Ascribe {
+                expr,
+                ty,
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeName { name, ref node } => Expr::TypeName {
+                name,
+                id: None,
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeFn { from, to, ref node } => Expr::TypeFn {
+                from,
+                to,
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeProduct {
+                ref elements,
+                ref node,
+            } => Expr::TypeProduct {
+                elements: elements.clone(),
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeVariant {
+                ref fields,
+                ref node,
+            } => Expr::TypeVariant {
+                fields: fields.clone(),
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeSum {
+                left,
+                right,
+                ref node,
+            } => Expr::TypeSum {
+                left,
+                right,
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeRecord {
+                ref fields,
+                ref node,
+            } => Expr::TypeRecord {
+                fields: fields.clone(),
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeList { element, ref node } => Expr::TypeList {
+                element,
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeRef { ty, ref node } => Expr::TypeRef {
+                ty,
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeRec { name, ty, ref node } => Expr::TypeRec {
+                name,
+                ty,
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeForAll { name, ty, ref node } => Expr::TypeForAll {
+                name,
+                ty,
+                node: node.clone(),
+            },
+            crate::ast::Expr::TypeSome { name, ty, ref node } => Expr::TypeSome {
+                name,
+                ty,
+                node: node.clone(),
+            },
+            // `[a, b]` is a derived form of `cons a (cons b nil)`
+            crate::ast::Expr::List {
+                ref elements,
+                ref node,
+            } => {
+                let Some((first, rest)) = elements.split_first() else {
+                    return Expr::Nil { node: node.clone() };
+                };
+                let mut tail = lowering.push(Expr::Nil { node: None });
+                for el in rest.iter().rev() {
+                    tail = lowering.push(Expr::Cons {
+                        head: *el,
+                        tail: Some(tail),
+                        node: None,
+                    });
+                }
+                Expr::Cons {
+                    head: *first,
+                    tail: Some(tail),
+                    node: node.clone(),
+                }
+            }
+            // `first; then` is a derived form of `(_: Unit => then) first`, as in TAPL 11.3
+            crate::ast::Expr::Sequence {
+                first,
+                then,
+                ref node,
+            } => {
+                let arg = lowering.push(Expr::Wildcard { node: None });
+                let ty = lowering.push(Expr::TypeName {
+                    name: lowering.unit,
+                    id: None,
+                    node: None,
+                });
+                let func = lowering.push(Expr::Def {
+                    arg: Some(arg),
+                    ty: Some(ty),
+                    body: then,
+                    node: None,
+                });
+                Expr::Call {
+                    func: Some(func),
+                    arg: first,
+                    node: node.clone(),
+                }
+            }
         }
     }
 }
@@ -371,7 +1575,100 @@ impl<'a> Expr<'a> {
 impl<'a> std::fmt::Debug for DebugExpr<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.e {
+            Expr::Unit { node: _ } => write!(f, "Unit"),
             Expr::Bool { value: b, node: _ } => f.debug_tuple("Bool").field(b).finish(),
+            Expr::Nat { value: n, node: _ } => f.debug_tuple("Nat").field(n).finish(),
+            Expr::String { value, node: _ } => f.debug_tuple("String").field(value).finish(),
+            Expr::Succ { arg, node: _ } => {
+                f.debug_tuple("Succ").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Pred { arg, node: _ } => {
+                f.debug_tuple("Pred").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::IsZero { arg, node: _ } => {
+                f.debug_tuple("IsZero").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Concat {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("Concat")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::Length { arg, node: _ } => {
+                f.debug_tuple("Length").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::StringEq {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("StringEq")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::Not { arg, node: _ } => f.debug_tuple("Not").field(&self.ex.debug(*arg)).finish(),
+            Expr::And {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("And")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::Or {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("Or")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::Equal {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("Equal")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::Tuple { elements, node: _ } => {
+                let mut t = f.debug_tuple("Tuple");
+                for el in elements {
+                    t.field(&self.ex.debug(*el));
+                }
+                t.finish()
+            }
+            Expr::Projection {
+                expr,
+                index,
+                node: _,
+            } => f
+                .debug_tuple("Projection")
+                .field(&self.ex.debug(*expr))
+                .field(index)
+                .finish(),
+            Expr::Record { fields, node: _ } => {
+                let mut t = f.debug_struct("Record");
+                for (label, value) in fields {
+                    t.field(self.ex.get_str(*label), &self.ex.debug(*value));
+                }
+                t.finish()
+            }
+            Expr::Field {
+                expr,
+                label,
+                node: _,
+            } => f
+                .debug_tuple("Field")
+                .field(&self.ex.debug(*expr))
+                .field(&format_args!("{}", self.ex.get_str(*label)))
+                .finish(),
             Expr::Var {
                 name: v,
                 id,
@@ -380,15 +1677,171 @@ impl<'a> std::fmt::Debug for DebugExpr<'a> {
             Expr::VarDef { name, id, node: _ } => {
                 write!(f, "VarDef({}, {id:?})", self.ex.get_str(*name))
             }
+            Expr::PatternTuple { elements, node: _ } => {
+                let mut t = f.debug_tuple("PatternTuple");
+                for el in elements {
+                    t.field(&self.ex.debug(*el));
+                }
+                t.finish()
+            }
+            Expr::PatternRecord { fields, node: _ } => {
+                let mut t = f.debug_struct("PatternRecord");
+                for (label, pattern) in fields {
+                    t.field(self.ex.get_str(*label), &self.ex.debug(*pattern));
+                }
+                t.finish()
+            }
+            Expr::Wildcard { node: _ } => write!(f, "Wildcard"),
+            Expr::Inl { arg, node: _ } => f.debug_tuple("Inl").field(&self.ex.debug(*arg)).finish(),
+            Expr::Inr { arg, node: _ } => f.debug_tuple("Inr").field(&self.ex.debug(*arg)).finish(),
+            Expr::Case {
+                expr,
+                inl,
+                inr,
+                node: _,
+            } => f
+                .debug_tuple("Case")
+                .field(&self.ex.debug(*expr))
+                .field(&self.ex.debug(*inl))
+                .field(&self.ex.debug(*inr))
+                .finish(),
+            Expr::CaseArm {
+                binder,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("CaseArm")
+                .field(&self.ex.debug(*binder))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::Variant {
+                label,
+                value,
+                node: _,
+            } => f
+                .debug_tuple("Variant")
+                .field(&format_args!("{}", self.ex.get_str(*label)))
+                .field(&self.ex.debug(*value))
+                .finish(),
+            Expr::VariantCase {
+                expr,
+                arms,
+                node: _,
+            } => {
+                let mut t = f.debug_tuple("VariantCase");
+                t.field(&self.ex.debug(*expr));
+                for arm in arms {
+                    t.field(&self.ex.debug(*arm));
+                }
+                t.finish()
+            }
+            Expr::VariantArm {
+                label,
+                binder,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("VariantArm")
+                .field(&format_args!("{}", self.ex.get_str(*label)))
+                .field(&self.ex.debug(*binder))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::Nil { node: _ } => write!(f, "Nil"),
+            Expr::Cons {
+                head,
+                tail,
+                node: _,
+            } => f
+                .debug_tuple("Cons")
+                .field(&self.ex.debug(*head))
+                .field(&self.ex.debug(*tail))
+                .finish(),
+            Expr::IsNil { arg, node: _ } => {
+                f.debug_tuple("IsNil").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Ref { arg, node: _ } => f.debug_tuple("Ref").field(&self.ex.debug(*arg)).finish(),
+            Expr::Deref { arg, node: _ } => {
+                f.debug_tuple("Deref").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Assign {
+                target,
+                value,
+                node: _,
+            } => f
+                .debug_tuple("Assign")
+                .field(&self.ex.debug(*target))
+                .field(&self.ex.debug(*value))
+                .finish(),
+            Expr::Error { node: _ } => write!(f, "Error"),
+            Expr::Raise { arg, node: _ } => {
+                f.debug_tuple("Raise").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Try {
+                body,
+                handler,
+                node: _,
+            } => f
+                .debug_tuple("Try")
+                .field(&self.ex.debug(*body))
+                .field(&self.ex.debug(*handler))
+                .finish(),
+            Expr::TypeAbs {
+                name,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("TypeAbs")
+                .field(&self.ex.debug(*name))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::TypeApp { expr, ty, node: _ } => f
+                .debug_tuple("TypeApp")
+                .field(&self.ex.debug(*expr))
+                .field(&self.ex.debug(*ty))
+                .finish(),
+            Expr::Pack {
+                ty,
+                value,
+                existential,
+                node: _,
+            } => f
+                .debug_tuple("Pack")
+                .field(&self.ex.debug(*ty))
+                .field(&self.ex.debug(*value))
+                .field(&self.ex.debug(*existential))
+                .finish(),
+            Expr::Unpack {
+                ty_name,
+                name,
+                value,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("Unpack")
+                .field(&self.ex.debug(*ty_name))
+                .field(&self.ex.debug(*name))
+                .field(&self.ex.debug(*value))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::Head { arg, node: _ } => {
+                f.debug_tuple("Head").field(&self.ex.debug(*arg)).finish()
+            }
+            Expr::Tail { arg, node: _ } => {
+                f.debug_tuple("Tail").field(&self.ex.debug(*arg)).finish()
+            }
             Expr::Def {
                 arg,
+                ty,
                 body: ret,
                 node: _,
-            } => f
-                .debug_tuple("Def")
-                .field(&self.ex.debug(*arg))
-                .field(&self.ex.debug(*ret))
-                .finish(),
+            } => {
+                let mut t = f.debug_tuple("Def");
+                t.field(&self.ex.debug(*arg));
+                if ty.is_some() {
+                    t.field(&self.ex.debug(*ty));
+                }
+                t.field(&self.ex.debug(*ret)).finish()
+            }
             Expr::Call {
                 func: fun,
                 arg,
@@ -398,6 +1851,7 @@ impl<'a> std::fmt::Debug for DebugExpr<'a> {
                 .field(&self.ex.debug(*fun))
                 .field(&self.ex.debug(*arg))
                 .finish(),
+            Expr::Fix { arg, node: _ } => f.debug_tuple("Fix").field(&self.ex.debug(*arg)).finish(),
             Expr::IfElse {
                 cond,
                 then,
@@ -420,6 +1874,108 @@ impl<'a> std::fmt::Debug for DebugExpr<'a> {
                 .field(&self.ex.debug(*value))
                 .field(&self.ex.debug(*then))
                 .finish(),
+            Expr::Letrec {
+                name,
+                ty,
+                value,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("Letrec")
+                .field(&self.ex.debug(*name))
+                .field(&self.ex.debug(*ty))
+                .field(&self.ex.debug(*value))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::TypeAlias {
+                name,
+                ty,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("TypeAlias")
+                .field(&self.ex.debug(*name))
+                .field(&self.ex.debug(*ty))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::Import {
+                path,
+                name,
+                module: _,
+                body,
+                node: _,
+            } => f
+                .debug_tuple("Import")
+                .field(path)
+                .field(&self.ex.debug(*name))
+                .field(&self.ex.debug(*body))
+                .finish(),
+            Expr::Ascribe { expr, ty, node: _ } => f
+                .debug_tuple("Ascribe")
+                .field(&self.ex.debug(*expr))
+                .field(&self.ex.debug(*ty))
+                .finish(),
+            Expr::TypeName { name, id, node: _ } => match id {
+                Some(id) => write!(f, "TypeName({}, {id:?})", self.ex.get_str(*name)),
+                None => write!(f, "TypeName({})", self.ex.get_str(*name)),
+            },
+            Expr::TypeFn { from, to, node: _ } => f
+                .debug_tuple("TypeFn")
+                .field(&self.ex.debug(*from))
+                .field(&self.ex.debug(*to))
+                .finish(),
+            Expr::TypeProduct { elements, node: _ } => {
+                let mut t = f.debug_tuple("TypeProduct");
+                for el in elements {
+                    t.field(&self.ex.debug(*el));
+                }
+                t.finish()
+            }
+            Expr::TypeSum {
+                left,
+                right,
+                node: _,
+            } => f
+                .debug_tuple("TypeSum")
+                .field(&self.ex.debug(*left))
+                .field(&self.ex.debug(*right))
+                .finish(),
+            Expr::TypeVariant { fields, node: _ } => {
+                let mut t = f.debug_struct("TypeVariant");
+                for (label, ty) in fields {
+                    t.field(self.ex.get_str(*label), &self.ex.debug(*ty));
+                }
+                t.finish()
+            }
+            Expr::TypeRecord { fields, node: _ } => {
+                let mut t = f.debug_struct("TypeRecord");
+                for (label, ty) in fields {
+                    t.field(self.ex.get_str(*label), &self.ex.debug(*ty));
+                }
+                t.finish()
+            }
+            Expr::TypeList { element, node: _ } => f
+                .debug_tuple("TypeList")
+                .field(&self.ex.debug(*element))
+                .finish(),
+            Expr::TypeRef { ty, node: _ } => {
+                f.debug_tuple("TypeRef").field(&self.ex.debug(*ty)).finish()
+            }
+            Expr::TypeRec { name, ty, node: _ } => f
+                .debug_tuple("TypeRec")
+                .field(&format_args!("{}", self.ex.get_str(*name)))
+                .field(&self.ex.debug(*ty))
+                .finish(),
+            Expr::TypeForAll { name, ty, node: _ } => f
+                .debug_tuple("TypeForAll")
+                .field(&format_args!("{}", self.ex.get_str(*name)))
+                .field(&self.ex.debug(*ty))
+                .finish(),
+            Expr::TypeSome { name, ty, node: _ } => f
+                .debug_tuple("TypeSome")
+                .field(&format_args!("{}", self.ex.get_str(*name)))
+                .field(&self.ex.debug(*ty))
+                .finish(),
         }
     }
 }
@@ -427,18 +1983,85 @@ impl<'a> std::fmt::Debug for DebugExpr<'a> {
 #[allow(clippy::expect_used)]
 #[cfg(test)]
 mod tests {
-    use crate::ast::from_cst::{from_tree, get_tree};
+    use crate::ast::from_cst::{from_files, from_tree, get_tree};
+    use crate::ast::imports::{load_files, SourceFile};
 
     use super::*;
 
     #[test]
     fn ir_tests() -> test_runner::Result {
-        test_runner::test_snapshots("tests/", "ir", |input, _deps| {
-            let tree = get_tree(input);
-            let (r, exprs) = from_tree(&tree, input, "test");
+        test_runner::test_snapshots("tests/", "ir", |input, deps| {
+            let root = SourceFile::new("test", input);
+            let files = load_files(root, |path| deps.get(path).map(|dep| dep.to_string()));
+            let (r, exprs) = from_files(&files);
             let mut diagnostics = Diagnostics::default();
             let ir = Exprs::from_ast(&exprs, r.expect("Root node"), &mut diagnostics);
             format!("{:#?}", ir.debug(r))
         })
     }
+
+    #[test]
+    fn case_arm_scopes() {
+        let input = "let s = inl 0; case s of inl a => a | inr b => b";
+        let tree = get_tree(input);
+        let (r, exprs) = from_tree(&tree, input, "test");
+        let mut diagnostics = Diagnostics::default();
+        let ir = Exprs::from_ast(&exprs, r.expect("Root node"), &mut diagnostics);
+
+        let names_at = |column| {
+            let mut scopes = ir
+                .scopes_in_point("test", Point::new(0, column))
+                .collect::<Vec<_>>();
+            scopes.sort_by_key(|s| s.depth);
+            scopes
+                .into_iter()
+                .flat_map(|s| s.vars.keys().map(|name| ir.get_str(*name)))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names_at(35), ["s", "a"]);
+        assert_eq!(names_at(48), ["s", "b"]);
+    }
+
+    #[test]
+    fn import_references() {
+        let root = SourceFile::new("test", "import \"other.lbd\" as m; m.x");
+        let files = load_files(root, |_| Some("let x = 0; unit".into()));
+        let (r, exprs) = from_files(&files);
+        let mut diagnostics = Diagnostics::default();
+        let ir = Exprs::from_ast(&exprs, r.expect("Root node"), &mut diagnostics);
+
+        let (field, _) = ir
+            .iter()
+            .find(|(_, e)| matches!(e, Expr::Field { .. }))
+            .expect("Field");
+        let var = ir.get_var(ir.var_of(field).expect("Exported variable"));
+        let def = ir.get(var.defined).node().expect("Definition");
+        assert_eq!(&*def.filename, "other.lbd");
+        assert_eq!(&def.source[def.range.start_byte..def.range.end_byte], "x");
+    }
+
+    #[test]
+    fn shadowed_type_name_references() {
+        let input = "type X = Nat; let f = /\\X. x: X => x; \
+            let {X, y} = {*Nat, 0} as {Some Y, Y} in (z: X => z) y";
+        let tree = get_tree(input);
+        let (r, exprs) = from_tree(&tree, input, "test");
+        let mut diagnostics = Diagnostics::default();
+        let ir = Exprs::from_ast(&exprs, r.expect("Root node"), &mut diagnostics);
+
+        let definitions = ir
+            .iter()
+            .filter(|(_, e)| matches!(e, Expr::TypeName { name, .. } if ir.get_str(*name) == "X"))
+            .map(|(id, _)| {
+                let var = ir.get_var(ir.var_of(id).expect("Type variable"));
+                let def = ir.get(var.defined).node().expect("Definition");
+                def.range.start_byte
+            })
+            .collect::<Vec<_>>();
+
+        let type_abs = input.find("/\\X").expect("Type abstraction") + 2;
+        let unpack = input.find("{X").expect("Unpack") + 1;
+        assert_eq!(definitions, [type_abs, unpack]);
+    }
 }
@@ -6,11 +6,12 @@ use std::{
 
 use lambda::{
     ast::{
-        from_cst::{from_tree, get_tree, get_tree_diff, to_spanned},
+        from_cst::{from_files, get_tree, get_tree_diff, to_spanned},
+        imports::{load_files, SourceFile},
         queries::Queries,
-        SyntaxTree,
+        ExprId, Exprs, SyntaxNode, SyntaxTree,
     },
-    diagnostics::Diagnostics,
+    diagnostics::{Diagnostics, Severity},
     types::TypeEnv,
 };
 use ropey::Rope;
@@ -32,7 +33,7 @@ use tower_lsp::{
     Client, LanguageServer,
 };
 use tree_sitter::Point;
-use utils::{intersects, to_point, to_position, NodeExt, RopeExt};
+use utils::{intersects, location, to_point, to_position, RopeExt};
 
 mod utils;
 
@@ -65,6 +66,37 @@ impl State {
         let p = p?;
         self.get_tree(p)
     }
+
+    /// `file` followed by the files it imports, from the forest if they are open,
+    /// otherwise from the disk
+    fn files_with_imports(&self, file: &File) -> Vec<SourceFile> {
+        let root = SourceFile {
+            filename: file.filename.clone(),
+            source: file.source.to_string(),
+            tree: file.tree.clone(),
+        };
+        load_files(root, |path| match self.get_tree(path) {
+            Some(file) => Some(file.source.to_string()),
+            None => std::fs::read_to_string(path).ok(),
+        })
+    }
+}
+
+/// Expression of the node, the label in `m.x` stands for the whole projection
+fn find_expr<'t>(exprs: &Exprs<'t>, node: SyntaxNode<'t>) -> Option<ExprId> {
+    let parent = node.clone().map(|node| node.parent()).transpose();
+    exprs
+        .find_expr_with_node(node)
+        .or_else(|| exprs.find_expr_with_node(parent?))
+}
+
+/// Node of the variable name in an occurrence of a variable
+fn name_node<'t>(ir: &'t lambda::ir::Exprs<'t>, id: ExprId) -> Option<SyntaxNode<'t>> {
+    let node = ir.get(id).node()?;
+    match ir.get(id) {
+        lambda::ir::Expr::Field { .. } => node.map(|n| n.child_by_field_name("label")).transpose(),
+        _ => Some(node),
+    }
 }
 
 impl Backend {
@@ -175,25 +207,30 @@ impl Backend {
         let Some(file) = file else {
             return;
         };
+        let files = self.state.read().await.files_with_imports(&file);
         let File {
-            tree,
-            source,
-            filename,
+            source, filename, ..
         } = &*file;
-        let src = format!("{source}");
-        let (root_expr, exprs) = from_tree(tree, &src, filename);
+        let (root_expr, exprs) = from_files(&files);
         let mut diagnostics = Diagnostics::default();
-        let Some(root_expr) = root_expr else {
-            return;
-        };
-        let ir = lambda::ir::Exprs::from_ast(&exprs, root_expr, &mut diagnostics);
-        _ = TypeEnv::infer(&ir, root_expr, &mut diagnostics);
+        match root_expr {
+            Some(root_expr) => {
+                let ir = lambda::ir::Exprs::from_ast(&exprs, root_expr, &mut diagnostics);
+                _ = TypeEnv::infer(&ir, root_expr, &mut diagnostics);
+            }
+            None => exprs.report_errors(&mut diagnostics),
+        }
 
+        // Errors in imported files are shown when they are opened
         let diagnostics = diagnostics
             .iter()
+            .filter(|i| *i.message.filename == **filename)
             .map(|i| Diagnostic {
                 range: source.to_lsp_range(i.message.range),
-                severity: Some(DiagnosticSeverity::ERROR),
+                severity: Some(match i.severity {
+                    Severity::Error => DiagnosticSeverity::ERROR,
+                    Severity::Warning => DiagnosticSeverity::WARNING,
+                }),
                 code: None,
                 code_description: None,
                 source: Some("lambda".to_string()),
@@ -352,23 +389,23 @@ impl Backend {
         let (file, point) = self
             .file_from_text_document_position(&params.text_document_position_params)
             .await?;
-        let File {
+        let files = self.state.read().await.files_with_imports(&file);
+        let SourceFile {
             tree,
-            source,
+            source: src,
             filename,
-        } = &*file;
+        } = &files[0];
 
-        let src = format!("{source}");
-        let (root_expr, exprs) = from_tree(tree, &src, filename);
+        let (root_expr, exprs) = from_files(&files);
         let root_expr = root_expr?;
         let mut diagnostics = Diagnostics::default();
         let ir = lambda::ir::Exprs::from_ast(&exprs, root_expr, &mut diagnostics);
 
         let root = tree.root_node();
         let node = root.named_descendant_for_point_range(point, point)?;
-        let node = to_spanned(node, &src, filename);
+        let node = to_spanned(node, src, filename);
 
-        let node_expr_id = exprs.find_expr_with_node(node)?;
+        let node_expr_id = find_expr(&exprs, node)?;
 
         let (types, _ty) = TypeEnv::infer(&ir, root_expr, &mut diagnostics);
 
@@ -389,14 +426,8 @@ impl Backend {
             .read()
             .await
             .maybe_get_tree(params.text_document.uri.to_file_path().ok())?;
-        let File {
-            tree,
-            source,
-            filename,
-        } = &*file;
-
-        let src = format!("{source}");
-        let (root_expr, exprs) = from_tree(tree, &src, filename);
+        let files = self.state.read().await.files_with_imports(&file);
+        let (root_expr, exprs) = from_files(&files);
         let root_expr = root_expr?;
         let mut diagnostics = Diagnostics::default();
         let ir = lambda::ir::Exprs::from_ast(&exprs, root_expr, &mut diagnostics);
@@ -407,11 +438,16 @@ impl Backend {
         let range_end = to_point(range.end);
         let mut hints = Vec::new();
         for (e, ty) in types.exprs() {
-            let e = exprs.get(e);
+            // Synthesized expressions exist only in IR
+            let e = ir.get(e);
             let Some(node) = e.node() else {
                 continue;
             };
-            if e.is_literal() {
+            if *node.filename != *file.filename || e.is_literal() {
+                continue;
+            }
+            // Inner functions of `a b: body` end where the whole function ends
+            if node.range != node.node.range() {
                 continue;
             }
             let sp = node.node.start_position();
@@ -442,14 +478,14 @@ impl Backend {
         let (file, point) = self
             .file_from_text_document_position(&params.text_document_position_params)
             .await?;
-        let File {
+        let files = self.state.read().await.files_with_imports(&file);
+        let SourceFile {
             tree,
-            source,
+            source: src,
             filename,
-        } = &*file;
-        let src = format!("{source}");
+        } = &files[0];
 
-        let (root_expr, exprs) = from_tree(tree, &src, filename);
+        let (root_expr, exprs) = from_files(&files);
         let root_expr = root_expr?;
 
         let mut diagnostics = Diagnostics::default();
@@ -457,49 +493,31 @@ impl Backend {
 
         let root = tree.root_node();
         let node = root.named_descendant_for_point_range(point, point)?;
-        let node = to_spanned(node, &src, filename);
-        let node_expr_id = exprs.find_expr_with_node(node)?;
-
-        let expr = ir.get(node_expr_id);
-        let var = match expr {
-            lambda::ir::Expr::Var {
-                name: _,
-                id: Some(id),
-                node: _,
-            } => *id,
-            _ => return None,
-        };
-        let var = ir.get_var(var);
-        let def_id = var.defined;
-        let def = ir.get(def_id).node()?;
+        let node = to_spanned(node, src, filename);
+        let node_expr_id = find_expr(&exprs, node)?;
 
-        let def_range = NodeExt::range(&def);
+        let var = ir.get_var(ir.var_of(node_expr_id)?);
+        let def = ir.get(var.defined).node()?;
 
-        Some(GotoDefinitionResponse::Scalar(Location {
-            // For now we have only one file
-            uri: params.text_document_position_params.text_document.uri,
-            range: def_range,
-        }))
+        let uri = params.text_document_position_params.text_document.uri;
+        Some(GotoDefinitionResponse::Scalar(location(&def, &uri)))
     }
 
     async fn completion_inner(&self, params: CompletionParams) -> Option<CompletionResponse> {
         let (file, point) = self
             .file_from_text_document_position(&params.text_document_position)
             .await?;
-        let File {
-            tree,
-            source,
-            filename,
-        } = &*file;
-        let src = source.to_string();
-        let (root_expr, exprs) = from_tree(tree, &src, filename);
+        let files = self.state.read().await.files_with_imports(&file);
+        let (root_expr, exprs) = from_files(&files);
         let root_expr = root_expr?;
 
         let mut diagnostics = Diagnostics::default();
         let ir = lambda::ir::Exprs::from_ast(&exprs, root_expr, &mut diagnostics);
         let (types, _) = TypeEnv::infer(&ir, root_expr, &mut diagnostics);
 
-        let mut scopes = ir.scopes_in_point(point).collect::<Vec<_>>();
+        let mut scopes = ir
+            .scopes_in_point(&file.filename, point)
+            .collect::<Vec<_>>();
         scopes.sort_by_key(|s| s.depth);
 
         let completions = scopes
@@ -540,14 +558,14 @@ impl Backend {
         let (file, point) = self
             .file_from_text_document_position(&params.text_document_position)
             .await?;
-        let File {
+        let files = self.state.read().await.files_with_imports(&file);
+        let SourceFile {
             tree,
-            source,
+            source: src,
             filename,
-        } = &*file;
-        let src = format!("{source}");
+        } = &files[0];
 
-        let (root_expr, exprs) = from_tree(tree, &src, filename);
+        let (root_expr, exprs) = from_files(&files);
         let root_expr = root_expr?;
 
         let mut diagnostics = Diagnostics::default();
@@ -555,34 +573,17 @@ impl Backend {
 
         let root = tree.root_node();
         let node = root.named_descendant_for_point_range(point, point)?;
-        let node = to_spanned(node, &src, filename);
-        let node_expr_id = exprs.find_expr_with_node(node)?;
-
-        let expr = ir.get(node_expr_id);
-        let var = match expr {
-            lambda::ir::Expr::Var {
-                name: _,
-                id: Some(id),
-                node: _,
-            } => *id,
-            lambda::ir::Expr::VarDef {
-                name: _,
-                id,
-                node: _,
-            } => *id,
-            _ => return None,
-        };
-        let var = ir.get_var(var);
-        let ref_ids = &var.references;
+        let node = to_spanned(node, src, filename);
+        let node_expr_id = find_expr(&exprs, node)?;
+
+        let var = ir.get_var(ir.var_of(node_expr_id)?);
 
-        let refs = ref_ids
+        let uri = params.text_document_position.text_document.uri;
+        let refs = var
+            .references
             .iter()
-            .filter_map(|id| ir.get(*id).node())
-            .map(|node| NodeExt::range(&node))
-            .map(|range| Location {
-                uri: params.text_document_position.text_document.uri.clone(),
-                range,
-            })
+            .filter_map(|id| name_node(&ir, *id))
+            .map(|node| location(&node, &uri))
             .collect::<Vec<_>>();
 
         Some(refs)
@@ -592,14 +593,14 @@ impl Backend {
         let (file, point) = self
             .file_from_text_document_position(&params.text_document_position)
             .await?;
-        let File {
+        let files = self.state.read().await.files_with_imports(&file);
+        let SourceFile {
             tree,
-            source,
+            source: src,
             filename,
-        } = &*file;
-        let src = format!("{source}");
+        } = &files[0];
 
-        let (root_expr, exprs) = from_tree(tree, &src, filename);
+        let (root_expr, exprs) = from_files(&files);
         let root_expr = root_expr?;
 
         let mut diagnostics = Diagnostics::default();
@@ -607,36 +608,19 @@ impl Backend {
 
         let root = tree.root_node();
         let node = root.named_descendant_for_point_range(point, point)?;
-        let node = to_spanned(node, &src, filename);
-        let node_expr_id = exprs.find_expr_with_node(node)?;
-        let expr = ir.get(node_expr_id);
-        let var = match expr {
-            lambda::ir::Expr::Var {
-                name: _,
-                id: Some(id),
-                node: _,
-            } => *id,
-            lambda::ir::Expr::VarDef {
-                name: _,
-                id,
-                node: _,
-            } => *id,
-            _ => return None,
-        };
-        let var = ir.get_var(var);
-
-        let text_edits = var
-            .all_occurrences()
-            .filter_map(|e| ir.get(e).node())
-            .map(|node| TextEdit {
-                range: NodeExt::range(&node),
+        let node = to_spanned(node, src, filename);
+        let node_expr_id = find_expr(&exprs, node)?;
+        let var = ir.get_var(ir.var_of(node_expr_id)?);
+
+        let uri = params.text_document_position.text_document.uri;
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        for node in var.all_occurrences().filter_map(|e| name_node(&ir, e)) {
+            let Location { uri, range } = location(&node, &uri);
+            changes.entry(uri).or_default().push(TextEdit {
+                range,
                 new_text: params.new_name.clone(),
-            })
-            .collect();
-
-        let changes =
-            std::iter::once((params.text_document_position.text_document.uri, text_edits))
-                .collect();
+            });
+        }
         Some(WorkspaceEdit {
             changes: Some(changes),
             ..Default::default()
@@ -1,6 +1,8 @@
-use lambda::ast::SyntaxNode;
+use std::path::Path;
+
+use lambda::ast::{imports::resolve_import, SyntaxNode};
 use ropey::Rope;
-use tower_lsp::lsp_types::{Position, Range};
+use tower_lsp::lsp_types::{Location, Position, Range, Url};
 use tree_sitter::{InputEdit, Point};
 
 pub trait NodeExt {
@@ -19,6 +21,21 @@ impl<'a> NodeExt for SyntaxNode<'a> {
     }
 }
 
+/// Location of the node in its own file, `uri` when the file is not on the disk.
+/// A relative path of an import is resolved against the directory of the document at `uri`
+pub fn location(node: &SyntaxNode, uri: &Url) -> Location {
+    let path = match uri.to_file_path() {
+        Ok(document) if Path::new(&*node.filename).is_relative() => {
+            resolve_import(&document.display().to_string(), &node.filename)
+        }
+        _ => node.filename.to_string(),
+    };
+    Location {
+        uri: Url::from_file_path(path).unwrap_or_else(|_| uri.clone()),
+        range: NodeExt::range(node),
+    }
+}
+
 pub trait RopeExt {
     fn to_byte(&self, position: Position) -> usize;
     fn to_position(&self, offset: usize) -> Position;
@@ -2,13 +2,17 @@
 
 use clap::{Parser, Subcommand};
 use lambda::{
-    ast::from_cst::{from_tree, get_tree},
+    ast::{
+        from_cst::from_files,
+        imports::{load_files, SourceFile},
+        Exprs,
+    },
     diagnostics::Diagnostics,
-    runtime::eval,
+    runtime::{eval, Value},
     types::TypeEnv,
 };
 use lsp::Backend;
-use std::{path::PathBuf, sync::Arc};
+use std::path::PathBuf;
 use tokio::net::{TcpListener, TcpStream};
 use tower_lsp::{LspService, Server};
 
@@ -28,9 +32,15 @@ enum Command {
     },
     Run {
         source: Option<PathBuf>,
+        /// Check types as System F, with explicit polymorphism only
+        #[arg(long)]
+        system_f: bool,
     },
     Debug {
         source: Option<PathBuf>,
+        /// Check types as System F, with explicit polymorphism only
+        #[arg(long)]
+        system_f: bool,
     },
 }
 
@@ -63,56 +73,89 @@ async fn main_inner() -> anyhow::Result<()> {
             let (service, socket) = LspService::new(Backend::new);
             Server::new(read, write, socket).serve(service).await;
         }
-        Command::Debug { source } => {
+        Command::Debug { source, system_f } => {
             if let Some(source_name) = source {
-                let source = tokio::fs::read_to_string(&source_name).await?;
-                let tree = get_tree(&source);
-                println!("{:#}", tree.root_node());
+                let files = read_files(source_name).await?;
+                println!("{:#}", files[0].tree.root_node());
 
-                let filename = source_name.display().to_string();
-                let (root, exprs) = from_tree(&tree, &source, &filename);
+                let (root, exprs) = from_files(&files);
+                let mut diagnostics = Diagnostics::default();
                 let Some(root) = root else {
-                    eprintln!("<Nothing to do>");
-                    return Ok(());
+                    return nothing_to_do(&exprs, &mut diagnostics);
                 };
-                let mut diagnostics = Diagnostics::default();
                 let ir = lambda::ir::Exprs::from_ast(&exprs, root, &mut diagnostics);
 
                 println!("{:#?}", exprs.debug(Some(root)));
-                let (types, ty) = TypeEnv::infer(&ir, root, &mut diagnostics);
+                let (types, ty) = infer(&ir, root, system_f, &mut diagnostics);
                 println!(":: {:?}", ty.debug(&types))
             }
         }
-        Command::Run { source } => {
+        Command::Run { source, system_f } => {
             if let Some(source_name) = source {
-                let source = tokio::fs::read_to_string(&source_name).await?;
-                let tree = get_tree(&source);
-                let filename = source_name.display().to_string();
-                let (root, exprs) = from_tree(&tree, &source, &filename);
+                let files = read_files(source_name).await?;
+                let (root, exprs) = from_files(&files);
+                let mut diagnostics = Diagnostics::default();
                 let Some(root) = root else {
-                    eprintln!("<Nothing to do>");
-                    return Ok(());
+                    return nothing_to_do(&exprs, &mut diagnostics);
                 };
-                let mut diagnostics = Diagnostics::default();
                 let ir = lambda::ir::Exprs::from_ast(&exprs, root, &mut diagnostics);
                 let mut runtime = Default::default();
-                _ = TypeEnv::infer(&ir, root, &mut diagnostics);
+                _ = infer(&ir, root, system_f, &mut diagnostics);
+                print_diagnostics(&diagnostics)?;
                 if diagnostics.has_errors() {
-                    for d in diagnostics.iter() {
-                        d.to_report()
-                            .eprint((Arc::from("test"), ariadne::Source::from(source.clone())))?;
-                    }
                     return Ok(());
                 }
 
-                let result = eval(&exprs, &mut runtime, root);
-                println!("{result}");
+                match eval(&ir, &mut runtime, root) {
+                    // A string is the output of the program, not a literal
+                    Ok(Value::String(output)) => println!("{output}"),
+                    Ok(result) => println!("{result}"),
+                    Err(error) => eprintln!("{error}"),
+                }
             }
         }
     }
     Ok(())
 }
 
+fn infer(
+    ir: &lambda::ir::Exprs,
+    root: lambda::ast::ExprId,
+    system_f: bool,
+    diagnostics: &mut Diagnostics,
+) -> (TypeEnv, lambda::types::Type) {
+    if system_f {
+        TypeEnv::infer_system_f(ir, root, diagnostics)
+    } else {
+        TypeEnv::infer(ir, root, diagnostics)
+    }
+}
+
+fn print_diagnostics(diagnostics: &Diagnostics) -> anyhow::Result<()> {
+    for d in diagnostics.iter() {
+        d.to_report()
+            .eprint(ariadne::sources(diagnostics.sources.clone()))?;
+    }
+    Ok(())
+}
+
+/// Without an expression to check, errors in the source are still shown
+fn nothing_to_do(exprs: &Exprs, diagnostics: &mut Diagnostics) -> anyhow::Result<()> {
+    exprs.report_errors(diagnostics);
+    print_diagnostics(diagnostics)?;
+    if !diagnostics.has_errors() {
+        eprintln!("<Nothing to do>");
+    }
+    Ok(())
+}
+
+/// The file and every file it imports
+async fn read_files(source_name: PathBuf) -> anyhow::Result<Vec<SourceFile>> {
+    let source = tokio::fs::read_to_string(&source_name).await?;
+    let root = SourceFile::new(source_name.display(), source);
+    Ok(load_files(root, |path| std::fs::read_to_string(path).ok()))
+}
+
 #[tokio::main]
 async fn main() {
     if let Err(e) = main_inner().await {
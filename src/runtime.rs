@@ -1,21 +1,74 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)] // We allow these in runtime because
                                                     // the whole point of static analysis is to prevent these from happening
 
-use std::{cell::RefCell, fmt::Display, mem::MaybeUninit, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    fmt::Display,
+    rc::Rc,
+};
 
-use crate::ast::{var_def_to_intern, var_def_to_str, Expr, ExprId, Exprs, InternId};
+use thiserror::Error;
+
+use crate::ast::{ExprId, InternId};
+use crate::ir::{var_def_to_intern, var_def_to_str, Expr, Exprs};
 
 #[derive(Clone, Debug)]
 pub enum Value {
+    Unit,
     Bool(bool),
-    // Keeping string only for displaying
-    Fn(String, InternId, ExprId, RunEnv),
+    Nat(u64),
+    String(String),
+    Tuple(Vec<Value>),
+    Record(BTreeMap<String, Value>),
+    Inl(Box<Value>),
+    Inr(Box<Value>),
+    Variant(String, Box<Value>),
+    List(Rc<List>),
+    /// Location in the store
+    Ref(usize),
+    // Keeping string only for displaying, the parameter is bound with `RunEnv::bind`
+    Fn(String, ExprId, ExprId, RunEnv),
+    /// `/\X. e`, types are erased but the body waits for the type application
+    TypeFn(String, ExprId, RunEnv),
+}
+
+/// Persistent list, `cons` shares the tail instead of copying it
+#[derive(Debug, PartialEq)]
+pub enum List {
+    Nil,
+    Cons(Value, Rc<List>),
+}
+
+impl List {
+    pub fn iter(&self) -> impl Iterator<Item = &Value> {
+        std::iter::successors(Some(self), |list| match list {
+            List::Nil => None,
+            List::Cons(_, tail) => Some(tail),
+        })
+        .filter_map(|list| match list {
+            List::Nil => None,
+            List::Cons(head, _) => Some(head),
+        })
+    }
 }
 
 impl PartialEq for Value {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
+            (Value::Unit, Value::Unit) => true,
             (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::Nat(a), Value::Nat(b)) => a == b,
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Tuple(a), Value::Tuple(b)) => a == b,
+            (Value::Record(a), Value::Record(b)) => a == b,
+            (Value::Inl(a), Value::Inl(b)) => a == b,
+            (Value::Inr(a), Value::Inr(b)) => a == b,
+            (Value::Variant(a, a_value), Value::Variant(b, b_value)) => {
+                a == b && a_value == b_value
+            }
+            (Value::List(a), Value::List(b)) => a == b,
+            (Value::Ref(a), Value::Ref(b)) => a == b,
             _ => false,
         }
     }
@@ -24,49 +77,136 @@ impl PartialEq for Value {
 impl Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Value::Unit => write!(f, "unit"),
             Value::Bool(b) => write!(f, "{b}"),
+            Value::Nat(n) => write!(f, "{n}"),
+            // Written back as a literal, with the same escapes
+            Value::String(s) => {
+                write!(f, "\"")?;
+                for c in s.chars() {
+                    match c {
+                        '"' => write!(f, "\\\"")?,
+                        '\\' => write!(f, "\\\\")?,
+                        '\n' => write!(f, "\\n")?,
+                        '\t' => write!(f, "\\t")?,
+                        c => write!(f, "{c}")?,
+                    }
+                }
+                write!(f, "\"")
+            }
+            Value::Tuple(elements) => {
+                let mut elements = elements.iter();
+                write!(f, "{{")?;
+                if let Some(el) = elements.next() {
+                    write!(f, "{el}")?;
+                }
+                for el in elements {
+                    write!(f, ", {el}")?;
+                }
+                write!(f, "}}")
+            }
+            Value::Record(fields) => {
+                let mut fields = fields.iter();
+                write!(f, "{{")?;
+                if let Some((label, field)) = fields.next() {
+                    write!(f, "{label} = {field}")?;
+                }
+                for (label, field) in fields {
+                    write!(f, ", {label} = {field}")?;
+                }
+                write!(f, "}}")
+            }
+            Value::Inl(value) => write!(f, "inl {value}"),
+            Value::Inr(value) => write!(f, "inr {value}"),
+            Value::Variant(label, value) => write!(f, "<{label} = {value}>"),
+            Value::List(list) => {
+                let mut elements = list.iter();
+                write!(f, "[")?;
+                if let Some(el) = elements.next() {
+                    write!(f, "{el}")?;
+                }
+                for el in elements {
+                    write!(f, ", {el}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Ref(location) => write!(f, "<loc {location}>"),
             Value::Fn(arg, _, _ret, _) => write!(f, "fn {arg}."),
+            Value::TypeFn(name, _body, _) => write!(f, "/\\{name}."),
         }
     }
 }
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Clone)]
 pub struct RunEnv {
     scope: Option<Rc<Scope>>,
+    /// Values of references, shared by every scope
+    store: Rc<RefCell<Vec<Value>>>,
+    /// Modules evaluated so far by their root, each file is evaluated once however many
+    /// times it's imported, so its references are the same for every importer
+    modules: Rc<RefCell<BTreeMap<ExprId, Value>>>,
+}
+
+// The store and modules are left out, closures would print every reference of the program
+impl std::fmt::Debug for RunEnv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RunEnv")
+            .field("scope", &self.scope)
+            .finish()
+    }
 }
 
 /// Our scope stores only one variable
 #[derive(Debug)]
 struct Scope {
     name: InternId,
-    value: RefCell<MaybeUninit<Value>>,
+    value: Binding,
     parent: Option<Rc<Scope>>,
 }
 
+#[derive(Debug)]
+enum Binding {
+    Value(Value),
+    /// Recursive binding, the expression is evaluated in the scope of the binding itself,
+    /// as `fix` unrolled once. Nothing is kept, a closure holding its own value would never
+    /// be freed. The flag is set while it's evaluated, a use then would unroll it forever
+    Fix(ExprId, Cell<bool>),
+}
+
 impl RunEnv {
-    fn get(&self, name: InternId) -> Option<Value> {
+    fn get(&self, e: &Exprs, name: InternId) -> Option<Outcome> {
         std::iter::successors(self.scope.clone(), |scope| scope.parent.clone()).find_map(|scope| {
-            let Scope {
-                name: scoped_name,
-                value,
-                parent: _,
-            } = &*scope;
-            if &name == scoped_name {
-                let value = value.borrow();
-                let value = unsafe { value.assume_init_ref() };
-                Some(value.clone())
-            } else {
-                None
+            if name != scope.name {
+                return None;
+            }
+            match &scope.value {
+                Binding::Value(value) => Some(Ok(value.clone())),
+                Binding::Fix(body, in_progress) => {
+                    if in_progress.replace(true) {
+                        let name = e.get_str(name).to_string();
+                        return Some(Err(RuntimeError::Uninitialized { name }));
+                    }
+                    let mut inner = RunEnv {
+                        scope: Some(scope.clone()),
+                        store: self.store.clone(),
+                        modules: self.modules.clone(),
+                    };
+                    let evaluated = eval(e, &mut inner, *body);
+                    in_progress.set(false);
+                    Some(evaluated)
+                }
             }
         })
     }
 
-    fn push_uninit(&self, name: InternId) -> Self {
+    fn push_fix(&self, name: InternId, body: ExprId) -> Self {
         Self {
             scope: Some(Rc::new(Scope {
                 name,
-                value: RefCell::new(MaybeUninit::uninit()),
+                value: Binding::Fix(body, Cell::new(false)),
                 parent: self.scope.clone(),
             })),
+            store: self.store.clone(),
+            modules: self.modules.clone(),
         }
     }
 
@@ -74,34 +214,367 @@ impl RunEnv {
         Self {
             scope: Some(Rc::new(Scope {
                 name,
-                value: RefCell::new(MaybeUninit::new(value)),
+                value: Binding::Value(value),
                 parent: self.scope.clone(),
             })),
+            store: self.store.clone(),
+            modules: self.modules.clone(),
         }
     }
+
+    /// Pushes every name of the binder, a pattern gets each name its part of `value`
+    fn bind(&self, e: &Exprs, pattern: ExprId, value: Value) -> Self {
+        match (e.get(pattern), value) {
+            (Expr::VarDef { name, .. }, value) => self.push(*name, value),
+            (Expr::Wildcard { .. }, _) => self.clone(),
+            (Expr::PatternTuple { elements, .. }, Value::Tuple(values)) => elements
+                .iter()
+                .zip(values)
+                .fold(self.clone(), |env, (el, value)| {
+                    env.bind(e, el.expect("element"), value)
+                }),
+            (Expr::PatternRecord { fields, .. }, Value::Record(mut values)) => {
+                fields.iter().fold(self.clone(), |env, (label, field)| {
+                    let value = values.remove(e.get_str(*label)).expect("label");
+                    env.bind(e, field.expect("field"), value)
+                })
+            }
+            (pattern, value) => panic!("{value} doesn't match {:?}", pattern.debug(e)),
+        }
+    }
+}
+
+/// Why the evaluation stopped before reaching a value
+#[derive(Clone, Debug, PartialEq, Error)]
+pub enum RuntimeError {
+    /// Raised exception that no `try` has caught yet,
+    /// its value has the type `types::EXCEPTION`
+    #[error("Uncaught exception {0}")]
+    Exception(Value),
+
+    /// The other errors can't be caught, they stop the program
+    #[error("Recursive value `{name}` is used while it's being evaluated")]
+    Uninitialized { name: String },
+
+    #[error("Number is too large, the largest is {}", u64::MAX)]
+    Overflow,
 }
 
-/// Runtime does not have any error handling, it always panics,
+/// Either the value of an expression, or why it has none
+pub type Outcome = Result<Value, RuntimeError>;
+
+/// Exception raised by `error`, by `head` and `tail` of an empty list
+fn error() -> RuntimeError {
+    RuntimeError::Exception(Value::Nat(0))
+}
+
+/// Runtime does not have any error handling besides exceptions, it panics otherwise,
 /// because these are unrecoverable and unexpected errors.
 /// The whole point of having type system is to prevent those from occurring
-pub fn eval(e: &Exprs, env: &mut RunEnv, id: ExprId) -> Value {
-    match e.get(id) {
+pub fn eval(e: &Exprs, env: &mut RunEnv, id: ExprId) -> Outcome {
+    let value = match e.get(id) {
+        Expr::Unit { node: _ } => Value::Unit,
         Expr::Bool { value: b, node: _ } => Value::Bool(*b),
-        Expr::Var { name: v, node: _ } => env.get(*v).expect("Var not found"),
-        Expr::VarDef { .. } => unreachable!(),
+        Expr::Nat { value: n, node: _ } => Value::Nat(*n),
+        Expr::String { value: s, node: _ } => Value::String(s.clone()),
+        Expr::Succ { arg, node: _ } => match eval(e, env, arg.expect("arg"))? {
+            Value::Nat(n) => Value::Nat(n.checked_add(1).ok_or(RuntimeError::Overflow)?),
+            _ => panic!("Expected nat"),
+        },
+        Expr::Pred { arg, node: _ } => match eval(e, env, arg.expect("arg"))? {
+            // As in TAPL, `pred 0` evaluates to `0`
+            Value::Nat(n) => Value::Nat(n.saturating_sub(1)),
+            _ => panic!("Expected nat"),
+        },
+        Expr::IsZero { arg, node: _ } => match eval(e, env, arg.expect("arg"))? {
+            Value::Nat(n) => Value::Bool(n == 0),
+            _ => panic!("Expected nat"),
+        },
+        Expr::Concat {
+            left,
+            right,
+            node: _,
+        } => match (
+            eval(e, env, left.expect("left"))?,
+            eval(e, env, right.expect("right"))?,
+        ) {
+            (Value::String(left), Value::String(right)) => Value::String(left + &right),
+            _ => panic!("Expected strings"),
+        },
+        Expr::Not { arg, node: _ } => match eval(e, env, arg.expect("arg"))? {
+            Value::Bool(b) => Value::Bool(!b),
+            _ => panic!("Expected bool"),
+        },
+        // The right side is evaluated only when the left doesn't decide the result
+        Expr::And {
+            left,
+            right,
+            node: _,
+        } => match eval(e, env, left.expect("left"))? {
+            Value::Bool(false) => Value::Bool(false),
+            Value::Bool(true) => return eval(e, env, right.expect("right")),
+            _ => panic!("Expected bool"),
+        },
+        Expr::Or {
+            left,
+            right,
+            node: _,
+        } => match eval(e, env, left.expect("left"))? {
+            Value::Bool(true) => Value::Bool(true),
+            Value::Bool(false) => return eval(e, env, right.expect("right")),
+            _ => panic!("Expected bool"),
+        },
+        Expr::Equal {
+            left,
+            right,
+            node: _,
+        } => {
+            let left = eval(e, env, left.expect("left"))?;
+            let right = eval(e, env, right.expect("right"))?;
+            Value::Bool(left == right)
+        }
+        // Characters, not bytes
+        Expr::Length { arg, node: _ } => match eval(e, env, arg.expect("arg"))? {
+            Value::String(s) => Value::Nat(s.chars().count() as u64),
+            _ => panic!("Expected string"),
+        },
+        Expr::StringEq {
+            left,
+            right,
+            node: _,
+        } => match (
+            eval(e, env, left.expect("left"))?,
+            eval(e, env, right.expect("right"))?,
+        ) {
+            (Value::String(left), Value::String(right)) => Value::Bool(left == right),
+            _ => panic!("Expected strings"),
+        },
+        Expr::Tuple { elements, node: _ } => Value::Tuple(
+            elements
+                .iter()
+                .map(|el| eval(e, env, el.expect("element")))
+                .collect::<Result<_, _>>()?,
+        ),
+        Expr::Projection {
+            expr,
+            index,
+            node: _,
+        } => match eval(e, env, expr.expect("expr"))? {
+            // Elements are numbered from 1, as in TAPL
+            Value::Tuple(mut elements) => elements.swap_remove(index - 1),
+            _ => panic!("Expected tuple"),
+        },
+        Expr::Record { fields, node: _ } => Value::Record(
+            fields
+                .iter()
+                .map(|(label, field)| {
+                    let field = eval(e, env, field.expect("field"))?;
+                    Ok((e.get_str(*label).to_string(), field))
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        Expr::Field {
+            expr,
+            label,
+            node: _,
+        } => match eval(e, env, expr.expect("expr"))? {
+            Value::Record(mut fields) => fields.remove(e.get_str(*label)).expect("label"),
+            _ => panic!("Expected record"),
+        },
+        Expr::Nil { node: _ } => Value::List(Rc::new(List::Nil)),
+        Expr::Cons {
+            head,
+            tail,
+            node: _,
+        } => {
+            let head = eval(e, env, head.expect("head"))?;
+            match eval(e, env, tail.expect("tail"))? {
+                Value::List(tail) => Value::List(Rc::new(List::Cons(head, tail))),
+                _ => panic!("Expected list"),
+            }
+        }
+        Expr::IsNil { arg, node: _ } => match eval(e, env, arg.expect("arg"))? {
+            Value::List(list) => Value::Bool(matches!(*list, List::Nil)),
+            _ => panic!("Expected list"),
+        },
+        Expr::Head { arg, node: _ } => match eval(e, env, arg.expect("arg"))? {
+            Value::List(list) => match &*list {
+                List::Cons(head, _) => head.clone(),
+                List::Nil => return Err(error()),
+            },
+            _ => panic!("Expected list"),
+        },
+        Expr::Tail { arg, node: _ } => match eval(e, env, arg.expect("arg"))? {
+            Value::List(list) => match &*list {
+                List::Cons(_, tail) => Value::List(tail.clone()),
+                List::Nil => return Err(error()),
+            },
+            _ => panic!("Expected list"),
+        },
+        Expr::Inl { arg, node: _ } => Value::Inl(Box::new(eval(e, env, arg.expect("arg"))?)),
+        Expr::Inr { arg, node: _ } => Value::Inr(Box::new(eval(e, env, arg.expect("arg"))?)),
+        Expr::Case {
+            expr,
+            inl,
+            inr,
+            node: _,
+        } => {
+            let (arm, value) = match eval(e, env, expr.expect("expr"))? {
+                Value::Inl(value) => (inl.expect("inl"), *value),
+                Value::Inr(value) => (inr.expect("inr"), *value),
+                _ => panic!("Expected sum"),
+            };
+            match e.get(arm) {
+                Expr::CaseArm {
+                    binder,
+                    body,
+                    node: _,
+                } => {
+                    let mut inner = env.bind(e, binder.expect("binder"), value);
+                    return eval(e, &mut inner, body.expect("body"));
+                }
+                _ => panic!("Expected case arm"),
+            }
+        }
+        Expr::Variant {
+            label,
+            value,
+            node: _,
+        } => Value::Variant(
+            e.get_str(*label).into(),
+            Box::new(eval(e, env, value.expect("value"))?),
+        ),
+        Expr::VariantCase {
+            expr,
+            arms,
+            node: _,
+        } => {
+            let Value::Variant(label, value) = eval(e, env, expr.expect("expr"))? else {
+                panic!("Expected variant")
+            };
+            // First matching arm wins, the rest are redundant
+            let (binder, body) = arms
+                .iter()
+                .map(|arm| e.get(arm.expect("arm")))
+                .find_map(|arm| match arm {
+                    Expr::VariantArm {
+                        label: arm_label,
+                        binder,
+                        body,
+                        node: _,
+                    } if e.get_str(*arm_label) == label => Some((*binder, *body)),
+                    _ => None,
+                })
+                .expect("Exhaustive case");
+            let mut inner = env.bind(e, binder.expect("binder"), *value);
+            return eval(e, &mut inner, body.expect("body"));
+        }
+        Expr::Var {
+            name: v,
+            id: _,
+            node: _,
+        } => return env.get(e, *v).expect("Var not found"),
+        Expr::VarDef { .. }
+        | Expr::PatternTuple { .. }
+        | Expr::PatternRecord { .. }
+        | Expr::Wildcard { .. }
+        | Expr::CaseArm { .. }
+        | Expr::VariantArm { .. }
+        | Expr::TypeName { .. }
+        | Expr::TypeFn { .. }
+        | Expr::TypeProduct { .. }
+        | Expr::TypeRecord { .. }
+        | Expr::TypeSum { .. }
+        | Expr::TypeVariant { .. }
+        | Expr::TypeList { .. }
+        | Expr::TypeRef { .. }
+        | Expr::TypeRec { .. }
+        | Expr::TypeForAll { .. }
+        | Expr::TypeSome { .. } => unreachable!(),
+        Expr::Ascribe {
+            expr,
+            ty: _,
+            node: _,
+        }
+        | Expr::Pack {
+            ty: _,
+            value: expr,
+            existential: _,
+            node: _,
+        } => return eval(e, env, expr.expect("expr")),
+        Expr::Ref { arg, node: _ } => {
+            let value = eval(e, env, arg.expect("arg"))?;
+            let mut store = env.store.borrow_mut();
+            store.push(value);
+            Value::Ref(store.len() - 1)
+        }
+        Expr::Deref { arg, node: _ } => match eval(e, env, arg.expect("arg"))? {
+            Value::Ref(location) => env.store.borrow()[location].clone(),
+            _ => panic!("Expected reference"),
+        },
+        Expr::Assign {
+            target,
+            value,
+            node: _,
+        } => match eval(e, env, target.expect("target"))? {
+            Value::Ref(location) => {
+                let value = eval(e, env, value.expect("value"))?;
+                env.store.borrow_mut()[location] = value;
+                Value::Unit
+            }
+            _ => panic!("Expected reference"),
+        },
+        Expr::Error { node: _ } => return Err(error()),
+        Expr::Raise { arg, node: _ } => {
+            let value = eval(e, env, arg.expect("arg"))?;
+            return Err(RuntimeError::Exception(value));
+        }
+        // The handler is only evaluated when the body raises
+        Expr::Try {
+            body,
+            handler,
+            node: _,
+        } => match eval(e, env, body.expect("body")) {
+            Ok(value) => value,
+            Err(RuntimeError::Exception(exception)) => {
+                match apply_types(e, eval(e, env, handler.expect("handler"))?)? {
+                    Value::Fn(_name, arg, body, captured_scope) => {
+                        let mut inner = captured_scope.bind(e, arg, exception);
+                        return eval(e, &mut inner, body);
+                    }
+                    _ => panic!("Expected function"),
+                }
+            }
+            Err(error) => return Err(error),
+        },
+        Expr::TypeAbs {
+            name,
+            body,
+            node: _,
+        } => Value::TypeFn(
+            var_def_to_str(e, name.expect("name")).into(),
+            body.expect("body"),
+            env.clone(),
+        ),
+        Expr::TypeApp {
+            expr,
+            ty: _,
+            node: _,
+        } => match eval(e, env, expr.expect("expr"))? {
+            Value::TypeFn(_name, body, mut captured_scope) => {
+                return eval(e, &mut captured_scope, body)
+            }
+            _ => panic!("Expected type abstraction"),
+        },
         Expr::Def {
             arg: name,
+            ty: _,
             body,
             node: _,
         } => {
             let name = name.expect("Name");
             let body = body.expect("Body");
-            Value::Fn(
-                var_def_to_str(e, name).into(),
-                var_def_to_intern(e, name),
-                body,
-                env.clone(),
-            )
+            Value::Fn(var_def_to_str(e, name).into(), name, body, env.clone())
         }
         Expr::Call {
             func: f,
@@ -110,11 +583,11 @@ pub fn eval(e: &Exprs, env: &mut RunEnv, id: ExprId) -> Value {
         } => {
             let f = f.expect("f");
             let arg = arg.expect("arg");
-            match eval(e, env, f) {
+            match apply_types(e, eval(e, env, f)?)? {
                 Value::Fn(_name, name, body, captured_scope) => {
-                    let arg = eval(e, env, arg);
-                    let mut inner = captured_scope.push(name, arg);
-                    eval(e, &mut inner, body)
+                    let arg = eval(e, env, arg)?;
+                    let mut inner = captured_scope.bind(e, name, arg);
+                    return eval(e, &mut inner, body);
                 }
                 _ => panic!("Expected function"),
             }
@@ -128,37 +601,143 @@ pub fn eval(e: &Exprs, env: &mut RunEnv, id: ExprId) -> Value {
             let cond = cond.expect("cond");
             let then = then.expect("then");
             let else_ = else_.expect("else");
-            match eval(e, env, cond) {
-                Value::Bool(true) => eval(e, env, then),
-                Value::Bool(false) => eval(e, env, else_),
+            match eval(e, env, cond)? {
+                Value::Bool(true) => return eval(e, env, then),
+                Value::Bool(false) => return eval(e, env, else_),
                 _ => panic!("Expected bool"),
             }
         }
+        // Types are erased, so the package is just its value
         Expr::Let {
             name,
             value,
             body,
             node: _,
+        }
+        | Expr::Unpack {
+            ty_name: _,
+            name,
+            value,
+            body,
+            node: _,
+        } => {
+            let value = eval(e, env, value.expect("value"))?;
+            let mut inner = env.bind(e, name.expect("name"), value);
+            return eval(e, &mut inner, body.expect("body"));
+        }
+        Expr::Letrec {
+            name,
+            ty: _,
+            value,
+            body,
+            node: _,
+        } => {
+            let name = var_def_to_intern(e, name.expect("name"));
+            // As `let name = fix (name: value)`, the body gets the value evaluated once,
+            // uses inside the value unroll it again
+            let inner = env.push_fix(name, value.expect("value"));
+            let value = inner.get(e, name).expect("letrec")?;
+            return eval(e, &mut inner.push(name, value), body.expect("body"));
+        }
+        Expr::Import {
+            path: _,
+            name,
+            module,
+            body,
+            node: _,
         } => {
             let name = var_def_to_intern(e, name.expect("name"));
-            let mut inner = env.push_uninit(name);
-            let value = eval(e, &mut inner, value.expect("value"));
-            let body = body.expect("body");
-            inner
-                .scope
-                .as_mut()
-                .expect("Scope")
-                .value
-                .borrow_mut()
-                .write(value);
-            eval(e, &mut inner, body)
+            let module = eval_module(e, env, module.expect("module"))?;
+            let mut inner = env.push(name, module);
+            return eval(e, &mut inner, body.expect("body"));
         }
+        Expr::TypeAlias {
+            name: _,
+            ty: _,
+            body,
+            node: _,
+        } => return eval(e, env, body.expect("body")),
+        // fix (x: body) evaluates body with x standing for fix (x: body) itself
+        Expr::Fix { arg, node: _ } => match apply_types(e, eval(e, env, arg.expect("arg"))?)? {
+            Value::Fn(_name, name, body, mut captured_scope) => {
+                return match e.get(name) {
+                    Expr::VarDef { name, .. } => captured_scope
+                        .push_fix(*name, body)
+                        .get(e, *name)
+                        .expect("fix"),
+                    _ => eval(e, &mut captured_scope, body),
+                };
+            }
+            _ => panic!("Expected function"),
+        },
+    };
+    Ok(value)
+}
+
+/// Let polymorphism instantiates type abstractions implicitly, so a function
+/// can still be behind them when it's called
+fn apply_types(e: &Exprs, mut value: Value) -> Outcome {
+    while let Value::TypeFn(_name, body, mut captured_scope) = value {
+        value = eval(e, &mut captured_scope, body)?;
     }
+    Ok(value)
+}
+
+/// Imported module is a record of the definitions at the top of its file,
+/// the expression at the end of the file is not evaluated
+fn eval_module(e: &Exprs, importer: &RunEnv, root: ExprId) -> Outcome {
+    if let Some(module) = importer.modules.borrow().get(&root) {
+        return Ok(module.clone());
+    }
+    let mut env = RunEnv {
+        scope: None,
+        store: importer.store.clone(),
+        modules: importer.modules.clone(),
+    };
+    let mut next = Some(root);
+    while let Some(id) = next {
+        next = match e.get(id) {
+            Expr::Let {
+                name, value, body, ..
+            } => {
+                let value = eval(e, &mut env, value.expect("value"))?;
+                env = env.bind(e, name.expect("name"), value);
+                *body
+            }
+            Expr::Letrec {
+                name, value, body, ..
+            } => {
+                let name = var_def_to_intern(e, name.expect("name"));
+                let inner = env.push_fix(name, value.expect("value"));
+                let value = inner.get(e, name).expect("letrec")?;
+                env = inner.push(name, value);
+                *body
+            }
+            Expr::Import {
+                name, module, body, ..
+            } => {
+                let name = var_def_to_intern(e, name.expect("name"));
+                let module = eval_module(e, &env, module.expect("module"))?;
+                env = env.push(name, module);
+                *body
+            }
+            Expr::TypeAlias { body, .. } => *body,
+            _ => None,
+        };
+    }
+    let exports = e.exports(root).into_keys().map(|label| {
+        let value = env.get(e, label).expect("export")?;
+        Ok((e.get_str(label).to_string(), value))
+    });
+    let module = Value::Record(exports.collect::<Result<_, _>>()?);
+    importer.modules.borrow_mut().insert(root, module.clone());
+    Ok(module)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ast::from_cst::{from_tree, get_tree};
+    use crate::ast::from_cst::from_files;
+    use crate::ast::imports::{load_files, SourceFile};
     use crate::diagnostics::Diagnostics;
     use crate::ir::Exprs;
     use crate::types::TypeEnv;
@@ -167,9 +746,10 @@ mod tests {
 
     #[test]
     fn eval_tests() -> test_runner::Result {
-        test_runner::test_snapshots("tests/", "eval", |input, _deps| {
-            let tree = get_tree(input);
-            let (r, exprs) = from_tree(&tree, input, "test");
+        test_runner::test_snapshots("tests/", "eval", |input, deps| {
+            let root = SourceFile::new("test", input);
+            let files = load_files(root, |path| deps.get(path).map(|dep| dep.to_string()));
+            let (r, exprs) = from_files(&files);
             let r = r.expect("Root");
             let mut diagnostics = Diagnostics::default();
             let ir = Exprs::from_ast(&exprs, r, &mut diagnostics);
@@ -178,8 +758,10 @@ mod tests {
                 return "<No eval, errors found>".into();
             }
             let mut env = RunEnv::default();
-            let res = eval(&exprs, &mut env, r);
-            format!("{res:#?}")
+            match eval(&ir, &mut env, r) {
+                Ok(res) => format!("{res:#?}"),
+                Err(error) => format!("{error}"),
+            }
         })
     }
 }
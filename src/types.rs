@@ -1,33 +1,96 @@
-use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use thiserror::Error;
 
 use crate::ast::{ExprId, InternId, SyntaxNode};
 use crate::diagnostics::Diagnostics;
-use crate::ir::{Expr, Exprs, VarId};
+use crate::ir::{var_def_to_str, Expr, Exprs, VarId};
 
 mod debug;
 pub use debug::*;
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Type {
+    /// Supertype of every type
+    Top,
+    Unit,
     Bool,
+    Nat,
+    String,
     Function(TypeId, TypeId),
+    /// {T, U, ...}
+    Product(Vec<TypeId>),
+    /// {x: T, y: U, ...}
+    Record(BTreeMap<InternId, TypeId>),
+    /// T + U
+    Sum(TypeId, TypeId),
+    /// <some: T, none: U, ...>
+    Variant(BTreeMap<InternId, TypeId>),
+    /// List T
+    List(TypeId),
+    /// Ref T
+    Ref(TypeId),
     /// For All T, U: ...
     ForAll(Vec<TypeId>, TypeId),
+    /// Rec X. T, where `X` is the `Bound` variable standing for the whole type inside `T`
+    Rec(TypeId, TypeId),
+    /// {Some X, T}, where the `Bound` variable `X` is the abstract type inside `T`
+    Exists(TypeId, TypeId),
+    /// Rigid variable bound by `Rec` or `/\`, numbered apart from `Var`s
+    Bound(usize, String),
     Var(usize),
 }
 
+/// Type of the values carried by exceptions, error codes as in TAPL 14.3
+pub const EXCEPTION: Type = Type::Nat;
+
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct TypeId(usize);
 
 #[derive(Default)]
 pub struct TypeEnv {
-    vars: BTreeMap<VarId, Option<TypeId>>,
+    vars: BTreeMap<VarId, TypeId>,
     exprs: HashMap<ExprId, TypeId>,
     types: Vec<Type>,
-    /// For Type::Var counter
-    var_counter: usize,
+    /// Index of `types`, each type is added once
+    type_ids: HashMap<Type, TypeId>,
+    /// Union-find of `Type::Var`s, indexed by their number. `Bound` variables are numbered
+    /// from it too, but they are never unified
+    type_vars: Vec<TypeVar>,
+    /// Depth of the `let` values being gathered, see `TypeVar::level`
+    level: usize,
+    /// Names of record labels, for printing
+    labels: BTreeMap<InternId, String>,
+    /// Names of types bound in the type being turned from syntax, like `X` in `Rec X. T`
+    type_names: Vec<(InternId, TypeId)>,
     constraints: Cons,
+    /// Variables which are not generalized because of the value restriction
+    weak_vars: BTreeSet<VarId>,
+    /// Uses of weak variables, solved after all other constraints
+    weak_uses: Vec<Con>,
+    /// Subtyping constraints, checked once equality constraints are solved
+    subs: Vec<Sub>,
+    /// Bodies of `let {X, x}` and their abstract type `X`, which can't be in the type of the body
+    unpacks: Vec<(ExprId, TypeId)>,
+    /// Left sides of `==` and their type, which can't have functions once it's solved
+    comparisons: Vec<(ExprId, TypeId)>,
+    /// Comparisons with each variable generalized by `let`, checked again for each instance
+    generic_comparisons: HashMap<TypeId, Vec<usize>>,
+    /// Types named by `type Name = T`, for printing
+    aliases: HashMap<TypeId, String>,
+    /// Polymorphism is only explicit, as in System F: no let polymorphism,
+    /// and polymorphic values are not instantiated implicitly
+    system_f: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TypeVar {
+    /// Type the variable is unified with, `None` while it is unknown
+    link: Option<TypeId>,
+    /// Depth of the `let` value the variable is known in, as in OCaml. A variable deeper than
+    /// the `let` being generalized is only known in its value, so it can be generalized
+    level: usize,
+    /// Unknown variables unified together are linked by rank, to keep the chains short
+    rank: usize,
 }
 
 #[derive(Eq, PartialEq, PartialOrd, Ord, Hash)]
@@ -37,9 +100,19 @@ struct Con {
     right: TypeId,
 }
 
+/// `sub` has to be a subtype of `sup`
+#[derive(PartialEq)]
+struct Sub {
+    sub: TypeId,
+    sup: TypeId,
+    node: Option<ExprId>,
+}
+
 #[derive(Default, Debug)]
 struct Cons {
     cons: VecDeque<Con>,
+    /// Pairs of types in `cons`, a constraint which is already there is not pushed again
+    pending: HashSet<(TypeId, TypeId)>,
 }
 
 impl Cons {
@@ -48,7 +121,7 @@ impl Cons {
             return;
         }
 
-        if self.cons.iter().any(|c| c.left == left && c.right == right) {
+        if !self.pending.insert((left, right)) {
             return;
         }
 
@@ -60,7 +133,9 @@ impl Cons {
     }
 
     fn pop(&mut self) -> Option<Con> {
-        self.cons.pop_front()
+        let con = self.cons.pop_front()?;
+        self.pending.remove(&(con.left, con.right));
+        Some(con)
     }
 }
 
@@ -72,11 +147,88 @@ pub enum TypeError {
     #[error("Variable `{name}` is not defined anywhere")]
     UndefinedVariable { name: String },
 
-    #[error("Use of uninitialized value: {name}")]
-    Uninitialized { name: String },
-
     #[error("Infinite type is not allowed")]
     InfiniteType,
+
+    #[error("Unknown type `{name}`")]
+    UnknownType { name: String },
+
+    #[error("Expected a tuple, found {ty}")]
+    NotATuple { ty: String },
+
+    #[error("Tuple {ty} has no element {index}")]
+    NoSuchElement { ty: String, index: usize },
+
+    #[error("Type of the tuple is not known here, consider adding a type annotation")]
+    UnknownTuple,
+
+    #[error("Expected a record, found {ty}")]
+    NotARecord { ty: String },
+
+    #[error("Type of the record is not known here, consider adding a type annotation")]
+    UnknownRecord,
+
+    #[error("Label `{label}` is defined more than once")]
+    DuplicateLabel { label: String },
+
+    #[error("Label `{label}` is missing in {ty}")]
+    MissingLabel { label: String, ty: String },
+
+    #[error("Label `{label}` is not expected in {ty}")]
+    ExtraLabel { label: String, ty: String },
+
+    #[error("Expected a variant, found {ty}")]
+    NotAVariant { ty: String },
+
+    #[error("Type of the variant is not known here, consider adding a type ascription")]
+    UnknownVariant,
+
+    #[error("Case is not exhaustive, missing {labels}")]
+    NonExhaustive { labels: String },
+
+    #[error("Arm for `{label}` is redundant")]
+    RedundantArm { label: String },
+
+    #[error("Case is incomplete, missing {parts}")]
+    IncompleteCase { parts: String },
+
+    #[error("{error}, `{name}` is not polymorphic, because its value is not a syntactic value")]
+    ValueRestriction { name: String, error: String },
+
+    #[error("{sub} is not a subtype of {sup}")]
+    NotASubtype { sub: String, sup: String },
+
+    #[error("Recursive type `{name}` has to be inside of another type, like a variant")]
+    NotContractive { name: String },
+
+    #[error("Expected a polymorphic type, found {ty}")]
+    NotPolymorphic { ty: String },
+
+    #[error("Type of the polymorphic value is not known here, consider adding a type annotation")]
+    UnknownPolymorphic,
+
+    #[error("Polymorphic {ty} has to be applied to a type first, like `f [Nat]`")]
+    NotInstantiated { ty: String },
+
+    #[error("Expected an existential type, found {ty}")]
+    NotAnExistential { ty: String },
+
+    #[error("Type of the package is not known here, consider adding a type ascription")]
+    UnknownExistential,
+
+    #[error("Abstract type `{name}` can't escape the `let` that unpacks it")]
+    EscapingType { name: String },
+
+    #[error(
+        "Type alias `{name}` refers to itself, recursive types are written as `Rec {name}. T`"
+    )]
+    RecursiveAlias { name: String },
+
+    #[error("Values of type {ty} can't be compared, functions are never equal")]
+    NotComparable { ty: String },
+
+    #[error("Values of type {ty} can't be compared, they could be functions")]
+    UnknownComparable { ty: String },
 }
 
 impl TypeEnv {
@@ -85,12 +237,26 @@ impl TypeEnv {
         let id = type_of(e, &mut env, root, diagnostics);
         (env, id)
     }
+
+    /// Same as `infer`, but polymorphic values have to be applied to types explicitly
+    pub fn infer_system_f(e: &Exprs, root: ExprId, diagnostics: &mut Diagnostics) -> (Self, Type) {
+        let mut env = TypeEnv {
+            system_f: true,
+            ..Default::default()
+        };
+        let id = type_of(e, &mut env, root, diagnostics);
+        (env, id)
+    }
 }
 
 /// Infers the type of an expression
 fn type_of(e: &Exprs, env: &mut TypeEnv, id: ExprId, diagnostics: &mut Diagnostics) -> Type {
     let type_id = gather_cons(e, env, id, diagnostics);
-    let type_id = unify(env, e, type_id, diagnostics);
+    unify(env, e, diagnostics);
+    solve_subs(env, e, diagnostics);
+    unify_weak_uses(env, e, diagnostics);
+    check_unpacks(env, e, diagnostics);
+    check_comparisons(env, e, diagnostics);
 
     env.get_type(type_id)
 }
@@ -110,7 +276,465 @@ fn maybe_gather_cons(
 /// First step of type inference - gathering constraints, and solving trivial types
 fn gather_cons(e: &Exprs, env: &mut TypeEnv, id: ExprId, diagnostics: &mut Diagnostics) -> TypeId {
     match e.get(id) {
+        Expr::Unit { node: _ } => env.set_type_for_expr(id, Type::Unit),
         Expr::Bool { value: _, node: _ } => env.set_type_for_expr(id, Type::Bool),
+        Expr::Nat { value: _, node: _ } => env.set_type_for_expr(id, Type::Nat),
+        Expr::Succ { arg, node: _ } | Expr::Pred { arg, node: _ } => {
+            let arg_id = maybe_gather_cons(e, env, arg, diagnostics);
+            let is_nat = env.add_type(Type::Nat);
+            env.constraints.push(arg_id, is_nat, *arg);
+            env.set_type_id_for_expr(id, is_nat)
+        }
+        Expr::IsZero { arg, node: _ } => {
+            let arg_id = maybe_gather_cons(e, env, arg, diagnostics);
+            let is_nat = env.add_type(Type::Nat);
+            env.constraints.push(arg_id, is_nat, *arg);
+            env.set_type_for_expr(id, Type::Bool)
+        }
+        Expr::String { value: _, node: _ } => env.set_type_for_expr(id, Type::String),
+        Expr::Concat {
+            left,
+            right,
+            node: _,
+        }
+        | Expr::StringEq {
+            left,
+            right,
+            node: _,
+        } => {
+            let left_id = maybe_gather_cons(e, env, left, diagnostics);
+            let right_id = maybe_gather_cons(e, env, right, diagnostics);
+            let is_string = env.add_type(Type::String);
+            env.constraints.push(left_id, is_string, *left);
+            env.constraints.push(right_id, is_string, *right);
+            match e.get(id) {
+                Expr::Concat { .. } => env.set_type_id_for_expr(id, is_string),
+                _ => env.set_type_for_expr(id, Type::Bool),
+            }
+        }
+        Expr::Not { arg, node: _ } => {
+            let arg_id = maybe_gather_cons(e, env, arg, diagnostics);
+            let is_bool = env.add_type(Type::Bool);
+            env.constraints.push(arg_id, is_bool, *arg);
+            env.set_type_id_for_expr(id, is_bool)
+        }
+        Expr::And {
+            left,
+            right,
+            node: _,
+        }
+        | Expr::Or {
+            left,
+            right,
+            node: _,
+        } => {
+            let left_id = maybe_gather_cons(e, env, left, diagnostics);
+            let right_id = maybe_gather_cons(e, env, right, diagnostics);
+            let is_bool = env.add_type(Type::Bool);
+            env.constraints.push(left_id, is_bool, *left);
+            env.constraints.push(right_id, is_bool, *right);
+            env.set_type_id_for_expr(id, is_bool)
+        }
+        // Both sides have the same type, structural equality as in `runtime::Value`
+        Expr::Equal {
+            left,
+            right,
+            node: _,
+        } => {
+            let left_id = maybe_gather_cons(e, env, left, diagnostics);
+            let right_id = maybe_gather_cons(e, env, right, diagnostics);
+            env.constraints.push(right_id, left_id, *right);
+            if let Some(left) = left {
+                env.comparisons.push((*left, left_id));
+            }
+            env.set_type_for_expr(id, Type::Bool)
+        }
+        Expr::Length { arg, node: _ } => {
+            let arg_id = maybe_gather_cons(e, env, arg, diagnostics);
+            let is_string = env.add_type(Type::String);
+            env.constraints.push(arg_id, is_string, *arg);
+            env.set_type_for_expr(id, Type::Nat)
+        }
+        Expr::Nil { node: _ } => {
+            let element = env.new_type_var_id();
+            env.set_type_for_expr(id, Type::List(element))
+        }
+        Expr::Cons {
+            head,
+            tail,
+            node: _,
+        } => {
+            let head_id = maybe_gather_cons(e, env, head, diagnostics);
+            let tail_id = maybe_gather_cons(e, env, tail, diagnostics);
+            // Head is checked against the tail, so that errors in `[a, b]` point to an element
+            let element = match env.get_type(tail_id) {
+                Type::List(element) => element,
+                _ => {
+                    let element = env.new_type_var_id();
+                    let list = env.add_type(Type::List(element));
+                    env.constraints.push(tail_id, list, *tail);
+                    element
+                }
+            };
+            env.constraints.push(head_id, element, *head);
+            env.set_type_for_expr(id, Type::List(element))
+        }
+        Expr::Ref { arg, node: _ } => {
+            let arg_id = maybe_gather_cons(e, env, arg, diagnostics);
+            env.set_type_for_expr(id, Type::Ref(arg_id))
+        }
+        Expr::Deref { arg, node: _ } => {
+            let arg_id = maybe_gather_cons(e, env, arg, diagnostics);
+            let element = ref_element(env, arg_id, *arg);
+            env.set_type_id_for_expr(id, element)
+        }
+        Expr::Assign {
+            target,
+            value,
+            node: _,
+        } => {
+            let target_id = maybe_gather_cons(e, env, target, diagnostics);
+            let element = ref_element(env, target_id, *target);
+            maybe_check(e, env, value, element, *value, diagnostics);
+            env.set_type_for_expr(id, Type::Unit)
+        }
+        // `error` and `raise` never return, so they fit in anywhere
+        Expr::Error { node: _ } => {
+            let never = env.new_type_var_id();
+            env.set_type_id_for_expr(id, never)
+        }
+        Expr::Raise { arg, node: _ } => {
+            let arg_id = maybe_gather_cons(e, env, arg, diagnostics);
+            let exception = env.add_type(EXCEPTION);
+            env.constraints.push(arg_id, exception, *arg);
+            let never = env.new_type_var_id();
+            env.set_type_id_for_expr(id, never)
+        }
+        Expr::Try {
+            body,
+            handler,
+            node: _,
+        } => {
+            let body_id = maybe_gather_cons(e, env, body, diagnostics);
+            let exception = env.add_type(EXCEPTION);
+            let handler_ty = env.add_type(Type::Function(exception, body_id));
+            let handler_id = maybe_gather_expected(e, env, handler, Some(handler_ty), diagnostics);
+            env.constraints.push(handler_id, handler_ty, *handler);
+            env.set_type_id_for_expr(id, body_id)
+        }
+        Expr::TypeAbs {
+            name,
+            body,
+            node: _,
+        } => {
+            let var = env.new_bound_var(name.map_or("_", |name| var_def_to_str(e, name)));
+            if let Some(name_var) = name.and_then(|name| e.get(name).binder_var()) {
+                env.set_var(name_var, var);
+            }
+            env.maybe_set_type_id_for_expr(*name, var);
+            let body = maybe_gather_cons(e, env, body, diagnostics);
+            env.set_type_for_expr(id, Type::ForAll(vec![var], body))
+        }
+        Expr::Pack {
+            ty,
+            value,
+            existential,
+            node: _,
+        } => {
+            let existential_node = existential.and_then(|existential| e.get(existential).node());
+            let ty_id = maybe_type_from_syntax(e, env, ty, diagnostics);
+            let existential_id = maybe_type_from_syntax(e, env, existential, diagnostics);
+            let existential_id = unfold(env, existential_id);
+            // The value is checked with the hidden type in place of the abstract one
+            match env.get_type(existential_id) {
+                Type::Exists(var, body) => {
+                    let expected = replace(env, var, body, ty_id);
+                    maybe_check(e, env, value, expected, *value, diagnostics);
+                }
+                ty => {
+                    maybe_gather_cons(e, env, value, diagnostics);
+                    if !matches!(ty, Type::Var(_)) {
+                        let ty = ty.debug(env).to_string();
+                        diagnostics.push(&existential_node, TypeError::NotAnExistential { ty });
+                    }
+                }
+            }
+            env.set_type_id_for_expr(id, existential_id)
+        }
+        Expr::Unpack {
+            ty_name,
+            name,
+            value,
+            body,
+            node: _,
+        } => {
+            let value_node = value.and_then(|value| e.get(value).node());
+            let value_id = maybe_gather_cons(e, env, value, diagnostics);
+            let value_id = unfold(env, value_id);
+            // Each unpacking gets its own abstract type, different from any other,
+            // a level deeper than the variables it can't escape into
+            env.level += 1;
+            let abstract_ty =
+                env.new_bound_var(ty_name.map_or("_", |ty_name| var_def_to_str(e, ty_name)));
+            if let Some(ty_var) = ty_name.and_then(|ty_name| e.get(ty_name).binder_var()) {
+                env.set_var(ty_var, abstract_ty);
+            }
+            env.maybe_set_type_id_for_expr(*ty_name, abstract_ty);
+            let element = match env.get_type(value_id) {
+                Type::Exists(var, inner) => Some(replace(env, var, inner, abstract_ty)),
+                Type::Var(_) => {
+                    diagnostics.push(&value_node, TypeError::UnknownExistential);
+                    None
+                }
+                ty => {
+                    let ty = ty.debug(env).to_string();
+                    diagnostics.push(&value_node, TypeError::NotAnExistential { ty });
+                    None
+                }
+            };
+            let element = element.unwrap_or_else(|| env.new_type_var_id());
+            if let Some(name_var) = name.and_then(|name| e.get(name).binder_var()) {
+                env.set_var(name_var, element);
+            }
+            env.maybe_set_type_id_for_expr(*name, element);
+
+            let body_id = maybe_gather_cons(e, env, body, diagnostics);
+            env.level -= 1;
+            if let Some(body) = body {
+                env.unpacks.push((*body, abstract_ty));
+            }
+            env.set_type_id_for_expr(id, body_id)
+        }
+        Expr::TypeApp { expr, ty, node: _ } => {
+            let expr_node = expr.and_then(|expr| e.get(expr).node());
+            let expr_id = maybe_gather_cons(e, env, expr, diagnostics);
+            let expr_id = unfold(env, expr_id);
+            let ty_id = maybe_type_from_syntax(e, env, ty, diagnostics);
+            // As with tuples, the quantifier has to be known before it can be applied
+            let applied = match env.get_type(expr_id) {
+                Type::ForAll(vars, body) => {
+                    // The first variable is applied, the rest stay quantified
+                    let applied = instantiate(env, &[(vars[0], ty_id)], body);
+                    match &vars[1..] {
+                        [] => Some(applied),
+                        rest => Some(env.add_type(Type::ForAll(rest.to_vec(), applied))),
+                    }
+                }
+                Type::Var(_) => {
+                    diagnostics.push(&expr_node, TypeError::UnknownPolymorphic);
+                    None
+                }
+                ty => {
+                    let ty = ty.debug(env).to_string();
+                    diagnostics.push(&expr_node, TypeError::NotPolymorphic { ty });
+                    None
+                }
+            };
+            let applied = applied.unwrap_or_else(|| env.new_type_var_id());
+            env.set_type_id_for_expr(id, applied)
+        }
+        Expr::IsNil { arg, node: _ }
+        | Expr::Head { arg, node: _ }
+        | Expr::Tail { arg, node: _ } => {
+            let arg_id = maybe_gather_cons(e, env, arg, diagnostics);
+            let element = env.new_type_var_id();
+            let list = env.add_type(Type::List(element));
+            env.constraints.push(arg_id, list, *arg);
+            match e.get(id) {
+                Expr::IsNil { .. } => env.set_type_for_expr(id, Type::Bool),
+                Expr::Head { .. } => env.set_type_id_for_expr(id, element),
+                _ => env.set_type_id_for_expr(id, list),
+            }
+        }
+        Expr::Tuple { elements, node: _ } => {
+            let elements = elements
+                .iter()
+                .map(|el| maybe_gather_cons(e, env, el, diagnostics))
+                .collect();
+            env.set_type_for_expr(id, Type::Product(elements))
+        }
+        Expr::Projection { expr, index, node } => {
+            let expr_node = expr.and_then(|expr| e.get(expr).node());
+            let expr_id = maybe_gather_cons(e, env, expr, diagnostics);
+            let expr_id = unfold(env, expr_id);
+            // Arity of the tuple has to be known before its element can be picked
+            let element = match env.get_type(expr_id) {
+                Type::Product(elements) => match index.checked_sub(1).and_then(|i| elements.get(i))
+                {
+                    Some(element) => Some(*element),
+                    None => {
+                        let ty = env.debug(expr_id).to_string();
+                        let index = *index;
+                        diagnostics.push(node, TypeError::NoSuchElement { ty, index });
+                        None
+                    }
+                },
+                Type::Var(_) => {
+                    diagnostics.push(&expr_node, TypeError::UnknownTuple);
+                    None
+                }
+                ty => {
+                    let ty = ty.debug(env).to_string();
+                    diagnostics.push(&expr_node, TypeError::NotATuple { ty });
+                    None
+                }
+            };
+            let element = element.unwrap_or_else(|| env.new_type_var_id());
+            env.set_type_id_for_expr(id, element)
+        }
+        Expr::Record { fields, node } => {
+            let fields = record_fields(e, env, fields, node, diagnostics, maybe_gather_cons);
+            env.set_type_for_expr(id, Type::Record(fields))
+        }
+        Expr::Field { expr, label, node } => {
+            let expr_node = expr.and_then(|expr| e.get(expr).node());
+            let expr_id = maybe_gather_cons(e, env, expr, diagnostics);
+            let expr_id = unfold(env, expr_id);
+            // As with tuples, labels of the record have to be known before one is picked
+            let field = match env.get_type(expr_id) {
+                Type::Record(fields) => match fields.get(label) {
+                    Some(field) => Some(*field),
+                    None => {
+                        let label = e.get_str(*label).into();
+                        let ty = env.debug(expr_id).to_string();
+                        diagnostics.push(node, TypeError::MissingLabel { label, ty });
+                        None
+                    }
+                },
+                Type::Var(_) => {
+                    diagnostics.push(&expr_node, TypeError::UnknownRecord);
+                    None
+                }
+                ty => {
+                    let ty = ty.debug(env).to_string();
+                    diagnostics.push(&expr_node, TypeError::NotARecord { ty });
+                    None
+                }
+            };
+            let field = field.unwrap_or_else(|| env.new_type_var_id());
+            env.set_type_id_for_expr(id, field)
+        }
+        Expr::Inl { arg, node: _ } => {
+            let left = maybe_gather_cons(e, env, arg, diagnostics);
+            let right = env.new_type_var_id();
+            env.set_type_for_expr(id, Type::Sum(left, right))
+        }
+        Expr::Inr { arg, node: _ } => {
+            let left = env.new_type_var_id();
+            let right = maybe_gather_cons(e, env, arg, diagnostics);
+            env.set_type_for_expr(id, Type::Sum(left, right))
+        }
+        Expr::Case {
+            expr,
+            inl,
+            inr,
+            node,
+        } => {
+            // Partial input can leave out parts of the case, which can't be evaluated
+            let missing = [
+                (expr, "the expression to match"),
+                (inl, "the `inl` arm"),
+                (inr, "the `inr` arm"),
+            ]
+            .into_iter()
+            .filter(|(part, _)| part.is_none())
+            .map(|(_, name)| name)
+            .collect::<Vec<_>>();
+            if !missing.is_empty() {
+                let parts = missing.join(", ");
+                diagnostics.push(node, TypeError::IncompleteCase { parts });
+            }
+            let expr_id = maybe_gather_cons(e, env, expr, diagnostics);
+            let expr_id = unfold(env, expr_id);
+            // When the sum is already known, binders get its types right away
+            let (left, right) = match env.get_type(expr_id) {
+                Type::Sum(left, right) => (left, right),
+                _ => {
+                    let left = env.new_type_var_id();
+                    let right = env.new_type_var_id();
+                    let sum = env.add_type(Type::Sum(left, right));
+                    env.constraints.push(expr_id, sum, *expr);
+                    (left, right)
+                }
+            };
+            let inl_id = maybe_gather_case_arm(e, env, inl, left, diagnostics);
+            let inr_id = maybe_gather_case_arm(e, env, inr, right, diagnostics);
+            env.constraints.push(inr_id, inl_id, *inr);
+            env.set_type_id_for_expr(id, inl_id)
+        }
+        Expr::Variant { .. } => gather_variant(e, env, id, None, diagnostics),
+        Expr::VariantCase { expr, arms, node } => {
+            let expr_node = expr.and_then(|expr| e.get(expr).node());
+            let expr_id = maybe_gather_cons(e, env, expr, diagnostics);
+            let labels = arms
+                .iter()
+                .flatten()
+                .map(|arm| match e.get(*arm) {
+                    Expr::VariantArm { label, .. } => *label,
+                    e => unreachable!("{e:?} is not VariantArm"),
+                })
+                .collect::<Vec<_>>();
+            let expr_id = unfold(env, expr_id);
+            let fields = match env.get_type(expr_id) {
+                Type::Variant(fields) => {
+                    let missing = fields
+                        .keys()
+                        .filter(|label| !labels.contains(label))
+                        .map(|label| format!("`{}`", env.labels[label]))
+                        .collect::<Vec<_>>();
+                    if !missing.is_empty() {
+                        let labels = missing.join(", ");
+                        diagnostics.push(node, TypeError::NonExhaustive { labels });
+                    }
+                    Some(fields)
+                }
+                // Without a known type, arms of the case are all the labels of the variant
+                Type::Var(_) => {
+                    let mut fields = BTreeMap::new();
+                    for label in labels.iter() {
+                        env.labels.insert(*label, e.get_str(*label).into());
+                        if !fields.contains_key(label) {
+                            fields.insert(*label, env.new_type_var_id());
+                        }
+                    }
+                    let variant = env.add_type(Type::Variant(fields.clone()));
+                    env.constraints.push(expr_id, variant, *expr);
+                    Some(fields)
+                }
+                ty => {
+                    let ty = ty.debug(env).to_string();
+                    diagnostics.push(&expr_node, TypeError::NotAVariant { ty });
+                    None
+                }
+            };
+
+            let mut seen = BTreeSet::new();
+            let mut case_id = None;
+            for (arm, label) in arms.iter().flatten().zip(labels) {
+                let arm_node = e.get(*arm).node();
+                let binder_ty = match fields.as_ref().map(|fields| fields.get(&label)) {
+                    Some(Some(binder_ty)) => *binder_ty,
+                    Some(None) => {
+                        let label = e.get_str(label).into();
+                        let ty = env.debug(expr_id).to_string();
+                        diagnostics.push(&arm_node, TypeError::MissingLabel { label, ty });
+                        env.new_type_var_id()
+                    }
+                    None => env.new_type_var_id(),
+                };
+                if !seen.insert(label) {
+                    let label = e.get_str(label).into();
+                    diagnostics.push_warning(&arm_node, TypeError::RedundantArm { label });
+                }
+                let arm_id = maybe_gather_case_arm(e, env, &Some(*arm), binder_ty, diagnostics);
+                match case_id {
+                    Some(case_id) => env.constraints.push(arm_id, case_id, Some(*arm)),
+                    None => case_id = Some(arm_id),
+                }
+            }
+            let case_id = case_id.unwrap_or_else(|| env.new_type_var_id());
+            env.set_type_id_for_expr(id, case_id)
+        }
         Expr::Var {
             name,
             id: var_id,
@@ -119,28 +743,60 @@ fn gather_cons(e: &Exprs, env: &mut TypeEnv, id: ExprId, diagnostics: &mut Diagn
             let type_id = env
                 .get_vars_type_id(e, diagnostics, *name, *var_id, node)
                 .unwrap_or_else(|| env.new_type_var_id());
+            let type_id = instantiate_value(env, type_id);
+            let type_id = match var_id {
+                Some(var_id) if env.weak_vars.contains(var_id) => {
+                    let use_id = env.new_type_var_id();
+                    pin_vars(env, use_id);
+                    pin_vars(env, type_id);
+                    env.weak_uses.push(Con {
+                        left: use_id,
+                        right: type_id,
+                        left_node: Some(id),
+                    });
+                    use_id
+                }
+                _ => type_id,
+            };
             env.set_type_id_for_expr(id, type_id)
         }
-        Expr::VarDef { .. } => unreachable!(),
-        Expr::Def {
-            arg: name,
-            body,
-            node: _,
-        } => {
-            let var = env.new_type_var_id();
-            let name_var = name.map(|name| e.get(name).unwrap_var_def());
-            if let Some(name_var) = name_var {
-                env.set_var(name_var, var);
-            }
-            env.maybe_set_type_id_for_expr(*name, var);
-            let ret = maybe_gather_cons(e, env, body, diagnostics);
-            env.set_type_for_expr(id, Type::Function(var, ret))
-        }
+        Expr::VarDef { .. }
+        | Expr::PatternTuple { .. }
+        | Expr::PatternRecord { .. }
+        | Expr::Wildcard { .. }
+        | Expr::CaseArm { .. }
+        | Expr::VariantArm { .. }
+        | Expr::TypeName { .. }
+        | Expr::TypeFn { .. }
+        | Expr::TypeProduct { .. }
+        | Expr::TypeRecord { .. }
+        | Expr::TypeSum { .. }
+        | Expr::TypeVariant { .. }
+        | Expr::TypeList { .. }
+        | Expr::TypeRef { .. }
+        | Expr::TypeRec { .. }
+        | Expr::TypeForAll { .. }
+        | Expr::TypeSome { .. } => unreachable!(),
+        Expr::Def { .. } => gather_def(e, env, id, None, diagnostics),
         Expr::Call { func, arg, node: _ } => {
             let func_node = func.and_then(|func| e.get(func).node());
             let func_type_id = maybe_gather_cons(e, env, func, diagnostics);
+            let func_type_id = unfold(env, func_type_id);
             let func_type = env.get_type(func_type_id);
 
+            let expected = match func_type {
+                Type::Function(from, _) => Some(from),
+                _ => None,
+            };
+            // A function with a known parameter type checks its argument against it
+            if let Some(from) = expected {
+                maybe_check(e, env, arg, from, *arg, diagnostics);
+                let to = match func_type {
+                    Type::Function(_, to) => to,
+                    _ => unreachable!(),
+                };
+                return env.set_type_id_for_expr(id, to);
+            }
             let arg_id = maybe_gather_cons(e, env, arg, diagnostics);
             let (from, to) = match func_type.clone() {
                 Type::Var(_) => {
@@ -152,9 +808,31 @@ fn gather_cons(e: &Exprs, env: &mut TypeEnv, id: ExprId, diagnostics: &mut Diagn
 
                     (arg_id, some_to)
                 }
-                poly @ Type::ForAll(_, _) => instantiate_poly(env, poly),
+                poly @ Type::ForAll(_, _) if !env.system_f && is_poly_function(env, &poly) => {
+                    instantiate_poly(env, poly)
+                }
+                Type::ForAll(_, _) if env.system_f => {
+                    let ty = func_type.debug(env).to_string();
+                    diagnostics.push(&func_node, TypeError::NotInstantiated { ty });
+                    let some_to = env.new_type_var_id();
+                    (arg_id, some_to)
+                }
                 Type::Function(from, to) => (from, to),
-                Type::Bool => {
+                Type::ForAll(_, _)
+                | Type::Top
+                | Type::Unit
+                | Type::Bool
+                | Type::Nat
+                | Type::String
+                | Type::Product(_)
+                | Type::Record(_)
+                | Type::Sum(_, _)
+                | Type::Variant(_)
+                | Type::List(_)
+                | Type::Ref(_)
+                | Type::Rec(_, _)
+                | Type::Exists(_, _)
+                | Type::Bound(_, _) => {
                     diagnostics.push(
                         &func_node,
                         TypeError::UnifyError {
@@ -170,68 +848,726 @@ fn gather_cons(e: &Exprs, env: &mut TypeEnv, id: ExprId, diagnostics: &mut Diagn
             env.constraints.push(from, arg_id, *func);
             env.set_type_id_for_expr(id, to)
         }
-        Expr::IfElse {
-            cond,
-            then,
-            else_,
-            node: _,
-        } => {
-            let then_id = maybe_gather_cons(e, env, then, diagnostics);
-            let cond_id = maybe_gather_cons(e, env, cond, diagnostics);
-            let else_id = maybe_gather_cons(e, env, else_, diagnostics);
+        Expr::IfElse {
+            cond,
+            then,
+            else_,
+            node: _,
+        } => {
+            let then_id = maybe_gather_cons(e, env, then, diagnostics);
+            let cond_id = maybe_gather_cons(e, env, cond, diagnostics);
+            let else_id = maybe_gather_cons(e, env, else_, diagnostics);
+
+            let is_bool = env.add_type(Type::Bool);
+            env.constraints.push(cond_id, is_bool, *cond);
+            env.constraints.push(else_id, then_id, *else_);
+
+            env.set_type_id_for_expr(id, then_id)
+        }
+        Expr::Let {
+            name,
+            value: value_id,
+            body: then,
+            node: _,
+        } => {
+            let name_var = name.and_then(|name| match e.get(name) {
+                Expr::VarDef { id, .. } => Some(*id),
+                _ => None,
+            });
+            // Variables of the value are a level deeper than the ones of the environment
+            env.level += 1;
+            let outer_subs = env.subs.len();
+            let outer_comparisons = env.comparisons.len();
+            let value = maybe_gather_cons(e, env, value_id, diagnostics);
+            if !env.system_f {
+                // Generalization needs the type of the value to be solved first,
+                // its subtyping constraints too, the ones from outside of it can wait
+                unify(env, e, diagnostics);
+                let outer_subs: Vec<Sub> = env.subs.drain(..outer_subs).collect();
+                solve_subs(env, e, diagnostics);
+                env.subs = outer_subs;
+            }
+            env.level -= 1;
+
+            let poly_vars = match env.system_f {
+                true => vec![],
+                false => generalizable_vars(env, value),
+            };
+            let poly_vars = match env.get_type(value) {
+                _ if poly_vars.is_empty() => poly_vars,
+                // Value restriction: `let r = ref (x: x)` is a single reference,
+                // it can't hold functions of different types
+                Type::Function(_, _) | Type::Ref(_) if !is_syntactic_value(e, *value_id) => {
+                    if let Some(name_var) = name_var {
+                        env.weak_vars.insert(name_var);
+                    }
+                    vec![]
+                }
+                _ if !is_syntactic_value(e, *value_id) => vec![],
+                _ => {
+                    env.maybe_set_type_for_expr(*value_id, Type::ForAll(poly_vars.clone(), value));
+                    poly_vars
+                }
+            };
+            if poly_vars.is_empty() {
+                // Variables that aren't generalized belong to the environment
+                lower_levels(env, value, env.level);
+            }
+            // Comparisons of values of a generalized type are checked for each instance instead
+            for index in outer_comparisons..env.comparisons.len() {
+                for var in free_vars(env, env.comparisons[index].1) {
+                    if poly_vars.contains(&var) {
+                        env.generic_comparisons.entry(var).or_default().push(index);
+                    }
+                }
+            }
+
+            if let Some(name) = name {
+                bind_pattern(e, env, *name, value, &poly_vars, *value_id, diagnostics);
+            }
+
+            let then = maybe_gather_cons(e, env, then, diagnostics);
+            env.set_type_id_for_expr(id, then)
+        }
+        Expr::Letrec {
+            name,
+            ty,
+            value,
+            body,
+            node: _,
+        } => {
+            // The annotation is the type of `name` already inside its own value
+            let ty_id = maybe_type_from_syntax(e, env, ty, diagnostics);
+            if let Some(name_var) = name.map(|name| e.get(name).unwrap_var_def()) {
+                env.set_var(name_var, ty_id);
+            }
+            env.maybe_set_type_id_for_expr(*name, ty_id);
+
+            maybe_check(e, env, value, ty_id, *value, diagnostics);
+
+            let body = maybe_gather_cons(e, env, body, diagnostics);
+            env.set_type_id_for_expr(id, body)
+        }
+        Expr::TypeAlias {
+            name,
+            ty,
+            body,
+            node: _,
+        } => {
+            let ty_id = maybe_type_from_syntax(e, env, ty, diagnostics);
+            if let Some(name_var) = name.map(|name| e.get(name).unwrap_var_def()) {
+                env.set_var(name_var, ty_id);
+            }
+            env.maybe_set_type_id_for_expr(*name, ty_id);
+
+            // `type N = Nat` doesn't rename every `Nat`, and the first alias of a type wins
+            let atomic = matches!(
+                env.get_type(ty_id),
+                Type::Top
+                    | Type::Unit
+                    | Type::Bool
+                    | Type::Nat
+                    | Type::String
+                    | Type::Var(_)
+                    | Type::Bound(_, _)
+            );
+            if let (Some(Expr::VarDef { name, .. }), false) = (name.map(|name| e.get(name)), atomic)
+            {
+                // The first name given to a type is the one it's printed with
+                env.aliases
+                    .entry(ty_id)
+                    .or_insert_with(|| e.get_str(*name).into());
+            }
+
+            let body = maybe_gather_cons(e, env, body, diagnostics);
+            env.set_type_id_for_expr(id, body)
+        }
+        Expr::Import {
+            path: _,
+            name,
+            module,
+            body,
+            node: _,
+        } => {
+            // The module is a record of the definitions at the top of the imported file
+            let module_id = match module {
+                Some(root) => {
+                    // A file imported more than once is only checked the first time
+                    if !env.exprs.contains_key(root) {
+                        gather_cons(e, env, *root, diagnostics);
+                    }
+                    let fields = e
+                        .exports(*root)
+                        .into_iter()
+                        .filter_map(|(label, var)| {
+                            env.labels.insert(label, e.get_str(label).into());
+                            Some((label, *env.vars.get(&var)?))
+                        })
+                        .collect();
+                    env.add_type(Type::Record(fields))
+                }
+                None => env.new_type_var_id(),
+            };
+            if let Some(name_var) = name.map(|name| e.get(name).unwrap_var_def()) {
+                env.set_var(name_var, module_id);
+            }
+            env.maybe_set_type_id_for_expr(*name, module_id);
+
+            let body = maybe_gather_cons(e, env, body, diagnostics);
+            env.set_type_id_for_expr(id, body)
+        }
+        Expr::Fix { arg, node: _ } => {
+            // fix: (T -> T) -> T
+            let arg_id = maybe_gather_cons(e, env, arg, diagnostics);
+            let (from, to) = match env.get_type(arg_id) {
+                poly @ Type::ForAll(_, _) if !env.system_f && is_poly_function(env, &poly) => {
+                    instantiate_poly(env, poly)
+                }
+                Type::Function(from, to) => (from, to),
+                _ => {
+                    let some_t = env.new_type_var_id();
+                    let has_to_be_function = env.add_type(Type::Function(some_t, some_t));
+                    env.constraints.push(arg_id, has_to_be_function, *arg);
+                    (some_t, some_t)
+                }
+            };
+            env.constraints.push(to, from, *arg);
+            env.set_type_id_for_expr(id, to)
+        }
+        Expr::Ascribe { expr, ty, node: _ } => {
+            // Variant gets its type from the ascription
+            if let Some(Expr::Variant { .. }) = expr.map(|expr| e.get(expr)) {
+                let ty_id = maybe_type_from_syntax(e, env, ty, diagnostics);
+                maybe_gather_expected(e, env, expr, Some(ty_id), diagnostics);
+                return env.set_type_id_for_expr(id, ty_id);
+            }
+            let ty_id = maybe_type_from_syntax(e, env, ty, diagnostics);
+
+            // Ascription can forget fields of a record, as an upcast.
+            // Constraint is attached to the annotation, so that the mismatch is reported there
+            maybe_check(e, env, expr, ty_id, *ty, diagnostics);
+            env.set_type_id_for_expr(id, ty_id)
+        }
+    }
+}
+
+/// Checking mode: the type of `id` only has to be a subtype of the `expected` type,
+/// the mismatch is reported at `node`
+fn maybe_check(
+    e: &Exprs,
+    env: &mut TypeEnv,
+    id: &Option<ExprId>,
+    expected: TypeId,
+    node: Option<ExprId>,
+    diagnostics: &mut Diagnostics,
+) -> TypeId {
+    let actual = maybe_gather_expected(e, env, id, Some(expected), diagnostics);
+    let expects_poly = matches!(env.get_type(expected), Type::ForAll(_, _));
+    let actual = match env.get_type(actual) {
+        poly @ Type::ForAll(_, _)
+            if !env.system_f && !expects_poly && is_poly_function(env, &poly) =>
+        {
+            let (from, to) = instantiate_poly(env, poly);
+            env.add_type(Type::Function(from, to))
+        }
+        _ => actual,
+    };
+    if actual != expected {
+        env.subs.push(Sub {
+            sub: actual,
+            sup: expected,
+            node,
+        });
+    }
+    actual
+}
+
+/// Abstract type of `let {X, x} = p in body` is only known inside of the body,
+/// it can't be a part of the type of the whole `let`
+fn check_unpacks(env: &mut TypeEnv, e: &Exprs, diagnostics: &mut Diagnostics) {
+    for (body, abstract_ty) in std::mem::take(&mut env.unpacks) {
+        let Some(body_ty) = env.exprs.get(&body).copied() else {
+            continue;
+        };
+        if occurs(env, abstract_ty, body_ty) {
+            let name = env.debug(abstract_ty).to_string();
+            diagnostics.push(&e.get(body).node(), TypeError::EscapingType { name });
+        }
+    }
+}
+
+fn check_comparisons(env: &mut TypeEnv, e: &Exprs, diagnostics: &mut Diagnostics) {
+    for (left, left_ty) in std::mem::take(&mut env.comparisons) {
+        let ty = || env.debug(left_ty).to_string();
+        let error = match comparable(env, left_ty, &mut vec![]) {
+            Comparable::Yes => continue,
+            Comparable::Unknown => TypeError::UnknownComparable { ty: ty() },
+            Comparable::Function => TypeError::NotComparable { ty: ty() },
+        };
+        diagnostics.push(&e.get(left).node(), error);
+    }
+}
+
+/// Ordered by how bad it is to compare the values, a function always is
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Comparable {
+    Yes,
+    /// A variable that isn't generalized, `Top` or an abstract type could be hiding a function
+    Unknown,
+    Function,
+}
+
+/// References are compared by their location, so a function behind one is fine,
+/// `rec_vars` are the variables of the enclosing `Rec`s, checked as the whole type
+fn comparable(env: &TypeEnv, ty: TypeId, rec_vars: &mut Vec<TypeId>) -> Comparable {
+    let ty = env.find(ty);
+    match env.get_type(ty) {
+        Type::Function(_, _) | Type::ForAll(_, _) => Comparable::Function,
+        // Instances of a generalized variable are checked instead
+        Type::Var(_) if env.generic_comparisons.contains_key(&ty) => Comparable::Yes,
+        Type::Top | Type::Var(_) => Comparable::Unknown,
+        Type::Bound(_, _) if rec_vars.contains(&ty) => Comparable::Yes,
+        Type::Bound(_, _) => Comparable::Unknown,
+        Type::Sum(left, right) => {
+            comparable(env, left, rec_vars).max(comparable(env, right, rec_vars))
+        }
+        Type::Product(elements) => elements
+            .iter()
+            .map(|el| comparable(env, *el, rec_vars))
+            .fold(Comparable::Yes, Comparable::max),
+        Type::Record(fields) | Type::Variant(fields) => fields
+            .values()
+            .map(|field| comparable(env, *field, rec_vars))
+            .fold(Comparable::Yes, Comparable::max),
+        Type::List(element) | Type::Exists(_, element) => comparable(env, element, rec_vars),
+        Type::Rec(var, element) => {
+            rec_vars.push(var);
+            let comparable = comparable(env, element, rec_vars);
+            rec_vars.pop();
+            comparable
+        }
+        Type::Unit | Type::Bool | Type::Nat | Type::String | Type::Ref(_) => Comparable::Yes,
+    }
+}
+
+/// Type of the value behind a reference of type `ref_id`
+fn ref_element(env: &mut TypeEnv, ref_id: TypeId, ref_node: Option<ExprId>) -> TypeId {
+    let ref_id = unfold(env, ref_id);
+    match env.get_type(ref_id) {
+        Type::Ref(element) => element,
+        _ => {
+            let element = env.new_type_var_id();
+            let reference = env.add_type(Type::Ref(element));
+            env.constraints.push(ref_id, reference, ref_node);
+            element
+        }
+    }
+}
+
+/// Names of the `let` pattern get the parts of `ty` they stand for,
+/// `let {a, b} = {0, true}` gives `Nat` to `a` and `Bool` to `b`.
+/// Each name is generalized over the variables of `poly_vars` in its own part
+fn bind_pattern(
+    e: &Exprs,
+    env: &mut TypeEnv,
+    pattern: ExprId,
+    ty: TypeId,
+    poly_vars: &[TypeId],
+    value: Option<ExprId>,
+    diagnostics: &mut Diagnostics,
+) {
+    let ty = match e.get(pattern) {
+        Expr::VarDef { id, .. } => {
+            let free = free_vars(env, ty);
+            let vars: Vec<_> = poly_vars
+                .iter()
+                .copied()
+                .filter(|var| free.contains(var))
+                .collect();
+            let ty = match vars.is_empty() {
+                true => ty,
+                false => env.add_type(Type::ForAll(vars, ty)),
+            };
+            env.set_var(*id, ty);
+            ty
+        }
+        Expr::Wildcard { .. } => ty,
+        Expr::PatternTuple { elements, node: _ } => {
+            let product = unfold(env, ty);
+            let types = match env.get_type(product) {
+                Type::Product(types) if types.len() == elements.len() => types,
+                _ => {
+                    // The pattern already tells the arity of the tuple
+                    let types: Vec<_> = elements.iter().map(|_| env.new_type_var_id()).collect();
+                    let product = env.add_type(Type::Product(types.clone()));
+                    env.constraints.push(ty, product, value);
+                    types
+                }
+            };
+            for (el, el_ty) in elements.iter().zip(types) {
+                if let Some(el) = el {
+                    bind_pattern(e, env, *el, el_ty, poly_vars, value, diagnostics);
+                }
+            }
+            ty
+        }
+        Expr::PatternRecord { fields, node } => {
+            let value_node = value.and_then(|value| e.get(value).node());
+            let record = unfold(env, ty);
+            // As with `x.y`, labels of the record have to be known before they are picked
+            let record = match env.get_type(record) {
+                Type::Record(types) => Some(types),
+                Type::Var(_) => {
+                    diagnostics.push(&value_node, TypeError::UnknownRecord);
+                    None
+                }
+                other => {
+                    let ty = other.debug(env).to_string();
+                    diagnostics.push(&value_node, TypeError::NotARecord { ty });
+                    None
+                }
+            };
+            for (label, field) in fields {
+                let field_ty = match record.as_ref().map(|types| types.get(label)) {
+                    Some(Some(field_ty)) => *field_ty,
+                    Some(None) => {
+                        let label = e.get_str(*label).into();
+                        let ty = env.debug(ty).to_string();
+                        diagnostics.push(node, TypeError::MissingLabel { label, ty });
+                        env.new_type_var_id()
+                    }
+                    None => env.new_type_var_id(),
+                };
+                if let Some(field) = field {
+                    bind_pattern(e, env, *field, field_ty, poly_vars, value, diagnostics);
+                }
+            }
+            ty
+        }
+        other => unreachable!("{:?} is not a pattern", other.debug(e)),
+    };
+    env.set_type_id_for_expr(pattern, ty);
+}
+
+/// Recursive type `Rec X. T` is the same type as `T` with `X` replaced by `Rec X. T`,
+/// other types are returned as they are
+fn unfold(env: &mut TypeEnv, mut id: TypeId) -> TypeId {
+    // Recursive types are contractive, so nested `Rec`s end
+    while let Type::Rec(var, body) = env.get_type(id) {
+        id = replace(env, var, body, id);
+    }
+    id
+}
+
+/// Syntactic values in the sense of the value restriction, evaluating them
+/// can't create a reference
+fn is_syntactic_value(e: &Exprs, id: Option<ExprId>) -> bool {
+    let Some(id) = id else {
+        return true;
+    };
+    match e.get(id) {
+        Expr::Unit { .. }
+        | Expr::Bool { .. }
+        | Expr::Nat { .. }
+        | Expr::String { .. }
+        | Expr::Nil { .. }
+        | Expr::Var { .. }
+        | Expr::Def { .. }
+        | Expr::TypeAbs { .. } => true,
+        Expr::Succ { arg, .. }
+        | Expr::Inl { arg, .. }
+        | Expr::Inr { arg, .. }
+        | Expr::Variant { value: arg, .. }
+        | Expr::Pack { value: arg, .. }
+        | Expr::Ascribe { expr: arg, .. } => is_syntactic_value(e, *arg),
+        Expr::Cons { head, tail, .. } => {
+            is_syntactic_value(e, *head) && is_syntactic_value(e, *tail)
+        }
+        // `fix (f: x: ..)` unfolds to a function
+        Expr::Fix { arg, .. } => match arg.map(|arg| e.get(arg)) {
+            Some(Expr::Def { body, .. }) => {
+                matches!(body.map(|body| e.get(body)), Some(Expr::Def { .. }))
+            }
+            _ => false,
+        },
+        Expr::Tuple { elements, .. } => elements.iter().all(|el| is_syntactic_value(e, *el)),
+        Expr::Record { fields, .. } => fields
+            .iter()
+            .all(|(_, value)| is_syntactic_value(e, *value)),
+        _ => false,
+    }
+}
+
+/// Same as `maybe_gather_cons`, but expressions that can't be inferred on their own
+/// (like variants) take the `expected` type.
+fn maybe_gather_expected(
+    e: &Exprs,
+    env: &mut TypeEnv,
+    id: &Option<ExprId>,
+    expected: Option<TypeId>,
+    diagnostics: &mut Diagnostics,
+) -> TypeId {
+    match id.map(|id| (id, e.get(id))) {
+        Some((id, Expr::Variant { .. })) => gather_variant(e, env, id, expected, diagnostics),
+        Some((id, Expr::Def { ty: None, .. })) => {
+            let expected = expected.map(|expected| unfold(env, expected));
+            let from = match expected.map(|expected| env.get_type(expected)) {
+                Some(Type::Function(from, _)) => Some(from),
+                _ => None,
+            };
+            gather_def(e, env, id, from, diagnostics)
+        }
+        _ => maybe_gather_cons(e, env, id, diagnostics),
+    }
+}
+
+fn gather_variant(
+    e: &Exprs,
+    env: &mut TypeEnv,
+    id: ExprId,
+    expected: Option<TypeId>,
+    diagnostics: &mut Diagnostics,
+) -> TypeId {
+    let Expr::Variant { label, value, node } = e.get(id) else {
+        unreachable!("{:?} is not Variant", e.get(id).debug(e))
+    };
+    let value_id = maybe_gather_cons(e, env, value, diagnostics);
+    let expected = expected.map(|expected| unfold(env, expected));
+    let variant = match expected.map(|expected| (expected, env.get_type(expected))) {
+        Some((expected, Type::Variant(fields))) => match fields.get(label) {
+            Some(field) => {
+                env.constraints.push(value_id, *field, *value);
+                Some(expected)
+            }
+            None => {
+                let label = e.get_str(*label).into();
+                let ty = env.debug(expected).to_string();
+                diagnostics.push(node, TypeError::MissingLabel { label, ty });
+                None
+            }
+        },
+        None | Some((_, Type::Var(_))) => {
+            diagnostics.push(node, TypeError::UnknownVariant);
+            None
+        }
+        Some((_, ty)) => {
+            let ty = ty.debug(env).to_string();
+            diagnostics.push(node, TypeError::NotAVariant { ty });
+            None
+        }
+    };
+    let variant = variant.unwrap_or_else(|| env.new_type_var_id());
+    env.set_type_id_for_expr(id, variant)
+}
 
-            let is_bool = env.add_type(Type::Bool);
-            env.constraints.push(cond_id, is_bool, *cond);
-            env.constraints.push(else_id, then_id, *else_);
+/// Parameter without annotation has the type `from`, when it's known from the context,
+/// otherwise the type is inferred
+fn gather_def(
+    e: &Exprs,
+    env: &mut TypeEnv,
+    id: ExprId,
+    from: Option<TypeId>,
+    diagnostics: &mut Diagnostics,
+) -> TypeId {
+    let Expr::Def {
+        arg: name,
+        ty,
+        body,
+        node: _,
+    } = e.get(id)
+    else {
+        unreachable!("{:?} is not Def", e.get(id).debug(e))
+    };
+    let var = match from {
+        Some(from) if ty.is_none() => from,
+        _ => maybe_type_from_syntax(e, env, ty, diagnostics),
+    };
+    if let Some(name_var) = name.and_then(|name| e.get(name).binder_var()) {
+        env.set_var(name_var, var);
+    }
+    env.maybe_set_type_id_for_expr(*name, var);
+    let ret = maybe_gather_cons(e, env, body, diagnostics);
+    env.set_type_for_expr(id, Type::Function(var, ret))
+}
 
-            env.set_type_id_for_expr(id, then_id)
+/// Binder of the arm has type `binder_ty`, the arm has type of its body
+fn maybe_gather_case_arm(
+    e: &Exprs,
+    env: &mut TypeEnv,
+    arm: &Option<ExprId>,
+    binder_ty: TypeId,
+    diagnostics: &mut Diagnostics,
+) -> TypeId {
+    let Some(arm) = *arm else {
+        return env.new_type_var_id();
+    };
+    match e.get(arm) {
+        Expr::CaseArm {
+            binder,
+            body,
+            node: _,
         }
-        Expr::Let {
-            name,
-            value: value_id,
-            body: then,
+        | Expr::VariantArm {
+            label: _,
+            binder,
+            body,
             node: _,
         } => {
-            let name_var = name.map(|name| e.get(name).unwrap_var_def());
-            if let Some(name_var) = name_var {
-                env.new_var(name_var);
+            if let Some(binder_var) = binder.and_then(|binder| e.get(binder).binder_var()) {
+                env.set_var(binder_var, binder_ty);
             }
+            env.maybe_set_type_id_for_expr(*binder, binder_ty);
+            let body = maybe_gather_cons(e, env, body, diagnostics);
+            env.set_type_id_for_expr(arm, body)
+        }
+        e => unreachable!("{e:?} is not an arm of case"),
+    }
+}
 
-            let value = maybe_gather_cons(e, env, value_id, diagnostics);
+fn maybe_type_from_syntax(
+    e: &Exprs,
+    env: &mut TypeEnv,
+    id: &Option<ExprId>,
+    diagnostics: &mut Diagnostics,
+) -> TypeId {
+    match id {
+        Some(id) => type_from_syntax(e, env, *id, diagnostics),
+        None => env.new_type_var_id(),
+    }
+}
 
-            let value_type = env.get_type(value);
-            let value = match value_type {
-                Type::Function(from, to) => {
-                    let poly_var = [from, to]
-                        .into_iter()
-                        .flat_map(|t| collect_vars(env, t))
-                        .collect::<BTreeSet<_>>();
-
-                    if poly_var.is_empty() {
-                        value
-                    } else {
-                        env.maybe_set_type_for_expr(
-                            *value_id,
-                            Type::ForAll(poly_var.into_iter().collect(), value),
-                        )
+/// Turns type written by the user (for example in ascription) into a type
+fn type_from_syntax(
+    e: &Exprs,
+    env: &mut TypeEnv,
+    id: ExprId,
+    diagnostics: &mut Diagnostics,
+) -> TypeId {
+    match e.get(id) {
+        Expr::TypeName { name, id, node } => {
+            // Innermost binding of the name wins
+            if let Some((_, ty)) = env.type_names.iter().rev().find(|(bound, _)| bound == name) {
+                return *ty;
+            }
+            if let Some(var) = id {
+                // The alias has no type yet only inside its own definition
+                return match env.vars.get(var) {
+                    Some(ty) => *ty,
+                    None => {
+                        let name = e.get_str(*name).into();
+                        diagnostics.push(node, TypeError::RecursiveAlias { name });
+                        env.new_type_var_id()
                     }
+                };
+            }
+            match e.get_str(*name) {
+                "Top" => env.add_type(Type::Top),
+                "Unit" => env.add_type(Type::Unit),
+                "Bool" => env.add_type(Type::Bool),
+                "Nat" => env.add_type(Type::Nat),
+                "String" => env.add_type(Type::String),
+                name => {
+                    let name = name.into();
+                    diagnostics.push(node, TypeError::UnknownType { name });
+                    env.new_type_var_id()
                 }
-                _ => value,
-            };
-
-            env.maybe_set_type_id_for_expr(*name, value);
-            if let Some(name_var) = name_var {
-                env.set_var(name_var, value);
             }
+        }
+        Expr::TypeFn { from, to, node: _ } => {
+            let from = maybe_type_from_syntax(e, env, from, diagnostics);
+            let to = maybe_type_from_syntax(e, env, to, diagnostics);
+            env.add_type(Type::Function(from, to))
+        }
+        Expr::TypeProduct { elements, node: _ } => {
+            let elements = elements
+                .iter()
+                .map(|el| maybe_type_from_syntax(e, env, el, diagnostics))
+                .collect();
+            env.add_type(Type::Product(elements))
+        }
+        Expr::TypeSum {
+            left,
+            right,
+            node: _,
+        } => {
+            let left = maybe_type_from_syntax(e, env, left, diagnostics);
+            let right = maybe_type_from_syntax(e, env, right, diagnostics);
+            env.add_type(Type::Sum(left, right))
+        }
+        Expr::TypeRecord { fields, node } => {
+            let fields = record_fields(e, env, fields, node, diagnostics, maybe_type_from_syntax);
+            env.add_type(Type::Record(fields))
+        }
+        Expr::TypeVariant { fields, node } => {
+            let fields = record_fields(e, env, fields, node, diagnostics, maybe_type_from_syntax);
+            env.add_type(Type::Variant(fields))
+        }
+        Expr::TypeList { element, node: _ } => {
+            let element = maybe_type_from_syntax(e, env, element, diagnostics);
+            env.add_type(Type::List(element))
+        }
+        Expr::TypeRef { ty, node: _ } => {
+            let ty = maybe_type_from_syntax(e, env, ty, diagnostics);
+            env.add_type(Type::Ref(ty))
+        }
+        Expr::TypeForAll { name, ty, node: _ } => {
+            let var = env.new_bound_var(e.get_str(*name));
+            env.type_names.push((*name, var));
+            let body = maybe_type_from_syntax(e, env, ty, diagnostics);
+            env.type_names.pop();
+            env.add_type(Type::ForAll(vec![var], body))
+        }
+        Expr::TypeSome { name, ty, node: _ } => {
+            let var = env.new_bound_var(e.get_str(*name));
+            env.type_names.push((*name, var));
+            let body = maybe_type_from_syntax(e, env, ty, diagnostics);
+            env.type_names.pop();
+            env.add_type(Type::Exists(var, body))
+        }
+        Expr::TypeRec { name, ty, node } => {
+            let var = env.new_bound_var(e.get_str(*name));
+            env.type_names.push((*name, var));
+            let body = maybe_type_from_syntax(e, env, ty, diagnostics);
+            env.type_names.pop();
+
+            // `Rec X. X` could be unfolded forever without getting anywhere
+            let mut inner = body;
+            while let Type::Rec(_, inner_body) = env.get_type(inner) {
+                inner = inner_body;
+            }
+            if let Type::Bound(_, _) = env.get_type(inner) {
+                let name = e.get_str(*name).into();
+                diagnostics.push(node, TypeError::NotContractive { name });
+                return env.new_type_var_id();
+            }
+            env.add_type(Type::Rec(var, body))
+        }
+        e => unreachable!("{e:?} is not a type"),
+    }
+}
 
-            let then = maybe_gather_cons(e, env, then, diagnostics);
-            env.set_type_id_for_expr(id, then)
+/// Types of record fields (or of a record type), where `type_of` gives a type to each field
+fn record_fields(
+    e: &Exprs,
+    env: &mut TypeEnv,
+    fields: &[(InternId, Option<ExprId>)],
+    node: &Option<SyntaxNode>,
+    diagnostics: &mut Diagnostics,
+    type_of: fn(&Exprs, &mut TypeEnv, &Option<ExprId>, &mut Diagnostics) -> TypeId,
+) -> BTreeMap<InternId, TypeId> {
+    let mut types = BTreeMap::new();
+    for (label, field) in fields {
+        let ty = type_of(e, env, field, diagnostics);
+        env.labels.insert(*label, e.get_str(*label).into());
+        if types.insert(*label, ty).is_some() {
+            let label = e.get_str(*label).into();
+            diagnostics.push(node, TypeError::DuplicateLabel { label });
         }
     }
+    types
 }
 
-/// For let polymorphism, we want to see if the function takes generic argument.
-/// If its signature has `Type::Var(_)`, then we add it to the list of generics.
+/// For let polymorphism, we want to see which variables of a type are generic.
 /// In Rust syntax:
 /// We want to transform:
 ///
@@ -243,42 +1579,199 @@ fn gather_cons(e: &Exprs, env: &mut TypeEnv, id: ExprId, diagnostics: &mut Diagn
 /// fn foo<T0, T1>(a: T0, b: T1) -> T1
 /// ```
 ///
-/// And this is the step where we collect all `?Tn` variables.
-fn collect_vars(env: &TypeEnv, id: TypeId) -> Vec<TypeId> {
-    let mut vars: HashMap<usize, TypeId> = Default::default();
-    let mut queue: VecDeque<TypeId> = Default::default();
-    queue.push_back(id);
-
-    while let Some(id) = queue.pop_front() {
-        match env.get_type(id) {
-            Type::Bool => (),
-            Type::Function(from, to) => {
-                queue.push_back(from);
-                queue.push_back(to);
+/// And this is the step where we collect all `?Tn` variables, except the ones a quantifier binds.
+fn free_vars(env: &TypeEnv, id: TypeId) -> BTreeSet<TypeId> {
+    let id = env.find(id);
+    match env.get_type(id) {
+        Type::Top | Type::Unit | Type::Bool | Type::Nat | Type::String | Type::Bound(_, _) => {
+            BTreeSet::new()
+        }
+        Type::Function(from, to) | Type::Sum(from, to) => {
+            let mut vars = free_vars(env, from);
+            vars.extend(free_vars(env, to));
+            vars
+        }
+        Type::Product(elements) => elements.iter().flat_map(|el| free_vars(env, *el)).collect(),
+        Type::Record(fields) | Type::Variant(fields) => fields
+            .values()
+            .flat_map(|field| free_vars(env, *field))
+            .collect(),
+        Type::List(element) | Type::Ref(element) => free_vars(env, element),
+        Type::Rec(_, body) | Type::Exists(_, body) => free_vars(env, body),
+        Type::ForAll(bound, body) => {
+            let mut vars = free_vars(env, body);
+            for var in bound {
+                vars.remove(&env.find(var));
+            }
+            vars
+        }
+        Type::Var(_) => BTreeSet::from([id]),
+    }
+}
+
+/// Variables of `ty` which `let` generalizes: the ones deeper than the current level,
+/// which only the value knows. Unlike in Algorithm W, the environment is not searched for them.
+/// For example in `f: let g = y: f y; g` the type of `g` is `?T1 -> ?T2`, but `f` is
+/// `?T1 -> ?T2` as well, so its variables are at the level of `f`.
+fn generalizable_vars(env: &TypeEnv, ty: TypeId) -> Vec<TypeId> {
+    free_vars(env, ty)
+        .into_iter()
+        .filter(|var| env.var_level(*var) > env.level)
+        .collect()
+}
+
+/// Uses of weak variables are solved after all other constraints,
+/// variables of `ty` waiting for them are never generalized
+fn pin_vars(env: &mut TypeEnv, ty: TypeId) {
+    lower_levels(env, ty, 0);
+}
+
+/// Each use of a weak variable is solved on its own, after everything else.
+/// So when uses need different types, the error points at the use that conflicts.
+fn unify_weak_uses(env: &mut TypeEnv, e: &Exprs, diagnostics: &mut Diagnostics) {
+    for Con {
+        left,
+        right,
+        left_node,
+    } in std::mem::take(&mut env.weak_uses)
+    {
+        env.constraints.push(left, right, left_node);
+        let mut errors = Diagnostics::default();
+        unify(env, e, &mut errors);
+
+        let Some(Expr::Var { name, node, .. }) = left_node.map(|id| e.get(id)) else {
+            continue;
+        };
+        for error in errors.iter() {
+            let name = e.get_str(*name).into();
+            let error = error.message.node.clone();
+            diagnostics.push(node, TypeError::ValueRestriction { name, error });
+        }
+    }
+}
+
+/// Third step of type inference, subtyping constraints are checked once the types are known.
+/// A type still unknown after all the others has to be equal instead.
+fn solve_subs(env: &mut TypeEnv, e: &Exprs, diagnostics: &mut Diagnostics) {
+    let is_var = |env: &TypeEnv, id| matches!(env.get_type(id), Type::Var(_));
+    let is_rec = |env: &TypeEnv, id| matches!(env.get_type(id), Type::Rec(_, _));
+    // As in `unify`, recursive types already being compared are assumed to be subtypes
+    let mut assumed = BTreeSet::new();
+    let mut subs: VecDeque<Sub> = std::mem::take(&mut env.subs).into();
+    // Checking known types doesn't make any variable known,
+    // so the ones waiting for a variable keep waiting until all known ones are checked
+    let mut waiting = VecDeque::new();
+    loop {
+        while let Some(Sub { sub, sup, node }) = subs.pop_front() {
+            if is_var(env, sub) || is_var(env, sup) {
+                waiting.push_back(Sub { sub, sup, node });
+                continue;
             }
-            Type::ForAll(_, _) => (),
-            Type::Var(var_id) => {
-                vars.insert(var_id, id);
+            if (is_rec(env, sub) || is_rec(env, sup)) && !assumed.insert((sub, sup)) {
+                continue;
             }
+            match subtype(env, sub, sup) {
+                Ok(parts) => {
+                    subs.extend(parts.into_iter().map(|(sub, sup)| Sub { sub, sup, node }))
+                }
+                Err(error) => {
+                    let node = node.and_then(|node| e.get(node).node());
+                    diagnostics.push(&node, error);
+                }
+            }
+        }
+        let Some(Sub { sub, sup, node }) = waiting.pop_front() else {
+            break;
+        };
+        // An earlier equality may have made it known
+        if !is_var(env, sub) && !is_var(env, sup) {
+            subs.push_back(Sub { sub, sup, node });
+            continue;
         }
+        env.constraints.push(sub, sup, node);
+        unify(env, e, diagnostics);
     }
-    vars.into_values().collect()
 }
 
-/// Second step of type inference.
-fn unify(
+/// Checks that `sub` is a subtype of `sup` as far as their outermost types go,
+/// returning the pairs of inner types that have to be subtypes too.
+/// Types are compared by TAPL chapter 15 rules:
+/// records by width, depth and permutation, functions with contravariant arguments.
+fn subtype(
     env: &mut TypeEnv,
-    e: &Exprs,
-    mut root_id: TypeId,
-    diagnostics: &mut Diagnostics,
-) -> TypeId {
+    sub: TypeId,
+    sup: TypeId,
+) -> Result<Vec<(TypeId, TypeId)>, TypeError> {
+    let not_a_subtype = || TypeError::NotASubtype {
+        sub: env.debug(sub).to_string(),
+        sup: env.debug(sup).to_string(),
+    };
+    let sub = env.find(sub);
+    let sup = env.find(sup);
+    if sub == sup {
+        return Ok(vec![]);
+    }
+    match (env.get_type(sub), env.get_type(sup)) {
+        (_, Type::Top) => Ok(vec![]),
+        (Type::Rec(_, _), _) | (_, Type::Rec(_, _)) => {
+            Ok(vec![(unfold(env, sub), unfold(env, sup))])
+        }
+        (Type::ForAll(vars_a, a), Type::ForAll(vars_b, b)) if vars_a.len() == vars_b.len() => {
+            Ok(vec![rename_bound(env, vars_a, a, vars_b, b)])
+        }
+        (Type::Exists(var_a, a), Type::Exists(var_b, b)) => {
+            Ok(vec![rename_bound(env, vec![var_a], a, vec![var_b], b)])
+        }
+        (Type::Function(from_a, to_a), Type::Function(from_b, to_b)) => {
+            Ok(vec![(from_b, from_a), (to_a, to_b)])
+        }
+        (Type::Sum(left_a, right_a), Type::Sum(left_b, right_b)) => {
+            Ok(vec![(left_a, left_b), (right_a, right_b)])
+        }
+        (Type::Product(a), Type::Product(b)) if a.len() == b.len() => {
+            Ok(a.into_iter().zip(b).collect())
+        }
+        (Type::List(a), Type::List(b)) => Ok(vec![(a, b)]),
+        // A reference can be read and written, so its element can't change
+        (Type::Ref(a), Type::Ref(b)) => Ok(vec![(a, b), (b, a)]),
+        (Type::Record(a), Type::Record(b)) => b
+            .into_iter()
+            .map(|(label, b_ty)| match a.get(&label) {
+                Some(a_ty) => Ok((*a_ty, b_ty)),
+                None => Err(TypeError::MissingLabel {
+                    label: env.labels[&label].clone(),
+                    ty: env.debug(sub).to_string(),
+                }),
+            })
+            .collect(),
+        // Variant with fewer labels fits in one with more of them
+        (Type::Variant(a), Type::Variant(b)) => a
+            .into_iter()
+            .map(|(label, a_ty)| match b.get(&label) {
+                Some(b_ty) => Ok((a_ty, *b_ty)),
+                None => Err(TypeError::ExtraLabel {
+                    label: env.labels[&label].clone(),
+                    ty: env.debug(sup).to_string(),
+                }),
+            })
+            .collect(),
+        _ => Err(not_a_subtype()),
+    }
+}
+
+/// Second step of type inference.
+fn unify(env: &mut TypeEnv, e: &Exprs, diagnostics: &mut Diagnostics) {
     let mut cons = std::mem::take(&mut env.constraints);
+    // Pairs of recursive types which are being compared already
+    let mut assumed = BTreeSet::new();
     while let Some(Con {
         left,
         right,
         left_node,
     }) = cons.pop()
     {
+        let left = env.find_mut(left);
+        let right = env.find_mut(right);
         if left == right {
             continue;
         }
@@ -287,28 +1780,61 @@ fn unify(
         let left_n = left_node.and_then(|left_node| e.get(left_node).node());
 
         match (l, r) {
-            (Type::Var(_), _r) => replace_all(
-                env,
-                left,
-                &left_n,
-                right,
-                &mut cons,
-                &mut root_id,
-                diagnostics,
-            ),
-            (_l, Type::Var(_)) => replace_all(
-                env,
-                right,
-                &left_n,
-                left,
-                &mut cons,
-                &mut root_id,
-                diagnostics,
-            ),
-            (Type::Function(fr_a, to_a), Type::Function(fr_b, to_b)) => {
+            (Type::Var(_), _r) => bind_var(env, left, &left_n, right, diagnostics),
+            (_l, Type::Var(_)) => bind_var(env, right, &left_n, left, diagnostics),
+            // Equi-recursive types are equal to their unfolding. When the same pair comes
+            // back around the cycle, the types are assumed to be equal, instead of an infinite type
+            (Type::Rec(_, _), _) | (_, Type::Rec(_, _)) => {
+                if assumed.insert((left, right)) {
+                    let left = unfold(env, left);
+                    let right = unfold(env, right);
+                    cons.push(left, right, left_node);
+                }
+            }
+            (Type::Function(fr_a, to_a), Type::Function(fr_b, to_b))
+            | (Type::Sum(fr_a, to_a), Type::Sum(fr_b, to_b)) => {
                 cons.push(fr_a, fr_b, left_node);
                 cons.push(to_a, to_b, left_node);
             }
+            (Type::List(a), Type::List(b)) | (Type::Ref(a), Type::Ref(b)) => {
+                cons.push(a, b, left_node)
+            }
+            (Type::ForAll(vars_a, a), Type::ForAll(vars_b, b)) if vars_a.len() == vars_b.len() => {
+                let (a, b) = rename_bound(env, vars_a, a, vars_b, b);
+                cons.push(a, b, left_node);
+            }
+            (Type::Exists(var_a, a), Type::Exists(var_b, b)) => {
+                let (a, b) = rename_bound(env, vec![var_a], a, vec![var_b], b);
+                cons.push(a, b, left_node);
+            }
+            (Type::Product(a), Type::Product(b)) if a.len() == b.len() => {
+                for (a, b) in a.into_iter().zip(b) {
+                    cons.push(a, b, left_node);
+                }
+            }
+            (Type::Record(a), Type::Record(b)) | (Type::Variant(a), Type::Variant(b)) => {
+                for (label, a_ty) in a.iter() {
+                    match b.get(label) {
+                        Some(b_ty) => cons.push(*a_ty, *b_ty, left_node),
+                        None => diagnostics.push(
+                            &left_n,
+                            TypeError::ExtraLabel {
+                                label: env.labels[label].clone(),
+                                ty: env.debug(right).to_string(),
+                            },
+                        ),
+                    }
+                }
+                for label in b.keys().filter(|label| !a.contains_key(label)) {
+                    diagnostics.push(
+                        &left_n,
+                        TypeError::MissingLabel {
+                            label: env.labels[label].clone(),
+                            ty: env.debug(left).to_string(),
+                        },
+                    );
+                }
+            }
             (l, r) => {
                 diagnostics.push(
                     &left_n,
@@ -320,7 +1846,6 @@ fn unify(
             }
         }
     }
-    root_id
 }
 
 /// Whenever a polymorphic (via. let polymorphism) function is called, we want to instantiate it into separate function
@@ -334,94 +1859,261 @@ fn unify(
 fn instantiate_poly(env: &mut TypeEnv, poly: Type) -> (TypeId, TypeId) {
     match poly.clone() {
         Type::ForAll(vars, poly_type) => match env.get_type(poly_type) {
-            Type::ForAll(_, _) => panic!("Higher order polymorphism is not supported"),
-
-            Type::Function(from, to) => instantiate(env, vars, from, to),
-            Type::Bool | Type::Var(_) => panic!("Expected function"),
+            // Nested quantifiers of System F, `All X. All Y. T`, are instantiated at once
+            Type::ForAll(inner_vars, inner) => {
+                instantiate_poly(env, Type::ForAll([vars, inner_vars].concat(), inner))
+            }
+            Type::Function(_, _) => {
+                let fresh = vars
+                    .into_iter()
+                    .rev()
+                    .map(|var| (var, env.new_type_var_id()))
+                    .collect::<Vec<_>>();
+                let function = instantiate(env, &fresh, poly_type);
+                match env.get_type(function) {
+                    Type::Function(from, to) => (from, to),
+                    _ => unreachable!(),
+                }
+            }
+            Type::Top
+            | Type::Unit
+            | Type::Bool
+            | Type::Nat
+            | Type::String
+            | Type::Product(_)
+            | Type::Record(_)
+            | Type::Sum(_, _)
+            | Type::Variant(_)
+            | Type::List(_)
+            | Type::Ref(_)
+            | Type::Rec(_, _)
+            | Type::Exists(_, _)
+            | Type::Bound(_, _)
+            | Type::Var(_) => panic!("Expected function"),
         },
         _ => unreachable!(),
     }
 }
 
-fn instantiate(
-    env: &mut TypeEnv,
-    vars: Vec<TypeId>,
-    mut from: TypeId,
-    mut to: TypeId,
-) -> (TypeId, TypeId) {
+/// Polymorphic values of `let` other than functions are instantiated where they are used,
+/// `let n = nil` gives a `List` of its own element type to each use of `n`.
+/// Functions are instantiated where they are called instead
+fn instantiate_value(env: &mut TypeEnv, id: TypeId) -> TypeId {
+    match env.get_type(id) {
+        // `All X. T` of System F is instantiated by a type application instead
+        Type::ForAll(vars, ty)
+            if !env.system_f
+                && !is_poly_function(env, &env.get_type(ty))
+                && vars
+                    .iter()
+                    .all(|var| matches!(env.get_type(*var), Type::Var(_))) =>
+        {
+            let fresh = vars
+                .into_iter()
+                .rev()
+                .map(|var| (var, env.new_type_var_id()))
+                .collect::<Vec<_>>();
+            instantiate(env, &fresh, ty)
+        }
+        _ => id,
+    }
+}
+
+/// Polymorphic function, possibly under more than one quantifier
+fn is_poly_function(env: &TypeEnv, ty: &Type) -> bool {
+    match ty {
+        Type::ForAll(_, inner) => is_poly_function(env, &env.get_type(*inner)),
+        Type::Function(_, _) => true,
+        _ => false,
+    }
+}
+
+/// Replaces each variable of `fresh` in `ty` with its new type, as well as in the constraints
+/// gathered so far, so that variables related to the replaced ones are instantiated too
+fn instantiate(env: &mut TypeEnv, fresh: &[(TypeId, TypeId)], mut ty: TypeId) -> TypeId {
+    for &(var, new_var_id) in fresh.iter() {
+        ty = replace(env, var, ty, new_var_id);
+    }
+
+    // Comparisons in the polymorphic value are checked for this instance too
+    let comparisons: BTreeSet<usize> = fresh
+        .iter()
+        .filter_map(|(var, _)| env.generic_comparisons.get(var))
+        .flatten()
+        .copied()
+        .collect();
+    for index in comparisons {
+        let (left, mut compared) = env.comparisons[index];
+        for &(var, new_var_id) in fresh.iter() {
+            compared = replace(env, var, compared, new_var_id);
+        }
+        env.comparisons.push((left, compared));
+    }
+
+    // Variables generalized by `let` are solved before, no constraint has them anymore.
+    // Constraints gathered in the body of `/\X.` may still have `X`
+    let is_bound = |env: &TypeEnv, var| matches!(env.get_type(var), Type::Bound(_, _));
+    if !fresh.iter().any(|(var, _)| is_bound(env, *var)) {
+        return ty;
+    }
+
     // Limitations of borrow checker, we can't replace types, while iterating over constraints.
     // So temporairly we move constraints out of `env`
     let mut cons = std::mem::take(&mut env.constraints);
     let mut new_cons = vec![];
 
-    for var in vars.into_iter().rev() {
-        let new_var = env.new_type_var();
-        let new_var_id = env.add_type(new_var);
-        from = replace(env, var, from, new_var_id);
-        to = replace(env, var, to, new_var_id);
-
-        for c in cons.cons.iter() {
-            let maybe_new_cons = Con {
-                left_node: c.left_node,
-                left: replace(env, var, c.left, new_var_id),
-                right: replace(env, var, c.right, new_var_id),
-            };
-            if c != &maybe_new_cons {
-                new_cons.push(maybe_new_cons);
-            }
+    // All variables are replaced at once, a constraint may mention more of them
+    for c in cons.cons.iter() {
+        let mut maybe_new_cons = Con {
+            left_node: c.left_node,
+            left: c.left,
+            right: c.right,
+        };
+        for &(var, new_var_id) in fresh.iter() {
+            maybe_new_cons.left = replace(env, var, maybe_new_cons.left, new_var_id);
+            maybe_new_cons.right = replace(env, var, maybe_new_cons.right, new_var_id);
+        }
+        if c != &maybe_new_cons {
+            new_cons.push(maybe_new_cons);
         }
     }
 
-    cons.cons.extend(new_cons);
+    for c in new_cons {
+        cons.push(c.left, c.right, c.left_node);
+    }
     env.constraints = cons;
 
-    (from, to)
+    ty
+}
+
+/// `All X. T` and `All Y. U` are compared with both `X` and `Y` renamed to the same variable
+fn rename_bound(
+    env: &mut TypeEnv,
+    vars_a: Vec<TypeId>,
+    mut a: TypeId,
+    vars_b: Vec<TypeId>,
+    mut b: TypeId,
+) -> (TypeId, TypeId) {
+    for (var_a, var_b) in vars_a.into_iter().zip(vars_b) {
+        let name = env.print_type_id(var_a);
+        let var = env.new_bound_var(&name);
+        a = replace(env, var_a, a, var);
+        b = replace(env, var_b, b, var);
+    }
+    (a, b)
 }
 
-fn replace_all(
+/// Unifies the unknown variable `var` with `ty` by linking it in the union-find.
+/// Variables of `ty` are lowered to the level of `var`: being known where `var` is,
+/// they can't be generalized in a deeper `let`
+fn bind_var(
     env: &mut TypeEnv,
-    all_occurrences: TypeId,
-    all_occurrences_node: &Option<SyntaxNode>,
-    with: TypeId,
-    cons: &mut Cons,
-    root_id: &mut TypeId,
+    var: TypeId,
+    var_node: &Option<SyntaxNode>,
+    ty: TypeId,
     diagnostics: &mut Diagnostics,
 ) {
-    if occurs(env, all_occurrences, with) {
-        diagnostics.push(all_occurrences_node, TypeError::InfiniteType);
+    let Type::Var(index) = env.types[var.0] else {
+        unreachable!("{:?} is not a variable", env.debug(var))
+    };
+    if let Type::Var(other) = env.types[ty.0] {
+        let level = env.type_vars[index].level.min(env.type_vars[other].level);
+        let (child, root, root_id) = match env.type_vars[index].rank <= env.type_vars[other].rank {
+            true => (index, other, ty),
+            false => (other, index, var),
+        };
+        env.type_vars[child].link = Some(root_id);
+        env.type_vars[root].level = level;
+        if env.type_vars[child].rank == env.type_vars[root].rank {
+            env.type_vars[root].rank += 1;
+        }
         return;
     }
 
-    for c in cons.cons.iter_mut() {
-        c.left = replace(env, all_occurrences, c.left, with);
-        c.right = replace(env, all_occurrences, c.right, with);
+    if occurs(env, var, ty) {
+        diagnostics.push(var_node, TypeError::InfiniteType);
+        return;
     }
+    let level = env.type_vars[index].level;
+    if let Some(abstract_ty) = escaping_type(env, ty, level) {
+        let name = env.debug(abstract_ty).to_string();
+        diagnostics.push(var_node, TypeError::EscapingType { name });
+        return;
+    }
+    lower_levels(env, ty, level);
+    env.type_vars[index].link = Some(ty);
+}
 
-    let mut exprs = std::mem::take(&mut env.exprs);
-
-    for (_e_id, type_id) in exprs.iter_mut() {
-        *type_id = replace(env, all_occurrences, *type_id, with);
+/// Abstract type of an unpacking in `ty` that is deeper than `level`,
+/// the variable it would be bound to lives outside of the unpacking
+fn escaping_type(env: &TypeEnv, ty: TypeId, level: usize) -> Option<TypeId> {
+    let ty = env.find(ty);
+    match env.get_type(ty) {
+        Type::Bound(index, _) if env.type_vars[index].level > level => env
+            .unpacks
+            .iter()
+            .any(|(_, abstract_ty)| *abstract_ty == ty)
+            .then_some(ty),
+        Type::Top
+        | Type::Unit
+        | Type::Bool
+        | Type::Nat
+        | Type::String
+        | Type::Var(_)
+        | Type::Bound(_, _) => None,
+        Type::Function(left, right) | Type::Sum(left, right) => {
+            escaping_type(env, left, level).or_else(|| escaping_type(env, right, level))
+        }
+        Type::Product(elements) => elements
+            .iter()
+            .find_map(|el| escaping_type(env, *el, level)),
+        Type::List(element)
+        | Type::Ref(element)
+        | Type::Rec(_, element)
+        | Type::Exists(_, element)
+        | Type::ForAll(_, element) => escaping_type(env, element, level),
+        Type::Record(fields) | Type::Variant(fields) => fields
+            .values()
+            .find_map(|field| escaping_type(env, *field, level)),
     }
-    env.exprs = exprs;
+}
 
-    *root_id = replace(env, all_occurrences, *root_id, with);
+/// Variables of `ty` get at most `level`
+fn lower_levels(env: &mut TypeEnv, ty: TypeId, level: usize) {
+    for var in free_vars(env, ty) {
+        if let Type::Var(index) = env.types[var.0] {
+            let var = &mut env.type_vars[index];
+            var.level = var.level.min(level);
+        }
+    }
 }
 
 fn occurs(env: &mut TypeEnv, ty: TypeId, inside: TypeId) -> bool {
+    let inside = env.find(inside);
     if inside == ty {
         return true;
     }
     match env.get_type(inside) {
-        Type::Bool => false,
+        Type::Top | Type::Unit | Type::Bool | Type::Nat | Type::String => false,
         Type::Function(arg, ret) => occurs(env, ty, arg) || occurs(env, ty, ret),
+        Type::Sum(left, right) => occurs(env, ty, left) || occurs(env, ty, right),
+        Type::Product(elements) => elements.iter().any(|el| occurs(env, ty, *el)),
+        Type::List(element)
+        | Type::Ref(element)
+        | Type::Rec(_, element)
+        | Type::Exists(_, element) => occurs(env, ty, element),
+        Type::Record(fields) | Type::Variant(fields) => {
+            fields.values().any(|field| occurs(env, ty, *field))
+        }
         Type::ForAll(vars, inside) => {
             vars.iter().any(|v| occurs(env, ty, *v)) || occurs(env, ty, inside)
         }
-        Type::Var(_) => false,
+        Type::Var(_) | Type::Bound(_, _) => false,
     }
 }
 
 fn replace(env: &mut TypeEnv, all_occurrences: TypeId, inside: TypeId, with: TypeId) -> TypeId {
+    let inside = env.find(inside);
     match env.get_type(inside) {
         Type::Function(in_arg, in_ret) => {
             let arg = replace(env, all_occurrences, in_arg, with);
@@ -429,6 +2121,54 @@ fn replace(env: &mut TypeEnv, all_occurrences: TypeId, inside: TypeId, with: Typ
 
             env.add_type(Type::Function(arg, ret))
         }
+        Type::Sum(in_left, in_right) => {
+            let left = replace(env, all_occurrences, in_left, with);
+            let right = replace(env, all_occurrences, in_right, with);
+
+            env.add_type(Type::Sum(left, right))
+        }
+        Type::Product(elements) => {
+            let elements = elements
+                .into_iter()
+                .map(|el| replace(env, all_occurrences, el, with))
+                .collect();
+            env.add_type(Type::Product(elements))
+        }
+        Type::List(element) => {
+            let element = replace(env, all_occurrences, element, with);
+            env.add_type(Type::List(element))
+        }
+        Type::Ref(element) => {
+            let element = replace(env, all_occurrences, element, with);
+            env.add_type(Type::Ref(element))
+        }
+        Type::Rec(var, body) => {
+            let body = replace(env, all_occurrences, body, with);
+            env.add_type(Type::Rec(var, body))
+        }
+        Type::Exists(var, body) if var != all_occurrences => {
+            let body = replace(env, all_occurrences, body, with);
+            env.add_type(Type::Exists(var, body))
+        }
+        // Variables bound by the quantifier itself are left alone
+        Type::ForAll(vars, ty) if !vars.contains(&all_occurrences) => {
+            let ty = replace(env, all_occurrences, ty, with);
+            env.add_type(Type::ForAll(vars, ty))
+        }
+        Type::Record(fields) => {
+            let fields = fields
+                .into_iter()
+                .map(|(label, field)| (label, replace(env, all_occurrences, field, with)))
+                .collect();
+            env.add_type(Type::Record(fields))
+        }
+        Type::Variant(fields) => {
+            let fields = fields
+                .into_iter()
+                .map(|(label, field)| (label, replace(env, all_occurrences, field, with)))
+                .collect();
+            env.add_type(Type::Variant(fields))
+        }
         _ if all_occurrences == inside => with,
         _ => inside,
     }
@@ -451,17 +2191,72 @@ impl TypeEnv {
                 diagnostics.push(node, TypeError::UndefinedVariable { name });
                 None
             }
-            Some(None) => {
-                let name = e.get_str(name).into();
-                diagnostics.push(node, TypeError::Uninitialized { name });
-                None
-            }
-            Some(Some(ty)) => Some(*ty),
+            Some(ty) => Some(*ty),
         }
     }
 
+    /// Type of the representative of `id`, its parts are representatives too
     pub fn get_type(&self, id: TypeId) -> Type {
-        self.types[id.0].clone()
+        let find = |id| self.find(id);
+        let find_fields = |fields: BTreeMap<InternId, TypeId>| {
+            fields
+                .into_iter()
+                .map(|(label, field)| (label, find(field)))
+                .collect()
+        };
+        match self.types[find(id).0].clone() {
+            Type::Function(from, to) => Type::Function(find(from), find(to)),
+            Type::Sum(left, right) => Type::Sum(find(left), find(right)),
+            Type::Product(elements) => Type::Product(elements.into_iter().map(find).collect()),
+            Type::Record(fields) => Type::Record(find_fields(fields)),
+            Type::Variant(fields) => Type::Variant(find_fields(fields)),
+            Type::List(element) => Type::List(find(element)),
+            Type::Ref(element) => Type::Ref(find(element)),
+            Type::Rec(var, body) => Type::Rec(var, find(body)),
+            Type::Exists(var, body) => Type::Exists(var, find(body)),
+            Type::ForAll(vars, body) => Type::ForAll(vars, find(body)),
+            ty @ (Type::Top
+            | Type::Unit
+            | Type::Bool
+            | Type::Nat
+            | Type::String
+            | Type::Bound(_, _)
+            | Type::Var(_)) => ty,
+        }
+    }
+
+    /// Representative of `id` in the union-find: the type its variable is unified with,
+    /// `id` itself for other types
+    fn find(&self, mut id: TypeId) -> TypeId {
+        while let Type::Var(var) = self.types[id.0] {
+            match self.type_vars[var].link {
+                Some(link) => id = link,
+                None => break,
+            }
+        }
+        id
+    }
+
+    /// Same as `find`, but the variables on the way are linked to the representative directly
+    fn find_mut(&mut self, mut id: TypeId) -> TypeId {
+        let found = self.find(id);
+        while id != found {
+            let Type::Var(var) = self.types[id.0] else {
+                break;
+            };
+            match self.type_vars[var].link.replace(found) {
+                Some(link) => id = link,
+                None => break,
+            }
+        }
+        found
+    }
+
+    fn var_level(&self, id: TypeId) -> usize {
+        match self.types[id.0] {
+            Type::Var(var) => self.type_vars[var].level,
+            _ => 0,
+        }
     }
 
     pub fn type_of(&self, id: ExprId) -> Option<Type> {
@@ -475,18 +2270,13 @@ impl TypeEnv {
     }
 
     fn add_type(&mut self, ty: Type) -> TypeId {
-        if let Some(t_id) = self
-            .types
-            .iter()
-            .enumerate()
-            .find(|(_, t)| t == &&ty)
-            .map(|(id, _)| id)
-        {
-            return TypeId(t_id);
+        if let Some(id) = self.type_ids.get(&ty) {
+            return *id;
         }
 
         let id = TypeId(self.types.len());
-        self.types.push(ty);
+        self.types.push(ty.clone());
+        self.type_ids.insert(ty, id);
         id
     }
 
@@ -512,18 +2302,26 @@ impl TypeEnv {
         self.set_type_id_for_expr(id, type_id)
     }
 
-    fn new_var(&mut self, id: VarId) {
-        self.vars.insert(id, None);
-    }
-
     fn set_var(&mut self, id: VarId, ty: TypeId) {
-        self.vars.insert(id, Some(ty));
+        self.vars.insert(id, ty);
     }
 
     fn new_type_var(&mut self) -> Type {
-        let id = self.var_counter;
-        self.var_counter += 1;
-        Type::Var(id)
+        Type::Var(self.next_var())
+    }
+
+    fn new_bound_var(&mut self, name: &str) -> TypeId {
+        let id = self.next_var();
+        self.add_type(Type::Bound(id, name.into()))
+    }
+
+    fn next_var(&mut self) -> usize {
+        self.type_vars.push(TypeVar {
+            link: None,
+            level: self.level,
+            rank: 0,
+        });
+        self.type_vars.len() - 1
     }
 
     fn new_type_var_id(&mut self) -> TypeId {
@@ -536,14 +2334,74 @@ impl TypeEnv {
         self.print_type(ty)
     }
 
+    /// Name of the type alias for `ty`, if there is one
+    pub fn alias_of(&self, ty: &Type) -> Option<&str> {
+        let id = self.type_ids.get(ty)?;
+        self.aliases.get(id).map(|name| name.as_str())
+    }
+
     pub fn print_type(&self, ty: Type) -> String {
+        if let Some(name) = self.alias_of(&ty) {
+            return name.to_owned();
+        }
         match ty {
+            Type::Top => "Top".to_owned(),
+            Type::Unit => "Unit".to_owned(),
             Type::Bool => "Bool".to_owned(),
+            Type::Nat => "Nat".to_owned(),
+            Type::String => "String".to_owned(),
             Type::Function(from, to) => {
                 let from = self.print_type_id(from);
                 let to = self.print_type_id(to);
                 format!("({from} -> {to})")
             }
+            Type::Sum(left, right) => {
+                let left = self.print_type_id(left);
+                let right = self.print_type_id(right);
+                format!("({left} + {right})")
+            }
+            Type::Product(elements) => {
+                let elements = elements
+                    .iter()
+                    .copied()
+                    .map(|el| self.print_type_id(el))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{{{elements}}}")
+            }
+            Type::Record(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(label, field)| {
+                        format!("{}: {}", self.labels[label], self.print_type_id(*field))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{{{fields}}}")
+            }
+            Type::Variant(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(label, field)| {
+                        format!("{}: {}", self.labels[label], self.print_type_id(*field))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("<{fields}>")
+            }
+            Type::List(element) => format!("List {}", self.print_type_id(element)),
+            Type::Ref(element) => format!("Ref {}", self.print_type_id(element)),
+            Type::Rec(var, body) => {
+                let var = self.print_type_id(var);
+                let body = self.print_type_id(body);
+                format!("(Rec {var}. {body})")
+            }
+            Type::Exists(var, body) => {
+                let var = self.print_type_id(var);
+                let body = self.print_type_id(body);
+                format!("{{Some {var}, {body}}}")
+            }
+            Type::Bound(_, name) => name,
             Type::Var(i) => format!("T{i}"),
             Type::ForAll(vars, ty) => {
                 let vars = vars
@@ -562,7 +2420,10 @@ impl TypeEnv {
 #[cfg(test)]
 mod tests {
     use crate::{
-        ast::from_cst::{from_tree, get_tree},
+        ast::{
+            from_cst::from_files,
+            imports::{load_files, SourceFile},
+        },
         diagnostics::Diagnostics,
     };
 
@@ -570,9 +2431,10 @@ mod tests {
 
     #[test]
     fn types_tests() -> test_runner::Result {
-        test_runner::test_snapshots("tests/", "type", |input, _deps| {
-            let tree = get_tree(input);
-            let (r, exprs) = from_tree(&tree, input, "test");
+        test_runner::test_snapshots("tests/", "type", |input, deps| {
+            let root = SourceFile::new("test", input);
+            let files = load_files(root, |path| deps.get(path).map(|dep| dep.to_string()));
+            let (r, exprs) = from_files(&files);
             let mut diagnostics = Diagnostics::default();
             let r = r.expect("Root node");
             let ir = Exprs::from_ast(&exprs, r, &mut diagnostics);
@@ -583,6 +2445,23 @@ mod tests {
         })
     }
 
+    #[test]
+    fn system_f_tests() -> test_runner::Result {
+        test_runner::test_snapshots("tests/", "system-f", |input, deps| {
+            let root = SourceFile::new("test", input);
+            let files = load_files(root, |path| deps.get(path).map(|dep| dep.to_string()));
+            let (r, exprs) = from_files(&files);
+            let mut diagnostics = Diagnostics::default();
+            let r = r.expect("Root node");
+            let ir = Exprs::from_ast(&exprs, r, &mut diagnostics);
+            let (types, ty) = TypeEnv::infer_system_f(&ir, r, &mut diagnostics);
+            let diagnostics = diagnostics.to_pretty_string().expect("Pretty print");
+            format!("{:#?}\n{diagnostics}", ty.debug(&types))
+                .trim_end()
+                .to_string()
+        })
+    }
+
     mod instantiate_tests {
         use super::*;
 
@@ -614,12 +2493,12 @@ mod tests {
             let (a_from, a_to) = instantiate_poly(&mut env, source);
             let actual = Type::Function(a_from, a_to);
 
-            let t3 = TypeId(4);
-            let t4 = TypeId(6);
-            let expected_inner = env.add_type(Type::Function(t3, t4));
-            let expected = Type::Function(t4, expected_inner);
-
-            println!("{:#?}", env);
+            // Fresh variables are numbered after T0 and T1, in reverse order: T2 replaces T1
+            // and T3 replaces T0. Types are hash-consed, so these are the ids they already have
+            let t2 = env.add_type(Type::Var(2));
+            let t3 = env.add_type(Type::Var(3));
+            let expected_inner = env.add_type(Type::Function(t2, t3));
+            let expected = Type::Function(t3, expected_inner);
 
             assert_eq!(expected.debug(&env), actual.debug(&env));
         }
@@ -1,10 +1,10 @@
-use crate::ast::{DebugExpr, Exprs};
+use crate::ir::{DebugExpr, Exprs};
 
-use super::{Con, Type, TypeEnv, TypeId};
+use super::{Con, Sub, Type, TypeEnv, TypeId};
 
 impl TypeEnv {
     pub fn debug(&self, id: TypeId) -> DebugType {
-        let t = &self.types[id.0];
+        let t = &self.types[self.find(id).0];
         t.debug(self)
     }
 }
@@ -27,22 +27,74 @@ impl Type {
 
 impl<'a> std::fmt::Display for DebugType<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.env.alias_of(self.t) {
+            return write!(f, "{name}");
+        }
         match self.t {
+            Type::Top => write!(f, "Top"),
+            Type::Unit => write!(f, "Unit"),
             Type::Bool => write!(f, "Bool"),
+            Type::Nat => write!(f, "Nat"),
+            Type::String => write!(f, "String"),
             Type::Function(from, to) => {
                 write!(f, "{} -> {}", self.env.debug(*from), self.env.debug(*to))
             }
+            Type::Sum(left, right) => {
+                write!(f, "{} + {}", self.env.debug(*left), self.env.debug(*right))
+            }
+            Type::Product(elements) => {
+                let mut elements = elements.iter().copied().map(|el| self.env.debug(el));
+                write!(f, "{{")?;
+                if let Some(el) = elements.next() {
+                    write!(f, "{el}")?;
+                }
+                for el in elements {
+                    write!(f, ", {el}")?;
+                }
+                write!(f, "}}")
+            }
+            Type::Record(fields) | Type::Variant(fields) => {
+                let (open, close) = match self.t {
+                    Type::Variant(_) => ("<", ">"),
+                    _ => ("{", "}"),
+                };
+                let mut fields = fields
+                    .iter()
+                    .map(|(label, field)| (&self.env.labels[label], self.env.debug(*field)));
+                write!(f, "{open}")?;
+                if let Some((label, field)) = fields.next() {
+                    write!(f, "{label}: {field}")?;
+                }
+                for (label, field) in fields {
+                    write!(f, ", {label}: {field}")?;
+                }
+                write!(f, "{close}")
+            }
+            Type::List(element) => write!(f, "List {}", self.env.debug(*element)),
+            Type::Ref(element) => write!(f, "Ref {}", self.env.debug(*element)),
             Type::ForAll(args, inner) => {
                 let mut args = args.iter().copied().map(|arg| self.env.debug(arg));
-                write!(f, "forall <")?;
+                write!(f, "All ")?;
                 if let Some(a) = args.next() {
                     write!(f, "{a}")?;
                 }
                 for a in args {
                     write!(f, ", {a}")?;
                 }
-                write!(f, ">: {}", self.env.debug(*inner))
+                write!(f, ". {}", self.env.debug(*inner))
+            }
+            Type::Rec(var, body) => {
+                write!(f, "Rec {}. {}", self.env.debug(*var), self.env.debug(*body))
+            }
+            Type::Exists(var, body) => {
+                write!(
+                    f,
+                    "{{Some {}, {}}}",
+                    self.env.debug(*var),
+                    self.env.debug(*body)
+                )
             }
+            Type::Bound(_, name) => write!(f, "{name}"),
             Type::Var(i) => write!(f, "T{i}"),
         }
     }
@@ -50,13 +102,54 @@ impl<'a> std::fmt::Display for DebugType<'a> {
 
 impl<'a> std::fmt::Debug for DebugType<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.env.alias_of(self.t) {
+            return f.debug_tuple("Alias").field(&name).finish();
+        }
         match self.t {
+            Type::Top => write!(f, "Top"),
+            Type::Unit => write!(f, "Unit"),
             Type::Bool => write!(f, "Bool"),
+            Type::Nat => write!(f, "Nat"),
+            Type::String => write!(f, "String"),
             Type::Function(from, to) => f
                 .debug_tuple("Fn")
                 .field(&self.env.debug(*from))
                 .field(&self.env.debug(*to))
                 .finish(),
+            Type::Sum(left, right) => f
+                .debug_tuple("Sum")
+                .field(&self.env.debug(*left))
+                .field(&self.env.debug(*right))
+                .finish(),
+            Type::Product(elements) => {
+                let mut t = f.debug_tuple("Product");
+                for el in elements {
+                    t.field(&self.env.debug(*el));
+                }
+                t.finish()
+            }
+            Type::Variant(fields) => {
+                let mut t = f.debug_struct("Variant");
+                for (label, field) in fields {
+                    t.field(&self.env.labels[label], &self.env.debug(*field));
+                }
+                t.finish()
+            }
+            Type::Record(fields) => {
+                let mut t = f.debug_struct("Record");
+                for (label, field) in fields {
+                    t.field(&self.env.labels[label], &self.env.debug(*field));
+                }
+                t.finish()
+            }
+            Type::List(element) => f
+                .debug_tuple("List")
+                .field(&self.env.debug(*element))
+                .finish(),
+            Type::Ref(element) => f
+                .debug_tuple("Ref")
+                .field(&self.env.debug(*element))
+                .finish(),
             Type::ForAll(args, inner) => {
                 let args = args
                     .iter()
@@ -66,6 +159,17 @@ impl<'a> std::fmt::Debug for DebugType<'a> {
                 let inner = self.env.debug(*inner);
                 f.debug_tuple("Poly").field(&args).field(&inner).finish()
             }
+            Type::Rec(var, body) => f
+                .debug_tuple("Rec")
+                .field(&self.env.debug(*var))
+                .field(&self.env.debug(*body))
+                .finish(),
+            Type::Exists(var, body) => f
+                .debug_tuple("Exists")
+                .field(&self.env.debug(*var))
+                .field(&self.env.debug(*body))
+                .finish(),
+            Type::Bound(_, name) => write!(f, "{name}"),
             Type::Var(i) => write!(f, "T{i}"),
         }
     }
@@ -117,3 +221,9 @@ impl std::fmt::Debug for Con {
         write!(f, "{:?} = {:?}", self.left, self.right)
     }
 }
+
+impl std::fmt::Debug for Sub {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} <: {:?}", self.sub, self.sup)
+    }
+}
@@ -1,9 +1,37 @@
 /// <reference types="tree-sitter-cli/dsl" />
 // @ts-check
 
+// Operators bind tighter from the bottom up: `not a == b && c || d` is `((not a) == b && c) || d`
 const PREC = {
-  "call": 2,
+  "projection": 7,
+  "nat": 6,
+  "call": 5,
+  "equal": 4,
+  "and": 3,
+  "or": 2,
+  "ascribe": 1,
+  "sum": 1,
+  "list": 2,
   "let": 1,
+  "letrec": 1,
+  "sequence": 1,
+  "assign": 1,
+}
+
+function infix($, precedence, operator) {
+  return prec.left(precedence, seq(
+    field("left", $._expr),
+    operator,
+    field("right", $._expr)
+  ))
+}
+
+function commaSep2(rule) {
+  return seq(rule, repeat1(seq(",", rule)), optional(","))
+}
+
+function commaSep1(rule) {
+  return seq(rule, repeat(seq(",", rule)), optional(","))
 }
 
 module.exports = grammar({
@@ -14,21 +42,190 @@ module.exports = grammar({
     $.comment
   ],
   word: $ => $.ident,
+  // Parameters stay ambiguous with call arguments until `:`, as with a plain `ident`
+  inline: $ => [$._binder],
+  conflicts: $ => [
+    // `x: (A) => A` and `x: (a)` are the same until `=>`
+    [$._expr, $._type],
+    // `f a b` is a call until `:` makes it the parameters of `f a b: body`
+    [$._expr, $.def],
+  ],
   rules: {
-    source_file: $ => $._expr,
+    source_file: $ => $._block,
     comment: $ => token(seq("#", /.*/)),
     _expr: $ => choice(
-      seq("(", $._expr,  ")"),
+      seq("(", $._block,  ")"),
+      $.unit,
       $.bool,
+      $.nat,
+      $.string,
+      $.succ,
+      $.pred,
+      $.iszero,
+      $.concat,
+      $.length,
+      $.eq,
+      $.not,
+      $.and,
+      $.or,
+      $.equal,
       $.ident,
+      $.wildcard,
+      $.tuple,
+      $.record,
+      $.projection,
+      $.inl,
+      $.inr,
+      $.variant,
+      $.case,
+      $.nil,
+      $.cons,
+      $.isnil,
+      $.head,
+      $.tail,
+      $.list,
+      $.ref,
+      $.deref,
+      $.assign,
+      $.error,
+      $.raise,
+      $.try,
+      $.type_abs,
+      $.type_app,
+      $.pack,
+      $.unpack,
       $.def,
       $.call,
       $.ifElse,
-      $["let"]
+      $["let"],
+      $.letrec,
+      $.type_alias,
+      $["import"],
+      $.fix,
+      $.ascribe
+    ),
+    _block: $ => choice(
+      $._expr,
+      $.sequence
     ),
+    unit: $ => "unit",
     bool: $ => choice("true", "false"),
-    ident: $ => /[a-zA-Z_0-9]+/,
-    def: $ => seq( field("arg", $.ident), ":", field("body", $._expr) ),
+    nat: $ => /[0-9]+/,
+    succ: $ => prec(PREC.nat, seq("succ", field("arg", $._expr))),
+    pred: $ => prec(PREC.nat, seq("pred", field("arg", $._expr))),
+    iszero: $ => prec(PREC.nat, seq("iszero", field("arg", $._expr))),
+    string: $ => seq(
+      "\"",
+      repeat(choice($.string_content, $.escape_sequence)),
+      token.immediate("\"")
+    ),
+    string_content: $ => token.immediate(prec(1, /[^"\\\n]+/)),
+    // Only the escapes the lexer understands, anything else is a syntax error
+    escape_sequence: $ => token.immediate(seq("\\", /["\\nt]/)),
+    concat: $ => prec(PREC.nat, seq("concat", field("left", $._expr), field("right", $._expr))),
+    length: $ => prec(PREC.nat, seq("length", field("arg", $._expr))),
+    eq: $ => prec(PREC.nat, seq("eq", field("left", $._expr), field("right", $._expr))),
+    not: $ => prec(PREC.nat, seq("not", field("arg", $._expr))),
+    and: $ => infix($, PREC.and, "&&"),
+    or: $ => infix($, PREC.or, "||"),
+    equal: $ => infix($, PREC.equal, "=="),
+    // A lone `_` is a wildcard, not a name
+    ident: $ => /[a-zA-Z][a-zA-Z_0-9]*|_[a-zA-Z_0-9]+/,
+    wildcard: $ => "_",
+    _binder: $ => choice($.ident, $.wildcard),
+    _type_ident: $ => token(prec(1, /[A-Z][a-zA-Z_0-9]*/)),
+    tuple: $ => choice(
+      seq("{", commaSep1(field("element", $._expr)), "}"),
+      seq("(", commaSep2(field("element", $._expr)), ")"),
+    ),
+    record: $ => seq("{", commaSep1(field("field", $.record_field)), "}"),
+    record_field: $ => seq(field("label", $.ident), "=", field("value", $._expr)),
+    projection: $ => prec(PREC.projection, seq(
+      field("expr", $._expr),
+      ".",
+      choice(field("index", $.nat), field("label", $.ident))
+    )),
+    inl: $ => prec(PREC.nat, seq("inl", field("arg", $._expr))),
+    inr: $ => prec(PREC.nat, seq("inr", field("arg", $._expr))),
+    variant: $ => seq("<", field("label", $.ident), "=", field("value", $._expr), ">"),
+    // Nested case without parentheses takes all the arms that follow it
+    case: $ => prec.right(seq(
+      "case",
+      field("expr", $._expr),
+      "of",
+      choice(
+        seq(field("inl", $.inl_arm), "|", field("inr", $.inr_arm)),
+        seq(field("arm", $.variant_arm), repeat(seq("|", field("arm", $.variant_arm)))),
+      )
+    )),
+    inl_arm: $ => seq("inl", field("binder", $._binder), "=>", field("body", $._expr)),
+    inr_arm: $ => seq("inr", field("binder", $._binder), "=>", field("body", $._expr)),
+    variant_arm: $ => seq(
+      "<",
+      field("label", $.ident),
+      "=",
+      field("binder", $._binder),
+      ">",
+      "=>",
+      field("body", $._expr)
+    ),
+    nil: $ => "nil",
+    cons: $ => prec(PREC.nat, seq("cons", field("head", $._expr), field("tail", $._expr))),
+    isnil: $ => prec(PREC.nat, seq("isnil", field("arg", $._expr))),
+    head: $ => prec(PREC.nat, seq("head", field("arg", $._expr))),
+    tail: $ => prec(PREC.nat, seq("tail", field("arg", $._expr))),
+    list: $ => seq("[", optional(commaSep1(field("element", $._expr))), "]"),
+    ref: $ => prec(PREC.nat, seq("ref", field("arg", $._expr))),
+    deref: $ => prec(PREC.nat, seq("!", field("arg", $._expr))),
+    assign: $ => prec.right(PREC.assign, seq(
+      field("target", $._expr),
+      ":=",
+      field("value", $._expr)
+    )),
+    error: $ => "error",
+    raise: $ => prec(PREC.nat, seq("raise", field("arg", $._expr))),
+    // The handler is a function of the raised value
+    try: $ => seq("try", field("body", $._expr), "with", field("handler", $._expr)),
+    type_abs: $ => seq("/\\", field("name", $.ident), ".", field("body", $._expr)),
+    // `f [x]` is a type application, a call with a list of one variable needs `f ([x])`
+    type_app: $ => prec.dynamic(1, prec.left(PREC.call, seq(
+      field("expr", $._expr),
+      "[",
+      field("type", $._type),
+      "]"
+    ))),
+    // `type` is hidden behind the abstract type of the existential type `as`
+    pack: $ => seq(
+      "{",
+      "*",
+      field("type", $._type),
+      ",",
+      field("value", $._expr),
+      "}",
+      "as",
+      field("as", $._type)
+    ),
+    // The abstract type is capitalized, `let {a, b} = p; a` is a tuple pattern instead
+    unpack: $ => prec.right(PREC.let, seq(
+      "let",
+      "{",
+      field("type", alias($._type_ident, $.ident)),
+      ",",
+      field("key", $._binder),
+      "}",
+      "=",
+      field("value", $._expr),
+      choice("in", ";"),
+      field("in", $._block)
+    )),
+    // `a b c: body` is `a: b: c: body`, only a single parameter can have a type
+    def: $ => seq(
+      choice(
+        seq(field("arg", $._binder), ":", optional(seq(field("type", $._type), "=>"))),
+        prec.dynamic(1, seq(field("arg", $._binder), repeat1(field("arg", $._binder)), ":")),
+      ),
+      field("body", $._expr)
+    ),
     call: $ => prec.left(PREC.call, seq(
       field("func", $._expr), 
       field("arg", $._expr), 
@@ -43,11 +240,93 @@ module.exports = grammar({
     ),
     "let": $ => prec.right(PREC.let, seq(
       "let",
+      field("key", $._pattern),
+      "=",
+      field("value", $._expr),
+      ";",
+      field("in", $._block)
+    )),
+    _pattern: $ => choice(
+      $._binder,
+      $.tuple_pattern,
+      $.record_pattern
+    ),
+    tuple_pattern: $ => seq("{", commaSep1(field("element", $._pattern)), "}"),
+    record_pattern: $ => seq("{", commaSep1(field("field", $.record_field_pattern)), "}"),
+    record_field_pattern: $ => seq(field("label", $.ident), "=", field("pattern", $._pattern)),
+    // `in` as in TAPL, or `;` like `let`
+    letrec: $ => prec.right(PREC.letrec, seq(
+      "letrec",
       field("key", $.ident),
+      ":",
+      field("type", $._type),
       "=",
       field("value", $._expr),
+      choice("in", ";"),
+      field("in", $._block)
+    )),
+    // `name` stands for `type` in the types that follow
+    type_alias: $ => prec.right(PREC.let, seq(
+      "type",
+      field("name", $.ident),
+      "=",
+      field("type", $._type),
+      ";",
+      field("in", $._block)
+    )),
+    "import": $ => prec.right(PREC.let, seq(
+      "import",
+      field("path", $.path),
+      "as",
+      field("name", $.ident),
+      ";",
+      field("in", $._block)
+    )),
+    path: $ => /"[^"]*"/,
+    fix: $ => prec(PREC.nat, seq("fix", field("arg", $._expr))),
+    ascribe: $ => prec.left(PREC.ascribe, seq(
+      field("expr", $._expr),
+      "as",
+      field("type", $._type)
+    )),
+    _type: $ => choice(
+      seq("(", $._type, ")"),
+      alias($.ident, $.type_name),
+      $.fn_type,
+      $.product_type,
+      $.record_type,
+      $.sum_type,
+      $.variant_type,
+      $.list_type,
+      $.ref_type,
+      $.rec_type,
+      $.forall_type,
+      $.some_type
+    ),
+    fn_type: $ => prec.right(seq(
+      field("from", $._type),
+      "->",
+      field("to", $._type)
+    )),
+    sum_type: $ => prec.left(PREC.sum, seq(
+      field("left", $._type),
+      "+",
+      field("right", $._type)
+    )),
+    list_type: $ => prec(PREC.list, seq("List", field("element", $._type))),
+    ref_type: $ => prec(PREC.list, seq("Ref", field("type", $._type))),
+    // `name` stands for the whole type inside `type`
+    rec_type: $ => prec.right(seq("Rec", field("name", $.ident), ".", field("type", $._type))),
+    forall_type: $ => prec.right(seq("All", field("name", $.ident), ".", field("type", $._type))),
+    some_type: $ => seq("{", "Some", field("name", $.ident), ",", field("type", $._type), "}"),
+    product_type: $ => seq("{", commaSep1(field("element", $._type)), "}"),
+    record_type: $ => seq("{", commaSep1(field("field", $.record_field_type)), "}"),
+    variant_type: $ => seq("<", commaSep1(field("field", $.record_field_type)), ">"),
+    record_field_type: $ => seq(field("label", $.ident), ":", field("type", $._type)),
+    sequence: $ => prec.right(PREC.sequence, seq(
+      field("first", $._expr),
       ";",
-      field("in", $._expr)
+      field("then", $._block)
     ))
   }
 });
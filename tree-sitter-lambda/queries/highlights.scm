@@ -1,13 +1,97 @@
 (comment) @comment.line
 
+(unit) @constant.builtin
 (bool) @constant.builtin
+(nat) @constant.numeric.integer
+(string) @string
+(escape_sequence) @constant.character.escape
+(nil) @constant.builtin
+(error) @constant.builtin
 
+"succ" @function.builtin
+"pred" @function.builtin
+"iszero" @function.builtin
+"concat" @function.builtin
+"length" @function.builtin
+"eq" @function.builtin
+"not" @keyword.operator
+"inl" @function.builtin
+"inr" @function.builtin
+"fix" @function.builtin
+"cons" @function.builtin
+"isnil" @function.builtin
+"head" @function.builtin
+"tail" @function.builtin
+"ref" @function.builtin
+"raise" @keyword.control.exception
+"try" @keyword.control.exception
+"with" @keyword.control.exception
+
+"|" @operator
 ":" @operator
+"=>" @operator
 "=" @operator
 ";" @operator
+"->" @operator
+"+" @operator
+"." @operator
+"!" @operator
+":=" @operator
+"&&" @operator
+"||" @operator
+"==" @operator
+
+"{" @punctuation.bracket
+"}" @punctuation.bracket
+"[" @punctuation.bracket
+"]" @punctuation.bracket
+"(" @punctuation.bracket
+")" @punctuation.bracket
+"<" @punctuation.bracket
+">" @punctuation.bracket
+"," @punctuation.delimiter
+
+"as" @keyword.operator
+(type_name) @type
+"List" @type.builtin
+"Ref" @type.builtin
+"Rec" @type.builtin
+(rec_type name: (ident) @type.parameter)
+"All" @type.builtin
+"Some" @type.builtin
+(some_type name: (ident) @type.parameter)
+(unpack type: (ident) @type.parameter)
+(pack "*" @operator)
+(forall_type name: (ident) @type.parameter)
+(type_abs name: (ident) @type.parameter)
+"/\\" @operator
+(projection index: (nat) @variable.other.member)
+(projection label: (ident) @variable.other.member)
+(record_field label: (ident) @variable.other.member)
+(record_field_type label: (ident) @variable.other.member)
+(variant label: (ident) @constructor)
+(variant_arm label: (ident) @constructor)
+(variant_type (record_field_type label: (ident) @constructor))
 
 "let" @keyword.storage.type
+"letrec" @keyword.storage.type
+"type" @keyword.storage.type
+(type_alias name: (ident) @type)
+"import" @keyword.control.import
+(path) @string
+"in" @keyword.storage.type
+"case" @keyword.control.conditional
+"of" @keyword.control.conditional
 
 (def) @function
 (let key: (ident) @variable)
+(wildcard) @variable.builtin
+(tuple_pattern element: (ident) @variable)
+(record_field_pattern label: (ident) @variable.other.member)
+(record_field_pattern pattern: (ident) @variable)
+(letrec key: (ident) @variable)
+(import name: (ident) @namespace)
 (def arg: (ident) @variable.parameter)
+(inl_arm binder: (ident) @variable.parameter)
+(inr_arm binder: (ident) @variable.parameter)
+(variant_arm binder: (ident) @variable.parameter)
@@ -1,6 +1,10 @@
 (let) @indent
+(letrec) @indent
+(import) @indent
 (def) @indent
 
 ";" @outdent
+"in" @outdent
 
 (let key: (_) @indent)
+(letrec key: (_) @indent)
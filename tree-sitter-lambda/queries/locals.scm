@@ -1,7 +1,17 @@
 (let) @local.scope
+(letrec) @local.scope
+(import) @local.scope
 (def) @local.scope
+(inl_arm) @local.scope
+(inr_arm) @local.scope
+(variant_arm) @local.scope
 
 (let key: (ident) @local.definition)
+(letrec key: (ident) @local.definition)
+(import name: (ident) @local.definition)
 (def arg: (ident) @local.definition)
+(inl_arm binder: (ident) @local.definition)
+(inr_arm binder: (ident) @local.definition)
+(variant_arm binder: (ident) @local.definition)
 
 (ident) @local.reference
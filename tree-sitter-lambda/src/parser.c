@@ -5,37 +5,166 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 24
-#define LARGE_STATE_COUNT 21
-#define SYMBOL_COUNT 21
-#define ALIAS_COUNT 0
-#define TOKEN_COUNT 14
+#define STATE_COUNT 483
+#define LARGE_STATE_COUNT 231
+#define SYMBOL_COUNT 149
+#define ALIAS_COUNT 1
+#define TOKEN_COUNT 74
 #define EXTERNAL_TOKEN_COUNT 0
-#define FIELD_COUNT 9
-#define MAX_ALIAS_SEQUENCE_LENGTH 6
-#define PRODUCTION_ID_COUNT 5
+#define FIELD_COUNT 32
+#define MAX_ALIAS_SEQUENCE_LENGTH 10
+#define PRODUCTION_ID_COUNT 47
 
 enum ts_symbol_identifiers {
   sym_ident = 1,
   sym_comment = 2,
   anon_sym_LPAREN = 3,
   anon_sym_RPAREN = 4,
-  anon_sym_true = 5,
-  anon_sym_false = 6,
-  anon_sym_COLON = 7,
-  anon_sym_if = 8,
-  anon_sym_then = 9,
-  anon_sym_else = 10,
-  anon_sym_let = 11,
-  anon_sym_EQ = 12,
-  anon_sym_SEMI = 13,
-  sym_source_file = 14,
-  sym__expr = 15,
-  sym_bool = 16,
-  sym_def = 17,
-  sym_call = 18,
-  sym_ifElse = 19,
-  sym_let = 20,
+  sym_unit = 5,
+  anon_sym_true = 6,
+  anon_sym_false = 7,
+  sym_nat = 8,
+  anon_sym_succ = 9,
+  anon_sym_pred = 10,
+  anon_sym_iszero = 11,
+  anon_sym_DQUOTE = 12,
+  anon_sym_DQUOTE2 = 13,
+  sym_string_content = 14,
+  sym_escape_sequence = 15,
+  anon_sym_concat = 16,
+  anon_sym_length = 17,
+  anon_sym_eq = 18,
+  anon_sym_not = 19,
+  anon_sym_AMP_AMP = 20,
+  anon_sym_PIPE_PIPE = 21,
+  anon_sym_EQ_EQ = 22,
+  sym_wildcard = 23,
+  sym__type_ident = 24,
+  anon_sym_LBRACE = 25,
+  anon_sym_COMMA = 26,
+  anon_sym_RBRACE = 27,
+  anon_sym_EQ = 28,
+  anon_sym_DOT = 29,
+  anon_sym_inl = 30,
+  anon_sym_inr = 31,
+  anon_sym_LT = 32,
+  anon_sym_GT = 33,
+  anon_sym_case = 34,
+  anon_sym_of = 35,
+  anon_sym_PIPE = 36,
+  anon_sym_EQ_GT = 37,
+  sym_nil = 38,
+  anon_sym_cons = 39,
+  anon_sym_isnil = 40,
+  anon_sym_head = 41,
+  anon_sym_tail = 42,
+  anon_sym_LBRACK = 43,
+  anon_sym_RBRACK = 44,
+  anon_sym_ref = 45,
+  anon_sym_BANG = 46,
+  anon_sym_COLON_EQ = 47,
+  sym_error = 48,
+  anon_sym_raise = 49,
+  anon_sym_try = 50,
+  anon_sym_with = 51,
+  anon_sym_SLASH_BSLASH = 52,
+  anon_sym_STAR = 53,
+  anon_sym_as = 54,
+  anon_sym_let = 55,
+  anon_sym_in = 56,
+  anon_sym_SEMI = 57,
+  anon_sym_COLON = 58,
+  anon_sym_if = 59,
+  anon_sym_then = 60,
+  anon_sym_else = 61,
+  anon_sym_letrec = 62,
+  anon_sym_type = 63,
+  anon_sym_import = 64,
+  sym_path = 65,
+  anon_sym_fix = 66,
+  anon_sym_DASH_GT = 67,
+  anon_sym_PLUS = 68,
+  anon_sym_List = 69,
+  anon_sym_Ref = 70,
+  anon_sym_Rec = 71,
+  anon_sym_All = 72,
+  anon_sym_Some = 73,
+  sym_source_file = 74,
+  sym__expr = 75,
+  sym__block = 76,
+  sym_bool = 77,
+  sym_succ = 78,
+  sym_pred = 79,
+  sym_iszero = 80,
+  sym_string = 81,
+  sym_concat = 82,
+  sym_length = 83,
+  sym_eq = 84,
+  sym_not = 85,
+  sym_and = 86,
+  sym_or = 87,
+  sym_equal = 88,
+  sym_tuple = 89,
+  sym_record = 90,
+  sym_record_field = 91,
+  sym_projection = 92,
+  sym_inl = 93,
+  sym_inr = 94,
+  sym_variant = 95,
+  sym_case = 96,
+  sym_inl_arm = 97,
+  sym_inr_arm = 98,
+  sym_variant_arm = 99,
+  sym_cons = 100,
+  sym_isnil = 101,
+  sym_head = 102,
+  sym_tail = 103,
+  sym_list = 104,
+  sym_ref = 105,
+  sym_deref = 106,
+  sym_assign = 107,
+  sym_raise = 108,
+  sym_try = 109,
+  sym_type_abs = 110,
+  sym_type_app = 111,
+  sym_pack = 112,
+  sym_unpack = 113,
+  sym_def = 114,
+  sym_call = 115,
+  sym_ifElse = 116,
+  sym_let = 117,
+  sym__pattern = 118,
+  sym_tuple_pattern = 119,
+  sym_record_pattern = 120,
+  sym_record_field_pattern = 121,
+  sym_letrec = 122,
+  sym_type_alias = 123,
+  sym_import = 124,
+  sym_fix = 125,
+  sym_ascribe = 126,
+  sym__type = 127,
+  sym_fn_type = 128,
+  sym_sum_type = 129,
+  sym_list_type = 130,
+  sym_ref_type = 131,
+  sym_rec_type = 132,
+  sym_forall_type = 133,
+  sym_some_type = 134,
+  sym_product_type = 135,
+  sym_record_type = 136,
+  sym_variant_type = 137,
+  sym_record_field_type = 138,
+  sym_sequence = 139,
+  aux_sym_string_repeat1 = 140,
+  aux_sym_tuple_repeat1 = 141,
+  aux_sym_record_repeat1 = 142,
+  aux_sym_case_repeat1 = 143,
+  aux_sym_def_repeat1 = 144,
+  aux_sym_tuple_pattern_repeat1 = 145,
+  aux_sym_record_pattern_repeat1 = 146,
+  aux_sym_product_type_repeat1 = 147,
+  aux_sym_record_type_repeat1 = 148,
+  alias_sym_type_name = 149,
 };
 
 static const char * const ts_symbol_names[] = {
@@ -44,22 +173,151 @@ static const char * const ts_symbol_names[] = {
   [sym_comment] = "comment",
   [anon_sym_LPAREN] = "(",
   [anon_sym_RPAREN] = ")",
+  [sym_unit] = "unit",
   [anon_sym_true] = "true",
   [anon_sym_false] = "false",
+  [sym_nat] = "nat",
+  [anon_sym_succ] = "succ",
+  [anon_sym_pred] = "pred",
+  [anon_sym_iszero] = "iszero",
+  [anon_sym_DQUOTE] = "\"",
+  [anon_sym_DQUOTE2] = "\"",
+  [sym_string_content] = "string_content",
+  [sym_escape_sequence] = "escape_sequence",
+  [anon_sym_concat] = "concat",
+  [anon_sym_length] = "length",
+  [anon_sym_eq] = "eq",
+  [anon_sym_not] = "not",
+  [anon_sym_AMP_AMP] = "&&",
+  [anon_sym_PIPE_PIPE] = "||",
+  [anon_sym_EQ_EQ] = "==",
+  [sym_wildcard] = "wildcard",
+  [sym__type_ident] = "ident",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_RBRACE] = "}",
+  [anon_sym_EQ] = "=",
+  [anon_sym_DOT] = ".",
+  [anon_sym_inl] = "inl",
+  [anon_sym_inr] = "inr",
+  [anon_sym_LT] = "<",
+  [anon_sym_GT] = ">",
+  [anon_sym_case] = "case",
+  [anon_sym_of] = "of",
+  [anon_sym_PIPE] = "|",
+  [anon_sym_EQ_GT] = "=>",
+  [sym_nil] = "nil",
+  [anon_sym_cons] = "cons",
+  [anon_sym_isnil] = "isnil",
+  [anon_sym_head] = "head",
+  [anon_sym_tail] = "tail",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_ref] = "ref",
+  [anon_sym_BANG] = "!",
+  [anon_sym_COLON_EQ] = ":=",
+  [sym_error] = "error",
+  [anon_sym_raise] = "raise",
+  [anon_sym_try] = "try",
+  [anon_sym_with] = "with",
+  [anon_sym_SLASH_BSLASH] = "/\\",
+  [anon_sym_STAR] = "*",
+  [anon_sym_as] = "as",
+  [anon_sym_let] = "let",
+  [anon_sym_in] = "in",
+  [anon_sym_SEMI] = ";",
   [anon_sym_COLON] = ":",
   [anon_sym_if] = "if",
   [anon_sym_then] = "then",
   [anon_sym_else] = "else",
-  [anon_sym_let] = "let",
-  [anon_sym_EQ] = "=",
-  [anon_sym_SEMI] = ";",
+  [anon_sym_letrec] = "letrec",
+  [anon_sym_type] = "type",
+  [anon_sym_import] = "import",
+  [sym_path] = "path",
+  [anon_sym_fix] = "fix",
+  [anon_sym_DASH_GT] = "->",
+  [anon_sym_PLUS] = "+",
+  [anon_sym_List] = "List",
+  [anon_sym_Ref] = "Ref",
+  [anon_sym_Rec] = "Rec",
+  [anon_sym_All] = "All",
+  [anon_sym_Some] = "Some",
   [sym_source_file] = "source_file",
   [sym__expr] = "_expr",
+  [sym__block] = "_block",
   [sym_bool] = "bool",
+  [sym_succ] = "succ",
+  [sym_pred] = "pred",
+  [sym_iszero] = "iszero",
+  [sym_string] = "string",
+  [sym_concat] = "concat",
+  [sym_length] = "length",
+  [sym_eq] = "eq",
+  [sym_not] = "not",
+  [sym_and] = "and",
+  [sym_or] = "or",
+  [sym_equal] = "equal",
+  [sym_tuple] = "tuple",
+  [sym_record] = "record",
+  [sym_record_field] = "record_field",
+  [sym_projection] = "projection",
+  [sym_inl] = "inl",
+  [sym_inr] = "inr",
+  [sym_variant] = "variant",
+  [sym_case] = "case",
+  [sym_inl_arm] = "inl_arm",
+  [sym_inr_arm] = "inr_arm",
+  [sym_variant_arm] = "variant_arm",
+  [sym_cons] = "cons",
+  [sym_isnil] = "isnil",
+  [sym_head] = "head",
+  [sym_tail] = "tail",
+  [sym_list] = "list",
+  [sym_ref] = "ref",
+  [sym_deref] = "deref",
+  [sym_assign] = "assign",
+  [sym_raise] = "raise",
+  [sym_try] = "try",
+  [sym_type_abs] = "type_abs",
+  [sym_type_app] = "type_app",
+  [sym_pack] = "pack",
+  [sym_unpack] = "unpack",
   [sym_def] = "def",
   [sym_call] = "call",
   [sym_ifElse] = "ifElse",
   [sym_let] = "let",
+  [sym__pattern] = "_pattern",
+  [sym_tuple_pattern] = "tuple_pattern",
+  [sym_record_pattern] = "record_pattern",
+  [sym_record_field_pattern] = "record_field_pattern",
+  [sym_letrec] = "letrec",
+  [sym_type_alias] = "type_alias",
+  [sym_import] = "import",
+  [sym_fix] = "fix",
+  [sym_ascribe] = "ascribe",
+  [sym__type] = "_type",
+  [sym_fn_type] = "fn_type",
+  [sym_sum_type] = "sum_type",
+  [sym_list_type] = "list_type",
+  [sym_ref_type] = "ref_type",
+  [sym_rec_type] = "rec_type",
+  [sym_forall_type] = "forall_type",
+  [sym_some_type] = "some_type",
+  [sym_product_type] = "product_type",
+  [sym_record_type] = "record_type",
+  [sym_variant_type] = "variant_type",
+  [sym_record_field_type] = "record_field_type",
+  [sym_sequence] = "sequence",
+  [aux_sym_string_repeat1] = "string_repeat1",
+  [aux_sym_tuple_repeat1] = "tuple_repeat1",
+  [aux_sym_record_repeat1] = "record_repeat1",
+  [aux_sym_case_repeat1] = "case_repeat1",
+  [aux_sym_def_repeat1] = "def_repeat1",
+  [aux_sym_tuple_pattern_repeat1] = "tuple_pattern_repeat1",
+  [aux_sym_record_pattern_repeat1] = "record_pattern_repeat1",
+  [aux_sym_product_type_repeat1] = "product_type_repeat1",
+  [aux_sym_record_type_repeat1] = "record_type_repeat1",
+  [alias_sym_type_name] = "type_name",
 };
 
 static const TSSymbol ts_symbol_map[] = {
@@ -68,22 +326,151 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_comment] = sym_comment,
   [anon_sym_LPAREN] = anon_sym_LPAREN,
   [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [sym_unit] = sym_unit,
   [anon_sym_true] = anon_sym_true,
   [anon_sym_false] = anon_sym_false,
+  [sym_nat] = sym_nat,
+  [anon_sym_succ] = anon_sym_succ,
+  [anon_sym_pred] = anon_sym_pred,
+  [anon_sym_iszero] = anon_sym_iszero,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [anon_sym_DQUOTE2] = anon_sym_DQUOTE,
+  [sym_string_content] = sym_string_content,
+  [sym_escape_sequence] = sym_escape_sequence,
+  [anon_sym_concat] = anon_sym_concat,
+  [anon_sym_length] = anon_sym_length,
+  [anon_sym_eq] = anon_sym_eq,
+  [anon_sym_not] = anon_sym_not,
+  [anon_sym_AMP_AMP] = anon_sym_AMP_AMP,
+  [anon_sym_PIPE_PIPE] = anon_sym_PIPE_PIPE,
+  [anon_sym_EQ_EQ] = anon_sym_EQ_EQ,
+  [sym_wildcard] = sym_wildcard,
+  [sym__type_ident] = sym_ident,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_inl] = anon_sym_inl,
+  [anon_sym_inr] = anon_sym_inr,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_GT] = anon_sym_GT,
+  [anon_sym_case] = anon_sym_case,
+  [anon_sym_of] = anon_sym_of,
+  [anon_sym_PIPE] = anon_sym_PIPE,
+  [anon_sym_EQ_GT] = anon_sym_EQ_GT,
+  [sym_nil] = sym_nil,
+  [anon_sym_cons] = anon_sym_cons,
+  [anon_sym_isnil] = anon_sym_isnil,
+  [anon_sym_head] = anon_sym_head,
+  [anon_sym_tail] = anon_sym_tail,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_ref] = anon_sym_ref,
+  [anon_sym_BANG] = anon_sym_BANG,
+  [anon_sym_COLON_EQ] = anon_sym_COLON_EQ,
+  [sym_error] = sym_error,
+  [anon_sym_raise] = anon_sym_raise,
+  [anon_sym_try] = anon_sym_try,
+  [anon_sym_with] = anon_sym_with,
+  [anon_sym_SLASH_BSLASH] = anon_sym_SLASH_BSLASH,
+  [anon_sym_STAR] = anon_sym_STAR,
+  [anon_sym_as] = anon_sym_as,
+  [anon_sym_let] = anon_sym_let,
+  [anon_sym_in] = anon_sym_in,
+  [anon_sym_SEMI] = anon_sym_SEMI,
   [anon_sym_COLON] = anon_sym_COLON,
   [anon_sym_if] = anon_sym_if,
   [anon_sym_then] = anon_sym_then,
   [anon_sym_else] = anon_sym_else,
-  [anon_sym_let] = anon_sym_let,
-  [anon_sym_EQ] = anon_sym_EQ,
-  [anon_sym_SEMI] = anon_sym_SEMI,
+  [anon_sym_letrec] = anon_sym_letrec,
+  [anon_sym_type] = anon_sym_type,
+  [anon_sym_import] = anon_sym_import,
+  [sym_path] = sym_path,
+  [anon_sym_fix] = anon_sym_fix,
+  [anon_sym_DASH_GT] = anon_sym_DASH_GT,
+  [anon_sym_PLUS] = anon_sym_PLUS,
+  [anon_sym_List] = anon_sym_List,
+  [anon_sym_Ref] = anon_sym_Ref,
+  [anon_sym_Rec] = anon_sym_Rec,
+  [anon_sym_All] = anon_sym_All,
+  [anon_sym_Some] = anon_sym_Some,
   [sym_source_file] = sym_source_file,
   [sym__expr] = sym__expr,
+  [sym__block] = sym__block,
   [sym_bool] = sym_bool,
+  [sym_succ] = sym_succ,
+  [sym_pred] = sym_pred,
+  [sym_iszero] = sym_iszero,
+  [sym_string] = sym_string,
+  [sym_concat] = sym_concat,
+  [sym_length] = sym_length,
+  [sym_eq] = sym_eq,
+  [sym_not] = sym_not,
+  [sym_and] = sym_and,
+  [sym_or] = sym_or,
+  [sym_equal] = sym_equal,
+  [sym_tuple] = sym_tuple,
+  [sym_record] = sym_record,
+  [sym_record_field] = sym_record_field,
+  [sym_projection] = sym_projection,
+  [sym_inl] = sym_inl,
+  [sym_inr] = sym_inr,
+  [sym_variant] = sym_variant,
+  [sym_case] = sym_case,
+  [sym_inl_arm] = sym_inl_arm,
+  [sym_inr_arm] = sym_inr_arm,
+  [sym_variant_arm] = sym_variant_arm,
+  [sym_cons] = sym_cons,
+  [sym_isnil] = sym_isnil,
+  [sym_head] = sym_head,
+  [sym_tail] = sym_tail,
+  [sym_list] = sym_list,
+  [sym_ref] = sym_ref,
+  [sym_deref] = sym_deref,
+  [sym_assign] = sym_assign,
+  [sym_raise] = sym_raise,
+  [sym_try] = sym_try,
+  [sym_type_abs] = sym_type_abs,
+  [sym_type_app] = sym_type_app,
+  [sym_pack] = sym_pack,
+  [sym_unpack] = sym_unpack,
   [sym_def] = sym_def,
   [sym_call] = sym_call,
   [sym_ifElse] = sym_ifElse,
   [sym_let] = sym_let,
+  [sym__pattern] = sym__pattern,
+  [sym_tuple_pattern] = sym_tuple_pattern,
+  [sym_record_pattern] = sym_record_pattern,
+  [sym_record_field_pattern] = sym_record_field_pattern,
+  [sym_letrec] = sym_letrec,
+  [sym_type_alias] = sym_type_alias,
+  [sym_import] = sym_import,
+  [sym_fix] = sym_fix,
+  [sym_ascribe] = sym_ascribe,
+  [sym__type] = sym__type,
+  [sym_fn_type] = sym_fn_type,
+  [sym_sum_type] = sym_sum_type,
+  [sym_list_type] = sym_list_type,
+  [sym_ref_type] = sym_ref_type,
+  [sym_rec_type] = sym_rec_type,
+  [sym_forall_type] = sym_forall_type,
+  [sym_some_type] = sym_some_type,
+  [sym_product_type] = sym_product_type,
+  [sym_record_type] = sym_record_type,
+  [sym_variant_type] = sym_variant_type,
+  [sym_record_field_type] = sym_record_field_type,
+  [sym_sequence] = sym_sequence,
+  [aux_sym_string_repeat1] = aux_sym_string_repeat1,
+  [aux_sym_tuple_repeat1] = aux_sym_tuple_repeat1,
+  [aux_sym_record_repeat1] = aux_sym_record_repeat1,
+  [aux_sym_case_repeat1] = aux_sym_case_repeat1,
+  [aux_sym_def_repeat1] = aux_sym_def_repeat1,
+  [aux_sym_tuple_pattern_repeat1] = aux_sym_tuple_pattern_repeat1,
+  [aux_sym_record_pattern_repeat1] = aux_sym_record_pattern_repeat1,
+  [aux_sym_product_type_repeat1] = aux_sym_product_type_repeat1,
+  [aux_sym_record_type_repeat1] = aux_sym_record_type_repeat1,
+  [alias_sym_type_name] = alias_sym_type_name,
 };
 
 static const TSSymbolMetadata ts_symbol_metadata[] = {
@@ -107,6 +494,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [sym_unit] = {
+    .visible = true,
+    .named = true,
+  },
   [anon_sym_true] = {
     .visible = true,
     .named = false,
@@ -115,6 +506,206 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [sym_nat] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_succ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_pred] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_iszero] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DQUOTE2] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_string_content] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_escape_sequence] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_concat] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_length] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_eq] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_not] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AMP_AMP] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PIPE_PIPE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_wildcard] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__type_ident] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_inl] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_inr] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_case] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_of] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PIPE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_nil] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_cons] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_isnil] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_head] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_tail] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ref] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANG] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_error] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_raise] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_try] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_with] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SLASH_BSLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_STAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_as] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_let] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_in] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SEMI] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_COLON] = {
     .visible = true,
     .named = false,
@@ -131,15 +722,51 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_let] = {
+  [anon_sym_letrec] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_EQ] = {
+  [anon_sym_type] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_SEMI] = {
+  [anon_sym_import] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_path] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_fix] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_List] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Ref] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Rec] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_All] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Some] = {
     .visible = true,
     .named = false,
   },
@@ -151,674 +778,30397 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = true,
   },
+  [sym__block] = {
+    .visible = false,
+    .named = true,
+  },
   [sym_bool] = {
     .visible = true,
     .named = true,
   },
-  [sym_def] = {
+  [sym_succ] = {
     .visible = true,
     .named = true,
   },
-  [sym_call] = {
+  [sym_pred] = {
     .visible = true,
     .named = true,
   },
-  [sym_ifElse] = {
+  [sym_iszero] = {
     .visible = true,
     .named = true,
   },
-  [sym_let] = {
+  [sym_string] = {
     .visible = true,
     .named = true,
   },
-};
-
-enum ts_field_identifiers {
-  field_arg = 1,
-  field_body = 2,
-  field_cond = 3,
-  field_else = 4,
-  field_func = 5,
-  field_in = 6,
-  field_key = 7,
-  field_then = 8,
-  field_value = 9,
-};
-
-static const char * const ts_field_names[] = {
-  [0] = NULL,
-  [field_arg] = "arg",
-  [field_body] = "body",
-  [field_cond] = "cond",
-  [field_else] = "else",
-  [field_func] = "func",
-  [field_in] = "in",
-  [field_key] = "key",
-  [field_then] = "then",
-  [field_value] = "value",
-};
-
-static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
-  [1] = {.index = 0, .length = 2},
-  [2] = {.index = 2, .length = 2},
-  [3] = {.index = 4, .length = 3},
-  [4] = {.index = 7, .length = 3},
-};
-
-static const TSFieldMapEntry ts_field_map_entries[] = {
-  [0] =
-    {field_arg, 1},
-    {field_func, 0},
-  [2] =
-    {field_arg, 0},
-    {field_body, 2},
-  [4] =
-    {field_cond, 1},
-    {field_else, 5},
-    {field_then, 3},
-  [7] =
-    {field_in, 5},
-    {field_key, 1},
-    {field_value, 3},
-};
-
-static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
-  [0] = {0},
-};
-
-static const uint16_t ts_non_terminal_alias_map[] = {
-  0,
-};
-
-static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
-  [0] = 0,
-  [1] = 1,
-  [2] = 2,
-  [3] = 3,
-  [4] = 4,
-  [5] = 5,
-  [6] = 6,
-  [7] = 7,
-  [8] = 8,
-  [9] = 9,
-  [10] = 10,
-  [11] = 11,
-  [12] = 12,
-  [13] = 13,
-  [14] = 14,
-  [15] = 15,
-  [16] = 16,
-  [17] = 17,
-  [18] = 18,
-  [19] = 19,
-  [20] = 20,
-  [21] = 21,
-  [22] = 22,
-  [23] = 23,
-};
-
-static bool ts_lex(TSLexer *lexer, TSStateId state) {
-  START_LEXER();
-  eof = lexer->eof(lexer);
-  switch (state) {
-    case 0:
-      if (eof) ADVANCE(1);
-      if (lookahead == '#') ADVANCE(2);
-      if (lookahead == '(') ADVANCE(3);
-      if (lookahead == ')') ADVANCE(4);
-      if (lookahead == ':') ADVANCE(6);
-      if (lookahead == ';') ADVANCE(8);
-      if (lookahead == '=') ADVANCE(7);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(0);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(5);
-      END_STATE();
-    case 1:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
-      END_STATE();
-    case 2:
-      ACCEPT_TOKEN(sym_comment);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(2);
-      END_STATE();
-    case 3:
-      ACCEPT_TOKEN(anon_sym_LPAREN);
-      END_STATE();
-    case 4:
-      ACCEPT_TOKEN(anon_sym_RPAREN);
-      END_STATE();
-    case 5:
-      ACCEPT_TOKEN(sym_ident);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(5);
-      END_STATE();
-    case 6:
-      ACCEPT_TOKEN(anon_sym_COLON);
-      END_STATE();
-    case 7:
-      ACCEPT_TOKEN(anon_sym_EQ);
-      END_STATE();
-    case 8:
-      ACCEPT_TOKEN(anon_sym_SEMI);
-      END_STATE();
-    default:
-      return false;
-  }
-}
-
-static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
-  START_LEXER();
-  eof = lexer->eof(lexer);
-  switch (state) {
-    case 0:
-      if (lookahead == 'e') ADVANCE(1);
-      if (lookahead == 'f') ADVANCE(2);
-      if (lookahead == 'i') ADVANCE(3);
-      if (lookahead == 'l') ADVANCE(4);
-      if (lookahead == 't') ADVANCE(5);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(0);
-      END_STATE();
-    case 1:
-      if (lookahead == 'l') ADVANCE(6);
-      END_STATE();
-    case 2:
-      if (lookahead == 'a') ADVANCE(7);
-      END_STATE();
-    case 3:
-      if (lookahead == 'f') ADVANCE(8);
-      END_STATE();
-    case 4:
-      if (lookahead == 'e') ADVANCE(9);
-      END_STATE();
-    case 5:
-      if (lookahead == 'h') ADVANCE(10);
-      if (lookahead == 'r') ADVANCE(11);
-      END_STATE();
-    case 6:
-      if (lookahead == 's') ADVANCE(12);
-      END_STATE();
-    case 7:
-      if (lookahead == 'l') ADVANCE(13);
-      END_STATE();
-    case 8:
-      ACCEPT_TOKEN(anon_sym_if);
-      END_STATE();
-    case 9:
-      if (lookahead == 't') ADVANCE(14);
-      END_STATE();
-    case 10:
-      if (lookahead == 'e') ADVANCE(15);
-      END_STATE();
-    case 11:
-      if (lookahead == 'u') ADVANCE(16);
-      END_STATE();
-    case 12:
-      if (lookahead == 'e') ADVANCE(17);
-      END_STATE();
-    case 13:
-      if (lookahead == 's') ADVANCE(18);
-      END_STATE();
-    case 14:
-      ACCEPT_TOKEN(anon_sym_let);
-      END_STATE();
-    case 15:
-      if (lookahead == 'n') ADVANCE(19);
-      END_STATE();
-    case 16:
-      if (lookahead == 'e') ADVANCE(20);
-      END_STATE();
-    case 17:
-      ACCEPT_TOKEN(anon_sym_else);
-      END_STATE();
-    case 18:
-      if (lookahead == 'e') ADVANCE(21);
-      END_STATE();
-    case 19:
-      ACCEPT_TOKEN(anon_sym_then);
-      END_STATE();
-    case 20:
-      ACCEPT_TOKEN(anon_sym_true);
-      END_STATE();
-    case 21:
-      ACCEPT_TOKEN(anon_sym_false);
-      END_STATE();
-    default:
-      return false;
-  }
-}
-
-static const TSLexMode ts_lex_modes[STATE_COUNT] = {
-  [0] = {.lex_state = 0},
-  [1] = {.lex_state = 0},
-  [2] = {.lex_state = 0},
-  [3] = {.lex_state = 0},
-  [4] = {.lex_state = 0},
-  [5] = {.lex_state = 0},
-  [6] = {.lex_state = 0},
-  [7] = {.lex_state = 0},
-  [8] = {.lex_state = 0},
-  [9] = {.lex_state = 0},
-  [10] = {.lex_state = 0},
-  [11] = {.lex_state = 0},
-  [12] = {.lex_state = 0},
-  [13] = {.lex_state = 0},
-  [14] = {.lex_state = 0},
-  [15] = {.lex_state = 0},
-  [16] = {.lex_state = 0},
-  [17] = {.lex_state = 0},
-  [18] = {.lex_state = 0},
-  [19] = {.lex_state = 0},
-  [20] = {.lex_state = 0},
-  [21] = {.lex_state = 0},
-  [22] = {.lex_state = 0},
-  [23] = {.lex_state = 0},
-};
-
-static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
-  [0] = {
-    [ts_builtin_sym_end] = ACTIONS(1),
-    [sym_ident] = ACTIONS(1),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(1),
-    [anon_sym_RPAREN] = ACTIONS(1),
-    [anon_sym_true] = ACTIONS(1),
-    [anon_sym_false] = ACTIONS(1),
-    [anon_sym_COLON] = ACTIONS(1),
-    [anon_sym_if] = ACTIONS(1),
-    [anon_sym_then] = ACTIONS(1),
-    [anon_sym_else] = ACTIONS(1),
-    [anon_sym_let] = ACTIONS(1),
-    [anon_sym_EQ] = ACTIONS(1),
-    [anon_sym_SEMI] = ACTIONS(1),
+  [sym_concat] = {
+    .visible = true,
+    .named = true,
   },
-  [1] = {
-    [sym_source_file] = STATE(22),
-    [sym__expr] = STATE(9),
-    [sym_bool] = STATE(9),
-    [sym_def] = STATE(9),
-    [sym_call] = STATE(9),
-    [sym_ifElse] = STATE(9),
-    [sym_let] = STATE(9),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_length] = {
+    .visible = true,
+    .named = true,
   },
-  [2] = {
-    [sym__expr] = STATE(3),
-    [sym_bool] = STATE(3),
-    [sym_def] = STATE(3),
-    [sym_call] = STATE(3),
-    [sym_ifElse] = STATE(3),
-    [sym_let] = STATE(3),
-    [ts_builtin_sym_end] = ACTIONS(15),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_RPAREN] = ACTIONS(15),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_then] = ACTIONS(17),
-    [anon_sym_else] = ACTIONS(17),
-    [anon_sym_let] = ACTIONS(13),
-    [anon_sym_SEMI] = ACTIONS(15),
+  [sym_eq] = {
+    .visible = true,
+    .named = true,
   },
-  [3] = {
-    [sym__expr] = STATE(3),
-    [sym_bool] = STATE(3),
-    [sym_def] = STATE(3),
-    [sym_call] = STATE(3),
-    [sym_ifElse] = STATE(3),
-    [sym_let] = STATE(3),
-    [ts_builtin_sym_end] = ACTIONS(19),
-    [sym_ident] = ACTIONS(21),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(19),
-    [anon_sym_RPAREN] = ACTIONS(19),
-    [anon_sym_true] = ACTIONS(21),
-    [anon_sym_false] = ACTIONS(21),
-    [anon_sym_if] = ACTIONS(21),
-    [anon_sym_then] = ACTIONS(21),
-    [anon_sym_else] = ACTIONS(21),
-    [anon_sym_let] = ACTIONS(21),
-    [anon_sym_SEMI] = ACTIONS(19),
+  [sym_not] = {
+    .visible = true,
+    .named = true,
   },
-  [4] = {
-    [sym__expr] = STATE(3),
-    [sym_bool] = STATE(3),
-    [sym_def] = STATE(3),
-    [sym_call] = STATE(3),
-    [sym_ifElse] = STATE(3),
-    [sym_let] = STATE(3),
-    [ts_builtin_sym_end] = ACTIONS(23),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_RPAREN] = ACTIONS(23),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_then] = ACTIONS(25),
-    [anon_sym_else] = ACTIONS(25),
-    [anon_sym_let] = ACTIONS(13),
-    [anon_sym_SEMI] = ACTIONS(23),
+  [sym_and] = {
+    .visible = true,
+    .named = true,
   },
-  [5] = {
-    [sym__expr] = STATE(3),
-    [sym_bool] = STATE(3),
-    [sym_def] = STATE(3),
-    [sym_call] = STATE(3),
-    [sym_ifElse] = STATE(3),
-    [sym_let] = STATE(3),
-    [ts_builtin_sym_end] = ACTIONS(27),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_RPAREN] = ACTIONS(27),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_then] = ACTIONS(29),
-    [anon_sym_else] = ACTIONS(29),
-    [anon_sym_let] = ACTIONS(13),
-    [anon_sym_SEMI] = ACTIONS(27),
+  [sym_or] = {
+    .visible = true,
+    .named = true,
   },
-  [6] = {
-    [sym__expr] = STATE(3),
-    [sym_bool] = STATE(3),
-    [sym_def] = STATE(3),
-    [sym_call] = STATE(3),
-    [sym_ifElse] = STATE(3),
-    [sym_let] = STATE(3),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_then] = ACTIONS(31),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_equal] = {
+    .visible = true,
+    .named = true,
   },
-  [7] = {
-    [sym__expr] = STATE(3),
-    [sym_bool] = STATE(3),
-    [sym_def] = STATE(3),
-    [sym_call] = STATE(3),
-    [sym_ifElse] = STATE(3),
-    [sym_let] = STATE(3),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
-    [anon_sym_SEMI] = ACTIONS(33),
+  [sym_tuple] = {
+    .visible = true,
+    .named = true,
   },
-  [8] = {
-    [sym__expr] = STATE(3),
-    [sym_bool] = STATE(3),
-    [sym_def] = STATE(3),
-    [sym_call] = STATE(3),
-    [sym_ifElse] = STATE(3),
-    [sym_let] = STATE(3),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_else] = ACTIONS(35),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_record] = {
+    .visible = true,
+    .named = true,
   },
-  [9] = {
-    [sym__expr] = STATE(3),
-    [sym_bool] = STATE(3),
-    [sym_def] = STATE(3),
-    [sym_call] = STATE(3),
-    [sym_ifElse] = STATE(3),
-    [sym_let] = STATE(3),
-    [ts_builtin_sym_end] = ACTIONS(37),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_record_field] = {
+    .visible = true,
+    .named = true,
   },
-  [10] = {
-    [sym__expr] = STATE(3),
-    [sym_bool] = STATE(3),
-    [sym_def] = STATE(3),
-    [sym_call] = STATE(3),
-    [sym_ifElse] = STATE(3),
-    [sym_let] = STATE(3),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_RPAREN] = ACTIONS(39),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_projection] = {
+    .visible = true,
+    .named = true,
   },
-  [11] = {
-    [sym__expr] = STATE(6),
-    [sym_bool] = STATE(6),
-    [sym_def] = STATE(6),
-    [sym_call] = STATE(6),
-    [sym_ifElse] = STATE(6),
-    [sym_let] = STATE(6),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_inl] = {
+    .visible = true,
+    .named = true,
   },
-  [12] = {
-    [sym__expr] = STATE(10),
-    [sym_bool] = STATE(10),
-    [sym_def] = STATE(10),
-    [sym_call] = STATE(10),
-    [sym_ifElse] = STATE(10),
-    [sym_let] = STATE(10),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_inr] = {
+    .visible = true,
+    .named = true,
   },
-  [13] = {
-    [sym__expr] = STATE(2),
-    [sym_bool] = STATE(2),
-    [sym_def] = STATE(2),
-    [sym_call] = STATE(2),
-    [sym_ifElse] = STATE(2),
-    [sym_let] = STATE(2),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_variant] = {
+    .visible = true,
+    .named = true,
   },
-  [14] = {
-    [sym__expr] = STATE(4),
-    [sym_bool] = STATE(4),
-    [sym_def] = STATE(4),
-    [sym_call] = STATE(4),
-    [sym_ifElse] = STATE(4),
-    [sym_let] = STATE(4),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_case] = {
+    .visible = true,
+    .named = true,
   },
-  [15] = {
-    [sym__expr] = STATE(8),
-    [sym_bool] = STATE(8),
-    [sym_def] = STATE(8),
-    [sym_call] = STATE(8),
-    [sym_ifElse] = STATE(8),
-    [sym_let] = STATE(8),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_inl_arm] = {
+    .visible = true,
+    .named = true,
   },
-  [16] = {
-    [sym__expr] = STATE(7),
-    [sym_bool] = STATE(7),
-    [sym_def] = STATE(7),
-    [sym_call] = STATE(7),
-    [sym_ifElse] = STATE(7),
-    [sym_let] = STATE(7),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_inr_arm] = {
+    .visible = true,
+    .named = true,
   },
-  [17] = {
-    [ts_builtin_sym_end] = ACTIONS(41),
-    [sym_ident] = ACTIONS(43),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(41),
-    [anon_sym_RPAREN] = ACTIONS(41),
-    [anon_sym_true] = ACTIONS(43),
-    [anon_sym_false] = ACTIONS(43),
-    [anon_sym_COLON] = ACTIONS(45),
-    [anon_sym_if] = ACTIONS(43),
-    [anon_sym_then] = ACTIONS(43),
-    [anon_sym_else] = ACTIONS(43),
-    [anon_sym_let] = ACTIONS(43),
-    [anon_sym_SEMI] = ACTIONS(41),
+  [sym_variant_arm] = {
+    .visible = true,
+    .named = true,
   },
-  [18] = {
-    [sym__expr] = STATE(5),
-    [sym_bool] = STATE(5),
-    [sym_def] = STATE(5),
-    [sym_call] = STATE(5),
-    [sym_ifElse] = STATE(5),
-    [sym_let] = STATE(5),
-    [sym_ident] = ACTIONS(5),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_true] = ACTIONS(9),
-    [anon_sym_false] = ACTIONS(9),
-    [anon_sym_if] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(13),
+  [sym_cons] = {
+    .visible = true,
+    .named = true,
   },
-  [19] = {
-    [ts_builtin_sym_end] = ACTIONS(47),
-    [sym_ident] = ACTIONS(49),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(47),
-    [anon_sym_RPAREN] = ACTIONS(47),
-    [anon_sym_true] = ACTIONS(49),
-    [anon_sym_false] = ACTIONS(49),
-    [anon_sym_if] = ACTIONS(49),
-    [anon_sym_then] = ACTIONS(49),
-    [anon_sym_else] = ACTIONS(49),
-    [anon_sym_let] = ACTIONS(49),
-    [anon_sym_SEMI] = ACTIONS(47),
+  [sym_isnil] = {
+    .visible = true,
+    .named = true,
   },
-  [20] = {
-    [ts_builtin_sym_end] = ACTIONS(51),
-    [sym_ident] = ACTIONS(53),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_LPAREN] = ACTIONS(51),
-    [anon_sym_RPAREN] = ACTIONS(51),
-    [anon_sym_true] = ACTIONS(53),
-    [anon_sym_false] = ACTIONS(53),
-    [anon_sym_if] = ACTIONS(53),
-    [anon_sym_then] = ACTIONS(53),
-    [anon_sym_else] = ACTIONS(53),
-    [anon_sym_let] = ACTIONS(53),
-    [anon_sym_SEMI] = ACTIONS(51),
+  [sym_head] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_tail] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_list] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ref] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_deref] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_assign] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_raise] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_try] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_abs] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_app] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pack] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_unpack] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_def] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ifElse] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_let] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__pattern] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_tuple_pattern] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_record_pattern] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_record_field_pattern] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_letrec] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_alias] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_import] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_fix] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ascribe] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__type] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_fn_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_sum_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_list_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ref_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rec_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_forall_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_some_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_product_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_record_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_variant_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_record_field_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_sequence] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_string_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_tuple_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_record_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_case_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_def_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_tuple_pattern_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_record_pattern_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_product_type_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_record_type_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [alias_sym_type_name] = {
+    .visible = true,
+    .named = true,
   },
 };
 
-static const uint16_t ts_small_parse_table[] = {
-  [0] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(55), 1,
-      sym_ident,
-  [7] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(57), 1,
-      ts_builtin_sym_end,
-  [14] = 2,
+enum ts_field_identifiers {
+  field_arg = 1,
+  field_arm = 2,
+  field_as = 3,
+  field_binder = 4,
+  field_body = 5,
+  field_cond = 6,
+  field_element = 7,
+  field_else = 8,
+  field_expr = 9,
+  field_field = 10,
+  field_first = 11,
+  field_from = 12,
+  field_func = 13,
+  field_handler = 14,
+  field_head = 15,
+  field_in = 16,
+  field_index = 17,
+  field_inl = 18,
+  field_inr = 19,
+  field_key = 20,
+  field_label = 21,
+  field_left = 22,
+  field_name = 23,
+  field_path = 24,
+  field_pattern = 25,
+  field_right = 26,
+  field_tail = 27,
+  field_target = 28,
+  field_then = 29,
+  field_to = 30,
+  field_type = 31,
+  field_value = 32,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_arg] = "arg",
+  [field_arm] = "arm",
+  [field_as] = "as",
+  [field_binder] = "binder",
+  [field_body] = "body",
+  [field_cond] = "cond",
+  [field_element] = "element",
+  [field_else] = "else",
+  [field_expr] = "expr",
+  [field_field] = "field",
+  [field_first] = "first",
+  [field_from] = "from",
+  [field_func] = "func",
+  [field_handler] = "handler",
+  [field_head] = "head",
+  [field_in] = "in",
+  [field_index] = "index",
+  [field_inl] = "inl",
+  [field_inr] = "inr",
+  [field_key] = "key",
+  [field_label] = "label",
+  [field_left] = "left",
+  [field_name] = "name",
+  [field_path] = "path",
+  [field_pattern] = "pattern",
+  [field_right] = "right",
+  [field_tail] = "tail",
+  [field_target] = "target",
+  [field_then] = "then",
+  [field_to] = "to",
+  [field_type] = "type",
+  [field_value] = "value",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 1},
+  [3] = {.index = 2, .length = 2},
+  [4] = {.index = 4, .length = 2},
+  [6] = {.index = 6, .length = 2},
+  [7] = {.index = 8, .length = 2},
+  [8] = {.index = 10, .length = 1},
+  [9] = {.index = 11, .length = 1},
+  [10] = {.index = 12, .length = 2},
+  [11] = {.index = 14, .length = 2},
+  [12] = {.index = 16, .length = 2},
+  [13] = {.index = 18, .length = 2},
+  [14] = {.index = 20, .length = 2},
+  [15] = {.index = 22, .length = 2},
+  [16] = {.index = 24, .length = 2},
+  [17] = {.index = 26, .length = 2},
+  [18] = {.index = 28, .length = 2},
+  [19] = {.index = 30, .length = 1},
+  [20] = {.index = 31, .length = 3},
+  [21] = {.index = 34, .length = 2},
+  [22] = {.index = 36, .length = 2},
+  [23] = {.index = 38, .length = 2},
+  [24] = {.index = 40, .length = 2},
+  [25] = {.index = 42, .length = 2},
+  [26] = {.index = 44, .length = 2},
+  [27] = {.index = 46, .length = 3},
+  [28] = {.index = 49, .length = 2},
+  [29] = {.index = 51, .length = 2},
+  [30] = {.index = 53, .length = 3},
+  [31] = {.index = 56, .length = 2},
+  [32] = {.index = 58, .length = 2},
+  [33] = {.index = 60, .length = 2},
+  [34] = {.index = 62, .length = 3},
+  [35] = {.index = 65, .length = 1},
+  [36] = {.index = 66, .length = 2},
+  [37] = {.index = 68, .length = 3},
+  [38] = {.index = 71, .length = 3},
+  [39] = {.index = 74, .length = 3},
+  [40] = {.index = 77, .length = 3},
+  [41] = {.index = 80, .length = 2},
+  [42] = {.index = 82, .length = 2},
+  [43] = {.index = 84, .length = 3},
+  [44] = {.index = 87, .length = 4},
+  [45] = {.index = 91, .length = 3},
+  [46] = {.index = 94, .length = 4},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_arg, 1},
+  [1] =
+    {field_arg, 0},
+  [2] =
+    {field_arg, 1},
+    {field_func, 0},
+  [4] =
+    {field_left, 1},
+    {field_right, 2},
+  [6] =
+    {field_arg, 0},
+    {field_body, 2},
+  [8] =
+    {field_arg, 0, .inherited = true},
+    {field_arg, 1, .inherited = true},
+  [10] =
+    {field_element, 1},
+  [11] =
+    {field_field, 1},
+  [12] =
+    {field_head, 1},
+    {field_tail, 2},
+  [14] =
+    {field_left, 0},
+    {field_right, 2},
+  [16] =
+    {field_expr, 0},
+    {field_index, 2},
+  [18] =
+    {field_expr, 0},
+    {field_label, 2},
+  [20] =
+    {field_target, 0},
+    {field_value, 2},
+  [22] =
+    {field_expr, 0},
+    {field_type, 2},
+  [24] =
+    {field_first, 0},
+    {field_then, 2},
+  [26] =
+    {field_element, 1},
+    {field_element, 2, .inherited = true},
+  [28] =
+    {field_element, 0, .inherited = true},
+    {field_element, 1, .inherited = true},
+  [30] =
+    {field_type, 1},
+  [31] =
+    {field_arg, 0},
+    {field_arg, 1, .inherited = true},
+    {field_body, 3},
+  [34] =
+    {field_label, 0},
+    {field_value, 2},
+  [36] =
+    {field_field, 1},
+    {field_field, 2, .inherited = true},
+  [38] =
+    {field_field, 0, .inherited = true},
+    {field_field, 1, .inherited = true},
+  [40] =
+    {field_arm, 3},
+    {field_expr, 1},
+  [42] =
+    {field_body, 1},
+    {field_handler, 3},
+  [44] =
+    {field_body, 3},
+    {field_name, 1},
+  [46] =
+    {field_arg, 0},
+    {field_body, 4},
+    {field_type, 2},
+  [49] =
+    {field_from, 0},
+    {field_to, 2},
+  [51] =
+    {field_label, 1},
+    {field_value, 3},
+  [53] =
+    {field_arm, 3},
+    {field_arm, 4, .inherited = true},
+    {field_expr, 1},
+  [56] =
+    {field_label, 0},
+    {field_pattern, 2},
+  [58] =
+    {field_label, 0},
+    {field_type, 2},
+  [60] =
+    {field_name, 1},
+    {field_type, 3},
+  [62] =
+    {field_expr, 1},
+    {field_inl, 3},
+    {field_inr, 5},
+  [65] =
+    {field_arm, 1},
+  [66] =
+    {field_arm, 0, .inherited = true},
+    {field_arm, 1, .inherited = true},
+  [68] =
+    {field_in, 5},
+    {field_key, 1},
+    {field_value, 3},
+  [71] =
+    {field_cond, 1},
+    {field_else, 5},
+    {field_then, 3},
+  [74] =
+    {field_in, 5},
+    {field_name, 1},
+    {field_type, 3},
+  [77] =
+    {field_in, 5},
+    {field_name, 3},
+    {field_path, 1},
+  [80] =
+    {field_binder, 1},
+    {field_body, 3},
+  [82] =
+    {field_name, 2},
+    {field_type, 4},
+  [84] =
+    {field_as, 7},
+    {field_type, 2},
+    {field_value, 4},
+  [87] =
+    {field_in, 7},
+    {field_key, 1},
+    {field_type, 3},
+    {field_value, 5},
+  [91] =
+    {field_binder, 3},
+    {field_body, 6},
+    {field_label, 1},
+  [94] =
+    {field_in, 9},
+    {field_key, 4},
+    {field_type, 2},
+    {field_value, 7},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+  [5] = {
+    [0] = alias_sym_type_name,
+  },
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 4,
+  [5] = 5,
+  [6] = 6,
+  [7] = 7,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 13,
+  [14] = 14,
+  [15] = 15,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 36,
+  [39] = 36,
+  [40] = 33,
+  [41] = 32,
+  [42] = 42,
+  [43] = 27,
+  [44] = 26,
+  [45] = 29,
+  [46] = 23,
+  [47] = 22,
+  [48] = 24,
+  [49] = 25,
+  [50] = 28,
+  [51] = 21,
+  [52] = 31,
+  [53] = 30,
+  [54] = 54,
+  [55] = 55,
+  [56] = 25,
+  [57] = 57,
+  [58] = 21,
+  [59] = 31,
+  [60] = 30,
+  [61] = 33,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 23,
+  [67] = 22,
+  [68] = 57,
+  [69] = 26,
+  [70] = 24,
+  [71] = 27,
+  [72] = 57,
+  [73] = 28,
+  [74] = 65,
+  [75] = 64,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 80,
+  [84] = 76,
+  [85] = 80,
+  [86] = 86,
+  [87] = 82,
+  [88] = 86,
+  [89] = 82,
+  [90] = 76,
+  [91] = 77,
+  [92] = 77,
+  [93] = 93,
+  [94] = 94,
+  [95] = 94,
+  [96] = 96,
+  [97] = 96,
+  [98] = 93,
+  [99] = 96,
+  [100] = 93,
+  [101] = 94,
+  [102] = 102,
+  [103] = 103,
+  [104] = 102,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 105,
+  [111] = 106,
+  [112] = 107,
+  [113] = 113,
+  [114] = 113,
+  [115] = 103,
+  [116] = 116,
+  [117] = 117,
+  [118] = 118,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 133,
+  [134] = 134,
+  [135] = 122,
+  [136] = 136,
+  [137] = 137,
+  [138] = 138,
+  [139] = 139,
+  [140] = 140,
+  [141] = 141,
+  [142] = 142,
+  [143] = 143,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 148,
+  [149] = 149,
+  [150] = 132,
+  [151] = 133,
+  [152] = 136,
+  [153] = 137,
+  [154] = 140,
+  [155] = 143,
+  [156] = 144,
+  [157] = 146,
+  [158] = 158,
+  [159] = 159,
+  [160] = 160,
+  [161] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 164,
+  [165] = 161,
+  [166] = 123,
+  [167] = 167,
+  [168] = 159,
+  [169] = 160,
+  [170] = 170,
+  [171] = 129,
+  [172] = 162,
+  [173] = 138,
+  [174] = 147,
+  [175] = 163,
+  [176] = 141,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 126,
+  [181] = 127,
+  [182] = 128,
+  [183] = 132,
+  [184] = 133,
+  [185] = 122,
+  [186] = 136,
+  [187] = 137,
+  [188] = 140,
+  [189] = 143,
+  [190] = 144,
+  [191] = 146,
+  [192] = 158,
+  [193] = 158,
+  [194] = 159,
+  [195] = 160,
+  [196] = 170,
+  [197] = 162,
+  [198] = 177,
+  [199] = 163,
+  [200] = 164,
+  [201] = 161,
+  [202] = 123,
+  [203] = 129,
+  [204] = 178,
+  [205] = 164,
+  [206] = 206,
+  [207] = 138,
+  [208] = 147,
+  [209] = 149,
+  [210] = 141,
+  [211] = 177,
+  [212] = 178,
+  [213] = 179,
+  [214] = 126,
+  [215] = 127,
+  [216] = 128,
+  [217] = 148,
+  [218] = 125,
+  [219] = 219,
+  [220] = 148,
+  [221] = 124,
+  [222] = 125,
+  [223] = 139,
+  [224] = 145,
+  [225] = 167,
+  [226] = 167,
+  [227] = 179,
+  [228] = 134,
+  [229] = 134,
+  [230] = 170,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 241,
+  [291] = 241,
+  [292] = 241,
+  [293] = 241,
+  [294] = 294,
+  [295] = 241,
+  [296] = 255,
+  [297] = 271,
+  [298] = 298,
+  [299] = 265,
+  [300] = 277,
+  [301] = 255,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
+  [317] = 316,
+  [318] = 311,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 322,
+  [323] = 322,
+  [324] = 324,
+  [325] = 325,
+  [326] = 326,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+  [330] = 327,
+  [331] = 331,
+  [332] = 332,
+  [333] = 333,
+  [334] = 334,
+  [335] = 335,
+  [336] = 336,
+  [337] = 335,
+  [338] = 335,
+  [339] = 339,
+  [340] = 340,
+  [341] = 341,
+  [342] = 342,
+  [343] = 341,
+  [344] = 344,
+  [345] = 341,
+  [346] = 346,
+  [347] = 347,
+  [348] = 348,
+  [349] = 349,
+  [350] = 350,
+  [351] = 351,
+  [352] = 352,
+  [353] = 353,
+  [354] = 354,
+  [355] = 355,
+  [356] = 356,
+  [357] = 357,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 361,
+  [362] = 362,
+  [363] = 363,
+  [364] = 364,
+  [365] = 365,
+  [366] = 366,
+  [367] = 364,
+  [368] = 366,
+  [369] = 369,
+  [370] = 370,
+  [371] = 371,
+  [372] = 372,
+  [373] = 373,
+  [374] = 374,
+  [375] = 375,
+  [376] = 376,
+  [377] = 377,
+  [378] = 378,
+  [379] = 379,
+  [380] = 364,
+  [381] = 381,
+  [382] = 382,
+  [383] = 383,
+  [384] = 384,
+  [385] = 385,
+  [386] = 386,
+  [387] = 387,
+  [388] = 388,
+  [389] = 389,
+  [390] = 390,
+  [391] = 390,
+  [392] = 392,
+  [393] = 382,
+  [394] = 366,
+  [395] = 395,
+  [396] = 396,
+  [397] = 397,
+  [398] = 398,
+  [399] = 399,
+  [400] = 400,
+  [401] = 401,
+  [402] = 402,
+  [403] = 403,
+  [404] = 404,
+  [405] = 405,
+  [406] = 406,
+  [407] = 407,
+  [408] = 408,
+  [409] = 409,
+  [410] = 408,
+  [411] = 411,
+  [412] = 412,
+  [413] = 413,
+  [414] = 408,
+  [415] = 415,
+  [416] = 412,
+  [417] = 404,
+  [418] = 418,
+  [419] = 419,
+  [420] = 409,
+  [421] = 399,
+  [422] = 412,
+  [423] = 423,
+  [424] = 424,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
+  [429] = 429,
+  [430] = 423,
+  [431] = 431,
+  [432] = 432,
+  [433] = 429,
+  [434] = 434,
+  [435] = 424,
+  [436] = 436,
+  [437] = 437,
+  [438] = 438,
+  [439] = 439,
+  [440] = 440,
+  [441] = 441,
+  [442] = 439,
+  [443] = 443,
+  [444] = 444,
+  [445] = 445,
+  [446] = 427,
+  [447] = 447,
+  [448] = 448,
+  [449] = 449,
+  [450] = 450,
+  [451] = 437,
+  [452] = 441,
+  [453] = 425,
+  [454] = 434,
+  [455] = 429,
+  [456] = 424,
+  [457] = 457,
+  [458] = 458,
+  [459] = 459,
+  [460] = 460,
+  [461] = 461,
+  [462] = 457,
+  [463] = 449,
+  [464] = 425,
+  [465] = 465,
+  [466] = 438,
+  [467] = 465,
+  [468] = 468,
+  [469] = 469,
+  [470] = 470,
+  [471] = 471,
+  [472] = 437,
+  [473] = 473,
+  [474] = 468,
+  [475] = 470,
+  [476] = 473,
+  [477] = 460,
+  [478] = 428,
+  [479] = 479,
+  [480] = 461,
+  [481] = 481,
+  [482] = 426,
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(18);
+      ADVANCE_MAP(
+        '!', 56,
+        '"', 24,
+        '#', 19,
+        '&', 8,
+        '(', 20,
+        ')', 21,
+        '*', 59,
+        '+', 65,
+        ',', 44,
+        '-', 10,
+        '.', 49,
+        '/', 12,
+        ':', 62,
+        ';', 60,
+        '<', 50,
+        '=', 48,
+        '>', 51,
+        'A', 36,
+        'L', 34,
+        'R', 32,
+        'S', 38,
+        '[', 54,
+        '\\', 14,
+        ']', 55,
+        '_', 42,
+        '{', 43,
+        '|', 52,
+        '}', 45,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(15);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(22);
+      if (('B' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 1:
+      if (lookahead == '\n') SKIP(7);
+      if (lookahead == '"') ADVANCE(24);
+      if (lookahead == '#') ADVANCE(26);
+      if (lookahead == '\\') ADVANCE(14);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(25);
+      if (lookahead != 0) ADVANCE(26);
+      END_STATE();
+    case 2:
+      ADVANCE_MAP(
+        '!', 56,
+        '"', 23,
+        '#', 19,
+        '&', 8,
+        '(', 20,
+        '+', 65,
+        ',', 44,
+        '-', 10,
+        '.', 49,
+        '/', 12,
+        ':', 62,
+        '<', 50,
+        '=', 47,
+        '[', 54,
+        '_', 42,
+        '{', 43,
+        '|', 13,
+        '}', 45,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(22);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 3:
+      ADVANCE_MAP(
+        '!', 56,
+        '"', 23,
+        '#', 19,
+        '(', 20,
+        '*', 59,
+        '+', 65,
+        ',', 44,
+        '-', 10,
+        '/', 12,
+        '<', 50,
+        '=', 11,
+        'A', 36,
+        'L', 34,
+        'R', 32,
+        'S', 38,
+        '[', 54,
+        '_', 42,
+        '{', 43,
+        '}', 45,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(3);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(22);
+      if (('B' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 4:
+      ADVANCE_MAP(
+        '!', 56,
+        '"', 23,
+        '#', 19,
+        '(', 20,
+        '+', 65,
+        ',', 44,
+        '-', 10,
+        '/', 12,
+        ':', 61,
+        '<', 50,
+        '=', 46,
+        'A', 36,
+        'L', 34,
+        'R', 32,
+        '[', 54,
+        ']', 55,
+        '_', 42,
+        '{', 43,
+        '}', 45,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(4);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(22);
+      if (('B' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 5:
+      if (lookahead == '"') ADVANCE(6);
+      if (lookahead == '#') ADVANCE(19);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(5);
+      END_STATE();
+    case 6:
+      if (lookahead == '"') ADVANCE(63);
+      if (lookahead != 0) ADVANCE(6);
+      END_STATE();
+    case 7:
+      if (lookahead == '#') ADVANCE(19);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(7);
+      END_STATE();
+    case 8:
+      if (lookahead == '&') ADVANCE(28);
+      END_STATE();
+    case 9:
+      if (lookahead == '=') ADVANCE(30);
+      if (lookahead == '>') ADVANCE(53);
+      END_STATE();
+    case 10:
+      if (lookahead == '>') ADVANCE(64);
+      END_STATE();
+    case 11:
+      if (lookahead == '>') ADVANCE(53);
+      END_STATE();
+    case 12:
+      if (lookahead == '\\') ADVANCE(58);
+      END_STATE();
+    case 13:
+      if (lookahead == '|') ADVANCE(29);
+      END_STATE();
+    case 14:
+      if (lookahead == '"' ||
+          lookahead == '\\' ||
+          lookahead == 'n' ||
+          lookahead == 't') ADVANCE(27);
+      END_STATE();
+    case 15:
+      if (eof) ADVANCE(18);
+      ADVANCE_MAP(
+        '!', 56,
+        '"', 23,
+        '#', 19,
+        '&', 8,
+        '(', 20,
+        ')', 21,
+        '*', 59,
+        '+', 65,
+        ',', 44,
+        '-', 10,
+        '.', 49,
+        '/', 12,
+        ':', 62,
+        ';', 60,
+        '<', 50,
+        '=', 48,
+        '>', 51,
+        'A', 36,
+        'L', 34,
+        'R', 32,
+        'S', 38,
+        '[', 54,
+        ']', 55,
+        '_', 42,
+        '{', 43,
+        '|', 52,
+        '}', 45,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(15);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(22);
+      if (('B' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 16:
+      if (eof) ADVANCE(18);
+      ADVANCE_MAP(
+        '!', 56,
+        '"', 23,
+        '#', 19,
+        '&', 8,
+        '(', 20,
+        ')', 21,
+        '*', 59,
+        '+', 65,
+        ',', 44,
+        '-', 10,
+        '.', 49,
+        '/', 12,
+        ':', 62,
+        ';', 60,
+        '<', 50,
+        '=', 48,
+        '>', 51,
+        '[', 54,
+        ']', 55,
+        '_', 42,
+        '{', 43,
+        '|', 52,
+        '}', 45,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(16);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(22);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 17:
+      if (eof) ADVANCE(18);
+      ADVANCE_MAP(
+        '!', 56,
+        '"', 23,
+        '#', 19,
+        '&', 8,
+        '(', 20,
+        ')', 21,
+        '+', 65,
+        ',', 44,
+        '-', 10,
+        '.', 49,
+        '/', 12,
+        ':', 62,
+        ';', 60,
+        '<', 50,
+        '=', 9,
+        '>', 51,
+        '[', 54,
+        ']', 55,
+        '_', 42,
+        '{', 43,
+        '|', 52,
+        '}', 45,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(17);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(22);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 18:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 19:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(19);
+      END_STATE();
+    case 20:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 21:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 22:
+      ACCEPT_TOKEN(sym_nat);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(22);
+      END_STATE();
+    case 23:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 24:
+      ACCEPT_TOKEN(anon_sym_DQUOTE2);
+      END_STATE();
+    case 25:
+      ACCEPT_TOKEN(sym_string_content);
+      if (lookahead == '#') ADVANCE(26);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(25);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != '"' &&
+          lookahead != '#' &&
+          lookahead != '\\') ADVANCE(26);
+      END_STATE();
+    case 26:
+      ACCEPT_TOKEN(sym_string_content);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(26);
+      END_STATE();
+    case 27:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      END_STATE();
+    case 28:
+      ACCEPT_TOKEN(anon_sym_AMP_AMP);
+      END_STATE();
+    case 29:
+      ACCEPT_TOKEN(anon_sym_PIPE_PIPE);
+      END_STATE();
+    case 30:
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 'c') ADVANCE(68);
+      if (lookahead == 'f') ADVANCE(67);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 'e') ADVANCE(31);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 'e') ADVANCE(70);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 34:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 'i') ADVANCE(39);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 'l') ADVANCE(69);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 'l') ADVANCE(35);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 37:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 'm') ADVANCE(33);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 'o') ADVANCE(37);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 's') ADVANCE(40);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 40:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 't') ADVANCE(66);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 41:
+      ACCEPT_TOKEN(sym_ident);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(sym_wildcard);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 43:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '=') ADVANCE(30);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '=') ADVANCE(30);
+      if (lookahead == '>') ADVANCE(53);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(anon_sym_LT);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(anon_sym_GT);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(anon_sym_PIPE);
+      if (lookahead == '|') ADVANCE(29);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(anon_sym_EQ_GT);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(anon_sym_COLON_EQ);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(anon_sym_SLASH_BSLASH);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(anon_sym_SEMI);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      if (lookahead == '=') ADVANCE(57);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(sym_path);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(anon_sym_DASH_GT);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(anon_sym_List);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(anon_sym_Ref);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(anon_sym_Rec);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(anon_sym_All);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(anon_sym_Some);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(41);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      ADVANCE_MAP(
+        'a', 1,
+        'c', 2,
+        'e', 3,
+        'f', 4,
+        'h', 5,
+        'i', 6,
+        'l', 7,
+        'n', 8,
+        'o', 9,
+        'p', 10,
+        'r', 11,
+        's', 12,
+        't', 13,
+        'u', 14,
+        'w', 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(0);
+      if (('A' <= lookahead && lookahead <= 'Z')) ADVANCE(16);
+      END_STATE();
+    case 1:
+      if (lookahead == 's') ADVANCE(17);
+      END_STATE();
+    case 2:
+      if (lookahead == 'a') ADVANCE(18);
+      if (lookahead == 'o') ADVANCE(19);
+      END_STATE();
+    case 3:
+      if (lookahead == 'l') ADVANCE(20);
+      if (lookahead == 'q') ADVANCE(21);
+      if (lookahead == 'r') ADVANCE(22);
+      END_STATE();
+    case 4:
+      if (lookahead == 'a') ADVANCE(23);
+      if (lookahead == 'i') ADVANCE(24);
+      END_STATE();
+    case 5:
+      if (lookahead == 'e') ADVANCE(25);
+      END_STATE();
+    case 6:
+      if (lookahead == 'f') ADVANCE(26);
+      if (lookahead == 'm') ADVANCE(27);
+      if (lookahead == 'n') ADVANCE(28);
+      if (lookahead == 's') ADVANCE(29);
+      END_STATE();
+    case 7:
+      if (lookahead == 'e') ADVANCE(30);
+      END_STATE();
+    case 8:
+      if (lookahead == 'i') ADVANCE(31);
+      if (lookahead == 'o') ADVANCE(32);
+      END_STATE();
+    case 9:
+      if (lookahead == 'f') ADVANCE(33);
+      END_STATE();
+    case 10:
+      if (lookahead == 'r') ADVANCE(34);
+      END_STATE();
+    case 11:
+      if (lookahead == 'a') ADVANCE(35);
+      if (lookahead == 'e') ADVANCE(36);
+      END_STATE();
+    case 12:
+      if (lookahead == 'u') ADVANCE(37);
+      END_STATE();
+    case 13:
+      if (lookahead == 'a') ADVANCE(38);
+      if (lookahead == 'h') ADVANCE(39);
+      if (lookahead == 'r') ADVANCE(40);
+      if (lookahead == 'y') ADVANCE(41);
+      END_STATE();
+    case 14:
+      if (lookahead == 'n') ADVANCE(42);
+      END_STATE();
+    case 15:
+      if (lookahead == 'i') ADVANCE(43);
+      END_STATE();
+    case 16:
+      ACCEPT_TOKEN(sym__type_ident);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(16);
+      END_STATE();
+    case 17:
+      ACCEPT_TOKEN(anon_sym_as);
+      END_STATE();
+    case 18:
+      if (lookahead == 's') ADVANCE(44);
+      END_STATE();
+    case 19:
+      if (lookahead == 'n') ADVANCE(45);
+      END_STATE();
+    case 20:
+      if (lookahead == 's') ADVANCE(46);
+      END_STATE();
+    case 21:
+      ACCEPT_TOKEN(anon_sym_eq);
+      END_STATE();
+    case 22:
+      if (lookahead == 'r') ADVANCE(47);
+      END_STATE();
+    case 23:
+      if (lookahead == 'l') ADVANCE(48);
+      END_STATE();
+    case 24:
+      if (lookahead == 'x') ADVANCE(49);
+      END_STATE();
+    case 25:
+      if (lookahead == 'a') ADVANCE(50);
+      END_STATE();
+    case 26:
+      ACCEPT_TOKEN(anon_sym_if);
+      END_STATE();
+    case 27:
+      if (lookahead == 'p') ADVANCE(51);
+      END_STATE();
+    case 28:
+      ACCEPT_TOKEN(anon_sym_in);
+      if (lookahead == 'l') ADVANCE(52);
+      if (lookahead == 'r') ADVANCE(53);
+      END_STATE();
+    case 29:
+      if (lookahead == 'n') ADVANCE(54);
+      if (lookahead == 'z') ADVANCE(55);
+      END_STATE();
+    case 30:
+      if (lookahead == 'n') ADVANCE(56);
+      if (lookahead == 't') ADVANCE(57);
+      END_STATE();
+    case 31:
+      if (lookahead == 'l') ADVANCE(58);
+      END_STATE();
+    case 32:
+      if (lookahead == 't') ADVANCE(59);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(anon_sym_of);
+      END_STATE();
+    case 34:
+      if (lookahead == 'e') ADVANCE(60);
+      END_STATE();
+    case 35:
+      if (lookahead == 'i') ADVANCE(61);
+      END_STATE();
+    case 36:
+      if (lookahead == 'f') ADVANCE(62);
+      END_STATE();
+    case 37:
+      if (lookahead == 'c') ADVANCE(63);
+      END_STATE();
+    case 38:
+      if (lookahead == 'i') ADVANCE(64);
+      END_STATE();
+    case 39:
+      if (lookahead == 'e') ADVANCE(65);
+      END_STATE();
+    case 40:
+      if (lookahead == 'u') ADVANCE(66);
+      if (lookahead == 'y') ADVANCE(67);
+      END_STATE();
+    case 41:
+      if (lookahead == 'p') ADVANCE(68);
+      END_STATE();
+    case 42:
+      if (lookahead == 'i') ADVANCE(69);
+      END_STATE();
+    case 43:
+      if (lookahead == 't') ADVANCE(70);
+      END_STATE();
+    case 44:
+      if (lookahead == 'e') ADVANCE(71);
+      END_STATE();
+    case 45:
+      if (lookahead == 'c') ADVANCE(72);
+      if (lookahead == 's') ADVANCE(73);
+      END_STATE();
+    case 46:
+      if (lookahead == 'e') ADVANCE(74);
+      END_STATE();
+    case 47:
+      if (lookahead == 'o') ADVANCE(75);
+      END_STATE();
+    case 48:
+      if (lookahead == 's') ADVANCE(76);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(anon_sym_fix);
+      END_STATE();
+    case 50:
+      if (lookahead == 'd') ADVANCE(77);
+      END_STATE();
+    case 51:
+      if (lookahead == 'o') ADVANCE(78);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(anon_sym_inl);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(anon_sym_inr);
+      END_STATE();
+    case 54:
+      if (lookahead == 'i') ADVANCE(79);
+      END_STATE();
+    case 55:
+      if (lookahead == 'e') ADVANCE(80);
+      END_STATE();
+    case 56:
+      if (lookahead == 'g') ADVANCE(81);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(anon_sym_let);
+      if (lookahead == 'r') ADVANCE(82);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(sym_nil);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(anon_sym_not);
+      END_STATE();
+    case 60:
+      if (lookahead == 'd') ADVANCE(83);
+      END_STATE();
+    case 61:
+      if (lookahead == 's') ADVANCE(84);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(anon_sym_ref);
+      END_STATE();
+    case 63:
+      if (lookahead == 'c') ADVANCE(85);
+      END_STATE();
+    case 64:
+      if (lookahead == 'l') ADVANCE(86);
+      END_STATE();
+    case 65:
+      if (lookahead == 'n') ADVANCE(87);
+      END_STATE();
+    case 66:
+      if (lookahead == 'e') ADVANCE(88);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(anon_sym_try);
+      END_STATE();
+    case 68:
+      if (lookahead == 'e') ADVANCE(89);
+      END_STATE();
+    case 69:
+      if (lookahead == 't') ADVANCE(90);
+      END_STATE();
+    case 70:
+      if (lookahead == 'h') ADVANCE(91);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(anon_sym_case);
+      END_STATE();
+    case 72:
+      if (lookahead == 'a') ADVANCE(92);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(anon_sym_cons);
+      END_STATE();
+    case 74:
+      ACCEPT_TOKEN(anon_sym_else);
+      END_STATE();
+    case 75:
+      if (lookahead == 'r') ADVANCE(93);
+      END_STATE();
+    case 76:
+      if (lookahead == 'e') ADVANCE(94);
+      END_STATE();
+    case 77:
+      ACCEPT_TOKEN(anon_sym_head);
+      END_STATE();
+    case 78:
+      if (lookahead == 'r') ADVANCE(95);
+      END_STATE();
+    case 79:
+      if (lookahead == 'l') ADVANCE(96);
+      END_STATE();
+    case 80:
+      if (lookahead == 'r') ADVANCE(97);
+      END_STATE();
+    case 81:
+      if (lookahead == 't') ADVANCE(98);
+      END_STATE();
+    case 82:
+      if (lookahead == 'e') ADVANCE(99);
+      END_STATE();
+    case 83:
+      ACCEPT_TOKEN(anon_sym_pred);
+      END_STATE();
+    case 84:
+      if (lookahead == 'e') ADVANCE(100);
+      END_STATE();
+    case 85:
+      ACCEPT_TOKEN(anon_sym_succ);
+      END_STATE();
+    case 86:
+      ACCEPT_TOKEN(anon_sym_tail);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(anon_sym_then);
+      END_STATE();
+    case 88:
+      ACCEPT_TOKEN(anon_sym_true);
+      END_STATE();
+    case 89:
+      ACCEPT_TOKEN(anon_sym_type);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(sym_unit);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(anon_sym_with);
+      END_STATE();
+    case 92:
+      if (lookahead == 't') ADVANCE(101);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(sym_error);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(anon_sym_false);
+      END_STATE();
+    case 95:
+      if (lookahead == 't') ADVANCE(102);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(anon_sym_isnil);
+      END_STATE();
+    case 97:
+      if (lookahead == 'o') ADVANCE(103);
+      END_STATE();
+    case 98:
+      if (lookahead == 'h') ADVANCE(104);
+      END_STATE();
+    case 99:
+      if (lookahead == 'c') ADVANCE(105);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(anon_sym_raise);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(anon_sym_concat);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(anon_sym_import);
+      END_STATE();
+    case 103:
+      ACCEPT_TOKEN(anon_sym_iszero);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(anon_sym_length);
+      END_STATE();
+    case 105:
+      ACCEPT_TOKEN(anon_sym_letrec);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 16},
+  [2] = {.lex_state = 16},
+  [3] = {.lex_state = 16},
+  [4] = {.lex_state = 16},
+  [5] = {.lex_state = 16},
+  [6] = {.lex_state = 16},
+  [7] = {.lex_state = 16},
+  [8] = {.lex_state = 16},
+  [9] = {.lex_state = 16},
+  [10] = {.lex_state = 16},
+  [11] = {.lex_state = 16},
+  [12] = {.lex_state = 16},
+  [13] = {.lex_state = 16},
+  [14] = {.lex_state = 16},
+  [15] = {.lex_state = 16},
+  [16] = {.lex_state = 16},
+  [17] = {.lex_state = 16},
+  [18] = {.lex_state = 3},
+  [19] = {.lex_state = 16},
+  [20] = {.lex_state = 16},
+  [21] = {.lex_state = 16},
+  [22] = {.lex_state = 16},
+  [23] = {.lex_state = 16},
+  [24] = {.lex_state = 16},
+  [25] = {.lex_state = 16},
+  [26] = {.lex_state = 16},
+  [27] = {.lex_state = 16},
+  [28] = {.lex_state = 16},
+  [29] = {.lex_state = 16},
+  [30] = {.lex_state = 16},
+  [31] = {.lex_state = 16},
+  [32] = {.lex_state = 16},
+  [33] = {.lex_state = 16},
+  [34] = {.lex_state = 4},
+  [35] = {.lex_state = 4},
+  [36] = {.lex_state = 4},
+  [37] = {.lex_state = 4},
+  [38] = {.lex_state = 4},
+  [39] = {.lex_state = 4},
+  [40] = {.lex_state = 16},
+  [41] = {.lex_state = 16},
+  [42] = {.lex_state = 16},
+  [43] = {.lex_state = 16},
+  [44] = {.lex_state = 16},
+  [45] = {.lex_state = 16},
+  [46] = {.lex_state = 16},
+  [47] = {.lex_state = 16},
+  [48] = {.lex_state = 16},
+  [49] = {.lex_state = 16},
+  [50] = {.lex_state = 16},
+  [51] = {.lex_state = 16},
+  [52] = {.lex_state = 16},
+  [53] = {.lex_state = 16},
+  [54] = {.lex_state = 16},
+  [55] = {.lex_state = 16},
+  [56] = {.lex_state = 16},
+  [57] = {.lex_state = 16},
+  [58] = {.lex_state = 16},
+  [59] = {.lex_state = 16},
+  [60] = {.lex_state = 16},
+  [61] = {.lex_state = 16},
+  [62] = {.lex_state = 16},
+  [63] = {.lex_state = 16},
+  [64] = {.lex_state = 16},
+  [65] = {.lex_state = 16},
+  [66] = {.lex_state = 16},
+  [67] = {.lex_state = 16},
+  [68] = {.lex_state = 16},
+  [69] = {.lex_state = 16},
+  [70] = {.lex_state = 16},
+  [71] = {.lex_state = 16},
+  [72] = {.lex_state = 16},
+  [73] = {.lex_state = 16},
+  [74] = {.lex_state = 16},
+  [75] = {.lex_state = 16},
+  [76] = {.lex_state = 16},
+  [77] = {.lex_state = 16},
+  [78] = {.lex_state = 16},
+  [79] = {.lex_state = 16},
+  [80] = {.lex_state = 16},
+  [81] = {.lex_state = 16},
+  [82] = {.lex_state = 16},
+  [83] = {.lex_state = 16},
+  [84] = {.lex_state = 16},
+  [85] = {.lex_state = 16},
+  [86] = {.lex_state = 16},
+  [87] = {.lex_state = 16},
+  [88] = {.lex_state = 16},
+  [89] = {.lex_state = 16},
+  [90] = {.lex_state = 16},
+  [91] = {.lex_state = 16},
+  [92] = {.lex_state = 16},
+  [93] = {.lex_state = 16},
+  [94] = {.lex_state = 16},
+  [95] = {.lex_state = 16},
+  [96] = {.lex_state = 16},
+  [97] = {.lex_state = 16},
+  [98] = {.lex_state = 16},
+  [99] = {.lex_state = 16},
+  [100] = {.lex_state = 16},
+  [101] = {.lex_state = 16},
+  [102] = {.lex_state = 16},
+  [103] = {.lex_state = 16},
+  [104] = {.lex_state = 16},
+  [105] = {.lex_state = 16},
+  [106] = {.lex_state = 16},
+  [107] = {.lex_state = 16},
+  [108] = {.lex_state = 16},
+  [109] = {.lex_state = 16},
+  [110] = {.lex_state = 16},
+  [111] = {.lex_state = 16},
+  [112] = {.lex_state = 16},
+  [113] = {.lex_state = 16},
+  [114] = {.lex_state = 16},
+  [115] = {.lex_state = 16},
+  [116] = {.lex_state = 16},
+  [117] = {.lex_state = 16},
+  [118] = {.lex_state = 16},
+  [119] = {.lex_state = 16},
+  [120] = {.lex_state = 16},
+  [121] = {.lex_state = 16},
+  [122] = {.lex_state = 16},
+  [123] = {.lex_state = 16},
+  [124] = {.lex_state = 16},
+  [125] = {.lex_state = 16},
+  [126] = {.lex_state = 16},
+  [127] = {.lex_state = 16},
+  [128] = {.lex_state = 16},
+  [129] = {.lex_state = 16},
+  [130] = {.lex_state = 16},
+  [131] = {.lex_state = 16},
+  [132] = {.lex_state = 16},
+  [133] = {.lex_state = 16},
+  [134] = {.lex_state = 16},
+  [135] = {.lex_state = 16},
+  [136] = {.lex_state = 16},
+  [137] = {.lex_state = 16},
+  [138] = {.lex_state = 16},
+  [139] = {.lex_state = 16},
+  [140] = {.lex_state = 16},
+  [141] = {.lex_state = 16},
+  [142] = {.lex_state = 16},
+  [143] = {.lex_state = 16},
+  [144] = {.lex_state = 16},
+  [145] = {.lex_state = 16},
+  [146] = {.lex_state = 16},
+  [147] = {.lex_state = 16},
+  [148] = {.lex_state = 16},
+  [149] = {.lex_state = 16},
+  [150] = {.lex_state = 16},
+  [151] = {.lex_state = 16},
+  [152] = {.lex_state = 16},
+  [153] = {.lex_state = 16},
+  [154] = {.lex_state = 16},
+  [155] = {.lex_state = 16},
+  [156] = {.lex_state = 16},
+  [157] = {.lex_state = 16},
+  [158] = {.lex_state = 16},
+  [159] = {.lex_state = 16},
+  [160] = {.lex_state = 16},
+  [161] = {.lex_state = 16},
+  [162] = {.lex_state = 16},
+  [163] = {.lex_state = 16},
+  [164] = {.lex_state = 16},
+  [165] = {.lex_state = 16},
+  [166] = {.lex_state = 16},
+  [167] = {.lex_state = 16},
+  [168] = {.lex_state = 16},
+  [169] = {.lex_state = 16},
+  [170] = {.lex_state = 16},
+  [171] = {.lex_state = 16},
+  [172] = {.lex_state = 16},
+  [173] = {.lex_state = 16},
+  [174] = {.lex_state = 16},
+  [175] = {.lex_state = 16},
+  [176] = {.lex_state = 16},
+  [177] = {.lex_state = 16},
+  [178] = {.lex_state = 16},
+  [179] = {.lex_state = 16},
+  [180] = {.lex_state = 16},
+  [181] = {.lex_state = 16},
+  [182] = {.lex_state = 16},
+  [183] = {.lex_state = 16},
+  [184] = {.lex_state = 16},
+  [185] = {.lex_state = 16},
+  [186] = {.lex_state = 16},
+  [187] = {.lex_state = 16},
+  [188] = {.lex_state = 16},
+  [189] = {.lex_state = 16},
+  [190] = {.lex_state = 16},
+  [191] = {.lex_state = 16},
+  [192] = {.lex_state = 16},
+  [193] = {.lex_state = 16},
+  [194] = {.lex_state = 16},
+  [195] = {.lex_state = 16},
+  [196] = {.lex_state = 16},
+  [197] = {.lex_state = 16},
+  [198] = {.lex_state = 16},
+  [199] = {.lex_state = 16},
+  [200] = {.lex_state = 16},
+  [201] = {.lex_state = 16},
+  [202] = {.lex_state = 16},
+  [203] = {.lex_state = 16},
+  [204] = {.lex_state = 16},
+  [205] = {.lex_state = 16},
+  [206] = {.lex_state = 16},
+  [207] = {.lex_state = 16},
+  [208] = {.lex_state = 16},
+  [209] = {.lex_state = 16},
+  [210] = {.lex_state = 16},
+  [211] = {.lex_state = 16},
+  [212] = {.lex_state = 16},
+  [213] = {.lex_state = 16},
+  [214] = {.lex_state = 16},
+  [215] = {.lex_state = 16},
+  [216] = {.lex_state = 16},
+  [217] = {.lex_state = 16},
+  [218] = {.lex_state = 16},
+  [219] = {.lex_state = 16},
+  [220] = {.lex_state = 16},
+  [221] = {.lex_state = 16},
+  [222] = {.lex_state = 16},
+  [223] = {.lex_state = 16},
+  [224] = {.lex_state = 16},
+  [225] = {.lex_state = 16},
+  [226] = {.lex_state = 16},
+  [227] = {.lex_state = 16},
+  [228] = {.lex_state = 16},
+  [229] = {.lex_state = 16},
+  [230] = {.lex_state = 16},
+  [231] = {.lex_state = 16},
+  [232] = {.lex_state = 16},
+  [233] = {.lex_state = 16},
+  [234] = {.lex_state = 16},
+  [235] = {.lex_state = 16},
+  [236] = {.lex_state = 16},
+  [237] = {.lex_state = 16},
+  [238] = {.lex_state = 16},
+  [239] = {.lex_state = 16},
+  [240] = {.lex_state = 16},
+  [241] = {.lex_state = 17},
+  [242] = {.lex_state = 16},
+  [243] = {.lex_state = 16},
+  [244] = {.lex_state = 16},
+  [245] = {.lex_state = 16},
+  [246] = {.lex_state = 16},
+  [247] = {.lex_state = 16},
+  [248] = {.lex_state = 16},
+  [249] = {.lex_state = 16},
+  [250] = {.lex_state = 16},
+  [251] = {.lex_state = 16},
+  [252] = {.lex_state = 16},
+  [253] = {.lex_state = 16},
+  [254] = {.lex_state = 16},
+  [255] = {.lex_state = 16},
+  [256] = {.lex_state = 16},
+  [257] = {.lex_state = 16},
+  [258] = {.lex_state = 16},
+  [259] = {.lex_state = 16},
+  [260] = {.lex_state = 16},
+  [261] = {.lex_state = 16},
+  [262] = {.lex_state = 16},
+  [263] = {.lex_state = 16},
+  [264] = {.lex_state = 16},
+  [265] = {.lex_state = 16},
+  [266] = {.lex_state = 16},
+  [267] = {.lex_state = 16},
+  [268] = {.lex_state = 16},
+  [269] = {.lex_state = 16},
+  [270] = {.lex_state = 16},
+  [271] = {.lex_state = 16},
+  [272] = {.lex_state = 16},
+  [273] = {.lex_state = 16},
+  [274] = {.lex_state = 16},
+  [275] = {.lex_state = 16},
+  [276] = {.lex_state = 16},
+  [277] = {.lex_state = 16},
+  [278] = {.lex_state = 16},
+  [279] = {.lex_state = 16},
+  [280] = {.lex_state = 16},
+  [281] = {.lex_state = 16},
+  [282] = {.lex_state = 16},
+  [283] = {.lex_state = 16},
+  [284] = {.lex_state = 16},
+  [285] = {.lex_state = 16},
+  [286] = {.lex_state = 16},
+  [287] = {.lex_state = 16},
+  [288] = {.lex_state = 16},
+  [289] = {.lex_state = 16},
+  [290] = {.lex_state = 17},
+  [291] = {.lex_state = 16},
+  [292] = {.lex_state = 17},
+  [293] = {.lex_state = 17},
+  [294] = {.lex_state = 2},
+  [295] = {.lex_state = 16},
+  [296] = {.lex_state = 16},
+  [297] = {.lex_state = 16},
+  [298] = {.lex_state = 2},
+  [299] = {.lex_state = 16},
+  [300] = {.lex_state = 16},
+  [301] = {.lex_state = 16},
+  [302] = {.lex_state = 0},
+  [303] = {.lex_state = 4},
+  [304] = {.lex_state = 4},
+  [305] = {.lex_state = 4},
+  [306] = {.lex_state = 4},
+  [307] = {.lex_state = 4},
+  [308] = {.lex_state = 4},
+  [309] = {.lex_state = 4},
+  [310] = {.lex_state = 4},
+  [311] = {.lex_state = 4},
+  [312] = {.lex_state = 4},
+  [313] = {.lex_state = 4},
+  [314] = {.lex_state = 4},
+  [315] = {.lex_state = 4},
+  [316] = {.lex_state = 4},
+  [317] = {.lex_state = 4},
+  [318] = {.lex_state = 4},
+  [319] = {.lex_state = 4},
+  [320] = {.lex_state = 4},
+  [321] = {.lex_state = 4},
+  [322] = {.lex_state = 16},
+  [323] = {.lex_state = 16},
+  [324] = {.lex_state = 16},
+  [325] = {.lex_state = 16},
+  [326] = {.lex_state = 16},
+  [327] = {.lex_state = 16},
+  [328] = {.lex_state = 16},
+  [329] = {.lex_state = 16},
+  [330] = {.lex_state = 16},
+  [331] = {.lex_state = 0},
+  [332] = {.lex_state = 3},
+  [333] = {.lex_state = 0},
+  [334] = {.lex_state = 0},
+  [335] = {.lex_state = 16},
+  [336] = {.lex_state = 0},
+  [337] = {.lex_state = 16},
+  [338] = {.lex_state = 16},
+  [339] = {.lex_state = 0},
+  [340] = {.lex_state = 1},
+  [341] = {.lex_state = 16},
+  [342] = {.lex_state = 16},
+  [343] = {.lex_state = 16},
+  [344] = {.lex_state = 16},
+  [345] = {.lex_state = 16},
+  [346] = {.lex_state = 1},
+  [347] = {.lex_state = 1},
+  [348] = {.lex_state = 0},
+  [349] = {.lex_state = 16},
+  [350] = {.lex_state = 0},
+  [351] = {.lex_state = 0},
+  [352] = {.lex_state = 0},
+  [353] = {.lex_state = 16},
+  [354] = {.lex_state = 0},
+  [355] = {.lex_state = 4},
+  [356] = {.lex_state = 0},
+  [357] = {.lex_state = 4},
+  [358] = {.lex_state = 16},
+  [359] = {.lex_state = 16},
+  [360] = {.lex_state = 0},
+  [361] = {.lex_state = 0},
+  [362] = {.lex_state = 0},
+  [363] = {.lex_state = 0},
+  [364] = {.lex_state = 3},
+  [365] = {.lex_state = 0},
+  [366] = {.lex_state = 0},
+  [367] = {.lex_state = 3},
+  [368] = {.lex_state = 0},
+  [369] = {.lex_state = 0},
+  [370] = {.lex_state = 4},
+  [371] = {.lex_state = 16},
+  [372] = {.lex_state = 0},
+  [373] = {.lex_state = 4},
+  [374] = {.lex_state = 0},
+  [375] = {.lex_state = 4},
+  [376] = {.lex_state = 16},
+  [377] = {.lex_state = 4},
+  [378] = {.lex_state = 0},
+  [379] = {.lex_state = 0},
+  [380] = {.lex_state = 3},
+  [381] = {.lex_state = 16},
+  [382] = {.lex_state = 0},
+  [383] = {.lex_state = 0},
+  [384] = {.lex_state = 4},
+  [385] = {.lex_state = 0},
+  [386] = {.lex_state = 16},
+  [387] = {.lex_state = 4},
+  [388] = {.lex_state = 0},
+  [389] = {.lex_state = 0},
+  [390] = {.lex_state = 4},
+  [391] = {.lex_state = 4},
+  [392] = {.lex_state = 0},
+  [393] = {.lex_state = 0},
+  [394] = {.lex_state = 0},
+  [395] = {.lex_state = 0},
+  [396] = {.lex_state = 16},
+  [397] = {.lex_state = 4},
+  [398] = {.lex_state = 0},
+  [399] = {.lex_state = 0},
+  [400] = {.lex_state = 4},
+  [401] = {.lex_state = 0},
+  [402] = {.lex_state = 0},
+  [403] = {.lex_state = 16},
+  [404] = {.lex_state = 16},
+  [405] = {.lex_state = 0},
+  [406] = {.lex_state = 16},
+  [407] = {.lex_state = 16},
+  [408] = {.lex_state = 16},
+  [409] = {.lex_state = 16},
+  [410] = {.lex_state = 16},
+  [411] = {.lex_state = 16},
+  [412] = {.lex_state = 16},
+  [413] = {.lex_state = 16},
+  [414] = {.lex_state = 16},
+  [415] = {.lex_state = 16},
+  [416] = {.lex_state = 16},
+  [417] = {.lex_state = 16},
+  [418] = {.lex_state = 0},
+  [419] = {.lex_state = 16},
+  [420] = {.lex_state = 16},
+  [421] = {.lex_state = 0},
+  [422] = {.lex_state = 16},
+  [423] = {.lex_state = 0},
+  [424] = {.lex_state = 0},
+  [425] = {.lex_state = 0},
+  [426] = {.lex_state = 4},
+  [427] = {.lex_state = 0},
+  [428] = {.lex_state = 0},
+  [429] = {.lex_state = 3},
+  [430] = {.lex_state = 0},
+  [431] = {.lex_state = 4},
+  [432] = {.lex_state = 0},
+  [433] = {.lex_state = 3},
+  [434] = {.lex_state = 3},
+  [435] = {.lex_state = 0},
+  [436] = {.lex_state = 16},
+  [437] = {.lex_state = 16},
+  [438] = {.lex_state = 0},
+  [439] = {.lex_state = 16},
+  [440] = {.lex_state = 0},
+  [441] = {.lex_state = 16},
+  [442] = {.lex_state = 16},
+  [443] = {.lex_state = 4},
+  [444] = {.lex_state = 0},
+  [445] = {.lex_state = 4},
+  [446] = {.lex_state = 0},
+  [447] = {.lex_state = 0},
+  [448] = {.lex_state = 0},
+  [449] = {.lex_state = 4},
+  [450] = {.lex_state = 16},
+  [451] = {.lex_state = 16},
+  [452] = {.lex_state = 16},
+  [453] = {.lex_state = 0},
+  [454] = {.lex_state = 3},
+  [455] = {.lex_state = 3},
+  [456] = {.lex_state = 0},
+  [457] = {.lex_state = 4},
+  [458] = {.lex_state = 3},
+  [459] = {.lex_state = 16},
+  [460] = {.lex_state = 16},
+  [461] = {.lex_state = 5},
+  [462] = {.lex_state = 4},
+  [463] = {.lex_state = 4},
+  [464] = {.lex_state = 0},
+  [465] = {.lex_state = 4},
+  [466] = {.lex_state = 0},
+  [467] = {.lex_state = 4},
+  [468] = {.lex_state = 0},
+  [469] = {.lex_state = 16},
+  [470] = {.lex_state = 16},
+  [471] = {.lex_state = 16},
+  [472] = {.lex_state = 16},
+  [473] = {.lex_state = 16},
+  [474] = {.lex_state = 0},
+  [475] = {.lex_state = 16},
+  [476] = {.lex_state = 16},
+  [477] = {.lex_state = 16},
+  [478] = {.lex_state = 0},
+  [479] = {.lex_state = 0},
+  [480] = {.lex_state = 5},
+  [481] = {.lex_state = 0},
+  [482] = {.lex_state = 4},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [sym_ident] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [sym_unit] = ACTIONS(1),
+    [anon_sym_true] = ACTIONS(1),
+    [anon_sym_false] = ACTIONS(1),
+    [sym_nat] = ACTIONS(1),
+    [anon_sym_succ] = ACTIONS(1),
+    [anon_sym_pred] = ACTIONS(1),
+    [anon_sym_iszero] = ACTIONS(1),
+    [anon_sym_DQUOTE] = ACTIONS(1),
+    [anon_sym_DQUOTE2] = ACTIONS(1),
+    [sym_escape_sequence] = ACTIONS(1),
+    [anon_sym_concat] = ACTIONS(1),
+    [anon_sym_length] = ACTIONS(1),
+    [anon_sym_eq] = ACTIONS(1),
+    [anon_sym_not] = ACTIONS(1),
+    [anon_sym_AMP_AMP] = ACTIONS(1),
+    [anon_sym_PIPE_PIPE] = ACTIONS(1),
+    [anon_sym_EQ_EQ] = ACTIONS(1),
+    [sym_wildcard] = ACTIONS(1),
+    [sym__type_ident] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_inl] = ACTIONS(1),
+    [anon_sym_inr] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [anon_sym_case] = ACTIONS(1),
+    [anon_sym_of] = ACTIONS(1),
+    [anon_sym_PIPE] = ACTIONS(1),
+    [anon_sym_EQ_GT] = ACTIONS(1),
+    [sym_nil] = ACTIONS(1),
+    [anon_sym_cons] = ACTIONS(1),
+    [anon_sym_isnil] = ACTIONS(1),
+    [anon_sym_head] = ACTIONS(1),
+    [anon_sym_tail] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_ref] = ACTIONS(1),
+    [anon_sym_BANG] = ACTIONS(1),
+    [anon_sym_COLON_EQ] = ACTIONS(1),
+    [sym_error] = ACTIONS(1),
+    [anon_sym_raise] = ACTIONS(1),
+    [anon_sym_try] = ACTIONS(1),
+    [anon_sym_with] = ACTIONS(1),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(1),
+    [anon_sym_STAR] = ACTIONS(1),
+    [anon_sym_as] = ACTIONS(1),
+    [anon_sym_let] = ACTIONS(1),
+    [anon_sym_in] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_if] = ACTIONS(1),
+    [anon_sym_then] = ACTIONS(1),
+    [anon_sym_else] = ACTIONS(1),
+    [anon_sym_letrec] = ACTIONS(1),
+    [anon_sym_type] = ACTIONS(1),
+    [anon_sym_import] = ACTIONS(1),
+    [anon_sym_fix] = ACTIONS(1),
+    [anon_sym_DASH_GT] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_List] = ACTIONS(1),
+    [anon_sym_Ref] = ACTIONS(1),
+    [anon_sym_Rec] = ACTIONS(1),
+    [anon_sym_All] = ACTIONS(1),
+    [anon_sym_Some] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_source_file] = STATE(448),
+    [sym__expr] = STATE(29),
+    [sym__block] = STATE(447),
+    [sym_bool] = STATE(29),
+    [sym_succ] = STATE(29),
+    [sym_pred] = STATE(29),
+    [sym_iszero] = STATE(29),
+    [sym_string] = STATE(29),
+    [sym_concat] = STATE(29),
+    [sym_length] = STATE(29),
+    [sym_eq] = STATE(29),
+    [sym_not] = STATE(29),
+    [sym_and] = STATE(29),
+    [sym_or] = STATE(29),
+    [sym_equal] = STATE(29),
+    [sym_tuple] = STATE(29),
+    [sym_record] = STATE(29),
+    [sym_projection] = STATE(29),
+    [sym_inl] = STATE(29),
+    [sym_inr] = STATE(29),
+    [sym_variant] = STATE(29),
+    [sym_case] = STATE(29),
+    [sym_cons] = STATE(29),
+    [sym_isnil] = STATE(29),
+    [sym_head] = STATE(29),
+    [sym_tail] = STATE(29),
+    [sym_list] = STATE(29),
+    [sym_ref] = STATE(29),
+    [sym_deref] = STATE(29),
+    [sym_assign] = STATE(29),
+    [sym_raise] = STATE(29),
+    [sym_try] = STATE(29),
+    [sym_type_abs] = STATE(29),
+    [sym_type_app] = STATE(29),
+    [sym_pack] = STATE(29),
+    [sym_unpack] = STATE(29),
+    [sym_def] = STATE(29),
+    [sym_call] = STATE(29),
+    [sym_ifElse] = STATE(29),
+    [sym_let] = STATE(29),
+    [sym_letrec] = STATE(29),
+    [sym_type_alias] = STATE(29),
+    [sym_import] = STATE(29),
+    [sym_fix] = STATE(29),
+    [sym_ascribe] = STATE(29),
+    [sym_sequence] = STATE(447),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(9),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(13),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(9),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(9),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [2] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(73),
+    [sym_ident] = ACTIONS(75),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(73),
+    [anon_sym_RPAREN] = ACTIONS(73),
+    [sym_unit] = ACTIONS(75),
+    [anon_sym_true] = ACTIONS(75),
+    [anon_sym_false] = ACTIONS(75),
+    [sym_nat] = ACTIONS(73),
+    [anon_sym_succ] = ACTIONS(75),
+    [anon_sym_pred] = ACTIONS(75),
+    [anon_sym_iszero] = ACTIONS(75),
+    [anon_sym_DQUOTE] = ACTIONS(73),
+    [anon_sym_concat] = ACTIONS(75),
+    [anon_sym_length] = ACTIONS(75),
+    [anon_sym_eq] = ACTIONS(75),
+    [anon_sym_not] = ACTIONS(75),
+    [anon_sym_AMP_AMP] = ACTIONS(73),
+    [anon_sym_PIPE_PIPE] = ACTIONS(73),
+    [anon_sym_EQ_EQ] = ACTIONS(73),
+    [sym_wildcard] = ACTIONS(75),
+    [anon_sym_LBRACE] = ACTIONS(73),
+    [anon_sym_COMMA] = ACTIONS(73),
+    [anon_sym_RBRACE] = ACTIONS(73),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(75),
+    [anon_sym_inr] = ACTIONS(75),
+    [anon_sym_LT] = ACTIONS(73),
+    [anon_sym_GT] = ACTIONS(73),
+    [anon_sym_case] = ACTIONS(75),
+    [anon_sym_of] = ACTIONS(75),
+    [anon_sym_PIPE] = ACTIONS(75),
+    [sym_nil] = ACTIONS(75),
+    [anon_sym_cons] = ACTIONS(75),
+    [anon_sym_isnil] = ACTIONS(75),
+    [anon_sym_head] = ACTIONS(75),
+    [anon_sym_tail] = ACTIONS(75),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_RBRACK] = ACTIONS(73),
+    [anon_sym_ref] = ACTIONS(75),
+    [anon_sym_BANG] = ACTIONS(73),
+    [anon_sym_COLON_EQ] = ACTIONS(73),
+    [sym_error] = ACTIONS(75),
+    [anon_sym_raise] = ACTIONS(75),
+    [anon_sym_try] = ACTIONS(75),
+    [anon_sym_with] = ACTIONS(75),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(73),
+    [anon_sym_as] = ACTIONS(75),
+    [anon_sym_let] = ACTIONS(75),
+    [anon_sym_in] = ACTIONS(75),
+    [anon_sym_SEMI] = ACTIONS(73),
+    [anon_sym_if] = ACTIONS(75),
+    [anon_sym_then] = ACTIONS(75),
+    [anon_sym_else] = ACTIONS(75),
+    [anon_sym_letrec] = ACTIONS(75),
+    [anon_sym_type] = ACTIONS(75),
+    [anon_sym_import] = ACTIONS(75),
+    [anon_sym_fix] = ACTIONS(75),
+  },
+  [3] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(79),
+    [sym_ident] = ACTIONS(81),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(79),
+    [anon_sym_RPAREN] = ACTIONS(79),
+    [sym_unit] = ACTIONS(81),
+    [anon_sym_true] = ACTIONS(81),
+    [anon_sym_false] = ACTIONS(81),
+    [sym_nat] = ACTIONS(79),
+    [anon_sym_succ] = ACTIONS(81),
+    [anon_sym_pred] = ACTIONS(81),
+    [anon_sym_iszero] = ACTIONS(81),
+    [anon_sym_DQUOTE] = ACTIONS(79),
+    [anon_sym_concat] = ACTIONS(81),
+    [anon_sym_length] = ACTIONS(81),
+    [anon_sym_eq] = ACTIONS(81),
+    [anon_sym_not] = ACTIONS(81),
+    [anon_sym_AMP_AMP] = ACTIONS(79),
+    [anon_sym_PIPE_PIPE] = ACTIONS(79),
+    [anon_sym_EQ_EQ] = ACTIONS(79),
+    [sym_wildcard] = ACTIONS(81),
+    [anon_sym_LBRACE] = ACTIONS(79),
+    [anon_sym_COMMA] = ACTIONS(79),
+    [anon_sym_RBRACE] = ACTIONS(79),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(81),
+    [anon_sym_inr] = ACTIONS(81),
+    [anon_sym_LT] = ACTIONS(79),
+    [anon_sym_GT] = ACTIONS(79),
+    [anon_sym_case] = ACTIONS(81),
+    [anon_sym_of] = ACTIONS(81),
+    [anon_sym_PIPE] = ACTIONS(81),
+    [sym_nil] = ACTIONS(81),
+    [anon_sym_cons] = ACTIONS(81),
+    [anon_sym_isnil] = ACTIONS(81),
+    [anon_sym_head] = ACTIONS(81),
+    [anon_sym_tail] = ACTIONS(81),
+    [anon_sym_LBRACK] = ACTIONS(79),
+    [anon_sym_RBRACK] = ACTIONS(79),
+    [anon_sym_ref] = ACTIONS(81),
+    [anon_sym_BANG] = ACTIONS(79),
+    [anon_sym_COLON_EQ] = ACTIONS(79),
+    [sym_error] = ACTIONS(81),
+    [anon_sym_raise] = ACTIONS(81),
+    [anon_sym_try] = ACTIONS(81),
+    [anon_sym_with] = ACTIONS(81),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(79),
+    [anon_sym_as] = ACTIONS(81),
+    [anon_sym_let] = ACTIONS(81),
+    [anon_sym_in] = ACTIONS(81),
+    [anon_sym_SEMI] = ACTIONS(79),
+    [anon_sym_if] = ACTIONS(81),
+    [anon_sym_then] = ACTIONS(81),
+    [anon_sym_else] = ACTIONS(81),
+    [anon_sym_letrec] = ACTIONS(81),
+    [anon_sym_type] = ACTIONS(81),
+    [anon_sym_import] = ACTIONS(81),
+    [anon_sym_fix] = ACTIONS(81),
+  },
+  [4] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(83),
+    [sym_ident] = ACTIONS(85),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(83),
+    [anon_sym_RPAREN] = ACTIONS(83),
+    [sym_unit] = ACTIONS(85),
+    [anon_sym_true] = ACTIONS(85),
+    [anon_sym_false] = ACTIONS(85),
+    [sym_nat] = ACTIONS(83),
+    [anon_sym_succ] = ACTIONS(85),
+    [anon_sym_pred] = ACTIONS(85),
+    [anon_sym_iszero] = ACTIONS(85),
+    [anon_sym_DQUOTE] = ACTIONS(83),
+    [anon_sym_concat] = ACTIONS(85),
+    [anon_sym_length] = ACTIONS(85),
+    [anon_sym_eq] = ACTIONS(85),
+    [anon_sym_not] = ACTIONS(85),
+    [anon_sym_AMP_AMP] = ACTIONS(83),
+    [anon_sym_PIPE_PIPE] = ACTIONS(83),
+    [anon_sym_EQ_EQ] = ACTIONS(83),
+    [sym_wildcard] = ACTIONS(85),
+    [anon_sym_LBRACE] = ACTIONS(83),
+    [anon_sym_COMMA] = ACTIONS(83),
+    [anon_sym_RBRACE] = ACTIONS(83),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(85),
+    [anon_sym_inr] = ACTIONS(85),
+    [anon_sym_LT] = ACTIONS(83),
+    [anon_sym_GT] = ACTIONS(83),
+    [anon_sym_case] = ACTIONS(85),
+    [anon_sym_of] = ACTIONS(85),
+    [anon_sym_PIPE] = ACTIONS(85),
+    [sym_nil] = ACTIONS(85),
+    [anon_sym_cons] = ACTIONS(85),
+    [anon_sym_isnil] = ACTIONS(85),
+    [anon_sym_head] = ACTIONS(85),
+    [anon_sym_tail] = ACTIONS(85),
+    [anon_sym_LBRACK] = ACTIONS(83),
+    [anon_sym_RBRACK] = ACTIONS(83),
+    [anon_sym_ref] = ACTIONS(85),
+    [anon_sym_BANG] = ACTIONS(83),
+    [anon_sym_COLON_EQ] = ACTIONS(83),
+    [sym_error] = ACTIONS(85),
+    [anon_sym_raise] = ACTIONS(85),
+    [anon_sym_try] = ACTIONS(85),
+    [anon_sym_with] = ACTIONS(85),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(83),
+    [anon_sym_as] = ACTIONS(85),
+    [anon_sym_let] = ACTIONS(85),
+    [anon_sym_in] = ACTIONS(85),
+    [anon_sym_SEMI] = ACTIONS(83),
+    [anon_sym_if] = ACTIONS(85),
+    [anon_sym_then] = ACTIONS(85),
+    [anon_sym_else] = ACTIONS(85),
+    [anon_sym_letrec] = ACTIONS(85),
+    [anon_sym_type] = ACTIONS(85),
+    [anon_sym_import] = ACTIONS(85),
+    [anon_sym_fix] = ACTIONS(85),
+  },
+  [5] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(87),
+    [sym_ident] = ACTIONS(89),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(87),
+    [anon_sym_RPAREN] = ACTIONS(87),
+    [sym_unit] = ACTIONS(89),
+    [anon_sym_true] = ACTIONS(89),
+    [anon_sym_false] = ACTIONS(89),
+    [sym_nat] = ACTIONS(87),
+    [anon_sym_succ] = ACTIONS(89),
+    [anon_sym_pred] = ACTIONS(89),
+    [anon_sym_iszero] = ACTIONS(89),
+    [anon_sym_DQUOTE] = ACTIONS(87),
+    [anon_sym_concat] = ACTIONS(89),
+    [anon_sym_length] = ACTIONS(89),
+    [anon_sym_eq] = ACTIONS(89),
+    [anon_sym_not] = ACTIONS(89),
+    [anon_sym_AMP_AMP] = ACTIONS(87),
+    [anon_sym_PIPE_PIPE] = ACTIONS(87),
+    [anon_sym_EQ_EQ] = ACTIONS(87),
+    [sym_wildcard] = ACTIONS(89),
+    [anon_sym_LBRACE] = ACTIONS(87),
+    [anon_sym_COMMA] = ACTIONS(87),
+    [anon_sym_RBRACE] = ACTIONS(87),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(89),
+    [anon_sym_inr] = ACTIONS(89),
+    [anon_sym_LT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(87),
+    [anon_sym_case] = ACTIONS(89),
+    [anon_sym_of] = ACTIONS(89),
+    [anon_sym_PIPE] = ACTIONS(89),
+    [sym_nil] = ACTIONS(89),
+    [anon_sym_cons] = ACTIONS(89),
+    [anon_sym_isnil] = ACTIONS(89),
+    [anon_sym_head] = ACTIONS(89),
+    [anon_sym_tail] = ACTIONS(89),
+    [anon_sym_LBRACK] = ACTIONS(87),
+    [anon_sym_RBRACK] = ACTIONS(87),
+    [anon_sym_ref] = ACTIONS(89),
+    [anon_sym_BANG] = ACTIONS(87),
+    [anon_sym_COLON_EQ] = ACTIONS(87),
+    [sym_error] = ACTIONS(89),
+    [anon_sym_raise] = ACTIONS(89),
+    [anon_sym_try] = ACTIONS(89),
+    [anon_sym_with] = ACTIONS(89),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(87),
+    [anon_sym_as] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(89),
+    [anon_sym_in] = ACTIONS(89),
+    [anon_sym_SEMI] = ACTIONS(87),
+    [anon_sym_if] = ACTIONS(89),
+    [anon_sym_then] = ACTIONS(89),
+    [anon_sym_else] = ACTIONS(89),
+    [anon_sym_letrec] = ACTIONS(89),
+    [anon_sym_type] = ACTIONS(89),
+    [anon_sym_import] = ACTIONS(89),
+    [anon_sym_fix] = ACTIONS(89),
+  },
+  [6] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(91),
+    [sym_ident] = ACTIONS(93),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(91),
+    [anon_sym_RPAREN] = ACTIONS(91),
+    [sym_unit] = ACTIONS(93),
+    [anon_sym_true] = ACTIONS(93),
+    [anon_sym_false] = ACTIONS(93),
+    [sym_nat] = ACTIONS(91),
+    [anon_sym_succ] = ACTIONS(93),
+    [anon_sym_pred] = ACTIONS(93),
+    [anon_sym_iszero] = ACTIONS(93),
+    [anon_sym_DQUOTE] = ACTIONS(91),
+    [anon_sym_concat] = ACTIONS(93),
+    [anon_sym_length] = ACTIONS(93),
+    [anon_sym_eq] = ACTIONS(93),
+    [anon_sym_not] = ACTIONS(93),
+    [anon_sym_AMP_AMP] = ACTIONS(91),
+    [anon_sym_PIPE_PIPE] = ACTIONS(91),
+    [anon_sym_EQ_EQ] = ACTIONS(91),
+    [sym_wildcard] = ACTIONS(93),
+    [anon_sym_LBRACE] = ACTIONS(91),
+    [anon_sym_COMMA] = ACTIONS(91),
+    [anon_sym_RBRACE] = ACTIONS(91),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(93),
+    [anon_sym_inr] = ACTIONS(93),
+    [anon_sym_LT] = ACTIONS(91),
+    [anon_sym_GT] = ACTIONS(91),
+    [anon_sym_case] = ACTIONS(93),
+    [anon_sym_of] = ACTIONS(93),
+    [anon_sym_PIPE] = ACTIONS(93),
+    [sym_nil] = ACTIONS(93),
+    [anon_sym_cons] = ACTIONS(93),
+    [anon_sym_isnil] = ACTIONS(93),
+    [anon_sym_head] = ACTIONS(93),
+    [anon_sym_tail] = ACTIONS(93),
+    [anon_sym_LBRACK] = ACTIONS(91),
+    [anon_sym_RBRACK] = ACTIONS(91),
+    [anon_sym_ref] = ACTIONS(93),
+    [anon_sym_BANG] = ACTIONS(91),
+    [anon_sym_COLON_EQ] = ACTIONS(91),
+    [sym_error] = ACTIONS(93),
+    [anon_sym_raise] = ACTIONS(93),
+    [anon_sym_try] = ACTIONS(93),
+    [anon_sym_with] = ACTIONS(93),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(91),
+    [anon_sym_as] = ACTIONS(93),
+    [anon_sym_let] = ACTIONS(93),
+    [anon_sym_in] = ACTIONS(93),
+    [anon_sym_SEMI] = ACTIONS(91),
+    [anon_sym_if] = ACTIONS(93),
+    [anon_sym_then] = ACTIONS(93),
+    [anon_sym_else] = ACTIONS(93),
+    [anon_sym_letrec] = ACTIONS(93),
+    [anon_sym_type] = ACTIONS(93),
+    [anon_sym_import] = ACTIONS(93),
+    [anon_sym_fix] = ACTIONS(93),
+  },
+  [7] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(95),
+    [sym_ident] = ACTIONS(97),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(95),
+    [anon_sym_RPAREN] = ACTIONS(95),
+    [sym_unit] = ACTIONS(97),
+    [anon_sym_true] = ACTIONS(97),
+    [anon_sym_false] = ACTIONS(97),
+    [sym_nat] = ACTIONS(95),
+    [anon_sym_succ] = ACTIONS(97),
+    [anon_sym_pred] = ACTIONS(97),
+    [anon_sym_iszero] = ACTIONS(97),
+    [anon_sym_DQUOTE] = ACTIONS(95),
+    [anon_sym_concat] = ACTIONS(97),
+    [anon_sym_length] = ACTIONS(97),
+    [anon_sym_eq] = ACTIONS(97),
+    [anon_sym_not] = ACTIONS(97),
+    [anon_sym_AMP_AMP] = ACTIONS(95),
+    [anon_sym_PIPE_PIPE] = ACTIONS(95),
+    [anon_sym_EQ_EQ] = ACTIONS(95),
+    [sym_wildcard] = ACTIONS(97),
+    [anon_sym_LBRACE] = ACTIONS(95),
+    [anon_sym_COMMA] = ACTIONS(95),
+    [anon_sym_RBRACE] = ACTIONS(95),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(97),
+    [anon_sym_inr] = ACTIONS(97),
+    [anon_sym_LT] = ACTIONS(95),
+    [anon_sym_GT] = ACTIONS(95),
+    [anon_sym_case] = ACTIONS(97),
+    [anon_sym_of] = ACTIONS(97),
+    [anon_sym_PIPE] = ACTIONS(97),
+    [sym_nil] = ACTIONS(97),
+    [anon_sym_cons] = ACTIONS(97),
+    [anon_sym_isnil] = ACTIONS(97),
+    [anon_sym_head] = ACTIONS(97),
+    [anon_sym_tail] = ACTIONS(97),
+    [anon_sym_LBRACK] = ACTIONS(95),
+    [anon_sym_RBRACK] = ACTIONS(95),
+    [anon_sym_ref] = ACTIONS(97),
+    [anon_sym_BANG] = ACTIONS(95),
+    [anon_sym_COLON_EQ] = ACTIONS(95),
+    [sym_error] = ACTIONS(97),
+    [anon_sym_raise] = ACTIONS(97),
+    [anon_sym_try] = ACTIONS(97),
+    [anon_sym_with] = ACTIONS(97),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(95),
+    [anon_sym_as] = ACTIONS(97),
+    [anon_sym_let] = ACTIONS(97),
+    [anon_sym_in] = ACTIONS(97),
+    [anon_sym_SEMI] = ACTIONS(95),
+    [anon_sym_if] = ACTIONS(97),
+    [anon_sym_then] = ACTIONS(97),
+    [anon_sym_else] = ACTIONS(97),
+    [anon_sym_letrec] = ACTIONS(97),
+    [anon_sym_type] = ACTIONS(97),
+    [anon_sym_import] = ACTIONS(97),
+    [anon_sym_fix] = ACTIONS(97),
+  },
+  [8] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(99),
+    [sym_ident] = ACTIONS(101),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(99),
+    [anon_sym_RPAREN] = ACTIONS(99),
+    [sym_unit] = ACTIONS(101),
+    [anon_sym_true] = ACTIONS(101),
+    [anon_sym_false] = ACTIONS(101),
+    [sym_nat] = ACTIONS(99),
+    [anon_sym_succ] = ACTIONS(101),
+    [anon_sym_pred] = ACTIONS(101),
+    [anon_sym_iszero] = ACTIONS(101),
+    [anon_sym_DQUOTE] = ACTIONS(99),
+    [anon_sym_concat] = ACTIONS(101),
+    [anon_sym_length] = ACTIONS(101),
+    [anon_sym_eq] = ACTIONS(101),
+    [anon_sym_not] = ACTIONS(101),
+    [anon_sym_AMP_AMP] = ACTIONS(99),
+    [anon_sym_PIPE_PIPE] = ACTIONS(99),
+    [anon_sym_EQ_EQ] = ACTIONS(99),
+    [sym_wildcard] = ACTIONS(101),
+    [anon_sym_LBRACE] = ACTIONS(99),
+    [anon_sym_COMMA] = ACTIONS(99),
+    [anon_sym_RBRACE] = ACTIONS(99),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(101),
+    [anon_sym_inr] = ACTIONS(101),
+    [anon_sym_LT] = ACTIONS(99),
+    [anon_sym_GT] = ACTIONS(99),
+    [anon_sym_case] = ACTIONS(101),
+    [anon_sym_of] = ACTIONS(101),
+    [anon_sym_PIPE] = ACTIONS(101),
+    [sym_nil] = ACTIONS(101),
+    [anon_sym_cons] = ACTIONS(101),
+    [anon_sym_isnil] = ACTIONS(101),
+    [anon_sym_head] = ACTIONS(101),
+    [anon_sym_tail] = ACTIONS(101),
+    [anon_sym_LBRACK] = ACTIONS(99),
+    [anon_sym_RBRACK] = ACTIONS(99),
+    [anon_sym_ref] = ACTIONS(101),
+    [anon_sym_BANG] = ACTIONS(99),
+    [anon_sym_COLON_EQ] = ACTIONS(99),
+    [sym_error] = ACTIONS(101),
+    [anon_sym_raise] = ACTIONS(101),
+    [anon_sym_try] = ACTIONS(101),
+    [anon_sym_with] = ACTIONS(101),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(99),
+    [anon_sym_as] = ACTIONS(101),
+    [anon_sym_let] = ACTIONS(101),
+    [anon_sym_in] = ACTIONS(101),
+    [anon_sym_SEMI] = ACTIONS(99),
+    [anon_sym_if] = ACTIONS(101),
+    [anon_sym_then] = ACTIONS(101),
+    [anon_sym_else] = ACTIONS(101),
+    [anon_sym_letrec] = ACTIONS(101),
+    [anon_sym_type] = ACTIONS(101),
+    [anon_sym_import] = ACTIONS(101),
+    [anon_sym_fix] = ACTIONS(101),
+  },
+  [9] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(103),
+    [sym_ident] = ACTIONS(105),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(103),
+    [anon_sym_RPAREN] = ACTIONS(103),
+    [sym_unit] = ACTIONS(105),
+    [anon_sym_true] = ACTIONS(105),
+    [anon_sym_false] = ACTIONS(105),
+    [sym_nat] = ACTIONS(103),
+    [anon_sym_succ] = ACTIONS(105),
+    [anon_sym_pred] = ACTIONS(105),
+    [anon_sym_iszero] = ACTIONS(105),
+    [anon_sym_DQUOTE] = ACTIONS(103),
+    [anon_sym_concat] = ACTIONS(105),
+    [anon_sym_length] = ACTIONS(105),
+    [anon_sym_eq] = ACTIONS(105),
+    [anon_sym_not] = ACTIONS(105),
+    [anon_sym_AMP_AMP] = ACTIONS(103),
+    [anon_sym_PIPE_PIPE] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [sym_wildcard] = ACTIONS(105),
+    [anon_sym_LBRACE] = ACTIONS(103),
+    [anon_sym_COMMA] = ACTIONS(103),
+    [anon_sym_RBRACE] = ACTIONS(103),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(105),
+    [anon_sym_inr] = ACTIONS(105),
+    [anon_sym_LT] = ACTIONS(103),
+    [anon_sym_GT] = ACTIONS(103),
+    [anon_sym_case] = ACTIONS(105),
+    [anon_sym_of] = ACTIONS(105),
+    [anon_sym_PIPE] = ACTIONS(105),
+    [sym_nil] = ACTIONS(105),
+    [anon_sym_cons] = ACTIONS(105),
+    [anon_sym_isnil] = ACTIONS(105),
+    [anon_sym_head] = ACTIONS(105),
+    [anon_sym_tail] = ACTIONS(105),
+    [anon_sym_LBRACK] = ACTIONS(103),
+    [anon_sym_RBRACK] = ACTIONS(103),
+    [anon_sym_ref] = ACTIONS(105),
+    [anon_sym_BANG] = ACTIONS(103),
+    [anon_sym_COLON_EQ] = ACTIONS(103),
+    [sym_error] = ACTIONS(105),
+    [anon_sym_raise] = ACTIONS(105),
+    [anon_sym_try] = ACTIONS(105),
+    [anon_sym_with] = ACTIONS(105),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(103),
+    [anon_sym_as] = ACTIONS(105),
+    [anon_sym_let] = ACTIONS(105),
+    [anon_sym_in] = ACTIONS(105),
+    [anon_sym_SEMI] = ACTIONS(103),
+    [anon_sym_if] = ACTIONS(105),
+    [anon_sym_then] = ACTIONS(105),
+    [anon_sym_else] = ACTIONS(105),
+    [anon_sym_letrec] = ACTIONS(105),
+    [anon_sym_type] = ACTIONS(105),
+    [anon_sym_import] = ACTIONS(105),
+    [anon_sym_fix] = ACTIONS(105),
+  },
+  [10] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(107),
+    [sym_ident] = ACTIONS(109),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(107),
+    [anon_sym_RPAREN] = ACTIONS(107),
+    [sym_unit] = ACTIONS(109),
+    [anon_sym_true] = ACTIONS(109),
+    [anon_sym_false] = ACTIONS(109),
+    [sym_nat] = ACTIONS(107),
+    [anon_sym_succ] = ACTIONS(109),
+    [anon_sym_pred] = ACTIONS(109),
+    [anon_sym_iszero] = ACTIONS(109),
+    [anon_sym_DQUOTE] = ACTIONS(107),
+    [anon_sym_concat] = ACTIONS(109),
+    [anon_sym_length] = ACTIONS(109),
+    [anon_sym_eq] = ACTIONS(109),
+    [anon_sym_not] = ACTIONS(109),
+    [anon_sym_AMP_AMP] = ACTIONS(107),
+    [anon_sym_PIPE_PIPE] = ACTIONS(107),
+    [anon_sym_EQ_EQ] = ACTIONS(107),
+    [sym_wildcard] = ACTIONS(109),
+    [anon_sym_LBRACE] = ACTIONS(107),
+    [anon_sym_COMMA] = ACTIONS(107),
+    [anon_sym_RBRACE] = ACTIONS(107),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(109),
+    [anon_sym_inr] = ACTIONS(109),
+    [anon_sym_LT] = ACTIONS(107),
+    [anon_sym_GT] = ACTIONS(107),
+    [anon_sym_case] = ACTIONS(109),
+    [anon_sym_of] = ACTIONS(109),
+    [anon_sym_PIPE] = ACTIONS(109),
+    [sym_nil] = ACTIONS(109),
+    [anon_sym_cons] = ACTIONS(109),
+    [anon_sym_isnil] = ACTIONS(109),
+    [anon_sym_head] = ACTIONS(109),
+    [anon_sym_tail] = ACTIONS(109),
+    [anon_sym_LBRACK] = ACTIONS(107),
+    [anon_sym_RBRACK] = ACTIONS(107),
+    [anon_sym_ref] = ACTIONS(109),
+    [anon_sym_BANG] = ACTIONS(107),
+    [anon_sym_COLON_EQ] = ACTIONS(107),
+    [sym_error] = ACTIONS(109),
+    [anon_sym_raise] = ACTIONS(109),
+    [anon_sym_try] = ACTIONS(109),
+    [anon_sym_with] = ACTIONS(109),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(107),
+    [anon_sym_as] = ACTIONS(109),
+    [anon_sym_let] = ACTIONS(109),
+    [anon_sym_in] = ACTIONS(109),
+    [anon_sym_SEMI] = ACTIONS(107),
+    [anon_sym_if] = ACTIONS(109),
+    [anon_sym_then] = ACTIONS(109),
+    [anon_sym_else] = ACTIONS(109),
+    [anon_sym_letrec] = ACTIONS(109),
+    [anon_sym_type] = ACTIONS(109),
+    [anon_sym_import] = ACTIONS(109),
+    [anon_sym_fix] = ACTIONS(109),
+  },
+  [11] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(111),
+    [sym_ident] = ACTIONS(113),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(111),
+    [anon_sym_RPAREN] = ACTIONS(111),
+    [sym_unit] = ACTIONS(113),
+    [anon_sym_true] = ACTIONS(113),
+    [anon_sym_false] = ACTIONS(113),
+    [sym_nat] = ACTIONS(111),
+    [anon_sym_succ] = ACTIONS(113),
+    [anon_sym_pred] = ACTIONS(113),
+    [anon_sym_iszero] = ACTIONS(113),
+    [anon_sym_DQUOTE] = ACTIONS(111),
+    [anon_sym_concat] = ACTIONS(113),
+    [anon_sym_length] = ACTIONS(113),
+    [anon_sym_eq] = ACTIONS(113),
+    [anon_sym_not] = ACTIONS(113),
+    [anon_sym_AMP_AMP] = ACTIONS(111),
+    [anon_sym_PIPE_PIPE] = ACTIONS(111),
+    [anon_sym_EQ_EQ] = ACTIONS(111),
+    [sym_wildcard] = ACTIONS(113),
+    [anon_sym_LBRACE] = ACTIONS(111),
+    [anon_sym_COMMA] = ACTIONS(111),
+    [anon_sym_RBRACE] = ACTIONS(111),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(113),
+    [anon_sym_inr] = ACTIONS(113),
+    [anon_sym_LT] = ACTIONS(111),
+    [anon_sym_GT] = ACTIONS(111),
+    [anon_sym_case] = ACTIONS(113),
+    [anon_sym_of] = ACTIONS(113),
+    [anon_sym_PIPE] = ACTIONS(113),
+    [sym_nil] = ACTIONS(113),
+    [anon_sym_cons] = ACTIONS(113),
+    [anon_sym_isnil] = ACTIONS(113),
+    [anon_sym_head] = ACTIONS(113),
+    [anon_sym_tail] = ACTIONS(113),
+    [anon_sym_LBRACK] = ACTIONS(111),
+    [anon_sym_RBRACK] = ACTIONS(111),
+    [anon_sym_ref] = ACTIONS(113),
+    [anon_sym_BANG] = ACTIONS(111),
+    [anon_sym_COLON_EQ] = ACTIONS(111),
+    [sym_error] = ACTIONS(113),
+    [anon_sym_raise] = ACTIONS(113),
+    [anon_sym_try] = ACTIONS(113),
+    [anon_sym_with] = ACTIONS(113),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(111),
+    [anon_sym_as] = ACTIONS(113),
+    [anon_sym_let] = ACTIONS(113),
+    [anon_sym_in] = ACTIONS(113),
+    [anon_sym_SEMI] = ACTIONS(111),
+    [anon_sym_if] = ACTIONS(113),
+    [anon_sym_then] = ACTIONS(113),
+    [anon_sym_else] = ACTIONS(113),
+    [anon_sym_letrec] = ACTIONS(113),
+    [anon_sym_type] = ACTIONS(113),
+    [anon_sym_import] = ACTIONS(113),
+    [anon_sym_fix] = ACTIONS(113),
+  },
+  [12] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(115),
+    [sym_ident] = ACTIONS(117),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(115),
+    [anon_sym_RPAREN] = ACTIONS(115),
+    [sym_unit] = ACTIONS(117),
+    [anon_sym_true] = ACTIONS(117),
+    [anon_sym_false] = ACTIONS(117),
+    [sym_nat] = ACTIONS(115),
+    [anon_sym_succ] = ACTIONS(117),
+    [anon_sym_pred] = ACTIONS(117),
+    [anon_sym_iszero] = ACTIONS(117),
+    [anon_sym_DQUOTE] = ACTIONS(115),
+    [anon_sym_concat] = ACTIONS(117),
+    [anon_sym_length] = ACTIONS(117),
+    [anon_sym_eq] = ACTIONS(117),
+    [anon_sym_not] = ACTIONS(117),
+    [anon_sym_AMP_AMP] = ACTIONS(115),
+    [anon_sym_PIPE_PIPE] = ACTIONS(115),
+    [anon_sym_EQ_EQ] = ACTIONS(115),
+    [sym_wildcard] = ACTIONS(117),
+    [anon_sym_LBRACE] = ACTIONS(115),
+    [anon_sym_COMMA] = ACTIONS(115),
+    [anon_sym_RBRACE] = ACTIONS(115),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(117),
+    [anon_sym_inr] = ACTIONS(117),
+    [anon_sym_LT] = ACTIONS(115),
+    [anon_sym_GT] = ACTIONS(115),
+    [anon_sym_case] = ACTIONS(117),
+    [anon_sym_of] = ACTIONS(117),
+    [anon_sym_PIPE] = ACTIONS(117),
+    [sym_nil] = ACTIONS(117),
+    [anon_sym_cons] = ACTIONS(117),
+    [anon_sym_isnil] = ACTIONS(117),
+    [anon_sym_head] = ACTIONS(117),
+    [anon_sym_tail] = ACTIONS(117),
+    [anon_sym_LBRACK] = ACTIONS(115),
+    [anon_sym_RBRACK] = ACTIONS(115),
+    [anon_sym_ref] = ACTIONS(117),
+    [anon_sym_BANG] = ACTIONS(115),
+    [anon_sym_COLON_EQ] = ACTIONS(115),
+    [sym_error] = ACTIONS(117),
+    [anon_sym_raise] = ACTIONS(117),
+    [anon_sym_try] = ACTIONS(117),
+    [anon_sym_with] = ACTIONS(117),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(115),
+    [anon_sym_as] = ACTIONS(117),
+    [anon_sym_let] = ACTIONS(117),
+    [anon_sym_in] = ACTIONS(117),
+    [anon_sym_SEMI] = ACTIONS(115),
+    [anon_sym_if] = ACTIONS(117),
+    [anon_sym_then] = ACTIONS(117),
+    [anon_sym_else] = ACTIONS(117),
+    [anon_sym_letrec] = ACTIONS(117),
+    [anon_sym_type] = ACTIONS(117),
+    [anon_sym_import] = ACTIONS(117),
+    [anon_sym_fix] = ACTIONS(117),
+  },
+  [13] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(119),
+    [sym_ident] = ACTIONS(121),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(119),
+    [anon_sym_RPAREN] = ACTIONS(119),
+    [sym_unit] = ACTIONS(121),
+    [anon_sym_true] = ACTIONS(121),
+    [anon_sym_false] = ACTIONS(121),
+    [sym_nat] = ACTIONS(119),
+    [anon_sym_succ] = ACTIONS(121),
+    [anon_sym_pred] = ACTIONS(121),
+    [anon_sym_iszero] = ACTIONS(121),
+    [anon_sym_DQUOTE] = ACTIONS(119),
+    [anon_sym_concat] = ACTIONS(121),
+    [anon_sym_length] = ACTIONS(121),
+    [anon_sym_eq] = ACTIONS(121),
+    [anon_sym_not] = ACTIONS(121),
+    [anon_sym_AMP_AMP] = ACTIONS(119),
+    [anon_sym_PIPE_PIPE] = ACTIONS(119),
+    [anon_sym_EQ_EQ] = ACTIONS(119),
+    [sym_wildcard] = ACTIONS(121),
+    [anon_sym_LBRACE] = ACTIONS(119),
+    [anon_sym_COMMA] = ACTIONS(119),
+    [anon_sym_RBRACE] = ACTIONS(119),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(121),
+    [anon_sym_inr] = ACTIONS(121),
+    [anon_sym_LT] = ACTIONS(119),
+    [anon_sym_GT] = ACTIONS(119),
+    [anon_sym_case] = ACTIONS(121),
+    [anon_sym_of] = ACTIONS(121),
+    [anon_sym_PIPE] = ACTIONS(121),
+    [sym_nil] = ACTIONS(121),
+    [anon_sym_cons] = ACTIONS(121),
+    [anon_sym_isnil] = ACTIONS(121),
+    [anon_sym_head] = ACTIONS(121),
+    [anon_sym_tail] = ACTIONS(121),
+    [anon_sym_LBRACK] = ACTIONS(119),
+    [anon_sym_RBRACK] = ACTIONS(119),
+    [anon_sym_ref] = ACTIONS(121),
+    [anon_sym_BANG] = ACTIONS(119),
+    [anon_sym_COLON_EQ] = ACTIONS(119),
+    [sym_error] = ACTIONS(121),
+    [anon_sym_raise] = ACTIONS(121),
+    [anon_sym_try] = ACTIONS(121),
+    [anon_sym_with] = ACTIONS(121),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(119),
+    [anon_sym_as] = ACTIONS(121),
+    [anon_sym_let] = ACTIONS(121),
+    [anon_sym_in] = ACTIONS(121),
+    [anon_sym_SEMI] = ACTIONS(119),
+    [anon_sym_if] = ACTIONS(121),
+    [anon_sym_then] = ACTIONS(121),
+    [anon_sym_else] = ACTIONS(121),
+    [anon_sym_letrec] = ACTIONS(121),
+    [anon_sym_type] = ACTIONS(121),
+    [anon_sym_import] = ACTIONS(121),
+    [anon_sym_fix] = ACTIONS(121),
+  },
+  [14] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(123),
+    [sym_ident] = ACTIONS(125),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(123),
+    [anon_sym_RPAREN] = ACTIONS(123),
+    [sym_unit] = ACTIONS(125),
+    [anon_sym_true] = ACTIONS(125),
+    [anon_sym_false] = ACTIONS(125),
+    [sym_nat] = ACTIONS(123),
+    [anon_sym_succ] = ACTIONS(125),
+    [anon_sym_pred] = ACTIONS(125),
+    [anon_sym_iszero] = ACTIONS(125),
+    [anon_sym_DQUOTE] = ACTIONS(123),
+    [anon_sym_concat] = ACTIONS(125),
+    [anon_sym_length] = ACTIONS(125),
+    [anon_sym_eq] = ACTIONS(125),
+    [anon_sym_not] = ACTIONS(125),
+    [anon_sym_AMP_AMP] = ACTIONS(123),
+    [anon_sym_PIPE_PIPE] = ACTIONS(123),
+    [anon_sym_EQ_EQ] = ACTIONS(123),
+    [sym_wildcard] = ACTIONS(125),
+    [anon_sym_LBRACE] = ACTIONS(123),
+    [anon_sym_COMMA] = ACTIONS(123),
+    [anon_sym_RBRACE] = ACTIONS(123),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(125),
+    [anon_sym_inr] = ACTIONS(125),
+    [anon_sym_LT] = ACTIONS(123),
+    [anon_sym_GT] = ACTIONS(123),
+    [anon_sym_case] = ACTIONS(125),
+    [anon_sym_of] = ACTIONS(125),
+    [anon_sym_PIPE] = ACTIONS(125),
+    [sym_nil] = ACTIONS(125),
+    [anon_sym_cons] = ACTIONS(125),
+    [anon_sym_isnil] = ACTIONS(125),
+    [anon_sym_head] = ACTIONS(125),
+    [anon_sym_tail] = ACTIONS(125),
+    [anon_sym_LBRACK] = ACTIONS(123),
+    [anon_sym_RBRACK] = ACTIONS(123),
+    [anon_sym_ref] = ACTIONS(125),
+    [anon_sym_BANG] = ACTIONS(123),
+    [anon_sym_COLON_EQ] = ACTIONS(123),
+    [sym_error] = ACTIONS(125),
+    [anon_sym_raise] = ACTIONS(125),
+    [anon_sym_try] = ACTIONS(125),
+    [anon_sym_with] = ACTIONS(125),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(123),
+    [anon_sym_as] = ACTIONS(125),
+    [anon_sym_let] = ACTIONS(125),
+    [anon_sym_in] = ACTIONS(125),
+    [anon_sym_SEMI] = ACTIONS(123),
+    [anon_sym_if] = ACTIONS(125),
+    [anon_sym_then] = ACTIONS(125),
+    [anon_sym_else] = ACTIONS(125),
+    [anon_sym_letrec] = ACTIONS(125),
+    [anon_sym_type] = ACTIONS(125),
+    [anon_sym_import] = ACTIONS(125),
+    [anon_sym_fix] = ACTIONS(125),
+  },
+  [15] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(127),
+    [sym_ident] = ACTIONS(129),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(127),
+    [anon_sym_RPAREN] = ACTIONS(127),
+    [sym_unit] = ACTIONS(129),
+    [anon_sym_true] = ACTIONS(129),
+    [anon_sym_false] = ACTIONS(129),
+    [sym_nat] = ACTIONS(127),
+    [anon_sym_succ] = ACTIONS(129),
+    [anon_sym_pred] = ACTIONS(129),
+    [anon_sym_iszero] = ACTIONS(129),
+    [anon_sym_DQUOTE] = ACTIONS(127),
+    [anon_sym_concat] = ACTIONS(129),
+    [anon_sym_length] = ACTIONS(129),
+    [anon_sym_eq] = ACTIONS(129),
+    [anon_sym_not] = ACTIONS(129),
+    [anon_sym_AMP_AMP] = ACTIONS(127),
+    [anon_sym_PIPE_PIPE] = ACTIONS(127),
+    [anon_sym_EQ_EQ] = ACTIONS(127),
+    [sym_wildcard] = ACTIONS(129),
+    [anon_sym_LBRACE] = ACTIONS(127),
+    [anon_sym_COMMA] = ACTIONS(127),
+    [anon_sym_RBRACE] = ACTIONS(127),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(129),
+    [anon_sym_inr] = ACTIONS(129),
+    [anon_sym_LT] = ACTIONS(127),
+    [anon_sym_GT] = ACTIONS(127),
+    [anon_sym_case] = ACTIONS(129),
+    [anon_sym_of] = ACTIONS(129),
+    [anon_sym_PIPE] = ACTIONS(129),
+    [sym_nil] = ACTIONS(129),
+    [anon_sym_cons] = ACTIONS(129),
+    [anon_sym_isnil] = ACTIONS(129),
+    [anon_sym_head] = ACTIONS(129),
+    [anon_sym_tail] = ACTIONS(129),
+    [anon_sym_LBRACK] = ACTIONS(127),
+    [anon_sym_RBRACK] = ACTIONS(127),
+    [anon_sym_ref] = ACTIONS(129),
+    [anon_sym_BANG] = ACTIONS(127),
+    [anon_sym_COLON_EQ] = ACTIONS(127),
+    [sym_error] = ACTIONS(129),
+    [anon_sym_raise] = ACTIONS(129),
+    [anon_sym_try] = ACTIONS(129),
+    [anon_sym_with] = ACTIONS(129),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(127),
+    [anon_sym_as] = ACTIONS(129),
+    [anon_sym_let] = ACTIONS(129),
+    [anon_sym_in] = ACTIONS(129),
+    [anon_sym_SEMI] = ACTIONS(127),
+    [anon_sym_if] = ACTIONS(129),
+    [anon_sym_then] = ACTIONS(129),
+    [anon_sym_else] = ACTIONS(129),
+    [anon_sym_letrec] = ACTIONS(129),
+    [anon_sym_type] = ACTIONS(129),
+    [anon_sym_import] = ACTIONS(129),
+    [anon_sym_fix] = ACTIONS(129),
+  },
+  [16] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(131),
+    [sym_ident] = ACTIONS(133),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(131),
+    [anon_sym_RPAREN] = ACTIONS(131),
+    [sym_unit] = ACTIONS(133),
+    [anon_sym_true] = ACTIONS(133),
+    [anon_sym_false] = ACTIONS(133),
+    [sym_nat] = ACTIONS(131),
+    [anon_sym_succ] = ACTIONS(133),
+    [anon_sym_pred] = ACTIONS(133),
+    [anon_sym_iszero] = ACTIONS(133),
+    [anon_sym_DQUOTE] = ACTIONS(131),
+    [anon_sym_concat] = ACTIONS(133),
+    [anon_sym_length] = ACTIONS(133),
+    [anon_sym_eq] = ACTIONS(133),
+    [anon_sym_not] = ACTIONS(133),
+    [anon_sym_AMP_AMP] = ACTIONS(131),
+    [anon_sym_PIPE_PIPE] = ACTIONS(131),
+    [anon_sym_EQ_EQ] = ACTIONS(131),
+    [sym_wildcard] = ACTIONS(133),
+    [anon_sym_LBRACE] = ACTIONS(131),
+    [anon_sym_COMMA] = ACTIONS(131),
+    [anon_sym_RBRACE] = ACTIONS(131),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(133),
+    [anon_sym_inr] = ACTIONS(133),
+    [anon_sym_LT] = ACTIONS(131),
+    [anon_sym_GT] = ACTIONS(131),
+    [anon_sym_case] = ACTIONS(133),
+    [anon_sym_of] = ACTIONS(133),
+    [anon_sym_PIPE] = ACTIONS(133),
+    [sym_nil] = ACTIONS(133),
+    [anon_sym_cons] = ACTIONS(133),
+    [anon_sym_isnil] = ACTIONS(133),
+    [anon_sym_head] = ACTIONS(133),
+    [anon_sym_tail] = ACTIONS(133),
+    [anon_sym_LBRACK] = ACTIONS(131),
+    [anon_sym_RBRACK] = ACTIONS(131),
+    [anon_sym_ref] = ACTIONS(133),
+    [anon_sym_BANG] = ACTIONS(131),
+    [anon_sym_COLON_EQ] = ACTIONS(131),
+    [sym_error] = ACTIONS(133),
+    [anon_sym_raise] = ACTIONS(133),
+    [anon_sym_try] = ACTIONS(133),
+    [anon_sym_with] = ACTIONS(133),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(131),
+    [anon_sym_as] = ACTIONS(133),
+    [anon_sym_let] = ACTIONS(133),
+    [anon_sym_in] = ACTIONS(133),
+    [anon_sym_SEMI] = ACTIONS(131),
+    [anon_sym_if] = ACTIONS(133),
+    [anon_sym_then] = ACTIONS(133),
+    [anon_sym_else] = ACTIONS(133),
+    [anon_sym_letrec] = ACTIONS(133),
+    [anon_sym_type] = ACTIONS(133),
+    [anon_sym_import] = ACTIONS(133),
+    [anon_sym_fix] = ACTIONS(133),
+  },
+  [17] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(135),
+    [sym_ident] = ACTIONS(137),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(135),
+    [anon_sym_RPAREN] = ACTIONS(135),
+    [sym_unit] = ACTIONS(137),
+    [anon_sym_true] = ACTIONS(137),
+    [anon_sym_false] = ACTIONS(137),
+    [sym_nat] = ACTIONS(135),
+    [anon_sym_succ] = ACTIONS(137),
+    [anon_sym_pred] = ACTIONS(137),
+    [anon_sym_iszero] = ACTIONS(137),
+    [anon_sym_DQUOTE] = ACTIONS(135),
+    [anon_sym_concat] = ACTIONS(137),
+    [anon_sym_length] = ACTIONS(137),
+    [anon_sym_eq] = ACTIONS(137),
+    [anon_sym_not] = ACTIONS(137),
+    [anon_sym_AMP_AMP] = ACTIONS(135),
+    [anon_sym_PIPE_PIPE] = ACTIONS(135),
+    [anon_sym_EQ_EQ] = ACTIONS(135),
+    [sym_wildcard] = ACTIONS(137),
+    [anon_sym_LBRACE] = ACTIONS(135),
+    [anon_sym_COMMA] = ACTIONS(135),
+    [anon_sym_RBRACE] = ACTIONS(135),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(137),
+    [anon_sym_inr] = ACTIONS(137),
+    [anon_sym_LT] = ACTIONS(135),
+    [anon_sym_GT] = ACTIONS(135),
+    [anon_sym_case] = ACTIONS(137),
+    [anon_sym_of] = ACTIONS(137),
+    [anon_sym_PIPE] = ACTIONS(137),
+    [sym_nil] = ACTIONS(137),
+    [anon_sym_cons] = ACTIONS(137),
+    [anon_sym_isnil] = ACTIONS(137),
+    [anon_sym_head] = ACTIONS(137),
+    [anon_sym_tail] = ACTIONS(137),
+    [anon_sym_LBRACK] = ACTIONS(135),
+    [anon_sym_RBRACK] = ACTIONS(135),
+    [anon_sym_ref] = ACTIONS(137),
+    [anon_sym_BANG] = ACTIONS(135),
+    [anon_sym_COLON_EQ] = ACTIONS(135),
+    [sym_error] = ACTIONS(137),
+    [anon_sym_raise] = ACTIONS(137),
+    [anon_sym_try] = ACTIONS(137),
+    [anon_sym_with] = ACTIONS(137),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(135),
+    [anon_sym_as] = ACTIONS(137),
+    [anon_sym_let] = ACTIONS(137),
+    [anon_sym_in] = ACTIONS(137),
+    [anon_sym_SEMI] = ACTIONS(135),
+    [anon_sym_if] = ACTIONS(137),
+    [anon_sym_then] = ACTIONS(137),
+    [anon_sym_else] = ACTIONS(137),
+    [anon_sym_letrec] = ACTIONS(137),
+    [anon_sym_type] = ACTIONS(137),
+    [anon_sym_import] = ACTIONS(137),
+    [anon_sym_fix] = ACTIONS(137),
+  },
+  [18] = {
+    [sym__expr] = STATE(54),
+    [sym_bool] = STATE(54),
+    [sym_succ] = STATE(54),
+    [sym_pred] = STATE(54),
+    [sym_iszero] = STATE(54),
+    [sym_string] = STATE(54),
+    [sym_concat] = STATE(54),
+    [sym_length] = STATE(54),
+    [sym_eq] = STATE(54),
+    [sym_not] = STATE(54),
+    [sym_and] = STATE(54),
+    [sym_or] = STATE(54),
+    [sym_equal] = STATE(54),
+    [sym_tuple] = STATE(54),
+    [sym_record] = STATE(54),
+    [sym_record_field] = STATE(389),
+    [sym_projection] = STATE(54),
+    [sym_inl] = STATE(54),
+    [sym_inr] = STATE(54),
+    [sym_variant] = STATE(54),
+    [sym_case] = STATE(54),
+    [sym_cons] = STATE(54),
+    [sym_isnil] = STATE(54),
+    [sym_head] = STATE(54),
+    [sym_tail] = STATE(54),
+    [sym_list] = STATE(54),
+    [sym_ref] = STATE(54),
+    [sym_deref] = STATE(54),
+    [sym_assign] = STATE(54),
+    [sym_raise] = STATE(54),
+    [sym_try] = STATE(54),
+    [sym_type_abs] = STATE(54),
+    [sym_type_app] = STATE(54),
+    [sym_pack] = STATE(54),
+    [sym_unpack] = STATE(54),
+    [sym_def] = STATE(54),
+    [sym_call] = STATE(54),
+    [sym_ifElse] = STATE(54),
+    [sym_let] = STATE(54),
+    [sym_letrec] = STATE(54),
+    [sym_type_alias] = STATE(54),
+    [sym_import] = STATE(54),
+    [sym_fix] = STATE(54),
+    [sym_ascribe] = STATE(54),
+    [sym__type] = STATE(331),
+    [sym_fn_type] = STATE(331),
+    [sym_sum_type] = STATE(331),
+    [sym_list_type] = STATE(331),
+    [sym_ref_type] = STATE(331),
+    [sym_rec_type] = STATE(331),
+    [sym_forall_type] = STATE(331),
+    [sym_some_type] = STATE(331),
+    [sym_product_type] = STATE(331),
+    [sym_record_type] = STATE(331),
+    [sym_variant_type] = STATE(331),
+    [sym_record_field_type] = STATE(398),
+    [sym_ident] = ACTIONS(139),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(141),
+    [sym_unit] = ACTIONS(143),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(145),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(163),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(169),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(143),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(143),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_STAR] = ACTIONS(191),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+    [anon_sym_List] = ACTIONS(197),
+    [anon_sym_Ref] = ACTIONS(199),
+    [anon_sym_Rec] = ACTIONS(201),
+    [anon_sym_All] = ACTIONS(203),
+    [anon_sym_Some] = ACTIONS(205),
+  },
+  [19] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(207),
+    [sym_ident] = ACTIONS(209),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(207),
+    [anon_sym_RPAREN] = ACTIONS(207),
+    [sym_unit] = ACTIONS(209),
+    [anon_sym_true] = ACTIONS(209),
+    [anon_sym_false] = ACTIONS(209),
+    [sym_nat] = ACTIONS(207),
+    [anon_sym_succ] = ACTIONS(209),
+    [anon_sym_pred] = ACTIONS(209),
+    [anon_sym_iszero] = ACTIONS(209),
+    [anon_sym_DQUOTE] = ACTIONS(207),
+    [anon_sym_concat] = ACTIONS(209),
+    [anon_sym_length] = ACTIONS(209),
+    [anon_sym_eq] = ACTIONS(209),
+    [anon_sym_not] = ACTIONS(209),
+    [anon_sym_AMP_AMP] = ACTIONS(207),
+    [anon_sym_PIPE_PIPE] = ACTIONS(207),
+    [anon_sym_EQ_EQ] = ACTIONS(207),
+    [sym_wildcard] = ACTIONS(209),
+    [anon_sym_LBRACE] = ACTIONS(207),
+    [anon_sym_COMMA] = ACTIONS(207),
+    [anon_sym_RBRACE] = ACTIONS(207),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(209),
+    [anon_sym_inr] = ACTIONS(209),
+    [anon_sym_LT] = ACTIONS(207),
+    [anon_sym_GT] = ACTIONS(207),
+    [anon_sym_case] = ACTIONS(209),
+    [anon_sym_of] = ACTIONS(209),
+    [anon_sym_PIPE] = ACTIONS(209),
+    [sym_nil] = ACTIONS(209),
+    [anon_sym_cons] = ACTIONS(209),
+    [anon_sym_isnil] = ACTIONS(209),
+    [anon_sym_head] = ACTIONS(209),
+    [anon_sym_tail] = ACTIONS(209),
+    [anon_sym_LBRACK] = ACTIONS(207),
+    [anon_sym_RBRACK] = ACTIONS(207),
+    [anon_sym_ref] = ACTIONS(209),
+    [anon_sym_BANG] = ACTIONS(207),
+    [anon_sym_COLON_EQ] = ACTIONS(207),
+    [sym_error] = ACTIONS(209),
+    [anon_sym_raise] = ACTIONS(209),
+    [anon_sym_try] = ACTIONS(209),
+    [anon_sym_with] = ACTIONS(209),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(207),
+    [anon_sym_as] = ACTIONS(209),
+    [anon_sym_let] = ACTIONS(209),
+    [anon_sym_in] = ACTIONS(209),
+    [anon_sym_SEMI] = ACTIONS(207),
+    [anon_sym_if] = ACTIONS(209),
+    [anon_sym_then] = ACTIONS(209),
+    [anon_sym_else] = ACTIONS(209),
+    [anon_sym_letrec] = ACTIONS(209),
+    [anon_sym_type] = ACTIONS(209),
+    [anon_sym_import] = ACTIONS(209),
+    [anon_sym_fix] = ACTIONS(209),
+  },
+  [20] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(211),
+    [sym_ident] = ACTIONS(213),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(211),
+    [anon_sym_RPAREN] = ACTIONS(211),
+    [sym_unit] = ACTIONS(213),
+    [anon_sym_true] = ACTIONS(213),
+    [anon_sym_false] = ACTIONS(213),
+    [sym_nat] = ACTIONS(211),
+    [anon_sym_succ] = ACTIONS(213),
+    [anon_sym_pred] = ACTIONS(213),
+    [anon_sym_iszero] = ACTIONS(213),
+    [anon_sym_DQUOTE] = ACTIONS(211),
+    [anon_sym_concat] = ACTIONS(213),
+    [anon_sym_length] = ACTIONS(213),
+    [anon_sym_eq] = ACTIONS(213),
+    [anon_sym_not] = ACTIONS(213),
+    [anon_sym_AMP_AMP] = ACTIONS(211),
+    [anon_sym_PIPE_PIPE] = ACTIONS(211),
+    [anon_sym_EQ_EQ] = ACTIONS(211),
+    [sym_wildcard] = ACTIONS(213),
+    [anon_sym_LBRACE] = ACTIONS(211),
+    [anon_sym_COMMA] = ACTIONS(211),
+    [anon_sym_RBRACE] = ACTIONS(211),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(213),
+    [anon_sym_inr] = ACTIONS(213),
+    [anon_sym_LT] = ACTIONS(211),
+    [anon_sym_GT] = ACTIONS(211),
+    [anon_sym_case] = ACTIONS(213),
+    [anon_sym_of] = ACTIONS(213),
+    [anon_sym_PIPE] = ACTIONS(213),
+    [sym_nil] = ACTIONS(213),
+    [anon_sym_cons] = ACTIONS(213),
+    [anon_sym_isnil] = ACTIONS(213),
+    [anon_sym_head] = ACTIONS(213),
+    [anon_sym_tail] = ACTIONS(213),
+    [anon_sym_LBRACK] = ACTIONS(211),
+    [anon_sym_RBRACK] = ACTIONS(211),
+    [anon_sym_ref] = ACTIONS(213),
+    [anon_sym_BANG] = ACTIONS(211),
+    [anon_sym_COLON_EQ] = ACTIONS(211),
+    [sym_error] = ACTIONS(213),
+    [anon_sym_raise] = ACTIONS(213),
+    [anon_sym_try] = ACTIONS(213),
+    [anon_sym_with] = ACTIONS(213),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(211),
+    [anon_sym_as] = ACTIONS(213),
+    [anon_sym_let] = ACTIONS(213),
+    [anon_sym_in] = ACTIONS(213),
+    [anon_sym_SEMI] = ACTIONS(211),
+    [anon_sym_if] = ACTIONS(213),
+    [anon_sym_then] = ACTIONS(213),
+    [anon_sym_else] = ACTIONS(213),
+    [anon_sym_letrec] = ACTIONS(213),
+    [anon_sym_type] = ACTIONS(213),
+    [anon_sym_import] = ACTIONS(213),
+    [anon_sym_fix] = ACTIONS(213),
+  },
+  [21] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(215),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(215),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(215),
+    [anon_sym_RBRACE] = ACTIONS(215),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(215),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(227),
+    [anon_sym_PIPE] = ACTIONS(227),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(227),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(227),
+    [anon_sym_SEMI] = ACTIONS(215),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(227),
+    [anon_sym_else] = ACTIONS(227),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [22] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(235),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(235),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(235),
+    [anon_sym_PIPE_PIPE] = ACTIONS(235),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(235),
+    [anon_sym_RBRACE] = ACTIONS(235),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(235),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(237),
+    [anon_sym_PIPE] = ACTIONS(237),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(235),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(237),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(237),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(237),
+    [anon_sym_SEMI] = ACTIONS(235),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(237),
+    [anon_sym_else] = ACTIONS(237),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [23] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(239),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(239),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(239),
+    [anon_sym_RBRACE] = ACTIONS(239),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(239),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(241),
+    [anon_sym_PIPE] = ACTIONS(241),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(241),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(241),
+    [anon_sym_SEMI] = ACTIONS(239),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(241),
+    [anon_sym_else] = ACTIONS(241),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [24] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(243),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(243),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(243),
+    [anon_sym_PIPE_PIPE] = ACTIONS(243),
+    [anon_sym_EQ_EQ] = ACTIONS(243),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(243),
+    [anon_sym_RBRACE] = ACTIONS(243),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(243),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(245),
+    [anon_sym_PIPE] = ACTIONS(245),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(243),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(245),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(245),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(245),
+    [anon_sym_SEMI] = ACTIONS(243),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(245),
+    [anon_sym_else] = ACTIONS(245),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [25] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(247),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(247),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(247),
+    [anon_sym_RBRACE] = ACTIONS(247),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(247),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(249),
+    [anon_sym_PIPE] = ACTIONS(249),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(249),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(249),
+    [anon_sym_SEMI] = ACTIONS(247),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(249),
+    [anon_sym_else] = ACTIONS(249),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [26] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(251),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(251),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(251),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(251),
+    [anon_sym_RBRACE] = ACTIONS(251),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(251),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(253),
+    [anon_sym_PIPE] = ACTIONS(253),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(251),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(253),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(253),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(253),
+    [anon_sym_SEMI] = ACTIONS(251),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(253),
+    [anon_sym_else] = ACTIONS(253),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [27] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(255),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(255),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(255),
+    [anon_sym_RBRACE] = ACTIONS(255),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(255),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(257),
+    [anon_sym_PIPE] = ACTIONS(257),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(257),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(257),
+    [anon_sym_SEMI] = ACTIONS(255),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(257),
+    [anon_sym_else] = ACTIONS(257),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [28] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(259),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(259),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(259),
+    [anon_sym_RBRACE] = ACTIONS(259),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(259),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(261),
+    [anon_sym_PIPE] = ACTIONS(261),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(261),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(261),
+    [anon_sym_SEMI] = ACTIONS(259),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(261),
+    [anon_sym_else] = ACTIONS(261),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [29] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(263),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(263),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(263),
+    [anon_sym_RBRACE] = ACTIONS(263),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(263),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(265),
+    [anon_sym_PIPE] = ACTIONS(265),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(265),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(265),
+    [anon_sym_SEMI] = ACTIONS(267),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(265),
+    [anon_sym_else] = ACTIONS(265),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [30] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(269),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(269),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(269),
+    [anon_sym_RBRACE] = ACTIONS(269),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(269),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(271),
+    [anon_sym_PIPE] = ACTIONS(271),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(271),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(271),
+    [anon_sym_SEMI] = ACTIONS(269),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(271),
+    [anon_sym_else] = ACTIONS(271),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [31] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(273),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(273),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(273),
+    [anon_sym_RBRACE] = ACTIONS(273),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(273),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(275),
+    [anon_sym_PIPE] = ACTIONS(275),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(275),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(275),
+    [anon_sym_SEMI] = ACTIONS(273),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(275),
+    [anon_sym_else] = ACTIONS(275),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [32] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(277),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(277),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(277),
+    [anon_sym_RBRACE] = ACTIONS(277),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(277),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(279),
+    [anon_sym_PIPE] = ACTIONS(279),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(279),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(279),
+    [anon_sym_SEMI] = ACTIONS(277),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(279),
+    [anon_sym_else] = ACTIONS(279),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [33] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [ts_builtin_sym_end] = ACTIONS(281),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(281),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(281),
+    [anon_sym_RBRACE] = ACTIONS(281),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(281),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(283),
+    [anon_sym_PIPE] = ACTIONS(283),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(283),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(283),
+    [anon_sym_SEMI] = ACTIONS(281),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(283),
+    [anon_sym_else] = ACTIONS(283),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [34] = {
+    [sym__expr] = STATE(42),
+    [sym__block] = STATE(440),
+    [sym_bool] = STATE(42),
+    [sym_succ] = STATE(42),
+    [sym_pred] = STATE(42),
+    [sym_iszero] = STATE(42),
+    [sym_string] = STATE(42),
+    [sym_concat] = STATE(42),
+    [sym_length] = STATE(42),
+    [sym_eq] = STATE(42),
+    [sym_not] = STATE(42),
+    [sym_and] = STATE(42),
+    [sym_or] = STATE(42),
+    [sym_equal] = STATE(42),
+    [sym_tuple] = STATE(42),
+    [sym_record] = STATE(42),
+    [sym_projection] = STATE(42),
+    [sym_inl] = STATE(42),
+    [sym_inr] = STATE(42),
+    [sym_variant] = STATE(42),
+    [sym_case] = STATE(42),
+    [sym_cons] = STATE(42),
+    [sym_isnil] = STATE(42),
+    [sym_head] = STATE(42),
+    [sym_tail] = STATE(42),
+    [sym_list] = STATE(42),
+    [sym_ref] = STATE(42),
+    [sym_deref] = STATE(42),
+    [sym_assign] = STATE(42),
+    [sym_raise] = STATE(42),
+    [sym_try] = STATE(42),
+    [sym_type_abs] = STATE(42),
+    [sym_type_app] = STATE(42),
+    [sym_pack] = STATE(42),
+    [sym_unpack] = STATE(42),
+    [sym_def] = STATE(42),
+    [sym_call] = STATE(42),
+    [sym_ifElse] = STATE(42),
+    [sym_let] = STATE(42),
+    [sym_letrec] = STATE(42),
+    [sym_type_alias] = STATE(42),
+    [sym_import] = STATE(42),
+    [sym_fix] = STATE(42),
+    [sym_ascribe] = STATE(42),
+    [sym__type] = STATE(369),
+    [sym_fn_type] = STATE(369),
+    [sym_sum_type] = STATE(369),
+    [sym_list_type] = STATE(369),
+    [sym_ref_type] = STATE(369),
+    [sym_rec_type] = STATE(369),
+    [sym_forall_type] = STATE(369),
+    [sym_some_type] = STATE(369),
+    [sym_product_type] = STATE(369),
+    [sym_record_type] = STATE(369),
+    [sym_variant_type] = STATE(369),
+    [sym_sequence] = STATE(440),
+    [sym_ident] = ACTIONS(285),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(141),
+    [sym_unit] = ACTIONS(287),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(289),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(163),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(169),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(287),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(287),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+    [anon_sym_List] = ACTIONS(197),
+    [anon_sym_Ref] = ACTIONS(199),
+    [anon_sym_Rec] = ACTIONS(201),
+    [anon_sym_All] = ACTIONS(203),
+  },
+  [35] = {
+    [sym__expr] = STATE(55),
+    [sym_bool] = STATE(55),
+    [sym_succ] = STATE(55),
+    [sym_pred] = STATE(55),
+    [sym_iszero] = STATE(55),
+    [sym_string] = STATE(55),
+    [sym_concat] = STATE(55),
+    [sym_length] = STATE(55),
+    [sym_eq] = STATE(55),
+    [sym_not] = STATE(55),
+    [sym_and] = STATE(55),
+    [sym_or] = STATE(55),
+    [sym_equal] = STATE(55),
+    [sym_tuple] = STATE(55),
+    [sym_record] = STATE(55),
+    [sym_projection] = STATE(55),
+    [sym_inl] = STATE(55),
+    [sym_inr] = STATE(55),
+    [sym_variant] = STATE(55),
+    [sym_case] = STATE(55),
+    [sym_cons] = STATE(55),
+    [sym_isnil] = STATE(55),
+    [sym_head] = STATE(55),
+    [sym_tail] = STATE(55),
+    [sym_list] = STATE(55),
+    [sym_ref] = STATE(55),
+    [sym_deref] = STATE(55),
+    [sym_assign] = STATE(55),
+    [sym_raise] = STATE(55),
+    [sym_try] = STATE(55),
+    [sym_type_abs] = STATE(55),
+    [sym_type_app] = STATE(55),
+    [sym_pack] = STATE(55),
+    [sym_unpack] = STATE(55),
+    [sym_def] = STATE(55),
+    [sym_call] = STATE(55),
+    [sym_ifElse] = STATE(55),
+    [sym_let] = STATE(55),
+    [sym_letrec] = STATE(55),
+    [sym_type_alias] = STATE(55),
+    [sym_import] = STATE(55),
+    [sym_fix] = STATE(55),
+    [sym_ascribe] = STATE(55),
+    [sym__type] = STATE(365),
+    [sym_fn_type] = STATE(365),
+    [sym_sum_type] = STATE(365),
+    [sym_list_type] = STATE(365),
+    [sym_ref_type] = STATE(365),
+    [sym_rec_type] = STATE(365),
+    [sym_forall_type] = STATE(365),
+    [sym_some_type] = STATE(365),
+    [sym_product_type] = STATE(365),
+    [sym_record_type] = STATE(365),
+    [sym_variant_type] = STATE(365),
+    [sym_ident] = ACTIONS(291),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(141),
+    [sym_unit] = ACTIONS(293),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(295),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(163),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(169),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(293),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_RBRACK] = ACTIONS(327),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(293),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+    [anon_sym_List] = ACTIONS(197),
+    [anon_sym_Ref] = ACTIONS(199),
+    [anon_sym_Rec] = ACTIONS(201),
+    [anon_sym_All] = ACTIONS(203),
+  },
+  [36] = {
+    [sym__expr] = STATE(23),
+    [sym_bool] = STATE(23),
+    [sym_succ] = STATE(23),
+    [sym_pred] = STATE(23),
+    [sym_iszero] = STATE(23),
+    [sym_string] = STATE(23),
+    [sym_concat] = STATE(23),
+    [sym_length] = STATE(23),
+    [sym_eq] = STATE(23),
+    [sym_not] = STATE(23),
+    [sym_and] = STATE(23),
+    [sym_or] = STATE(23),
+    [sym_equal] = STATE(23),
+    [sym_tuple] = STATE(23),
+    [sym_record] = STATE(23),
+    [sym_projection] = STATE(23),
+    [sym_inl] = STATE(23),
+    [sym_inr] = STATE(23),
+    [sym_variant] = STATE(23),
+    [sym_case] = STATE(23),
+    [sym_cons] = STATE(23),
+    [sym_isnil] = STATE(23),
+    [sym_head] = STATE(23),
+    [sym_tail] = STATE(23),
+    [sym_list] = STATE(23),
+    [sym_ref] = STATE(23),
+    [sym_deref] = STATE(23),
+    [sym_assign] = STATE(23),
+    [sym_raise] = STATE(23),
+    [sym_try] = STATE(23),
+    [sym_type_abs] = STATE(23),
+    [sym_type_app] = STATE(23),
+    [sym_pack] = STATE(23),
+    [sym_unpack] = STATE(23),
+    [sym_def] = STATE(23),
+    [sym_call] = STATE(23),
+    [sym_ifElse] = STATE(23),
+    [sym_let] = STATE(23),
+    [sym_letrec] = STATE(23),
+    [sym_type_alias] = STATE(23),
+    [sym_import] = STATE(23),
+    [sym_fix] = STATE(23),
+    [sym_ascribe] = STATE(23),
+    [sym__type] = STATE(367),
+    [sym_fn_type] = STATE(367),
+    [sym_sum_type] = STATE(367),
+    [sym_list_type] = STATE(367),
+    [sym_ref_type] = STATE(367),
+    [sym_rec_type] = STATE(367),
+    [sym_forall_type] = STATE(367),
+    [sym_some_type] = STATE(367),
+    [sym_product_type] = STATE(367),
+    [sym_record_type] = STATE(367),
+    [sym_variant_type] = STATE(367),
+    [sym_ident] = ACTIONS(351),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(141),
+    [sym_unit] = ACTIONS(353),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(355),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(163),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(169),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(353),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(353),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+    [anon_sym_List] = ACTIONS(197),
+    [anon_sym_Ref] = ACTIONS(199),
+    [anon_sym_Rec] = ACTIONS(201),
+    [anon_sym_All] = ACTIONS(203),
+  },
+  [37] = {
+    [sym__expr] = STATE(66),
+    [sym_bool] = STATE(66),
+    [sym_succ] = STATE(66),
+    [sym_pred] = STATE(66),
+    [sym_iszero] = STATE(66),
+    [sym_string] = STATE(66),
+    [sym_concat] = STATE(66),
+    [sym_length] = STATE(66),
+    [sym_eq] = STATE(66),
+    [sym_not] = STATE(66),
+    [sym_and] = STATE(66),
+    [sym_or] = STATE(66),
+    [sym_equal] = STATE(66),
+    [sym_tuple] = STATE(66),
+    [sym_record] = STATE(66),
+    [sym_projection] = STATE(66),
+    [sym_inl] = STATE(66),
+    [sym_inr] = STATE(66),
+    [sym_variant] = STATE(66),
+    [sym_case] = STATE(66),
+    [sym_cons] = STATE(66),
+    [sym_isnil] = STATE(66),
+    [sym_head] = STATE(66),
+    [sym_tail] = STATE(66),
+    [sym_list] = STATE(66),
+    [sym_ref] = STATE(66),
+    [sym_deref] = STATE(66),
+    [sym_assign] = STATE(66),
+    [sym_raise] = STATE(66),
+    [sym_try] = STATE(66),
+    [sym_type_abs] = STATE(66),
+    [sym_type_app] = STATE(66),
+    [sym_pack] = STATE(66),
+    [sym_unpack] = STATE(66),
+    [sym_def] = STATE(66),
+    [sym_call] = STATE(66),
+    [sym_ifElse] = STATE(66),
+    [sym_let] = STATE(66),
+    [sym_letrec] = STATE(66),
+    [sym_type_alias] = STATE(66),
+    [sym_import] = STATE(66),
+    [sym_fix] = STATE(66),
+    [sym_ascribe] = STATE(66),
+    [sym__type] = STATE(332),
+    [sym_fn_type] = STATE(332),
+    [sym_sum_type] = STATE(332),
+    [sym_list_type] = STATE(332),
+    [sym_ref_type] = STATE(332),
+    [sym_rec_type] = STATE(332),
+    [sym_forall_type] = STATE(332),
+    [sym_some_type] = STATE(332),
+    [sym_product_type] = STATE(332),
+    [sym_record_type] = STATE(332),
+    [sym_variant_type] = STATE(332),
+    [sym_ident] = ACTIONS(357),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(141),
+    [sym_unit] = ACTIONS(359),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(361),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(163),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(169),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(359),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(359),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+    [anon_sym_List] = ACTIONS(197),
+    [anon_sym_Ref] = ACTIONS(199),
+    [anon_sym_Rec] = ACTIONS(201),
+    [anon_sym_All] = ACTIONS(203),
+  },
+  [38] = {
+    [sym__expr] = STATE(66),
+    [sym_bool] = STATE(66),
+    [sym_succ] = STATE(66),
+    [sym_pred] = STATE(66),
+    [sym_iszero] = STATE(66),
+    [sym_string] = STATE(66),
+    [sym_concat] = STATE(66),
+    [sym_length] = STATE(66),
+    [sym_eq] = STATE(66),
+    [sym_not] = STATE(66),
+    [sym_and] = STATE(66),
+    [sym_or] = STATE(66),
+    [sym_equal] = STATE(66),
+    [sym_tuple] = STATE(66),
+    [sym_record] = STATE(66),
+    [sym_projection] = STATE(66),
+    [sym_inl] = STATE(66),
+    [sym_inr] = STATE(66),
+    [sym_variant] = STATE(66),
+    [sym_case] = STATE(66),
+    [sym_cons] = STATE(66),
+    [sym_isnil] = STATE(66),
+    [sym_head] = STATE(66),
+    [sym_tail] = STATE(66),
+    [sym_list] = STATE(66),
+    [sym_ref] = STATE(66),
+    [sym_deref] = STATE(66),
+    [sym_assign] = STATE(66),
+    [sym_raise] = STATE(66),
+    [sym_try] = STATE(66),
+    [sym_type_abs] = STATE(66),
+    [sym_type_app] = STATE(66),
+    [sym_pack] = STATE(66),
+    [sym_unpack] = STATE(66),
+    [sym_def] = STATE(66),
+    [sym_call] = STATE(66),
+    [sym_ifElse] = STATE(66),
+    [sym_let] = STATE(66),
+    [sym_letrec] = STATE(66),
+    [sym_type_alias] = STATE(66),
+    [sym_import] = STATE(66),
+    [sym_fix] = STATE(66),
+    [sym_ascribe] = STATE(66),
+    [sym__type] = STATE(364),
+    [sym_fn_type] = STATE(364),
+    [sym_sum_type] = STATE(364),
+    [sym_list_type] = STATE(364),
+    [sym_ref_type] = STATE(364),
+    [sym_rec_type] = STATE(364),
+    [sym_forall_type] = STATE(364),
+    [sym_some_type] = STATE(364),
+    [sym_product_type] = STATE(364),
+    [sym_record_type] = STATE(364),
+    [sym_variant_type] = STATE(364),
+    [sym_ident] = ACTIONS(363),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(141),
+    [sym_unit] = ACTIONS(359),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(361),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(163),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(169),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(359),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(359),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+    [anon_sym_List] = ACTIONS(197),
+    [anon_sym_Ref] = ACTIONS(199),
+    [anon_sym_Rec] = ACTIONS(201),
+    [anon_sym_All] = ACTIONS(203),
+  },
+  [39] = {
+    [sym__expr] = STATE(46),
+    [sym_bool] = STATE(46),
+    [sym_succ] = STATE(46),
+    [sym_pred] = STATE(46),
+    [sym_iszero] = STATE(46),
+    [sym_string] = STATE(46),
+    [sym_concat] = STATE(46),
+    [sym_length] = STATE(46),
+    [sym_eq] = STATE(46),
+    [sym_not] = STATE(46),
+    [sym_and] = STATE(46),
+    [sym_or] = STATE(46),
+    [sym_equal] = STATE(46),
+    [sym_tuple] = STATE(46),
+    [sym_record] = STATE(46),
+    [sym_projection] = STATE(46),
+    [sym_inl] = STATE(46),
+    [sym_inr] = STATE(46),
+    [sym_variant] = STATE(46),
+    [sym_case] = STATE(46),
+    [sym_cons] = STATE(46),
+    [sym_isnil] = STATE(46),
+    [sym_head] = STATE(46),
+    [sym_tail] = STATE(46),
+    [sym_list] = STATE(46),
+    [sym_ref] = STATE(46),
+    [sym_deref] = STATE(46),
+    [sym_assign] = STATE(46),
+    [sym_raise] = STATE(46),
+    [sym_try] = STATE(46),
+    [sym_type_abs] = STATE(46),
+    [sym_type_app] = STATE(46),
+    [sym_pack] = STATE(46),
+    [sym_unpack] = STATE(46),
+    [sym_def] = STATE(46),
+    [sym_call] = STATE(46),
+    [sym_ifElse] = STATE(46),
+    [sym_let] = STATE(46),
+    [sym_letrec] = STATE(46),
+    [sym_type_alias] = STATE(46),
+    [sym_import] = STATE(46),
+    [sym_fix] = STATE(46),
+    [sym_ascribe] = STATE(46),
+    [sym__type] = STATE(380),
+    [sym_fn_type] = STATE(380),
+    [sym_sum_type] = STATE(380),
+    [sym_list_type] = STATE(380),
+    [sym_ref_type] = STATE(380),
+    [sym_rec_type] = STATE(380),
+    [sym_forall_type] = STATE(380),
+    [sym_some_type] = STATE(380),
+    [sym_product_type] = STATE(380),
+    [sym_record_type] = STATE(380),
+    [sym_variant_type] = STATE(380),
+    [sym_ident] = ACTIONS(365),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(141),
+    [sym_unit] = ACTIONS(367),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(369),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(163),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(169),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(367),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(367),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+    [anon_sym_List] = ACTIONS(197),
+    [anon_sym_Ref] = ACTIONS(199),
+    [anon_sym_Rec] = ACTIONS(201),
+    [anon_sym_All] = ACTIONS(203),
+  },
+  [40] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(281),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(283),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(281),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(281),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [41] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(277),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(279),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(277),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(277),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [42] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [aux_sym_tuple_repeat1] = STATE(350),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(263),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(379),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_SEMI] = ACTIONS(267),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [43] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(255),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(257),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(255),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(255),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [44] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(251),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(251),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(253),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(251),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(251),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(253),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(251),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [45] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(263),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(265),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(263),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(381),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [46] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(239),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(241),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(239),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(239),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [47] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(235),
+    [anon_sym_PIPE_PIPE] = ACTIONS(235),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(235),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(237),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(235),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(235),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(237),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(235),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [48] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(243),
+    [anon_sym_PIPE_PIPE] = ACTIONS(243),
+    [anon_sym_EQ_EQ] = ACTIONS(243),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(243),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(245),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(243),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(243),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(245),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(243),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [49] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(247),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(249),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(247),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(247),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [50] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(259),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(261),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(259),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(259),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [51] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(215),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(227),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(215),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(215),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [52] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(273),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(275),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(273),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(273),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [53] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(269),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [anon_sym_PIPE] = ACTIONS(271),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(269),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_SEMI] = ACTIONS(269),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [54] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [aux_sym_tuple_repeat1] = STATE(379),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(389),
+    [anon_sym_RBRACE] = ACTIONS(391),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [55] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [aux_sym_tuple_repeat1] = STATE(348),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(395),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(397),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [56] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(247),
+    [anon_sym_RBRACE] = ACTIONS(247),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [57] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(399),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(399),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [58] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(215),
+    [anon_sym_RBRACE] = ACTIONS(215),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [59] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(273),
+    [anon_sym_RBRACE] = ACTIONS(273),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [60] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(269),
+    [anon_sym_RBRACE] = ACTIONS(269),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [61] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(281),
+    [anon_sym_RBRACE] = ACTIONS(281),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [62] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(259),
+    [anon_sym_RBRACE] = ACTIONS(259),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [63] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(401),
+    [anon_sym_RBRACE] = ACTIONS(401),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [64] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(403),
+    [anon_sym_SEMI] = ACTIONS(405),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [65] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(407),
+    [anon_sym_SEMI] = ACTIONS(409),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [66] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(239),
+    [anon_sym_RBRACE] = ACTIONS(239),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [67] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(235),
+    [anon_sym_PIPE_PIPE] = ACTIONS(235),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(235),
+    [anon_sym_RBRACE] = ACTIONS(235),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(235),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(237),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [68] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(371),
+    [anon_sym_PIPE_PIPE] = ACTIONS(373),
+    [anon_sym_EQ_EQ] = ACTIONS(375),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(399),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_RBRACK] = ACTIONS(399),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(377),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [69] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(251),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(251),
+    [anon_sym_RBRACE] = ACTIONS(251),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(251),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(253),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [70] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(243),
+    [anon_sym_PIPE_PIPE] = ACTIONS(243),
+    [anon_sym_EQ_EQ] = ACTIONS(243),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(243),
+    [anon_sym_RBRACE] = ACTIONS(243),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(243),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(245),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [71] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(255),
+    [anon_sym_RBRACE] = ACTIONS(255),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [72] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(399),
+    [anon_sym_RBRACE] = ACTIONS(399),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [73] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(383),
+    [anon_sym_PIPE_PIPE] = ACTIONS(385),
+    [anon_sym_EQ_EQ] = ACTIONS(387),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(259),
+    [anon_sym_RBRACE] = ACTIONS(259),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(393),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [74] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(411),
+    [anon_sym_SEMI] = ACTIONS(413),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [75] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_in] = ACTIONS(415),
+    [anon_sym_SEMI] = ACTIONS(417),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [76] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(419),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [77] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(421),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [78] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_RBRACE] = ACTIONS(423),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [79] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(425),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [80] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(427),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [81] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_PIPE] = ACTIONS(429),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [82] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_else] = ACTIONS(431),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [83] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(433),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [84] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(435),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [85] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_with] = ACTIONS(437),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [86] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_SEMI] = ACTIONS(439),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [87] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_else] = ACTIONS(441),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [88] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_SEMI] = ACTIONS(443),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [89] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_else] = ACTIONS(445),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [90] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_then] = ACTIONS(447),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [91] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(449),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [92] = {
+    [sym__expr] = STATE(15),
+    [sym_bool] = STATE(15),
+    [sym_succ] = STATE(15),
+    [sym_pred] = STATE(15),
+    [sym_iszero] = STATE(15),
+    [sym_string] = STATE(15),
+    [sym_concat] = STATE(15),
+    [sym_length] = STATE(15),
+    [sym_eq] = STATE(15),
+    [sym_not] = STATE(15),
+    [sym_and] = STATE(15),
+    [sym_or] = STATE(15),
+    [sym_equal] = STATE(15),
+    [sym_tuple] = STATE(15),
+    [sym_record] = STATE(15),
+    [sym_projection] = STATE(15),
+    [sym_inl] = STATE(15),
+    [sym_inr] = STATE(15),
+    [sym_variant] = STATE(15),
+    [sym_case] = STATE(15),
+    [sym_cons] = STATE(15),
+    [sym_isnil] = STATE(15),
+    [sym_head] = STATE(15),
+    [sym_tail] = STATE(15),
+    [sym_list] = STATE(15),
+    [sym_ref] = STATE(15),
+    [sym_deref] = STATE(15),
+    [sym_assign] = STATE(15),
+    [sym_raise] = STATE(15),
+    [sym_try] = STATE(15),
+    [sym_type_abs] = STATE(15),
+    [sym_type_app] = STATE(15),
+    [sym_pack] = STATE(15),
+    [sym_unpack] = STATE(15),
+    [sym_def] = STATE(15),
+    [sym_call] = STATE(15),
+    [sym_ifElse] = STATE(15),
+    [sym_let] = STATE(15),
+    [sym_letrec] = STATE(15),
+    [sym_type_alias] = STATE(15),
+    [sym_import] = STATE(15),
+    [sym_fix] = STATE(15),
+    [sym_ascribe] = STATE(15),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(217),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(219),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_of] = ACTIONS(451),
+    [sym_nil] = ACTIONS(217),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(217),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [93] = {
+    [sym__expr] = STATE(19),
+    [sym_bool] = STATE(19),
+    [sym_succ] = STATE(19),
+    [sym_pred] = STATE(19),
+    [sym_iszero] = STATE(19),
+    [sym_string] = STATE(19),
+    [sym_concat] = STATE(19),
+    [sym_length] = STATE(19),
+    [sym_eq] = STATE(19),
+    [sym_not] = STATE(19),
+    [sym_and] = STATE(19),
+    [sym_or] = STATE(19),
+    [sym_equal] = STATE(19),
+    [sym_tuple] = STATE(19),
+    [sym_record] = STATE(19),
+    [sym_projection] = STATE(19),
+    [sym_inl] = STATE(19),
+    [sym_inr] = STATE(19),
+    [sym_variant] = STATE(19),
+    [sym_case] = STATE(19),
+    [sym_cons] = STATE(19),
+    [sym_isnil] = STATE(19),
+    [sym_head] = STATE(19),
+    [sym_tail] = STATE(19),
+    [sym_list] = STATE(19),
+    [sym_ref] = STATE(19),
+    [sym_deref] = STATE(19),
+    [sym_assign] = STATE(19),
+    [sym_raise] = STATE(19),
+    [sym_try] = STATE(19),
+    [sym_type_abs] = STATE(19),
+    [sym_type_app] = STATE(19),
+    [sym_pack] = STATE(19),
+    [sym_unpack] = STATE(19),
+    [sym_def] = STATE(19),
+    [sym_call] = STATE(19),
+    [sym_ifElse] = STATE(19),
+    [sym_let] = STATE(19),
+    [sym_letrec] = STATE(19),
+    [sym_type_alias] = STATE(19),
+    [sym_import] = STATE(19),
+    [sym_fix] = STATE(19),
+    [sym_ascribe] = STATE(19),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(453),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(455),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(453),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(453),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [94] = {
+    [sym__expr] = STATE(16),
+    [sym_bool] = STATE(16),
+    [sym_succ] = STATE(16),
+    [sym_pred] = STATE(16),
+    [sym_iszero] = STATE(16),
+    [sym_string] = STATE(16),
+    [sym_concat] = STATE(16),
+    [sym_length] = STATE(16),
+    [sym_eq] = STATE(16),
+    [sym_not] = STATE(16),
+    [sym_and] = STATE(16),
+    [sym_or] = STATE(16),
+    [sym_equal] = STATE(16),
+    [sym_tuple] = STATE(16),
+    [sym_record] = STATE(16),
+    [sym_projection] = STATE(16),
+    [sym_inl] = STATE(16),
+    [sym_inr] = STATE(16),
+    [sym_variant] = STATE(16),
+    [sym_case] = STATE(16),
+    [sym_cons] = STATE(16),
+    [sym_isnil] = STATE(16),
+    [sym_head] = STATE(16),
+    [sym_tail] = STATE(16),
+    [sym_list] = STATE(16),
+    [sym_ref] = STATE(16),
+    [sym_deref] = STATE(16),
+    [sym_assign] = STATE(16),
+    [sym_raise] = STATE(16),
+    [sym_try] = STATE(16),
+    [sym_type_abs] = STATE(16),
+    [sym_type_app] = STATE(16),
+    [sym_pack] = STATE(16),
+    [sym_unpack] = STATE(16),
+    [sym_def] = STATE(16),
+    [sym_call] = STATE(16),
+    [sym_ifElse] = STATE(16),
+    [sym_let] = STATE(16),
+    [sym_letrec] = STATE(16),
+    [sym_type_alias] = STATE(16),
+    [sym_import] = STATE(16),
+    [sym_fix] = STATE(16),
+    [sym_ascribe] = STATE(16),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(457),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(459),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(457),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(457),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [95] = {
+    [sym__expr] = STATE(16),
+    [sym_bool] = STATE(16),
+    [sym_succ] = STATE(16),
+    [sym_pred] = STATE(16),
+    [sym_iszero] = STATE(16),
+    [sym_string] = STATE(16),
+    [sym_concat] = STATE(16),
+    [sym_length] = STATE(16),
+    [sym_eq] = STATE(16),
+    [sym_not] = STATE(16),
+    [sym_and] = STATE(16),
+    [sym_or] = STATE(16),
+    [sym_equal] = STATE(16),
+    [sym_tuple] = STATE(16),
+    [sym_record] = STATE(16),
+    [sym_projection] = STATE(16),
+    [sym_inl] = STATE(16),
+    [sym_inr] = STATE(16),
+    [sym_variant] = STATE(16),
+    [sym_case] = STATE(16),
+    [sym_cons] = STATE(16),
+    [sym_isnil] = STATE(16),
+    [sym_head] = STATE(16),
+    [sym_tail] = STATE(16),
+    [sym_list] = STATE(16),
+    [sym_ref] = STATE(16),
+    [sym_deref] = STATE(16),
+    [sym_assign] = STATE(16),
+    [sym_raise] = STATE(16),
+    [sym_try] = STATE(16),
+    [sym_type_abs] = STATE(16),
+    [sym_type_app] = STATE(16),
+    [sym_pack] = STATE(16),
+    [sym_unpack] = STATE(16),
+    [sym_def] = STATE(16),
+    [sym_call] = STATE(16),
+    [sym_ifElse] = STATE(16),
+    [sym_let] = STATE(16),
+    [sym_letrec] = STATE(16),
+    [sym_type_alias] = STATE(16),
+    [sym_import] = STATE(16),
+    [sym_fix] = STATE(16),
+    [sym_ascribe] = STATE(16),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(457),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(459),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(457),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(457),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [96] = {
+    [sym__expr] = STATE(2),
+    [sym_bool] = STATE(2),
+    [sym_succ] = STATE(2),
+    [sym_pred] = STATE(2),
+    [sym_iszero] = STATE(2),
+    [sym_string] = STATE(2),
+    [sym_concat] = STATE(2),
+    [sym_length] = STATE(2),
+    [sym_eq] = STATE(2),
+    [sym_not] = STATE(2),
+    [sym_and] = STATE(2),
+    [sym_or] = STATE(2),
+    [sym_equal] = STATE(2),
+    [sym_tuple] = STATE(2),
+    [sym_record] = STATE(2),
+    [sym_projection] = STATE(2),
+    [sym_inl] = STATE(2),
+    [sym_inr] = STATE(2),
+    [sym_variant] = STATE(2),
+    [sym_case] = STATE(2),
+    [sym_cons] = STATE(2),
+    [sym_isnil] = STATE(2),
+    [sym_head] = STATE(2),
+    [sym_tail] = STATE(2),
+    [sym_list] = STATE(2),
+    [sym_ref] = STATE(2),
+    [sym_deref] = STATE(2),
+    [sym_assign] = STATE(2),
+    [sym_raise] = STATE(2),
+    [sym_try] = STATE(2),
+    [sym_type_abs] = STATE(2),
+    [sym_type_app] = STATE(2),
+    [sym_pack] = STATE(2),
+    [sym_unpack] = STATE(2),
+    [sym_def] = STATE(2),
+    [sym_call] = STATE(2),
+    [sym_ifElse] = STATE(2),
+    [sym_let] = STATE(2),
+    [sym_letrec] = STATE(2),
+    [sym_type_alias] = STATE(2),
+    [sym_import] = STATE(2),
+    [sym_fix] = STATE(2),
+    [sym_ascribe] = STATE(2),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(461),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(463),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(461),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(461),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [97] = {
+    [sym__expr] = STATE(2),
+    [sym_bool] = STATE(2),
+    [sym_succ] = STATE(2),
+    [sym_pred] = STATE(2),
+    [sym_iszero] = STATE(2),
+    [sym_string] = STATE(2),
+    [sym_concat] = STATE(2),
+    [sym_length] = STATE(2),
+    [sym_eq] = STATE(2),
+    [sym_not] = STATE(2),
+    [sym_and] = STATE(2),
+    [sym_or] = STATE(2),
+    [sym_equal] = STATE(2),
+    [sym_tuple] = STATE(2),
+    [sym_record] = STATE(2),
+    [sym_projection] = STATE(2),
+    [sym_inl] = STATE(2),
+    [sym_inr] = STATE(2),
+    [sym_variant] = STATE(2),
+    [sym_case] = STATE(2),
+    [sym_cons] = STATE(2),
+    [sym_isnil] = STATE(2),
+    [sym_head] = STATE(2),
+    [sym_tail] = STATE(2),
+    [sym_list] = STATE(2),
+    [sym_ref] = STATE(2),
+    [sym_deref] = STATE(2),
+    [sym_assign] = STATE(2),
+    [sym_raise] = STATE(2),
+    [sym_try] = STATE(2),
+    [sym_type_abs] = STATE(2),
+    [sym_type_app] = STATE(2),
+    [sym_pack] = STATE(2),
+    [sym_unpack] = STATE(2),
+    [sym_def] = STATE(2),
+    [sym_call] = STATE(2),
+    [sym_ifElse] = STATE(2),
+    [sym_let] = STATE(2),
+    [sym_letrec] = STATE(2),
+    [sym_type_alias] = STATE(2),
+    [sym_import] = STATE(2),
+    [sym_fix] = STATE(2),
+    [sym_ascribe] = STATE(2),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(461),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(463),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(461),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(461),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [98] = {
+    [sym__expr] = STATE(19),
+    [sym_bool] = STATE(19),
+    [sym_succ] = STATE(19),
+    [sym_pred] = STATE(19),
+    [sym_iszero] = STATE(19),
+    [sym_string] = STATE(19),
+    [sym_concat] = STATE(19),
+    [sym_length] = STATE(19),
+    [sym_eq] = STATE(19),
+    [sym_not] = STATE(19),
+    [sym_and] = STATE(19),
+    [sym_or] = STATE(19),
+    [sym_equal] = STATE(19),
+    [sym_tuple] = STATE(19),
+    [sym_record] = STATE(19),
+    [sym_projection] = STATE(19),
+    [sym_inl] = STATE(19),
+    [sym_inr] = STATE(19),
+    [sym_variant] = STATE(19),
+    [sym_case] = STATE(19),
+    [sym_cons] = STATE(19),
+    [sym_isnil] = STATE(19),
+    [sym_head] = STATE(19),
+    [sym_tail] = STATE(19),
+    [sym_list] = STATE(19),
+    [sym_ref] = STATE(19),
+    [sym_deref] = STATE(19),
+    [sym_assign] = STATE(19),
+    [sym_raise] = STATE(19),
+    [sym_try] = STATE(19),
+    [sym_type_abs] = STATE(19),
+    [sym_type_app] = STATE(19),
+    [sym_pack] = STATE(19),
+    [sym_unpack] = STATE(19),
+    [sym_def] = STATE(19),
+    [sym_call] = STATE(19),
+    [sym_ifElse] = STATE(19),
+    [sym_let] = STATE(19),
+    [sym_letrec] = STATE(19),
+    [sym_type_alias] = STATE(19),
+    [sym_import] = STATE(19),
+    [sym_fix] = STATE(19),
+    [sym_ascribe] = STATE(19),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(453),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(455),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(453),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(453),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [99] = {
+    [sym__expr] = STATE(2),
+    [sym_bool] = STATE(2),
+    [sym_succ] = STATE(2),
+    [sym_pred] = STATE(2),
+    [sym_iszero] = STATE(2),
+    [sym_string] = STATE(2),
+    [sym_concat] = STATE(2),
+    [sym_length] = STATE(2),
+    [sym_eq] = STATE(2),
+    [sym_not] = STATE(2),
+    [sym_and] = STATE(2),
+    [sym_or] = STATE(2),
+    [sym_equal] = STATE(2),
+    [sym_tuple] = STATE(2),
+    [sym_record] = STATE(2),
+    [sym_projection] = STATE(2),
+    [sym_inl] = STATE(2),
+    [sym_inr] = STATE(2),
+    [sym_variant] = STATE(2),
+    [sym_case] = STATE(2),
+    [sym_cons] = STATE(2),
+    [sym_isnil] = STATE(2),
+    [sym_head] = STATE(2),
+    [sym_tail] = STATE(2),
+    [sym_list] = STATE(2),
+    [sym_ref] = STATE(2),
+    [sym_deref] = STATE(2),
+    [sym_assign] = STATE(2),
+    [sym_raise] = STATE(2),
+    [sym_try] = STATE(2),
+    [sym_type_abs] = STATE(2),
+    [sym_type_app] = STATE(2),
+    [sym_pack] = STATE(2),
+    [sym_unpack] = STATE(2),
+    [sym_def] = STATE(2),
+    [sym_call] = STATE(2),
+    [sym_ifElse] = STATE(2),
+    [sym_let] = STATE(2),
+    [sym_letrec] = STATE(2),
+    [sym_type_alias] = STATE(2),
+    [sym_import] = STATE(2),
+    [sym_fix] = STATE(2),
+    [sym_ascribe] = STATE(2),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(461),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(463),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(461),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(461),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [100] = {
+    [sym__expr] = STATE(19),
+    [sym_bool] = STATE(19),
+    [sym_succ] = STATE(19),
+    [sym_pred] = STATE(19),
+    [sym_iszero] = STATE(19),
+    [sym_string] = STATE(19),
+    [sym_concat] = STATE(19),
+    [sym_length] = STATE(19),
+    [sym_eq] = STATE(19),
+    [sym_not] = STATE(19),
+    [sym_and] = STATE(19),
+    [sym_or] = STATE(19),
+    [sym_equal] = STATE(19),
+    [sym_tuple] = STATE(19),
+    [sym_record] = STATE(19),
+    [sym_projection] = STATE(19),
+    [sym_inl] = STATE(19),
+    [sym_inr] = STATE(19),
+    [sym_variant] = STATE(19),
+    [sym_case] = STATE(19),
+    [sym_cons] = STATE(19),
+    [sym_isnil] = STATE(19),
+    [sym_head] = STATE(19),
+    [sym_tail] = STATE(19),
+    [sym_list] = STATE(19),
+    [sym_ref] = STATE(19),
+    [sym_deref] = STATE(19),
+    [sym_assign] = STATE(19),
+    [sym_raise] = STATE(19),
+    [sym_try] = STATE(19),
+    [sym_type_abs] = STATE(19),
+    [sym_type_app] = STATE(19),
+    [sym_pack] = STATE(19),
+    [sym_unpack] = STATE(19),
+    [sym_def] = STATE(19),
+    [sym_call] = STATE(19),
+    [sym_ifElse] = STATE(19),
+    [sym_let] = STATE(19),
+    [sym_letrec] = STATE(19),
+    [sym_type_alias] = STATE(19),
+    [sym_import] = STATE(19),
+    [sym_fix] = STATE(19),
+    [sym_ascribe] = STATE(19),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(453),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(455),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(453),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(453),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [101] = {
+    [sym__expr] = STATE(16),
+    [sym_bool] = STATE(16),
+    [sym_succ] = STATE(16),
+    [sym_pred] = STATE(16),
+    [sym_iszero] = STATE(16),
+    [sym_string] = STATE(16),
+    [sym_concat] = STATE(16),
+    [sym_length] = STATE(16),
+    [sym_eq] = STATE(16),
+    [sym_not] = STATE(16),
+    [sym_and] = STATE(16),
+    [sym_or] = STATE(16),
+    [sym_equal] = STATE(16),
+    [sym_tuple] = STATE(16),
+    [sym_record] = STATE(16),
+    [sym_projection] = STATE(16),
+    [sym_inl] = STATE(16),
+    [sym_inr] = STATE(16),
+    [sym_variant] = STATE(16),
+    [sym_case] = STATE(16),
+    [sym_cons] = STATE(16),
+    [sym_isnil] = STATE(16),
+    [sym_head] = STATE(16),
+    [sym_tail] = STATE(16),
+    [sym_list] = STATE(16),
+    [sym_ref] = STATE(16),
+    [sym_deref] = STATE(16),
+    [sym_assign] = STATE(16),
+    [sym_raise] = STATE(16),
+    [sym_try] = STATE(16),
+    [sym_type_abs] = STATE(16),
+    [sym_type_app] = STATE(16),
+    [sym_pack] = STATE(16),
+    [sym_unpack] = STATE(16),
+    [sym_def] = STATE(16),
+    [sym_call] = STATE(16),
+    [sym_ifElse] = STATE(16),
+    [sym_let] = STATE(16),
+    [sym_letrec] = STATE(16),
+    [sym_type_alias] = STATE(16),
+    [sym_import] = STATE(16),
+    [sym_fix] = STATE(16),
+    [sym_ascribe] = STATE(16),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(457),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(459),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [anon_sym_AMP_AMP] = ACTIONS(221),
+    [anon_sym_PIPE_PIPE] = ACTIONS(223),
+    [anon_sym_EQ_EQ] = ACTIONS(225),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_DOT] = ACTIONS(77),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(457),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(229),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [anon_sym_COLON_EQ] = ACTIONS(231),
+    [sym_error] = ACTIONS(457),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_as] = ACTIONS(233),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [102] = {
+    [sym__expr] = STATE(45),
+    [sym__block] = STATE(281),
+    [sym_bool] = STATE(45),
+    [sym_succ] = STATE(45),
+    [sym_pred] = STATE(45),
+    [sym_iszero] = STATE(45),
+    [sym_string] = STATE(45),
+    [sym_concat] = STATE(45),
+    [sym_length] = STATE(45),
+    [sym_eq] = STATE(45),
+    [sym_not] = STATE(45),
+    [sym_and] = STATE(45),
+    [sym_or] = STATE(45),
+    [sym_equal] = STATE(45),
+    [sym_tuple] = STATE(45),
+    [sym_record] = STATE(45),
+    [sym_projection] = STATE(45),
+    [sym_inl] = STATE(45),
+    [sym_inr] = STATE(45),
+    [sym_variant] = STATE(45),
+    [sym_case] = STATE(45),
+    [sym_cons] = STATE(45),
+    [sym_isnil] = STATE(45),
+    [sym_head] = STATE(45),
+    [sym_tail] = STATE(45),
+    [sym_list] = STATE(45),
+    [sym_ref] = STATE(45),
+    [sym_deref] = STATE(45),
+    [sym_assign] = STATE(45),
+    [sym_raise] = STATE(45),
+    [sym_try] = STATE(45),
+    [sym_type_abs] = STATE(45),
+    [sym_type_app] = STATE(45),
+    [sym_pack] = STATE(45),
+    [sym_unpack] = STATE(45),
+    [sym_def] = STATE(45),
+    [sym_call] = STATE(45),
+    [sym_ifElse] = STATE(45),
+    [sym_let] = STATE(45),
+    [sym_letrec] = STATE(45),
+    [sym_type_alias] = STATE(45),
+    [sym_import] = STATE(45),
+    [sym_fix] = STATE(45),
+    [sym_ascribe] = STATE(45),
+    [sym_sequence] = STATE(281),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(465),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(467),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(465),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(465),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [103] = {
+    [sym__expr] = STATE(45),
+    [sym__block] = STATE(278),
+    [sym_bool] = STATE(45),
+    [sym_succ] = STATE(45),
+    [sym_pred] = STATE(45),
+    [sym_iszero] = STATE(45),
+    [sym_string] = STATE(45),
+    [sym_concat] = STATE(45),
+    [sym_length] = STATE(45),
+    [sym_eq] = STATE(45),
+    [sym_not] = STATE(45),
+    [sym_and] = STATE(45),
+    [sym_or] = STATE(45),
+    [sym_equal] = STATE(45),
+    [sym_tuple] = STATE(45),
+    [sym_record] = STATE(45),
+    [sym_projection] = STATE(45),
+    [sym_inl] = STATE(45),
+    [sym_inr] = STATE(45),
+    [sym_variant] = STATE(45),
+    [sym_case] = STATE(45),
+    [sym_cons] = STATE(45),
+    [sym_isnil] = STATE(45),
+    [sym_head] = STATE(45),
+    [sym_tail] = STATE(45),
+    [sym_list] = STATE(45),
+    [sym_ref] = STATE(45),
+    [sym_deref] = STATE(45),
+    [sym_assign] = STATE(45),
+    [sym_raise] = STATE(45),
+    [sym_try] = STATE(45),
+    [sym_type_abs] = STATE(45),
+    [sym_type_app] = STATE(45),
+    [sym_pack] = STATE(45),
+    [sym_unpack] = STATE(45),
+    [sym_def] = STATE(45),
+    [sym_call] = STATE(45),
+    [sym_ifElse] = STATE(45),
+    [sym_let] = STATE(45),
+    [sym_letrec] = STATE(45),
+    [sym_type_alias] = STATE(45),
+    [sym_import] = STATE(45),
+    [sym_fix] = STATE(45),
+    [sym_ascribe] = STATE(45),
+    [sym_sequence] = STATE(278),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(465),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(467),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(465),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(465),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [104] = {
+    [sym__expr] = STATE(29),
+    [sym__block] = STATE(281),
+    [sym_bool] = STATE(29),
+    [sym_succ] = STATE(29),
+    [sym_pred] = STATE(29),
+    [sym_iszero] = STATE(29),
+    [sym_string] = STATE(29),
+    [sym_concat] = STATE(29),
+    [sym_length] = STATE(29),
+    [sym_eq] = STATE(29),
+    [sym_not] = STATE(29),
+    [sym_and] = STATE(29),
+    [sym_or] = STATE(29),
+    [sym_equal] = STATE(29),
+    [sym_tuple] = STATE(29),
+    [sym_record] = STATE(29),
+    [sym_projection] = STATE(29),
+    [sym_inl] = STATE(29),
+    [sym_inr] = STATE(29),
+    [sym_variant] = STATE(29),
+    [sym_case] = STATE(29),
+    [sym_cons] = STATE(29),
+    [sym_isnil] = STATE(29),
+    [sym_head] = STATE(29),
+    [sym_tail] = STATE(29),
+    [sym_list] = STATE(29),
+    [sym_ref] = STATE(29),
+    [sym_deref] = STATE(29),
+    [sym_assign] = STATE(29),
+    [sym_raise] = STATE(29),
+    [sym_try] = STATE(29),
+    [sym_type_abs] = STATE(29),
+    [sym_type_app] = STATE(29),
+    [sym_pack] = STATE(29),
+    [sym_unpack] = STATE(29),
+    [sym_def] = STATE(29),
+    [sym_call] = STATE(29),
+    [sym_ifElse] = STATE(29),
+    [sym_let] = STATE(29),
+    [sym_letrec] = STATE(29),
+    [sym_type_alias] = STATE(29),
+    [sym_import] = STATE(29),
+    [sym_fix] = STATE(29),
+    [sym_ascribe] = STATE(29),
+    [sym_sequence] = STATE(281),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(9),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(13),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(9),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(9),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [105] = {
+    [sym__expr] = STATE(29),
+    [sym__block] = STATE(256),
+    [sym_bool] = STATE(29),
+    [sym_succ] = STATE(29),
+    [sym_pred] = STATE(29),
+    [sym_iszero] = STATE(29),
+    [sym_string] = STATE(29),
+    [sym_concat] = STATE(29),
+    [sym_length] = STATE(29),
+    [sym_eq] = STATE(29),
+    [sym_not] = STATE(29),
+    [sym_and] = STATE(29),
+    [sym_or] = STATE(29),
+    [sym_equal] = STATE(29),
+    [sym_tuple] = STATE(29),
+    [sym_record] = STATE(29),
+    [sym_projection] = STATE(29),
+    [sym_inl] = STATE(29),
+    [sym_inr] = STATE(29),
+    [sym_variant] = STATE(29),
+    [sym_case] = STATE(29),
+    [sym_cons] = STATE(29),
+    [sym_isnil] = STATE(29),
+    [sym_head] = STATE(29),
+    [sym_tail] = STATE(29),
+    [sym_list] = STATE(29),
+    [sym_ref] = STATE(29),
+    [sym_deref] = STATE(29),
+    [sym_assign] = STATE(29),
+    [sym_raise] = STATE(29),
+    [sym_try] = STATE(29),
+    [sym_type_abs] = STATE(29),
+    [sym_type_app] = STATE(29),
+    [sym_pack] = STATE(29),
+    [sym_unpack] = STATE(29),
+    [sym_def] = STATE(29),
+    [sym_call] = STATE(29),
+    [sym_ifElse] = STATE(29),
+    [sym_let] = STATE(29),
+    [sym_letrec] = STATE(29),
+    [sym_type_alias] = STATE(29),
+    [sym_import] = STATE(29),
+    [sym_fix] = STATE(29),
+    [sym_ascribe] = STATE(29),
+    [sym_sequence] = STATE(256),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(9),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(13),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(9),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(9),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [106] = {
+    [sym__expr] = STATE(29),
+    [sym__block] = STATE(288),
+    [sym_bool] = STATE(29),
+    [sym_succ] = STATE(29),
+    [sym_pred] = STATE(29),
+    [sym_iszero] = STATE(29),
+    [sym_string] = STATE(29),
+    [sym_concat] = STATE(29),
+    [sym_length] = STATE(29),
+    [sym_eq] = STATE(29),
+    [sym_not] = STATE(29),
+    [sym_and] = STATE(29),
+    [sym_or] = STATE(29),
+    [sym_equal] = STATE(29),
+    [sym_tuple] = STATE(29),
+    [sym_record] = STATE(29),
+    [sym_projection] = STATE(29),
+    [sym_inl] = STATE(29),
+    [sym_inr] = STATE(29),
+    [sym_variant] = STATE(29),
+    [sym_case] = STATE(29),
+    [sym_cons] = STATE(29),
+    [sym_isnil] = STATE(29),
+    [sym_head] = STATE(29),
+    [sym_tail] = STATE(29),
+    [sym_list] = STATE(29),
+    [sym_ref] = STATE(29),
+    [sym_deref] = STATE(29),
+    [sym_assign] = STATE(29),
+    [sym_raise] = STATE(29),
+    [sym_try] = STATE(29),
+    [sym_type_abs] = STATE(29),
+    [sym_type_app] = STATE(29),
+    [sym_pack] = STATE(29),
+    [sym_unpack] = STATE(29),
+    [sym_def] = STATE(29),
+    [sym_call] = STATE(29),
+    [sym_ifElse] = STATE(29),
+    [sym_let] = STATE(29),
+    [sym_letrec] = STATE(29),
+    [sym_type_alias] = STATE(29),
+    [sym_import] = STATE(29),
+    [sym_fix] = STATE(29),
+    [sym_ascribe] = STATE(29),
+    [sym_sequence] = STATE(288),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(9),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(13),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(9),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(9),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [107] = {
+    [sym__expr] = STATE(45),
+    [sym__block] = STATE(282),
+    [sym_bool] = STATE(45),
+    [sym_succ] = STATE(45),
+    [sym_pred] = STATE(45),
+    [sym_iszero] = STATE(45),
+    [sym_string] = STATE(45),
+    [sym_concat] = STATE(45),
+    [sym_length] = STATE(45),
+    [sym_eq] = STATE(45),
+    [sym_not] = STATE(45),
+    [sym_and] = STATE(45),
+    [sym_or] = STATE(45),
+    [sym_equal] = STATE(45),
+    [sym_tuple] = STATE(45),
+    [sym_record] = STATE(45),
+    [sym_projection] = STATE(45),
+    [sym_inl] = STATE(45),
+    [sym_inr] = STATE(45),
+    [sym_variant] = STATE(45),
+    [sym_case] = STATE(45),
+    [sym_cons] = STATE(45),
+    [sym_isnil] = STATE(45),
+    [sym_head] = STATE(45),
+    [sym_tail] = STATE(45),
+    [sym_list] = STATE(45),
+    [sym_ref] = STATE(45),
+    [sym_deref] = STATE(45),
+    [sym_assign] = STATE(45),
+    [sym_raise] = STATE(45),
+    [sym_try] = STATE(45),
+    [sym_type_abs] = STATE(45),
+    [sym_type_app] = STATE(45),
+    [sym_pack] = STATE(45),
+    [sym_unpack] = STATE(45),
+    [sym_def] = STATE(45),
+    [sym_call] = STATE(45),
+    [sym_ifElse] = STATE(45),
+    [sym_let] = STATE(45),
+    [sym_letrec] = STATE(45),
+    [sym_type_alias] = STATE(45),
+    [sym_import] = STATE(45),
+    [sym_fix] = STATE(45),
+    [sym_ascribe] = STATE(45),
+    [sym_sequence] = STATE(282),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(465),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(467),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(465),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(465),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [108] = {
+    [sym__expr] = STATE(42),
+    [sym__block] = STATE(440),
+    [sym_bool] = STATE(42),
+    [sym_succ] = STATE(42),
+    [sym_pred] = STATE(42),
+    [sym_iszero] = STATE(42),
+    [sym_string] = STATE(42),
+    [sym_concat] = STATE(42),
+    [sym_length] = STATE(42),
+    [sym_eq] = STATE(42),
+    [sym_not] = STATE(42),
+    [sym_and] = STATE(42),
+    [sym_or] = STATE(42),
+    [sym_equal] = STATE(42),
+    [sym_tuple] = STATE(42),
+    [sym_record] = STATE(42),
+    [sym_projection] = STATE(42),
+    [sym_inl] = STATE(42),
+    [sym_inr] = STATE(42),
+    [sym_variant] = STATE(42),
+    [sym_case] = STATE(42),
+    [sym_cons] = STATE(42),
+    [sym_isnil] = STATE(42),
+    [sym_head] = STATE(42),
+    [sym_tail] = STATE(42),
+    [sym_list] = STATE(42),
+    [sym_ref] = STATE(42),
+    [sym_deref] = STATE(42),
+    [sym_assign] = STATE(42),
+    [sym_raise] = STATE(42),
+    [sym_try] = STATE(42),
+    [sym_type_abs] = STATE(42),
+    [sym_type_app] = STATE(42),
+    [sym_pack] = STATE(42),
+    [sym_unpack] = STATE(42),
+    [sym_def] = STATE(42),
+    [sym_call] = STATE(42),
+    [sym_ifElse] = STATE(42),
+    [sym_let] = STATE(42),
+    [sym_letrec] = STATE(42),
+    [sym_type_alias] = STATE(42),
+    [sym_import] = STATE(42),
+    [sym_fix] = STATE(42),
+    [sym_ascribe] = STATE(42),
+    [sym_sequence] = STATE(440),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(287),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(289),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(287),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(287),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [109] = {
+    [sym__expr] = STATE(54),
+    [sym_bool] = STATE(54),
+    [sym_succ] = STATE(54),
+    [sym_pred] = STATE(54),
+    [sym_iszero] = STATE(54),
+    [sym_string] = STATE(54),
+    [sym_concat] = STATE(54),
+    [sym_length] = STATE(54),
+    [sym_eq] = STATE(54),
+    [sym_not] = STATE(54),
+    [sym_and] = STATE(54),
+    [sym_or] = STATE(54),
+    [sym_equal] = STATE(54),
+    [sym_tuple] = STATE(54),
+    [sym_record] = STATE(54),
+    [sym_record_field] = STATE(389),
+    [sym_projection] = STATE(54),
+    [sym_inl] = STATE(54),
+    [sym_inr] = STATE(54),
+    [sym_variant] = STATE(54),
+    [sym_case] = STATE(54),
+    [sym_cons] = STATE(54),
+    [sym_isnil] = STATE(54),
+    [sym_head] = STATE(54),
+    [sym_tail] = STATE(54),
+    [sym_list] = STATE(54),
+    [sym_ref] = STATE(54),
+    [sym_deref] = STATE(54),
+    [sym_assign] = STATE(54),
+    [sym_raise] = STATE(54),
+    [sym_try] = STATE(54),
+    [sym_type_abs] = STATE(54),
+    [sym_type_app] = STATE(54),
+    [sym_pack] = STATE(54),
+    [sym_unpack] = STATE(54),
+    [sym_def] = STATE(54),
+    [sym_call] = STATE(54),
+    [sym_ifElse] = STATE(54),
+    [sym_let] = STATE(54),
+    [sym_letrec] = STATE(54),
+    [sym_type_alias] = STATE(54),
+    [sym_import] = STATE(54),
+    [sym_fix] = STATE(54),
+    [sym_ascribe] = STATE(54),
+    [sym_ident] = ACTIONS(469),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(143),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(145),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(143),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(143),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_STAR] = ACTIONS(191),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [110] = {
+    [sym__expr] = STATE(45),
+    [sym__block] = STATE(256),
+    [sym_bool] = STATE(45),
+    [sym_succ] = STATE(45),
+    [sym_pred] = STATE(45),
+    [sym_iszero] = STATE(45),
+    [sym_string] = STATE(45),
+    [sym_concat] = STATE(45),
+    [sym_length] = STATE(45),
+    [sym_eq] = STATE(45),
+    [sym_not] = STATE(45),
+    [sym_and] = STATE(45),
+    [sym_or] = STATE(45),
+    [sym_equal] = STATE(45),
+    [sym_tuple] = STATE(45),
+    [sym_record] = STATE(45),
+    [sym_projection] = STATE(45),
+    [sym_inl] = STATE(45),
+    [sym_inr] = STATE(45),
+    [sym_variant] = STATE(45),
+    [sym_case] = STATE(45),
+    [sym_cons] = STATE(45),
+    [sym_isnil] = STATE(45),
+    [sym_head] = STATE(45),
+    [sym_tail] = STATE(45),
+    [sym_list] = STATE(45),
+    [sym_ref] = STATE(45),
+    [sym_deref] = STATE(45),
+    [sym_assign] = STATE(45),
+    [sym_raise] = STATE(45),
+    [sym_try] = STATE(45),
+    [sym_type_abs] = STATE(45),
+    [sym_type_app] = STATE(45),
+    [sym_pack] = STATE(45),
+    [sym_unpack] = STATE(45),
+    [sym_def] = STATE(45),
+    [sym_call] = STATE(45),
+    [sym_ifElse] = STATE(45),
+    [sym_let] = STATE(45),
+    [sym_letrec] = STATE(45),
+    [sym_type_alias] = STATE(45),
+    [sym_import] = STATE(45),
+    [sym_fix] = STATE(45),
+    [sym_ascribe] = STATE(45),
+    [sym_sequence] = STATE(256),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(465),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(467),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(465),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(465),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [111] = {
+    [sym__expr] = STATE(45),
+    [sym__block] = STATE(288),
+    [sym_bool] = STATE(45),
+    [sym_succ] = STATE(45),
+    [sym_pred] = STATE(45),
+    [sym_iszero] = STATE(45),
+    [sym_string] = STATE(45),
+    [sym_concat] = STATE(45),
+    [sym_length] = STATE(45),
+    [sym_eq] = STATE(45),
+    [sym_not] = STATE(45),
+    [sym_and] = STATE(45),
+    [sym_or] = STATE(45),
+    [sym_equal] = STATE(45),
+    [sym_tuple] = STATE(45),
+    [sym_record] = STATE(45),
+    [sym_projection] = STATE(45),
+    [sym_inl] = STATE(45),
+    [sym_inr] = STATE(45),
+    [sym_variant] = STATE(45),
+    [sym_case] = STATE(45),
+    [sym_cons] = STATE(45),
+    [sym_isnil] = STATE(45),
+    [sym_head] = STATE(45),
+    [sym_tail] = STATE(45),
+    [sym_list] = STATE(45),
+    [sym_ref] = STATE(45),
+    [sym_deref] = STATE(45),
+    [sym_assign] = STATE(45),
+    [sym_raise] = STATE(45),
+    [sym_try] = STATE(45),
+    [sym_type_abs] = STATE(45),
+    [sym_type_app] = STATE(45),
+    [sym_pack] = STATE(45),
+    [sym_unpack] = STATE(45),
+    [sym_def] = STATE(45),
+    [sym_call] = STATE(45),
+    [sym_ifElse] = STATE(45),
+    [sym_let] = STATE(45),
+    [sym_letrec] = STATE(45),
+    [sym_type_alias] = STATE(45),
+    [sym_import] = STATE(45),
+    [sym_fix] = STATE(45),
+    [sym_ascribe] = STATE(45),
+    [sym_sequence] = STATE(288),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(465),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(467),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(465),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(465),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [112] = {
+    [sym__expr] = STATE(29),
+    [sym__block] = STATE(282),
+    [sym_bool] = STATE(29),
+    [sym_succ] = STATE(29),
+    [sym_pred] = STATE(29),
+    [sym_iszero] = STATE(29),
+    [sym_string] = STATE(29),
+    [sym_concat] = STATE(29),
+    [sym_length] = STATE(29),
+    [sym_eq] = STATE(29),
+    [sym_not] = STATE(29),
+    [sym_and] = STATE(29),
+    [sym_or] = STATE(29),
+    [sym_equal] = STATE(29),
+    [sym_tuple] = STATE(29),
+    [sym_record] = STATE(29),
+    [sym_projection] = STATE(29),
+    [sym_inl] = STATE(29),
+    [sym_inr] = STATE(29),
+    [sym_variant] = STATE(29),
+    [sym_case] = STATE(29),
+    [sym_cons] = STATE(29),
+    [sym_isnil] = STATE(29),
+    [sym_head] = STATE(29),
+    [sym_tail] = STATE(29),
+    [sym_list] = STATE(29),
+    [sym_ref] = STATE(29),
+    [sym_deref] = STATE(29),
+    [sym_assign] = STATE(29),
+    [sym_raise] = STATE(29),
+    [sym_try] = STATE(29),
+    [sym_type_abs] = STATE(29),
+    [sym_type_app] = STATE(29),
+    [sym_pack] = STATE(29),
+    [sym_unpack] = STATE(29),
+    [sym_def] = STATE(29),
+    [sym_call] = STATE(29),
+    [sym_ifElse] = STATE(29),
+    [sym_let] = STATE(29),
+    [sym_letrec] = STATE(29),
+    [sym_type_alias] = STATE(29),
+    [sym_import] = STATE(29),
+    [sym_fix] = STATE(29),
+    [sym_ascribe] = STATE(29),
+    [sym_sequence] = STATE(282),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(9),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(13),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(9),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(9),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [113] = {
+    [sym__expr] = STATE(29),
+    [sym__block] = STATE(261),
+    [sym_bool] = STATE(29),
+    [sym_succ] = STATE(29),
+    [sym_pred] = STATE(29),
+    [sym_iszero] = STATE(29),
+    [sym_string] = STATE(29),
+    [sym_concat] = STATE(29),
+    [sym_length] = STATE(29),
+    [sym_eq] = STATE(29),
+    [sym_not] = STATE(29),
+    [sym_and] = STATE(29),
+    [sym_or] = STATE(29),
+    [sym_equal] = STATE(29),
+    [sym_tuple] = STATE(29),
+    [sym_record] = STATE(29),
+    [sym_projection] = STATE(29),
+    [sym_inl] = STATE(29),
+    [sym_inr] = STATE(29),
+    [sym_variant] = STATE(29),
+    [sym_case] = STATE(29),
+    [sym_cons] = STATE(29),
+    [sym_isnil] = STATE(29),
+    [sym_head] = STATE(29),
+    [sym_tail] = STATE(29),
+    [sym_list] = STATE(29),
+    [sym_ref] = STATE(29),
+    [sym_deref] = STATE(29),
+    [sym_assign] = STATE(29),
+    [sym_raise] = STATE(29),
+    [sym_try] = STATE(29),
+    [sym_type_abs] = STATE(29),
+    [sym_type_app] = STATE(29),
+    [sym_pack] = STATE(29),
+    [sym_unpack] = STATE(29),
+    [sym_def] = STATE(29),
+    [sym_call] = STATE(29),
+    [sym_ifElse] = STATE(29),
+    [sym_let] = STATE(29),
+    [sym_letrec] = STATE(29),
+    [sym_type_alias] = STATE(29),
+    [sym_import] = STATE(29),
+    [sym_fix] = STATE(29),
+    [sym_ascribe] = STATE(29),
+    [sym_sequence] = STATE(261),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(9),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(13),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(9),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(9),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [114] = {
+    [sym__expr] = STATE(45),
+    [sym__block] = STATE(261),
+    [sym_bool] = STATE(45),
+    [sym_succ] = STATE(45),
+    [sym_pred] = STATE(45),
+    [sym_iszero] = STATE(45),
+    [sym_string] = STATE(45),
+    [sym_concat] = STATE(45),
+    [sym_length] = STATE(45),
+    [sym_eq] = STATE(45),
+    [sym_not] = STATE(45),
+    [sym_and] = STATE(45),
+    [sym_or] = STATE(45),
+    [sym_equal] = STATE(45),
+    [sym_tuple] = STATE(45),
+    [sym_record] = STATE(45),
+    [sym_projection] = STATE(45),
+    [sym_inl] = STATE(45),
+    [sym_inr] = STATE(45),
+    [sym_variant] = STATE(45),
+    [sym_case] = STATE(45),
+    [sym_cons] = STATE(45),
+    [sym_isnil] = STATE(45),
+    [sym_head] = STATE(45),
+    [sym_tail] = STATE(45),
+    [sym_list] = STATE(45),
+    [sym_ref] = STATE(45),
+    [sym_deref] = STATE(45),
+    [sym_assign] = STATE(45),
+    [sym_raise] = STATE(45),
+    [sym_try] = STATE(45),
+    [sym_type_abs] = STATE(45),
+    [sym_type_app] = STATE(45),
+    [sym_pack] = STATE(45),
+    [sym_unpack] = STATE(45),
+    [sym_def] = STATE(45),
+    [sym_call] = STATE(45),
+    [sym_ifElse] = STATE(45),
+    [sym_let] = STATE(45),
+    [sym_letrec] = STATE(45),
+    [sym_type_alias] = STATE(45),
+    [sym_import] = STATE(45),
+    [sym_fix] = STATE(45),
+    [sym_ascribe] = STATE(45),
+    [sym_sequence] = STATE(261),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(465),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(467),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(465),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(465),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [115] = {
+    [sym__expr] = STATE(29),
+    [sym__block] = STATE(278),
+    [sym_bool] = STATE(29),
+    [sym_succ] = STATE(29),
+    [sym_pred] = STATE(29),
+    [sym_iszero] = STATE(29),
+    [sym_string] = STATE(29),
+    [sym_concat] = STATE(29),
+    [sym_length] = STATE(29),
+    [sym_eq] = STATE(29),
+    [sym_not] = STATE(29),
+    [sym_and] = STATE(29),
+    [sym_or] = STATE(29),
+    [sym_equal] = STATE(29),
+    [sym_tuple] = STATE(29),
+    [sym_record] = STATE(29),
+    [sym_projection] = STATE(29),
+    [sym_inl] = STATE(29),
+    [sym_inr] = STATE(29),
+    [sym_variant] = STATE(29),
+    [sym_case] = STATE(29),
+    [sym_cons] = STATE(29),
+    [sym_isnil] = STATE(29),
+    [sym_head] = STATE(29),
+    [sym_tail] = STATE(29),
+    [sym_list] = STATE(29),
+    [sym_ref] = STATE(29),
+    [sym_deref] = STATE(29),
+    [sym_assign] = STATE(29),
+    [sym_raise] = STATE(29),
+    [sym_try] = STATE(29),
+    [sym_type_abs] = STATE(29),
+    [sym_type_app] = STATE(29),
+    [sym_pack] = STATE(29),
+    [sym_unpack] = STATE(29),
+    [sym_def] = STATE(29),
+    [sym_call] = STATE(29),
+    [sym_ifElse] = STATE(29),
+    [sym_let] = STATE(29),
+    [sym_letrec] = STATE(29),
+    [sym_type_alias] = STATE(29),
+    [sym_import] = STATE(29),
+    [sym_fix] = STATE(29),
+    [sym_ascribe] = STATE(29),
+    [sym_sequence] = STATE(278),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(9),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(13),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(9),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(9),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [116] = {
+    [sym__expr] = STATE(68),
+    [sym_bool] = STATE(68),
+    [sym_succ] = STATE(68),
+    [sym_pred] = STATE(68),
+    [sym_iszero] = STATE(68),
+    [sym_string] = STATE(68),
+    [sym_concat] = STATE(68),
+    [sym_length] = STATE(68),
+    [sym_eq] = STATE(68),
+    [sym_not] = STATE(68),
+    [sym_and] = STATE(68),
+    [sym_or] = STATE(68),
+    [sym_equal] = STATE(68),
+    [sym_tuple] = STATE(68),
+    [sym_record] = STATE(68),
+    [sym_projection] = STATE(68),
+    [sym_inl] = STATE(68),
+    [sym_inr] = STATE(68),
+    [sym_variant] = STATE(68),
+    [sym_case] = STATE(68),
+    [sym_cons] = STATE(68),
+    [sym_isnil] = STATE(68),
+    [sym_head] = STATE(68),
+    [sym_tail] = STATE(68),
+    [sym_list] = STATE(68),
+    [sym_ref] = STATE(68),
+    [sym_deref] = STATE(68),
+    [sym_assign] = STATE(68),
+    [sym_raise] = STATE(68),
+    [sym_try] = STATE(68),
+    [sym_type_abs] = STATE(68),
+    [sym_type_app] = STATE(68),
+    [sym_pack] = STATE(68),
+    [sym_unpack] = STATE(68),
+    [sym_def] = STATE(68),
+    [sym_call] = STATE(68),
+    [sym_ifElse] = STATE(68),
+    [sym_let] = STATE(68),
+    [sym_letrec] = STATE(68),
+    [sym_type_alias] = STATE(68),
+    [sym_import] = STATE(68),
+    [sym_fix] = STATE(68),
+    [sym_ascribe] = STATE(68),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(471),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(473),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(471),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_RBRACK] = ACTIONS(475),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(471),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [117] = {
+    [sym__expr] = STATE(55),
+    [sym_bool] = STATE(55),
+    [sym_succ] = STATE(55),
+    [sym_pred] = STATE(55),
+    [sym_iszero] = STATE(55),
+    [sym_string] = STATE(55),
+    [sym_concat] = STATE(55),
+    [sym_length] = STATE(55),
+    [sym_eq] = STATE(55),
+    [sym_not] = STATE(55),
+    [sym_and] = STATE(55),
+    [sym_or] = STATE(55),
+    [sym_equal] = STATE(55),
+    [sym_tuple] = STATE(55),
+    [sym_record] = STATE(55),
+    [sym_projection] = STATE(55),
+    [sym_inl] = STATE(55),
+    [sym_inr] = STATE(55),
+    [sym_variant] = STATE(55),
+    [sym_case] = STATE(55),
+    [sym_cons] = STATE(55),
+    [sym_isnil] = STATE(55),
+    [sym_head] = STATE(55),
+    [sym_tail] = STATE(55),
+    [sym_list] = STATE(55),
+    [sym_ref] = STATE(55),
+    [sym_deref] = STATE(55),
+    [sym_assign] = STATE(55),
+    [sym_raise] = STATE(55),
+    [sym_try] = STATE(55),
+    [sym_type_abs] = STATE(55),
+    [sym_type_app] = STATE(55),
+    [sym_pack] = STATE(55),
+    [sym_unpack] = STATE(55),
+    [sym_def] = STATE(55),
+    [sym_call] = STATE(55),
+    [sym_ifElse] = STATE(55),
+    [sym_let] = STATE(55),
+    [sym_letrec] = STATE(55),
+    [sym_type_alias] = STATE(55),
+    [sym_import] = STATE(55),
+    [sym_fix] = STATE(55),
+    [sym_ascribe] = STATE(55),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(293),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(295),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(293),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_RBRACK] = ACTIONS(327),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(293),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [118] = {
+    [sym__expr] = STATE(72),
+    [sym_bool] = STATE(72),
+    [sym_succ] = STATE(72),
+    [sym_pred] = STATE(72),
+    [sym_iszero] = STATE(72),
+    [sym_string] = STATE(72),
+    [sym_concat] = STATE(72),
+    [sym_length] = STATE(72),
+    [sym_eq] = STATE(72),
+    [sym_not] = STATE(72),
+    [sym_and] = STATE(72),
+    [sym_or] = STATE(72),
+    [sym_equal] = STATE(72),
+    [sym_tuple] = STATE(72),
+    [sym_record] = STATE(72),
+    [sym_projection] = STATE(72),
+    [sym_inl] = STATE(72),
+    [sym_inr] = STATE(72),
+    [sym_variant] = STATE(72),
+    [sym_case] = STATE(72),
+    [sym_cons] = STATE(72),
+    [sym_isnil] = STATE(72),
+    [sym_head] = STATE(72),
+    [sym_tail] = STATE(72),
+    [sym_list] = STATE(72),
+    [sym_ref] = STATE(72),
+    [sym_deref] = STATE(72),
+    [sym_assign] = STATE(72),
+    [sym_raise] = STATE(72),
+    [sym_try] = STATE(72),
+    [sym_type_abs] = STATE(72),
+    [sym_type_app] = STATE(72),
+    [sym_pack] = STATE(72),
+    [sym_unpack] = STATE(72),
+    [sym_def] = STATE(72),
+    [sym_call] = STATE(72),
+    [sym_ifElse] = STATE(72),
+    [sym_let] = STATE(72),
+    [sym_letrec] = STATE(72),
+    [sym_type_alias] = STATE(72),
+    [sym_import] = STATE(72),
+    [sym_fix] = STATE(72),
+    [sym_ascribe] = STATE(72),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(477),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(479),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_RBRACE] = ACTIONS(481),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(477),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(477),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [119] = {
+    [sym__expr] = STATE(57),
+    [sym_bool] = STATE(57),
+    [sym_succ] = STATE(57),
+    [sym_pred] = STATE(57),
+    [sym_iszero] = STATE(57),
+    [sym_string] = STATE(57),
+    [sym_concat] = STATE(57),
+    [sym_length] = STATE(57),
+    [sym_eq] = STATE(57),
+    [sym_not] = STATE(57),
+    [sym_and] = STATE(57),
+    [sym_or] = STATE(57),
+    [sym_equal] = STATE(57),
+    [sym_tuple] = STATE(57),
+    [sym_record] = STATE(57),
+    [sym_projection] = STATE(57),
+    [sym_inl] = STATE(57),
+    [sym_inr] = STATE(57),
+    [sym_variant] = STATE(57),
+    [sym_case] = STATE(57),
+    [sym_cons] = STATE(57),
+    [sym_isnil] = STATE(57),
+    [sym_head] = STATE(57),
+    [sym_tail] = STATE(57),
+    [sym_list] = STATE(57),
+    [sym_ref] = STATE(57),
+    [sym_deref] = STATE(57),
+    [sym_assign] = STATE(57),
+    [sym_raise] = STATE(57),
+    [sym_try] = STATE(57),
+    [sym_type_abs] = STATE(57),
+    [sym_type_app] = STATE(57),
+    [sym_pack] = STATE(57),
+    [sym_unpack] = STATE(57),
+    [sym_def] = STATE(57),
+    [sym_call] = STATE(57),
+    [sym_ifElse] = STATE(57),
+    [sym_let] = STATE(57),
+    [sym_letrec] = STATE(57),
+    [sym_type_alias] = STATE(57),
+    [sym_import] = STATE(57),
+    [sym_fix] = STATE(57),
+    [sym_ascribe] = STATE(57),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_RPAREN] = ACTIONS(483),
+    [sym_unit] = ACTIONS(485),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(487),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(485),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(485),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [120] = {
+    [sym__expr] = STATE(68),
+    [sym_bool] = STATE(68),
+    [sym_succ] = STATE(68),
+    [sym_pred] = STATE(68),
+    [sym_iszero] = STATE(68),
+    [sym_string] = STATE(68),
+    [sym_concat] = STATE(68),
+    [sym_length] = STATE(68),
+    [sym_eq] = STATE(68),
+    [sym_not] = STATE(68),
+    [sym_and] = STATE(68),
+    [sym_or] = STATE(68),
+    [sym_equal] = STATE(68),
+    [sym_tuple] = STATE(68),
+    [sym_record] = STATE(68),
+    [sym_projection] = STATE(68),
+    [sym_inl] = STATE(68),
+    [sym_inr] = STATE(68),
+    [sym_variant] = STATE(68),
+    [sym_case] = STATE(68),
+    [sym_cons] = STATE(68),
+    [sym_isnil] = STATE(68),
+    [sym_head] = STATE(68),
+    [sym_tail] = STATE(68),
+    [sym_list] = STATE(68),
+    [sym_ref] = STATE(68),
+    [sym_deref] = STATE(68),
+    [sym_assign] = STATE(68),
+    [sym_raise] = STATE(68),
+    [sym_try] = STATE(68),
+    [sym_type_abs] = STATE(68),
+    [sym_type_app] = STATE(68),
+    [sym_pack] = STATE(68),
+    [sym_unpack] = STATE(68),
+    [sym_def] = STATE(68),
+    [sym_call] = STATE(68),
+    [sym_ifElse] = STATE(68),
+    [sym_let] = STATE(68),
+    [sym_letrec] = STATE(68),
+    [sym_type_alias] = STATE(68),
+    [sym_import] = STATE(68),
+    [sym_fix] = STATE(68),
+    [sym_ascribe] = STATE(68),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(471),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(473),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(471),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_RBRACK] = ACTIONS(489),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(471),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [121] = {
+    [sym__expr] = STATE(72),
+    [sym_bool] = STATE(72),
+    [sym_succ] = STATE(72),
+    [sym_pred] = STATE(72),
+    [sym_iszero] = STATE(72),
+    [sym_string] = STATE(72),
+    [sym_concat] = STATE(72),
+    [sym_length] = STATE(72),
+    [sym_eq] = STATE(72),
+    [sym_not] = STATE(72),
+    [sym_and] = STATE(72),
+    [sym_or] = STATE(72),
+    [sym_equal] = STATE(72),
+    [sym_tuple] = STATE(72),
+    [sym_record] = STATE(72),
+    [sym_projection] = STATE(72),
+    [sym_inl] = STATE(72),
+    [sym_inr] = STATE(72),
+    [sym_variant] = STATE(72),
+    [sym_case] = STATE(72),
+    [sym_cons] = STATE(72),
+    [sym_isnil] = STATE(72),
+    [sym_head] = STATE(72),
+    [sym_tail] = STATE(72),
+    [sym_list] = STATE(72),
+    [sym_ref] = STATE(72),
+    [sym_deref] = STATE(72),
+    [sym_assign] = STATE(72),
+    [sym_raise] = STATE(72),
+    [sym_try] = STATE(72),
+    [sym_type_abs] = STATE(72),
+    [sym_type_app] = STATE(72),
+    [sym_pack] = STATE(72),
+    [sym_unpack] = STATE(72),
+    [sym_def] = STATE(72),
+    [sym_call] = STATE(72),
+    [sym_ifElse] = STATE(72),
+    [sym_let] = STATE(72),
+    [sym_letrec] = STATE(72),
+    [sym_type_alias] = STATE(72),
+    [sym_import] = STATE(72),
+    [sym_fix] = STATE(72),
+    [sym_ascribe] = STATE(72),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(477),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(479),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_RBRACE] = ACTIONS(491),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(477),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(477),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [122] = {
+    [sym__expr] = STATE(98),
+    [sym_bool] = STATE(98),
+    [sym_succ] = STATE(98),
+    [sym_pred] = STATE(98),
+    [sym_iszero] = STATE(98),
+    [sym_string] = STATE(98),
+    [sym_concat] = STATE(98),
+    [sym_length] = STATE(98),
+    [sym_eq] = STATE(98),
+    [sym_not] = STATE(98),
+    [sym_and] = STATE(98),
+    [sym_or] = STATE(98),
+    [sym_equal] = STATE(98),
+    [sym_tuple] = STATE(98),
+    [sym_record] = STATE(98),
+    [sym_projection] = STATE(98),
+    [sym_inl] = STATE(98),
+    [sym_inr] = STATE(98),
+    [sym_variant] = STATE(98),
+    [sym_case] = STATE(98),
+    [sym_cons] = STATE(98),
+    [sym_isnil] = STATE(98),
+    [sym_head] = STATE(98),
+    [sym_tail] = STATE(98),
+    [sym_list] = STATE(98),
+    [sym_ref] = STATE(98),
+    [sym_deref] = STATE(98),
+    [sym_assign] = STATE(98),
+    [sym_raise] = STATE(98),
+    [sym_try] = STATE(98),
+    [sym_type_abs] = STATE(98),
+    [sym_type_app] = STATE(98),
+    [sym_pack] = STATE(98),
+    [sym_unpack] = STATE(98),
+    [sym_def] = STATE(98),
+    [sym_call] = STATE(98),
+    [sym_ifElse] = STATE(98),
+    [sym_let] = STATE(98),
+    [sym_letrec] = STATE(98),
+    [sym_type_alias] = STATE(98),
+    [sym_import] = STATE(98),
+    [sym_fix] = STATE(98),
+    [sym_ascribe] = STATE(98),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(493),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(495),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(493),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(493),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [123] = {
+    [sym__expr] = STATE(21),
+    [sym_bool] = STATE(21),
+    [sym_succ] = STATE(21),
+    [sym_pred] = STATE(21),
+    [sym_iszero] = STATE(21),
+    [sym_string] = STATE(21),
+    [sym_concat] = STATE(21),
+    [sym_length] = STATE(21),
+    [sym_eq] = STATE(21),
+    [sym_not] = STATE(21),
+    [sym_and] = STATE(21),
+    [sym_or] = STATE(21),
+    [sym_equal] = STATE(21),
+    [sym_tuple] = STATE(21),
+    [sym_record] = STATE(21),
+    [sym_projection] = STATE(21),
+    [sym_inl] = STATE(21),
+    [sym_inr] = STATE(21),
+    [sym_variant] = STATE(21),
+    [sym_case] = STATE(21),
+    [sym_cons] = STATE(21),
+    [sym_isnil] = STATE(21),
+    [sym_head] = STATE(21),
+    [sym_tail] = STATE(21),
+    [sym_list] = STATE(21),
+    [sym_ref] = STATE(21),
+    [sym_deref] = STATE(21),
+    [sym_assign] = STATE(21),
+    [sym_raise] = STATE(21),
+    [sym_try] = STATE(21),
+    [sym_type_abs] = STATE(21),
+    [sym_type_app] = STATE(21),
+    [sym_pack] = STATE(21),
+    [sym_unpack] = STATE(21),
+    [sym_def] = STATE(21),
+    [sym_call] = STATE(21),
+    [sym_ifElse] = STATE(21),
+    [sym_let] = STATE(21),
+    [sym_letrec] = STATE(21),
+    [sym_type_alias] = STATE(21),
+    [sym_import] = STATE(21),
+    [sym_fix] = STATE(21),
+    [sym_ascribe] = STATE(21),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(497),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(499),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(497),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(497),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [124] = {
+    [sym__expr] = STATE(88),
+    [sym_bool] = STATE(88),
+    [sym_succ] = STATE(88),
+    [sym_pred] = STATE(88),
+    [sym_iszero] = STATE(88),
+    [sym_string] = STATE(88),
+    [sym_concat] = STATE(88),
+    [sym_length] = STATE(88),
+    [sym_eq] = STATE(88),
+    [sym_not] = STATE(88),
+    [sym_and] = STATE(88),
+    [sym_or] = STATE(88),
+    [sym_equal] = STATE(88),
+    [sym_tuple] = STATE(88),
+    [sym_record] = STATE(88),
+    [sym_projection] = STATE(88),
+    [sym_inl] = STATE(88),
+    [sym_inr] = STATE(88),
+    [sym_variant] = STATE(88),
+    [sym_case] = STATE(88),
+    [sym_cons] = STATE(88),
+    [sym_isnil] = STATE(88),
+    [sym_head] = STATE(88),
+    [sym_tail] = STATE(88),
+    [sym_list] = STATE(88),
+    [sym_ref] = STATE(88),
+    [sym_deref] = STATE(88),
+    [sym_assign] = STATE(88),
+    [sym_raise] = STATE(88),
+    [sym_try] = STATE(88),
+    [sym_type_abs] = STATE(88),
+    [sym_type_app] = STATE(88),
+    [sym_pack] = STATE(88),
+    [sym_unpack] = STATE(88),
+    [sym_def] = STATE(88),
+    [sym_call] = STATE(88),
+    [sym_ifElse] = STATE(88),
+    [sym_let] = STATE(88),
+    [sym_letrec] = STATE(88),
+    [sym_type_alias] = STATE(88),
+    [sym_import] = STATE(88),
+    [sym_fix] = STATE(88),
+    [sym_ascribe] = STATE(88),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(501),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(503),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(501),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(501),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [125] = {
+    [sym__expr] = STATE(89),
+    [sym_bool] = STATE(89),
+    [sym_succ] = STATE(89),
+    [sym_pred] = STATE(89),
+    [sym_iszero] = STATE(89),
+    [sym_string] = STATE(89),
+    [sym_concat] = STATE(89),
+    [sym_length] = STATE(89),
+    [sym_eq] = STATE(89),
+    [sym_not] = STATE(89),
+    [sym_and] = STATE(89),
+    [sym_or] = STATE(89),
+    [sym_equal] = STATE(89),
+    [sym_tuple] = STATE(89),
+    [sym_record] = STATE(89),
+    [sym_projection] = STATE(89),
+    [sym_inl] = STATE(89),
+    [sym_inr] = STATE(89),
+    [sym_variant] = STATE(89),
+    [sym_case] = STATE(89),
+    [sym_cons] = STATE(89),
+    [sym_isnil] = STATE(89),
+    [sym_head] = STATE(89),
+    [sym_tail] = STATE(89),
+    [sym_list] = STATE(89),
+    [sym_ref] = STATE(89),
+    [sym_deref] = STATE(89),
+    [sym_assign] = STATE(89),
+    [sym_raise] = STATE(89),
+    [sym_try] = STATE(89),
+    [sym_type_abs] = STATE(89),
+    [sym_type_app] = STATE(89),
+    [sym_pack] = STATE(89),
+    [sym_unpack] = STATE(89),
+    [sym_def] = STATE(89),
+    [sym_call] = STATE(89),
+    [sym_ifElse] = STATE(89),
+    [sym_let] = STATE(89),
+    [sym_letrec] = STATE(89),
+    [sym_type_alias] = STATE(89),
+    [sym_import] = STATE(89),
+    [sym_fix] = STATE(89),
+    [sym_ascribe] = STATE(89),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(505),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(507),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(505),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(505),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [126] = {
+    [sym__expr] = STATE(4),
+    [sym_bool] = STATE(4),
+    [sym_succ] = STATE(4),
+    [sym_pred] = STATE(4),
+    [sym_iszero] = STATE(4),
+    [sym_string] = STATE(4),
+    [sym_concat] = STATE(4),
+    [sym_length] = STATE(4),
+    [sym_eq] = STATE(4),
+    [sym_not] = STATE(4),
+    [sym_and] = STATE(4),
+    [sym_or] = STATE(4),
+    [sym_equal] = STATE(4),
+    [sym_tuple] = STATE(4),
+    [sym_record] = STATE(4),
+    [sym_projection] = STATE(4),
+    [sym_inl] = STATE(4),
+    [sym_inr] = STATE(4),
+    [sym_variant] = STATE(4),
+    [sym_case] = STATE(4),
+    [sym_cons] = STATE(4),
+    [sym_isnil] = STATE(4),
+    [sym_head] = STATE(4),
+    [sym_tail] = STATE(4),
+    [sym_list] = STATE(4),
+    [sym_ref] = STATE(4),
+    [sym_deref] = STATE(4),
+    [sym_assign] = STATE(4),
+    [sym_raise] = STATE(4),
+    [sym_try] = STATE(4),
+    [sym_type_abs] = STATE(4),
+    [sym_type_app] = STATE(4),
+    [sym_pack] = STATE(4),
+    [sym_unpack] = STATE(4),
+    [sym_def] = STATE(4),
+    [sym_call] = STATE(4),
+    [sym_ifElse] = STATE(4),
+    [sym_let] = STATE(4),
+    [sym_letrec] = STATE(4),
+    [sym_type_alias] = STATE(4),
+    [sym_import] = STATE(4),
+    [sym_fix] = STATE(4),
+    [sym_ascribe] = STATE(4),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(509),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(511),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(509),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(509),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [127] = {
+    [sym__expr] = STATE(97),
+    [sym_bool] = STATE(97),
+    [sym_succ] = STATE(97),
+    [sym_pred] = STATE(97),
+    [sym_iszero] = STATE(97),
+    [sym_string] = STATE(97),
+    [sym_concat] = STATE(97),
+    [sym_length] = STATE(97),
+    [sym_eq] = STATE(97),
+    [sym_not] = STATE(97),
+    [sym_and] = STATE(97),
+    [sym_or] = STATE(97),
+    [sym_equal] = STATE(97),
+    [sym_tuple] = STATE(97),
+    [sym_record] = STATE(97),
+    [sym_projection] = STATE(97),
+    [sym_inl] = STATE(97),
+    [sym_inr] = STATE(97),
+    [sym_variant] = STATE(97),
+    [sym_case] = STATE(97),
+    [sym_cons] = STATE(97),
+    [sym_isnil] = STATE(97),
+    [sym_head] = STATE(97),
+    [sym_tail] = STATE(97),
+    [sym_list] = STATE(97),
+    [sym_ref] = STATE(97),
+    [sym_deref] = STATE(97),
+    [sym_assign] = STATE(97),
+    [sym_raise] = STATE(97),
+    [sym_try] = STATE(97),
+    [sym_type_abs] = STATE(97),
+    [sym_type_app] = STATE(97),
+    [sym_pack] = STATE(97),
+    [sym_unpack] = STATE(97),
+    [sym_def] = STATE(97),
+    [sym_call] = STATE(97),
+    [sym_ifElse] = STATE(97),
+    [sym_let] = STATE(97),
+    [sym_letrec] = STATE(97),
+    [sym_type_alias] = STATE(97),
+    [sym_import] = STATE(97),
+    [sym_fix] = STATE(97),
+    [sym_ascribe] = STATE(97),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(513),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(515),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(513),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(513),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [128] = {
+    [sym__expr] = STATE(5),
+    [sym_bool] = STATE(5),
+    [sym_succ] = STATE(5),
+    [sym_pred] = STATE(5),
+    [sym_iszero] = STATE(5),
+    [sym_string] = STATE(5),
+    [sym_concat] = STATE(5),
+    [sym_length] = STATE(5),
+    [sym_eq] = STATE(5),
+    [sym_not] = STATE(5),
+    [sym_and] = STATE(5),
+    [sym_or] = STATE(5),
+    [sym_equal] = STATE(5),
+    [sym_tuple] = STATE(5),
+    [sym_record] = STATE(5),
+    [sym_projection] = STATE(5),
+    [sym_inl] = STATE(5),
+    [sym_inr] = STATE(5),
+    [sym_variant] = STATE(5),
+    [sym_case] = STATE(5),
+    [sym_cons] = STATE(5),
+    [sym_isnil] = STATE(5),
+    [sym_head] = STATE(5),
+    [sym_tail] = STATE(5),
+    [sym_list] = STATE(5),
+    [sym_ref] = STATE(5),
+    [sym_deref] = STATE(5),
+    [sym_assign] = STATE(5),
+    [sym_raise] = STATE(5),
+    [sym_try] = STATE(5),
+    [sym_type_abs] = STATE(5),
+    [sym_type_app] = STATE(5),
+    [sym_pack] = STATE(5),
+    [sym_unpack] = STATE(5),
+    [sym_def] = STATE(5),
+    [sym_call] = STATE(5),
+    [sym_ifElse] = STATE(5),
+    [sym_let] = STATE(5),
+    [sym_letrec] = STATE(5),
+    [sym_type_alias] = STATE(5),
+    [sym_import] = STATE(5),
+    [sym_fix] = STATE(5),
+    [sym_ascribe] = STATE(5),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(517),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(519),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(517),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(517),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [129] = {
+    [sym__expr] = STATE(31),
+    [sym_bool] = STATE(31),
+    [sym_succ] = STATE(31),
+    [sym_pred] = STATE(31),
+    [sym_iszero] = STATE(31),
+    [sym_string] = STATE(31),
+    [sym_concat] = STATE(31),
+    [sym_length] = STATE(31),
+    [sym_eq] = STATE(31),
+    [sym_not] = STATE(31),
+    [sym_and] = STATE(31),
+    [sym_or] = STATE(31),
+    [sym_equal] = STATE(31),
+    [sym_tuple] = STATE(31),
+    [sym_record] = STATE(31),
+    [sym_projection] = STATE(31),
+    [sym_inl] = STATE(31),
+    [sym_inr] = STATE(31),
+    [sym_variant] = STATE(31),
+    [sym_case] = STATE(31),
+    [sym_cons] = STATE(31),
+    [sym_isnil] = STATE(31),
+    [sym_head] = STATE(31),
+    [sym_tail] = STATE(31),
+    [sym_list] = STATE(31),
+    [sym_ref] = STATE(31),
+    [sym_deref] = STATE(31),
+    [sym_assign] = STATE(31),
+    [sym_raise] = STATE(31),
+    [sym_try] = STATE(31),
+    [sym_type_abs] = STATE(31),
+    [sym_type_app] = STATE(31),
+    [sym_pack] = STATE(31),
+    [sym_unpack] = STATE(31),
+    [sym_def] = STATE(31),
+    [sym_call] = STATE(31),
+    [sym_ifElse] = STATE(31),
+    [sym_let] = STATE(31),
+    [sym_letrec] = STATE(31),
+    [sym_type_alias] = STATE(31),
+    [sym_import] = STATE(31),
+    [sym_fix] = STATE(31),
+    [sym_ascribe] = STATE(31),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(521),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(523),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(521),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(521),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [130] = {
+    [sym__expr] = STATE(62),
+    [sym_bool] = STATE(62),
+    [sym_succ] = STATE(62),
+    [sym_pred] = STATE(62),
+    [sym_iszero] = STATE(62),
+    [sym_string] = STATE(62),
+    [sym_concat] = STATE(62),
+    [sym_length] = STATE(62),
+    [sym_eq] = STATE(62),
+    [sym_not] = STATE(62),
+    [sym_and] = STATE(62),
+    [sym_or] = STATE(62),
+    [sym_equal] = STATE(62),
+    [sym_tuple] = STATE(62),
+    [sym_record] = STATE(62),
+    [sym_projection] = STATE(62),
+    [sym_inl] = STATE(62),
+    [sym_inr] = STATE(62),
+    [sym_variant] = STATE(62),
+    [sym_case] = STATE(62),
+    [sym_cons] = STATE(62),
+    [sym_isnil] = STATE(62),
+    [sym_head] = STATE(62),
+    [sym_tail] = STATE(62),
+    [sym_list] = STATE(62),
+    [sym_ref] = STATE(62),
+    [sym_deref] = STATE(62),
+    [sym_assign] = STATE(62),
+    [sym_raise] = STATE(62),
+    [sym_try] = STATE(62),
+    [sym_type_abs] = STATE(62),
+    [sym_type_app] = STATE(62),
+    [sym_pack] = STATE(62),
+    [sym_unpack] = STATE(62),
+    [sym_def] = STATE(62),
+    [sym_call] = STATE(62),
+    [sym_ifElse] = STATE(62),
+    [sym_let] = STATE(62),
+    [sym_letrec] = STATE(62),
+    [sym_type_alias] = STATE(62),
+    [sym_import] = STATE(62),
+    [sym_fix] = STATE(62),
+    [sym_ascribe] = STATE(62),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(525),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(527),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(525),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(525),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [131] = {
+    [sym__expr] = STATE(78),
+    [sym_bool] = STATE(78),
+    [sym_succ] = STATE(78),
+    [sym_pred] = STATE(78),
+    [sym_iszero] = STATE(78),
+    [sym_string] = STATE(78),
+    [sym_concat] = STATE(78),
+    [sym_length] = STATE(78),
+    [sym_eq] = STATE(78),
+    [sym_not] = STATE(78),
+    [sym_and] = STATE(78),
+    [sym_or] = STATE(78),
+    [sym_equal] = STATE(78),
+    [sym_tuple] = STATE(78),
+    [sym_record] = STATE(78),
+    [sym_projection] = STATE(78),
+    [sym_inl] = STATE(78),
+    [sym_inr] = STATE(78),
+    [sym_variant] = STATE(78),
+    [sym_case] = STATE(78),
+    [sym_cons] = STATE(78),
+    [sym_isnil] = STATE(78),
+    [sym_head] = STATE(78),
+    [sym_tail] = STATE(78),
+    [sym_list] = STATE(78),
+    [sym_ref] = STATE(78),
+    [sym_deref] = STATE(78),
+    [sym_assign] = STATE(78),
+    [sym_raise] = STATE(78),
+    [sym_try] = STATE(78),
+    [sym_type_abs] = STATE(78),
+    [sym_type_app] = STATE(78),
+    [sym_pack] = STATE(78),
+    [sym_unpack] = STATE(78),
+    [sym_def] = STATE(78),
+    [sym_call] = STATE(78),
+    [sym_ifElse] = STATE(78),
+    [sym_let] = STATE(78),
+    [sym_letrec] = STATE(78),
+    [sym_type_alias] = STATE(78),
+    [sym_import] = STATE(78),
+    [sym_fix] = STATE(78),
+    [sym_ascribe] = STATE(78),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(529),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(531),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(529),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(529),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [132] = {
+    [sym__expr] = STATE(6),
+    [sym_bool] = STATE(6),
+    [sym_succ] = STATE(6),
+    [sym_pred] = STATE(6),
+    [sym_iszero] = STATE(6),
+    [sym_string] = STATE(6),
+    [sym_concat] = STATE(6),
+    [sym_length] = STATE(6),
+    [sym_eq] = STATE(6),
+    [sym_not] = STATE(6),
+    [sym_and] = STATE(6),
+    [sym_or] = STATE(6),
+    [sym_equal] = STATE(6),
+    [sym_tuple] = STATE(6),
+    [sym_record] = STATE(6),
+    [sym_projection] = STATE(6),
+    [sym_inl] = STATE(6),
+    [sym_inr] = STATE(6),
+    [sym_variant] = STATE(6),
+    [sym_case] = STATE(6),
+    [sym_cons] = STATE(6),
+    [sym_isnil] = STATE(6),
+    [sym_head] = STATE(6),
+    [sym_tail] = STATE(6),
+    [sym_list] = STATE(6),
+    [sym_ref] = STATE(6),
+    [sym_deref] = STATE(6),
+    [sym_assign] = STATE(6),
+    [sym_raise] = STATE(6),
+    [sym_try] = STATE(6),
+    [sym_type_abs] = STATE(6),
+    [sym_type_app] = STATE(6),
+    [sym_pack] = STATE(6),
+    [sym_unpack] = STATE(6),
+    [sym_def] = STATE(6),
+    [sym_call] = STATE(6),
+    [sym_ifElse] = STATE(6),
+    [sym_let] = STATE(6),
+    [sym_letrec] = STATE(6),
+    [sym_type_alias] = STATE(6),
+    [sym_import] = STATE(6),
+    [sym_fix] = STATE(6),
+    [sym_ascribe] = STATE(6),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(533),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(535),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(533),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(533),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [133] = {
+    [sym__expr] = STATE(7),
+    [sym_bool] = STATE(7),
+    [sym_succ] = STATE(7),
+    [sym_pred] = STATE(7),
+    [sym_iszero] = STATE(7),
+    [sym_string] = STATE(7),
+    [sym_concat] = STATE(7),
+    [sym_length] = STATE(7),
+    [sym_eq] = STATE(7),
+    [sym_not] = STATE(7),
+    [sym_and] = STATE(7),
+    [sym_or] = STATE(7),
+    [sym_equal] = STATE(7),
+    [sym_tuple] = STATE(7),
+    [sym_record] = STATE(7),
+    [sym_projection] = STATE(7),
+    [sym_inl] = STATE(7),
+    [sym_inr] = STATE(7),
+    [sym_variant] = STATE(7),
+    [sym_case] = STATE(7),
+    [sym_cons] = STATE(7),
+    [sym_isnil] = STATE(7),
+    [sym_head] = STATE(7),
+    [sym_tail] = STATE(7),
+    [sym_list] = STATE(7),
+    [sym_ref] = STATE(7),
+    [sym_deref] = STATE(7),
+    [sym_assign] = STATE(7),
+    [sym_raise] = STATE(7),
+    [sym_try] = STATE(7),
+    [sym_type_abs] = STATE(7),
+    [sym_type_app] = STATE(7),
+    [sym_pack] = STATE(7),
+    [sym_unpack] = STATE(7),
+    [sym_def] = STATE(7),
+    [sym_call] = STATE(7),
+    [sym_ifElse] = STATE(7),
+    [sym_let] = STATE(7),
+    [sym_letrec] = STATE(7),
+    [sym_type_alias] = STATE(7),
+    [sym_import] = STATE(7),
+    [sym_fix] = STATE(7),
+    [sym_ascribe] = STATE(7),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(537),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(539),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(537),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(537),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [134] = {
+    [sym__expr] = STATE(92),
+    [sym_bool] = STATE(92),
+    [sym_succ] = STATE(92),
+    [sym_pred] = STATE(92),
+    [sym_iszero] = STATE(92),
+    [sym_string] = STATE(92),
+    [sym_concat] = STATE(92),
+    [sym_length] = STATE(92),
+    [sym_eq] = STATE(92),
+    [sym_not] = STATE(92),
+    [sym_and] = STATE(92),
+    [sym_or] = STATE(92),
+    [sym_equal] = STATE(92),
+    [sym_tuple] = STATE(92),
+    [sym_record] = STATE(92),
+    [sym_projection] = STATE(92),
+    [sym_inl] = STATE(92),
+    [sym_inr] = STATE(92),
+    [sym_variant] = STATE(92),
+    [sym_case] = STATE(92),
+    [sym_cons] = STATE(92),
+    [sym_isnil] = STATE(92),
+    [sym_head] = STATE(92),
+    [sym_tail] = STATE(92),
+    [sym_list] = STATE(92),
+    [sym_ref] = STATE(92),
+    [sym_deref] = STATE(92),
+    [sym_assign] = STATE(92),
+    [sym_raise] = STATE(92),
+    [sym_try] = STATE(92),
+    [sym_type_abs] = STATE(92),
+    [sym_type_app] = STATE(92),
+    [sym_pack] = STATE(92),
+    [sym_unpack] = STATE(92),
+    [sym_def] = STATE(92),
+    [sym_call] = STATE(92),
+    [sym_ifElse] = STATE(92),
+    [sym_let] = STATE(92),
+    [sym_letrec] = STATE(92),
+    [sym_type_alias] = STATE(92),
+    [sym_import] = STATE(92),
+    [sym_fix] = STATE(92),
+    [sym_ascribe] = STATE(92),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(541),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(543),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(541),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(541),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [135] = {
+    [sym__expr] = STATE(100),
+    [sym_bool] = STATE(100),
+    [sym_succ] = STATE(100),
+    [sym_pred] = STATE(100),
+    [sym_iszero] = STATE(100),
+    [sym_string] = STATE(100),
+    [sym_concat] = STATE(100),
+    [sym_length] = STATE(100),
+    [sym_eq] = STATE(100),
+    [sym_not] = STATE(100),
+    [sym_and] = STATE(100),
+    [sym_or] = STATE(100),
+    [sym_equal] = STATE(100),
+    [sym_tuple] = STATE(100),
+    [sym_record] = STATE(100),
+    [sym_projection] = STATE(100),
+    [sym_inl] = STATE(100),
+    [sym_inr] = STATE(100),
+    [sym_variant] = STATE(100),
+    [sym_case] = STATE(100),
+    [sym_cons] = STATE(100),
+    [sym_isnil] = STATE(100),
+    [sym_head] = STATE(100),
+    [sym_tail] = STATE(100),
+    [sym_list] = STATE(100),
+    [sym_ref] = STATE(100),
+    [sym_deref] = STATE(100),
+    [sym_assign] = STATE(100),
+    [sym_raise] = STATE(100),
+    [sym_try] = STATE(100),
+    [sym_type_abs] = STATE(100),
+    [sym_type_app] = STATE(100),
+    [sym_pack] = STATE(100),
+    [sym_unpack] = STATE(100),
+    [sym_def] = STATE(100),
+    [sym_call] = STATE(100),
+    [sym_ifElse] = STATE(100),
+    [sym_let] = STATE(100),
+    [sym_letrec] = STATE(100),
+    [sym_type_alias] = STATE(100),
+    [sym_import] = STATE(100),
+    [sym_fix] = STATE(100),
+    [sym_ascribe] = STATE(100),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(545),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(547),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(545),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(545),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [136] = {
+    [sym__expr] = STATE(8),
+    [sym_bool] = STATE(8),
+    [sym_succ] = STATE(8),
+    [sym_pred] = STATE(8),
+    [sym_iszero] = STATE(8),
+    [sym_string] = STATE(8),
+    [sym_concat] = STATE(8),
+    [sym_length] = STATE(8),
+    [sym_eq] = STATE(8),
+    [sym_not] = STATE(8),
+    [sym_and] = STATE(8),
+    [sym_or] = STATE(8),
+    [sym_equal] = STATE(8),
+    [sym_tuple] = STATE(8),
+    [sym_record] = STATE(8),
+    [sym_projection] = STATE(8),
+    [sym_inl] = STATE(8),
+    [sym_inr] = STATE(8),
+    [sym_variant] = STATE(8),
+    [sym_case] = STATE(8),
+    [sym_cons] = STATE(8),
+    [sym_isnil] = STATE(8),
+    [sym_head] = STATE(8),
+    [sym_tail] = STATE(8),
+    [sym_list] = STATE(8),
+    [sym_ref] = STATE(8),
+    [sym_deref] = STATE(8),
+    [sym_assign] = STATE(8),
+    [sym_raise] = STATE(8),
+    [sym_try] = STATE(8),
+    [sym_type_abs] = STATE(8),
+    [sym_type_app] = STATE(8),
+    [sym_pack] = STATE(8),
+    [sym_unpack] = STATE(8),
+    [sym_def] = STATE(8),
+    [sym_call] = STATE(8),
+    [sym_ifElse] = STATE(8),
+    [sym_let] = STATE(8),
+    [sym_letrec] = STATE(8),
+    [sym_type_alias] = STATE(8),
+    [sym_import] = STATE(8),
+    [sym_fix] = STATE(8),
+    [sym_ascribe] = STATE(8),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(549),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(551),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(549),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(549),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [137] = {
+    [sym__expr] = STATE(9),
+    [sym_bool] = STATE(9),
+    [sym_succ] = STATE(9),
+    [sym_pred] = STATE(9),
+    [sym_iszero] = STATE(9),
+    [sym_string] = STATE(9),
+    [sym_concat] = STATE(9),
+    [sym_length] = STATE(9),
+    [sym_eq] = STATE(9),
+    [sym_not] = STATE(9),
+    [sym_and] = STATE(9),
+    [sym_or] = STATE(9),
+    [sym_equal] = STATE(9),
+    [sym_tuple] = STATE(9),
+    [sym_record] = STATE(9),
+    [sym_projection] = STATE(9),
+    [sym_inl] = STATE(9),
+    [sym_inr] = STATE(9),
+    [sym_variant] = STATE(9),
+    [sym_case] = STATE(9),
+    [sym_cons] = STATE(9),
+    [sym_isnil] = STATE(9),
+    [sym_head] = STATE(9),
+    [sym_tail] = STATE(9),
+    [sym_list] = STATE(9),
+    [sym_ref] = STATE(9),
+    [sym_deref] = STATE(9),
+    [sym_assign] = STATE(9),
+    [sym_raise] = STATE(9),
+    [sym_try] = STATE(9),
+    [sym_type_abs] = STATE(9),
+    [sym_type_app] = STATE(9),
+    [sym_pack] = STATE(9),
+    [sym_unpack] = STATE(9),
+    [sym_def] = STATE(9),
+    [sym_call] = STATE(9),
+    [sym_ifElse] = STATE(9),
+    [sym_let] = STATE(9),
+    [sym_letrec] = STATE(9),
+    [sym_type_alias] = STATE(9),
+    [sym_import] = STATE(9),
+    [sym_fix] = STATE(9),
+    [sym_ascribe] = STATE(9),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(553),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(555),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(553),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(553),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [138] = {
+    [sym__expr] = STATE(30),
+    [sym_bool] = STATE(30),
+    [sym_succ] = STATE(30),
+    [sym_pred] = STATE(30),
+    [sym_iszero] = STATE(30),
+    [sym_string] = STATE(30),
+    [sym_concat] = STATE(30),
+    [sym_length] = STATE(30),
+    [sym_eq] = STATE(30),
+    [sym_not] = STATE(30),
+    [sym_and] = STATE(30),
+    [sym_or] = STATE(30),
+    [sym_equal] = STATE(30),
+    [sym_tuple] = STATE(30),
+    [sym_record] = STATE(30),
+    [sym_projection] = STATE(30),
+    [sym_inl] = STATE(30),
+    [sym_inr] = STATE(30),
+    [sym_variant] = STATE(30),
+    [sym_case] = STATE(30),
+    [sym_cons] = STATE(30),
+    [sym_isnil] = STATE(30),
+    [sym_head] = STATE(30),
+    [sym_tail] = STATE(30),
+    [sym_list] = STATE(30),
+    [sym_ref] = STATE(30),
+    [sym_deref] = STATE(30),
+    [sym_assign] = STATE(30),
+    [sym_raise] = STATE(30),
+    [sym_try] = STATE(30),
+    [sym_type_abs] = STATE(30),
+    [sym_type_app] = STATE(30),
+    [sym_pack] = STATE(30),
+    [sym_unpack] = STATE(30),
+    [sym_def] = STATE(30),
+    [sym_call] = STATE(30),
+    [sym_ifElse] = STATE(30),
+    [sym_let] = STATE(30),
+    [sym_letrec] = STATE(30),
+    [sym_type_alias] = STATE(30),
+    [sym_import] = STATE(30),
+    [sym_fix] = STATE(30),
+    [sym_ascribe] = STATE(30),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(557),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(559),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(557),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(557),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [139] = {
+    [sym__expr] = STATE(64),
+    [sym_bool] = STATE(64),
+    [sym_succ] = STATE(64),
+    [sym_pred] = STATE(64),
+    [sym_iszero] = STATE(64),
+    [sym_string] = STATE(64),
+    [sym_concat] = STATE(64),
+    [sym_length] = STATE(64),
+    [sym_eq] = STATE(64),
+    [sym_not] = STATE(64),
+    [sym_and] = STATE(64),
+    [sym_or] = STATE(64),
+    [sym_equal] = STATE(64),
+    [sym_tuple] = STATE(64),
+    [sym_record] = STATE(64),
+    [sym_projection] = STATE(64),
+    [sym_inl] = STATE(64),
+    [sym_inr] = STATE(64),
+    [sym_variant] = STATE(64),
+    [sym_case] = STATE(64),
+    [sym_cons] = STATE(64),
+    [sym_isnil] = STATE(64),
+    [sym_head] = STATE(64),
+    [sym_tail] = STATE(64),
+    [sym_list] = STATE(64),
+    [sym_ref] = STATE(64),
+    [sym_deref] = STATE(64),
+    [sym_assign] = STATE(64),
+    [sym_raise] = STATE(64),
+    [sym_try] = STATE(64),
+    [sym_type_abs] = STATE(64),
+    [sym_type_app] = STATE(64),
+    [sym_pack] = STATE(64),
+    [sym_unpack] = STATE(64),
+    [sym_def] = STATE(64),
+    [sym_call] = STATE(64),
+    [sym_ifElse] = STATE(64),
+    [sym_let] = STATE(64),
+    [sym_letrec] = STATE(64),
+    [sym_type_alias] = STATE(64),
+    [sym_import] = STATE(64),
+    [sym_fix] = STATE(64),
+    [sym_ascribe] = STATE(64),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(561),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(563),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(561),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(561),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [140] = {
+    [sym__expr] = STATE(10),
+    [sym_bool] = STATE(10),
+    [sym_succ] = STATE(10),
+    [sym_pred] = STATE(10),
+    [sym_iszero] = STATE(10),
+    [sym_string] = STATE(10),
+    [sym_concat] = STATE(10),
+    [sym_length] = STATE(10),
+    [sym_eq] = STATE(10),
+    [sym_not] = STATE(10),
+    [sym_and] = STATE(10),
+    [sym_or] = STATE(10),
+    [sym_equal] = STATE(10),
+    [sym_tuple] = STATE(10),
+    [sym_record] = STATE(10),
+    [sym_projection] = STATE(10),
+    [sym_inl] = STATE(10),
+    [sym_inr] = STATE(10),
+    [sym_variant] = STATE(10),
+    [sym_case] = STATE(10),
+    [sym_cons] = STATE(10),
+    [sym_isnil] = STATE(10),
+    [sym_head] = STATE(10),
+    [sym_tail] = STATE(10),
+    [sym_list] = STATE(10),
+    [sym_ref] = STATE(10),
+    [sym_deref] = STATE(10),
+    [sym_assign] = STATE(10),
+    [sym_raise] = STATE(10),
+    [sym_try] = STATE(10),
+    [sym_type_abs] = STATE(10),
+    [sym_type_app] = STATE(10),
+    [sym_pack] = STATE(10),
+    [sym_unpack] = STATE(10),
+    [sym_def] = STATE(10),
+    [sym_call] = STATE(10),
+    [sym_ifElse] = STATE(10),
+    [sym_let] = STATE(10),
+    [sym_letrec] = STATE(10),
+    [sym_type_alias] = STATE(10),
+    [sym_import] = STATE(10),
+    [sym_fix] = STATE(10),
+    [sym_ascribe] = STATE(10),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(565),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(567),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(565),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(565),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [141] = {
+    [sym__expr] = STATE(17),
+    [sym_bool] = STATE(17),
+    [sym_succ] = STATE(17),
+    [sym_pred] = STATE(17),
+    [sym_iszero] = STATE(17),
+    [sym_string] = STATE(17),
+    [sym_concat] = STATE(17),
+    [sym_length] = STATE(17),
+    [sym_eq] = STATE(17),
+    [sym_not] = STATE(17),
+    [sym_and] = STATE(17),
+    [sym_or] = STATE(17),
+    [sym_equal] = STATE(17),
+    [sym_tuple] = STATE(17),
+    [sym_record] = STATE(17),
+    [sym_projection] = STATE(17),
+    [sym_inl] = STATE(17),
+    [sym_inr] = STATE(17),
+    [sym_variant] = STATE(17),
+    [sym_case] = STATE(17),
+    [sym_cons] = STATE(17),
+    [sym_isnil] = STATE(17),
+    [sym_head] = STATE(17),
+    [sym_tail] = STATE(17),
+    [sym_list] = STATE(17),
+    [sym_ref] = STATE(17),
+    [sym_deref] = STATE(17),
+    [sym_assign] = STATE(17),
+    [sym_raise] = STATE(17),
+    [sym_try] = STATE(17),
+    [sym_type_abs] = STATE(17),
+    [sym_type_app] = STATE(17),
+    [sym_pack] = STATE(17),
+    [sym_unpack] = STATE(17),
+    [sym_def] = STATE(17),
+    [sym_call] = STATE(17),
+    [sym_ifElse] = STATE(17),
+    [sym_let] = STATE(17),
+    [sym_letrec] = STATE(17),
+    [sym_type_alias] = STATE(17),
+    [sym_import] = STATE(17),
+    [sym_fix] = STATE(17),
+    [sym_ascribe] = STATE(17),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(569),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(571),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(569),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(569),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [142] = {
+    [sym__expr] = STATE(81),
+    [sym_bool] = STATE(81),
+    [sym_succ] = STATE(81),
+    [sym_pred] = STATE(81),
+    [sym_iszero] = STATE(81),
+    [sym_string] = STATE(81),
+    [sym_concat] = STATE(81),
+    [sym_length] = STATE(81),
+    [sym_eq] = STATE(81),
+    [sym_not] = STATE(81),
+    [sym_and] = STATE(81),
+    [sym_or] = STATE(81),
+    [sym_equal] = STATE(81),
+    [sym_tuple] = STATE(81),
+    [sym_record] = STATE(81),
+    [sym_projection] = STATE(81),
+    [sym_inl] = STATE(81),
+    [sym_inr] = STATE(81),
+    [sym_variant] = STATE(81),
+    [sym_case] = STATE(81),
+    [sym_cons] = STATE(81),
+    [sym_isnil] = STATE(81),
+    [sym_head] = STATE(81),
+    [sym_tail] = STATE(81),
+    [sym_list] = STATE(81),
+    [sym_ref] = STATE(81),
+    [sym_deref] = STATE(81),
+    [sym_assign] = STATE(81),
+    [sym_raise] = STATE(81),
+    [sym_try] = STATE(81),
+    [sym_type_abs] = STATE(81),
+    [sym_type_app] = STATE(81),
+    [sym_pack] = STATE(81),
+    [sym_unpack] = STATE(81),
+    [sym_def] = STATE(81),
+    [sym_call] = STATE(81),
+    [sym_ifElse] = STATE(81),
+    [sym_let] = STATE(81),
+    [sym_letrec] = STATE(81),
+    [sym_type_alias] = STATE(81),
+    [sym_import] = STATE(81),
+    [sym_fix] = STATE(81),
+    [sym_ascribe] = STATE(81),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(573),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(575),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(573),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(573),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [143] = {
+    [sym__expr] = STATE(11),
+    [sym_bool] = STATE(11),
+    [sym_succ] = STATE(11),
+    [sym_pred] = STATE(11),
+    [sym_iszero] = STATE(11),
+    [sym_string] = STATE(11),
+    [sym_concat] = STATE(11),
+    [sym_length] = STATE(11),
+    [sym_eq] = STATE(11),
+    [sym_not] = STATE(11),
+    [sym_and] = STATE(11),
+    [sym_or] = STATE(11),
+    [sym_equal] = STATE(11),
+    [sym_tuple] = STATE(11),
+    [sym_record] = STATE(11),
+    [sym_projection] = STATE(11),
+    [sym_inl] = STATE(11),
+    [sym_inr] = STATE(11),
+    [sym_variant] = STATE(11),
+    [sym_case] = STATE(11),
+    [sym_cons] = STATE(11),
+    [sym_isnil] = STATE(11),
+    [sym_head] = STATE(11),
+    [sym_tail] = STATE(11),
+    [sym_list] = STATE(11),
+    [sym_ref] = STATE(11),
+    [sym_deref] = STATE(11),
+    [sym_assign] = STATE(11),
+    [sym_raise] = STATE(11),
+    [sym_try] = STATE(11),
+    [sym_type_abs] = STATE(11),
+    [sym_type_app] = STATE(11),
+    [sym_pack] = STATE(11),
+    [sym_unpack] = STATE(11),
+    [sym_def] = STATE(11),
+    [sym_call] = STATE(11),
+    [sym_ifElse] = STATE(11),
+    [sym_let] = STATE(11),
+    [sym_letrec] = STATE(11),
+    [sym_type_alias] = STATE(11),
+    [sym_import] = STATE(11),
+    [sym_fix] = STATE(11),
+    [sym_ascribe] = STATE(11),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(577),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(579),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(577),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(577),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [144] = {
+    [sym__expr] = STATE(12),
+    [sym_bool] = STATE(12),
+    [sym_succ] = STATE(12),
+    [sym_pred] = STATE(12),
+    [sym_iszero] = STATE(12),
+    [sym_string] = STATE(12),
+    [sym_concat] = STATE(12),
+    [sym_length] = STATE(12),
+    [sym_eq] = STATE(12),
+    [sym_not] = STATE(12),
+    [sym_and] = STATE(12),
+    [sym_or] = STATE(12),
+    [sym_equal] = STATE(12),
+    [sym_tuple] = STATE(12),
+    [sym_record] = STATE(12),
+    [sym_projection] = STATE(12),
+    [sym_inl] = STATE(12),
+    [sym_inr] = STATE(12),
+    [sym_variant] = STATE(12),
+    [sym_case] = STATE(12),
+    [sym_cons] = STATE(12),
+    [sym_isnil] = STATE(12),
+    [sym_head] = STATE(12),
+    [sym_tail] = STATE(12),
+    [sym_list] = STATE(12),
+    [sym_ref] = STATE(12),
+    [sym_deref] = STATE(12),
+    [sym_assign] = STATE(12),
+    [sym_raise] = STATE(12),
+    [sym_try] = STATE(12),
+    [sym_type_abs] = STATE(12),
+    [sym_type_app] = STATE(12),
+    [sym_pack] = STATE(12),
+    [sym_unpack] = STATE(12),
+    [sym_def] = STATE(12),
+    [sym_call] = STATE(12),
+    [sym_ifElse] = STATE(12),
+    [sym_let] = STATE(12),
+    [sym_letrec] = STATE(12),
+    [sym_type_alias] = STATE(12),
+    [sym_import] = STATE(12),
+    [sym_fix] = STATE(12),
+    [sym_ascribe] = STATE(12),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(581),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(583),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(581),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(581),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [145] = {
+    [sym__expr] = STATE(65),
+    [sym_bool] = STATE(65),
+    [sym_succ] = STATE(65),
+    [sym_pred] = STATE(65),
+    [sym_iszero] = STATE(65),
+    [sym_string] = STATE(65),
+    [sym_concat] = STATE(65),
+    [sym_length] = STATE(65),
+    [sym_eq] = STATE(65),
+    [sym_not] = STATE(65),
+    [sym_and] = STATE(65),
+    [sym_or] = STATE(65),
+    [sym_equal] = STATE(65),
+    [sym_tuple] = STATE(65),
+    [sym_record] = STATE(65),
+    [sym_projection] = STATE(65),
+    [sym_inl] = STATE(65),
+    [sym_inr] = STATE(65),
+    [sym_variant] = STATE(65),
+    [sym_case] = STATE(65),
+    [sym_cons] = STATE(65),
+    [sym_isnil] = STATE(65),
+    [sym_head] = STATE(65),
+    [sym_tail] = STATE(65),
+    [sym_list] = STATE(65),
+    [sym_ref] = STATE(65),
+    [sym_deref] = STATE(65),
+    [sym_assign] = STATE(65),
+    [sym_raise] = STATE(65),
+    [sym_try] = STATE(65),
+    [sym_type_abs] = STATE(65),
+    [sym_type_app] = STATE(65),
+    [sym_pack] = STATE(65),
+    [sym_unpack] = STATE(65),
+    [sym_def] = STATE(65),
+    [sym_call] = STATE(65),
+    [sym_ifElse] = STATE(65),
+    [sym_let] = STATE(65),
+    [sym_letrec] = STATE(65),
+    [sym_type_alias] = STATE(65),
+    [sym_import] = STATE(65),
+    [sym_fix] = STATE(65),
+    [sym_ascribe] = STATE(65),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(585),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(587),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(585),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(585),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [146] = {
+    [sym__expr] = STATE(13),
+    [sym_bool] = STATE(13),
+    [sym_succ] = STATE(13),
+    [sym_pred] = STATE(13),
+    [sym_iszero] = STATE(13),
+    [sym_string] = STATE(13),
+    [sym_concat] = STATE(13),
+    [sym_length] = STATE(13),
+    [sym_eq] = STATE(13),
+    [sym_not] = STATE(13),
+    [sym_and] = STATE(13),
+    [sym_or] = STATE(13),
+    [sym_equal] = STATE(13),
+    [sym_tuple] = STATE(13),
+    [sym_record] = STATE(13),
+    [sym_projection] = STATE(13),
+    [sym_inl] = STATE(13),
+    [sym_inr] = STATE(13),
+    [sym_variant] = STATE(13),
+    [sym_case] = STATE(13),
+    [sym_cons] = STATE(13),
+    [sym_isnil] = STATE(13),
+    [sym_head] = STATE(13),
+    [sym_tail] = STATE(13),
+    [sym_list] = STATE(13),
+    [sym_ref] = STATE(13),
+    [sym_deref] = STATE(13),
+    [sym_assign] = STATE(13),
+    [sym_raise] = STATE(13),
+    [sym_try] = STATE(13),
+    [sym_type_abs] = STATE(13),
+    [sym_type_app] = STATE(13),
+    [sym_pack] = STATE(13),
+    [sym_unpack] = STATE(13),
+    [sym_def] = STATE(13),
+    [sym_call] = STATE(13),
+    [sym_ifElse] = STATE(13),
+    [sym_let] = STATE(13),
+    [sym_letrec] = STATE(13),
+    [sym_type_alias] = STATE(13),
+    [sym_import] = STATE(13),
+    [sym_fix] = STATE(13),
+    [sym_ascribe] = STATE(13),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(589),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(591),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(589),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(589),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [147] = {
+    [sym__expr] = STATE(33),
+    [sym_bool] = STATE(33),
+    [sym_succ] = STATE(33),
+    [sym_pred] = STATE(33),
+    [sym_iszero] = STATE(33),
+    [sym_string] = STATE(33),
+    [sym_concat] = STATE(33),
+    [sym_length] = STATE(33),
+    [sym_eq] = STATE(33),
+    [sym_not] = STATE(33),
+    [sym_and] = STATE(33),
+    [sym_or] = STATE(33),
+    [sym_equal] = STATE(33),
+    [sym_tuple] = STATE(33),
+    [sym_record] = STATE(33),
+    [sym_projection] = STATE(33),
+    [sym_inl] = STATE(33),
+    [sym_inr] = STATE(33),
+    [sym_variant] = STATE(33),
+    [sym_case] = STATE(33),
+    [sym_cons] = STATE(33),
+    [sym_isnil] = STATE(33),
+    [sym_head] = STATE(33),
+    [sym_tail] = STATE(33),
+    [sym_list] = STATE(33),
+    [sym_ref] = STATE(33),
+    [sym_deref] = STATE(33),
+    [sym_assign] = STATE(33),
+    [sym_raise] = STATE(33),
+    [sym_try] = STATE(33),
+    [sym_type_abs] = STATE(33),
+    [sym_type_app] = STATE(33),
+    [sym_pack] = STATE(33),
+    [sym_unpack] = STATE(33),
+    [sym_def] = STATE(33),
+    [sym_call] = STATE(33),
+    [sym_ifElse] = STATE(33),
+    [sym_let] = STATE(33),
+    [sym_letrec] = STATE(33),
+    [sym_type_alias] = STATE(33),
+    [sym_import] = STATE(33),
+    [sym_fix] = STATE(33),
+    [sym_ascribe] = STATE(33),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(593),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(595),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(593),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(593),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [148] = {
+    [sym__expr] = STATE(83),
+    [sym_bool] = STATE(83),
+    [sym_succ] = STATE(83),
+    [sym_pred] = STATE(83),
+    [sym_iszero] = STATE(83),
+    [sym_string] = STATE(83),
+    [sym_concat] = STATE(83),
+    [sym_length] = STATE(83),
+    [sym_eq] = STATE(83),
+    [sym_not] = STATE(83),
+    [sym_and] = STATE(83),
+    [sym_or] = STATE(83),
+    [sym_equal] = STATE(83),
+    [sym_tuple] = STATE(83),
+    [sym_record] = STATE(83),
+    [sym_projection] = STATE(83),
+    [sym_inl] = STATE(83),
+    [sym_inr] = STATE(83),
+    [sym_variant] = STATE(83),
+    [sym_case] = STATE(83),
+    [sym_cons] = STATE(83),
+    [sym_isnil] = STATE(83),
+    [sym_head] = STATE(83),
+    [sym_tail] = STATE(83),
+    [sym_list] = STATE(83),
+    [sym_ref] = STATE(83),
+    [sym_deref] = STATE(83),
+    [sym_assign] = STATE(83),
+    [sym_raise] = STATE(83),
+    [sym_try] = STATE(83),
+    [sym_type_abs] = STATE(83),
+    [sym_type_app] = STATE(83),
+    [sym_pack] = STATE(83),
+    [sym_unpack] = STATE(83),
+    [sym_def] = STATE(83),
+    [sym_call] = STATE(83),
+    [sym_ifElse] = STATE(83),
+    [sym_let] = STATE(83),
+    [sym_letrec] = STATE(83),
+    [sym_type_alias] = STATE(83),
+    [sym_import] = STATE(83),
+    [sym_fix] = STATE(83),
+    [sym_ascribe] = STATE(83),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(597),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(599),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(597),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(597),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [149] = {
+    [sym__expr] = STATE(32),
+    [sym_bool] = STATE(32),
+    [sym_succ] = STATE(32),
+    [sym_pred] = STATE(32),
+    [sym_iszero] = STATE(32),
+    [sym_string] = STATE(32),
+    [sym_concat] = STATE(32),
+    [sym_length] = STATE(32),
+    [sym_eq] = STATE(32),
+    [sym_not] = STATE(32),
+    [sym_and] = STATE(32),
+    [sym_or] = STATE(32),
+    [sym_equal] = STATE(32),
+    [sym_tuple] = STATE(32),
+    [sym_record] = STATE(32),
+    [sym_projection] = STATE(32),
+    [sym_inl] = STATE(32),
+    [sym_inr] = STATE(32),
+    [sym_variant] = STATE(32),
+    [sym_case] = STATE(32),
+    [sym_cons] = STATE(32),
+    [sym_isnil] = STATE(32),
+    [sym_head] = STATE(32),
+    [sym_tail] = STATE(32),
+    [sym_list] = STATE(32),
+    [sym_ref] = STATE(32),
+    [sym_deref] = STATE(32),
+    [sym_assign] = STATE(32),
+    [sym_raise] = STATE(32),
+    [sym_try] = STATE(32),
+    [sym_type_abs] = STATE(32),
+    [sym_type_app] = STATE(32),
+    [sym_pack] = STATE(32),
+    [sym_unpack] = STATE(32),
+    [sym_def] = STATE(32),
+    [sym_call] = STATE(32),
+    [sym_ifElse] = STATE(32),
+    [sym_let] = STATE(32),
+    [sym_letrec] = STATE(32),
+    [sym_type_alias] = STATE(32),
+    [sym_import] = STATE(32),
+    [sym_fix] = STATE(32),
+    [sym_ascribe] = STATE(32),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(601),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(603),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(601),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(601),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [150] = {
+    [sym__expr] = STATE(6),
+    [sym_bool] = STATE(6),
+    [sym_succ] = STATE(6),
+    [sym_pred] = STATE(6),
+    [sym_iszero] = STATE(6),
+    [sym_string] = STATE(6),
+    [sym_concat] = STATE(6),
+    [sym_length] = STATE(6),
+    [sym_eq] = STATE(6),
+    [sym_not] = STATE(6),
+    [sym_and] = STATE(6),
+    [sym_or] = STATE(6),
+    [sym_equal] = STATE(6),
+    [sym_tuple] = STATE(6),
+    [sym_record] = STATE(6),
+    [sym_projection] = STATE(6),
+    [sym_inl] = STATE(6),
+    [sym_inr] = STATE(6),
+    [sym_variant] = STATE(6),
+    [sym_case] = STATE(6),
+    [sym_cons] = STATE(6),
+    [sym_isnil] = STATE(6),
+    [sym_head] = STATE(6),
+    [sym_tail] = STATE(6),
+    [sym_list] = STATE(6),
+    [sym_ref] = STATE(6),
+    [sym_deref] = STATE(6),
+    [sym_assign] = STATE(6),
+    [sym_raise] = STATE(6),
+    [sym_try] = STATE(6),
+    [sym_type_abs] = STATE(6),
+    [sym_type_app] = STATE(6),
+    [sym_pack] = STATE(6),
+    [sym_unpack] = STATE(6),
+    [sym_def] = STATE(6),
+    [sym_call] = STATE(6),
+    [sym_ifElse] = STATE(6),
+    [sym_let] = STATE(6),
+    [sym_letrec] = STATE(6),
+    [sym_type_alias] = STATE(6),
+    [sym_import] = STATE(6),
+    [sym_fix] = STATE(6),
+    [sym_ascribe] = STATE(6),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(533),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(535),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(533),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(533),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [151] = {
+    [sym__expr] = STATE(7),
+    [sym_bool] = STATE(7),
+    [sym_succ] = STATE(7),
+    [sym_pred] = STATE(7),
+    [sym_iszero] = STATE(7),
+    [sym_string] = STATE(7),
+    [sym_concat] = STATE(7),
+    [sym_length] = STATE(7),
+    [sym_eq] = STATE(7),
+    [sym_not] = STATE(7),
+    [sym_and] = STATE(7),
+    [sym_or] = STATE(7),
+    [sym_equal] = STATE(7),
+    [sym_tuple] = STATE(7),
+    [sym_record] = STATE(7),
+    [sym_projection] = STATE(7),
+    [sym_inl] = STATE(7),
+    [sym_inr] = STATE(7),
+    [sym_variant] = STATE(7),
+    [sym_case] = STATE(7),
+    [sym_cons] = STATE(7),
+    [sym_isnil] = STATE(7),
+    [sym_head] = STATE(7),
+    [sym_tail] = STATE(7),
+    [sym_list] = STATE(7),
+    [sym_ref] = STATE(7),
+    [sym_deref] = STATE(7),
+    [sym_assign] = STATE(7),
+    [sym_raise] = STATE(7),
+    [sym_try] = STATE(7),
+    [sym_type_abs] = STATE(7),
+    [sym_type_app] = STATE(7),
+    [sym_pack] = STATE(7),
+    [sym_unpack] = STATE(7),
+    [sym_def] = STATE(7),
+    [sym_call] = STATE(7),
+    [sym_ifElse] = STATE(7),
+    [sym_let] = STATE(7),
+    [sym_letrec] = STATE(7),
+    [sym_type_alias] = STATE(7),
+    [sym_import] = STATE(7),
+    [sym_fix] = STATE(7),
+    [sym_ascribe] = STATE(7),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(537),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(539),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(537),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(537),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [152] = {
+    [sym__expr] = STATE(8),
+    [sym_bool] = STATE(8),
+    [sym_succ] = STATE(8),
+    [sym_pred] = STATE(8),
+    [sym_iszero] = STATE(8),
+    [sym_string] = STATE(8),
+    [sym_concat] = STATE(8),
+    [sym_length] = STATE(8),
+    [sym_eq] = STATE(8),
+    [sym_not] = STATE(8),
+    [sym_and] = STATE(8),
+    [sym_or] = STATE(8),
+    [sym_equal] = STATE(8),
+    [sym_tuple] = STATE(8),
+    [sym_record] = STATE(8),
+    [sym_projection] = STATE(8),
+    [sym_inl] = STATE(8),
+    [sym_inr] = STATE(8),
+    [sym_variant] = STATE(8),
+    [sym_case] = STATE(8),
+    [sym_cons] = STATE(8),
+    [sym_isnil] = STATE(8),
+    [sym_head] = STATE(8),
+    [sym_tail] = STATE(8),
+    [sym_list] = STATE(8),
+    [sym_ref] = STATE(8),
+    [sym_deref] = STATE(8),
+    [sym_assign] = STATE(8),
+    [sym_raise] = STATE(8),
+    [sym_try] = STATE(8),
+    [sym_type_abs] = STATE(8),
+    [sym_type_app] = STATE(8),
+    [sym_pack] = STATE(8),
+    [sym_unpack] = STATE(8),
+    [sym_def] = STATE(8),
+    [sym_call] = STATE(8),
+    [sym_ifElse] = STATE(8),
+    [sym_let] = STATE(8),
+    [sym_letrec] = STATE(8),
+    [sym_type_alias] = STATE(8),
+    [sym_import] = STATE(8),
+    [sym_fix] = STATE(8),
+    [sym_ascribe] = STATE(8),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(549),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(551),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(549),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(549),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [153] = {
+    [sym__expr] = STATE(9),
+    [sym_bool] = STATE(9),
+    [sym_succ] = STATE(9),
+    [sym_pred] = STATE(9),
+    [sym_iszero] = STATE(9),
+    [sym_string] = STATE(9),
+    [sym_concat] = STATE(9),
+    [sym_length] = STATE(9),
+    [sym_eq] = STATE(9),
+    [sym_not] = STATE(9),
+    [sym_and] = STATE(9),
+    [sym_or] = STATE(9),
+    [sym_equal] = STATE(9),
+    [sym_tuple] = STATE(9),
+    [sym_record] = STATE(9),
+    [sym_projection] = STATE(9),
+    [sym_inl] = STATE(9),
+    [sym_inr] = STATE(9),
+    [sym_variant] = STATE(9),
+    [sym_case] = STATE(9),
+    [sym_cons] = STATE(9),
+    [sym_isnil] = STATE(9),
+    [sym_head] = STATE(9),
+    [sym_tail] = STATE(9),
+    [sym_list] = STATE(9),
+    [sym_ref] = STATE(9),
+    [sym_deref] = STATE(9),
+    [sym_assign] = STATE(9),
+    [sym_raise] = STATE(9),
+    [sym_try] = STATE(9),
+    [sym_type_abs] = STATE(9),
+    [sym_type_app] = STATE(9),
+    [sym_pack] = STATE(9),
+    [sym_unpack] = STATE(9),
+    [sym_def] = STATE(9),
+    [sym_call] = STATE(9),
+    [sym_ifElse] = STATE(9),
+    [sym_let] = STATE(9),
+    [sym_letrec] = STATE(9),
+    [sym_type_alias] = STATE(9),
+    [sym_import] = STATE(9),
+    [sym_fix] = STATE(9),
+    [sym_ascribe] = STATE(9),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(553),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(555),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(553),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(553),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [154] = {
+    [sym__expr] = STATE(10),
+    [sym_bool] = STATE(10),
+    [sym_succ] = STATE(10),
+    [sym_pred] = STATE(10),
+    [sym_iszero] = STATE(10),
+    [sym_string] = STATE(10),
+    [sym_concat] = STATE(10),
+    [sym_length] = STATE(10),
+    [sym_eq] = STATE(10),
+    [sym_not] = STATE(10),
+    [sym_and] = STATE(10),
+    [sym_or] = STATE(10),
+    [sym_equal] = STATE(10),
+    [sym_tuple] = STATE(10),
+    [sym_record] = STATE(10),
+    [sym_projection] = STATE(10),
+    [sym_inl] = STATE(10),
+    [sym_inr] = STATE(10),
+    [sym_variant] = STATE(10),
+    [sym_case] = STATE(10),
+    [sym_cons] = STATE(10),
+    [sym_isnil] = STATE(10),
+    [sym_head] = STATE(10),
+    [sym_tail] = STATE(10),
+    [sym_list] = STATE(10),
+    [sym_ref] = STATE(10),
+    [sym_deref] = STATE(10),
+    [sym_assign] = STATE(10),
+    [sym_raise] = STATE(10),
+    [sym_try] = STATE(10),
+    [sym_type_abs] = STATE(10),
+    [sym_type_app] = STATE(10),
+    [sym_pack] = STATE(10),
+    [sym_unpack] = STATE(10),
+    [sym_def] = STATE(10),
+    [sym_call] = STATE(10),
+    [sym_ifElse] = STATE(10),
+    [sym_let] = STATE(10),
+    [sym_letrec] = STATE(10),
+    [sym_type_alias] = STATE(10),
+    [sym_import] = STATE(10),
+    [sym_fix] = STATE(10),
+    [sym_ascribe] = STATE(10),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(565),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(567),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(565),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(565),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [155] = {
+    [sym__expr] = STATE(11),
+    [sym_bool] = STATE(11),
+    [sym_succ] = STATE(11),
+    [sym_pred] = STATE(11),
+    [sym_iszero] = STATE(11),
+    [sym_string] = STATE(11),
+    [sym_concat] = STATE(11),
+    [sym_length] = STATE(11),
+    [sym_eq] = STATE(11),
+    [sym_not] = STATE(11),
+    [sym_and] = STATE(11),
+    [sym_or] = STATE(11),
+    [sym_equal] = STATE(11),
+    [sym_tuple] = STATE(11),
+    [sym_record] = STATE(11),
+    [sym_projection] = STATE(11),
+    [sym_inl] = STATE(11),
+    [sym_inr] = STATE(11),
+    [sym_variant] = STATE(11),
+    [sym_case] = STATE(11),
+    [sym_cons] = STATE(11),
+    [sym_isnil] = STATE(11),
+    [sym_head] = STATE(11),
+    [sym_tail] = STATE(11),
+    [sym_list] = STATE(11),
+    [sym_ref] = STATE(11),
+    [sym_deref] = STATE(11),
+    [sym_assign] = STATE(11),
+    [sym_raise] = STATE(11),
+    [sym_try] = STATE(11),
+    [sym_type_abs] = STATE(11),
+    [sym_type_app] = STATE(11),
+    [sym_pack] = STATE(11),
+    [sym_unpack] = STATE(11),
+    [sym_def] = STATE(11),
+    [sym_call] = STATE(11),
+    [sym_ifElse] = STATE(11),
+    [sym_let] = STATE(11),
+    [sym_letrec] = STATE(11),
+    [sym_type_alias] = STATE(11),
+    [sym_import] = STATE(11),
+    [sym_fix] = STATE(11),
+    [sym_ascribe] = STATE(11),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(577),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(579),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(577),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(577),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [156] = {
+    [sym__expr] = STATE(12),
+    [sym_bool] = STATE(12),
+    [sym_succ] = STATE(12),
+    [sym_pred] = STATE(12),
+    [sym_iszero] = STATE(12),
+    [sym_string] = STATE(12),
+    [sym_concat] = STATE(12),
+    [sym_length] = STATE(12),
+    [sym_eq] = STATE(12),
+    [sym_not] = STATE(12),
+    [sym_and] = STATE(12),
+    [sym_or] = STATE(12),
+    [sym_equal] = STATE(12),
+    [sym_tuple] = STATE(12),
+    [sym_record] = STATE(12),
+    [sym_projection] = STATE(12),
+    [sym_inl] = STATE(12),
+    [sym_inr] = STATE(12),
+    [sym_variant] = STATE(12),
+    [sym_case] = STATE(12),
+    [sym_cons] = STATE(12),
+    [sym_isnil] = STATE(12),
+    [sym_head] = STATE(12),
+    [sym_tail] = STATE(12),
+    [sym_list] = STATE(12),
+    [sym_ref] = STATE(12),
+    [sym_deref] = STATE(12),
+    [sym_assign] = STATE(12),
+    [sym_raise] = STATE(12),
+    [sym_try] = STATE(12),
+    [sym_type_abs] = STATE(12),
+    [sym_type_app] = STATE(12),
+    [sym_pack] = STATE(12),
+    [sym_unpack] = STATE(12),
+    [sym_def] = STATE(12),
+    [sym_call] = STATE(12),
+    [sym_ifElse] = STATE(12),
+    [sym_let] = STATE(12),
+    [sym_letrec] = STATE(12),
+    [sym_type_alias] = STATE(12),
+    [sym_import] = STATE(12),
+    [sym_fix] = STATE(12),
+    [sym_ascribe] = STATE(12),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(581),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(583),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(581),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(581),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [157] = {
+    [sym__expr] = STATE(13),
+    [sym_bool] = STATE(13),
+    [sym_succ] = STATE(13),
+    [sym_pred] = STATE(13),
+    [sym_iszero] = STATE(13),
+    [sym_string] = STATE(13),
+    [sym_concat] = STATE(13),
+    [sym_length] = STATE(13),
+    [sym_eq] = STATE(13),
+    [sym_not] = STATE(13),
+    [sym_and] = STATE(13),
+    [sym_or] = STATE(13),
+    [sym_equal] = STATE(13),
+    [sym_tuple] = STATE(13),
+    [sym_record] = STATE(13),
+    [sym_projection] = STATE(13),
+    [sym_inl] = STATE(13),
+    [sym_inr] = STATE(13),
+    [sym_variant] = STATE(13),
+    [sym_case] = STATE(13),
+    [sym_cons] = STATE(13),
+    [sym_isnil] = STATE(13),
+    [sym_head] = STATE(13),
+    [sym_tail] = STATE(13),
+    [sym_list] = STATE(13),
+    [sym_ref] = STATE(13),
+    [sym_deref] = STATE(13),
+    [sym_assign] = STATE(13),
+    [sym_raise] = STATE(13),
+    [sym_try] = STATE(13),
+    [sym_type_abs] = STATE(13),
+    [sym_type_app] = STATE(13),
+    [sym_pack] = STATE(13),
+    [sym_unpack] = STATE(13),
+    [sym_def] = STATE(13),
+    [sym_call] = STATE(13),
+    [sym_ifElse] = STATE(13),
+    [sym_let] = STATE(13),
+    [sym_letrec] = STATE(13),
+    [sym_type_alias] = STATE(13),
+    [sym_import] = STATE(13),
+    [sym_fix] = STATE(13),
+    [sym_ascribe] = STATE(13),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(589),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(591),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(589),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(589),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [158] = {
+    [sym__expr] = STATE(14),
+    [sym_bool] = STATE(14),
+    [sym_succ] = STATE(14),
+    [sym_pred] = STATE(14),
+    [sym_iszero] = STATE(14),
+    [sym_string] = STATE(14),
+    [sym_concat] = STATE(14),
+    [sym_length] = STATE(14),
+    [sym_eq] = STATE(14),
+    [sym_not] = STATE(14),
+    [sym_and] = STATE(14),
+    [sym_or] = STATE(14),
+    [sym_equal] = STATE(14),
+    [sym_tuple] = STATE(14),
+    [sym_record] = STATE(14),
+    [sym_projection] = STATE(14),
+    [sym_inl] = STATE(14),
+    [sym_inr] = STATE(14),
+    [sym_variant] = STATE(14),
+    [sym_case] = STATE(14),
+    [sym_cons] = STATE(14),
+    [sym_isnil] = STATE(14),
+    [sym_head] = STATE(14),
+    [sym_tail] = STATE(14),
+    [sym_list] = STATE(14),
+    [sym_ref] = STATE(14),
+    [sym_deref] = STATE(14),
+    [sym_assign] = STATE(14),
+    [sym_raise] = STATE(14),
+    [sym_try] = STATE(14),
+    [sym_type_abs] = STATE(14),
+    [sym_type_app] = STATE(14),
+    [sym_pack] = STATE(14),
+    [sym_unpack] = STATE(14),
+    [sym_def] = STATE(14),
+    [sym_call] = STATE(14),
+    [sym_ifElse] = STATE(14),
+    [sym_let] = STATE(14),
+    [sym_letrec] = STATE(14),
+    [sym_type_alias] = STATE(14),
+    [sym_import] = STATE(14),
+    [sym_fix] = STATE(14),
+    [sym_ascribe] = STATE(14),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(605),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(607),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(605),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(605),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [159] = {
+    [sym__expr] = STATE(67),
+    [sym_bool] = STATE(67),
+    [sym_succ] = STATE(67),
+    [sym_pred] = STATE(67),
+    [sym_iszero] = STATE(67),
+    [sym_string] = STATE(67),
+    [sym_concat] = STATE(67),
+    [sym_length] = STATE(67),
+    [sym_eq] = STATE(67),
+    [sym_not] = STATE(67),
+    [sym_and] = STATE(67),
+    [sym_or] = STATE(67),
+    [sym_equal] = STATE(67),
+    [sym_tuple] = STATE(67),
+    [sym_record] = STATE(67),
+    [sym_projection] = STATE(67),
+    [sym_inl] = STATE(67),
+    [sym_inr] = STATE(67),
+    [sym_variant] = STATE(67),
+    [sym_case] = STATE(67),
+    [sym_cons] = STATE(67),
+    [sym_isnil] = STATE(67),
+    [sym_head] = STATE(67),
+    [sym_tail] = STATE(67),
+    [sym_list] = STATE(67),
+    [sym_ref] = STATE(67),
+    [sym_deref] = STATE(67),
+    [sym_assign] = STATE(67),
+    [sym_raise] = STATE(67),
+    [sym_try] = STATE(67),
+    [sym_type_abs] = STATE(67),
+    [sym_type_app] = STATE(67),
+    [sym_pack] = STATE(67),
+    [sym_unpack] = STATE(67),
+    [sym_def] = STATE(67),
+    [sym_call] = STATE(67),
+    [sym_ifElse] = STATE(67),
+    [sym_let] = STATE(67),
+    [sym_letrec] = STATE(67),
+    [sym_type_alias] = STATE(67),
+    [sym_import] = STATE(67),
+    [sym_fix] = STATE(67),
+    [sym_ascribe] = STATE(67),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(609),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(611),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(609),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(609),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [160] = {
+    [sym__expr] = STATE(69),
+    [sym_bool] = STATE(69),
+    [sym_succ] = STATE(69),
+    [sym_pred] = STATE(69),
+    [sym_iszero] = STATE(69),
+    [sym_string] = STATE(69),
+    [sym_concat] = STATE(69),
+    [sym_length] = STATE(69),
+    [sym_eq] = STATE(69),
+    [sym_not] = STATE(69),
+    [sym_and] = STATE(69),
+    [sym_or] = STATE(69),
+    [sym_equal] = STATE(69),
+    [sym_tuple] = STATE(69),
+    [sym_record] = STATE(69),
+    [sym_projection] = STATE(69),
+    [sym_inl] = STATE(69),
+    [sym_inr] = STATE(69),
+    [sym_variant] = STATE(69),
+    [sym_case] = STATE(69),
+    [sym_cons] = STATE(69),
+    [sym_isnil] = STATE(69),
+    [sym_head] = STATE(69),
+    [sym_tail] = STATE(69),
+    [sym_list] = STATE(69),
+    [sym_ref] = STATE(69),
+    [sym_deref] = STATE(69),
+    [sym_assign] = STATE(69),
+    [sym_raise] = STATE(69),
+    [sym_try] = STATE(69),
+    [sym_type_abs] = STATE(69),
+    [sym_type_app] = STATE(69),
+    [sym_pack] = STATE(69),
+    [sym_unpack] = STATE(69),
+    [sym_def] = STATE(69),
+    [sym_call] = STATE(69),
+    [sym_ifElse] = STATE(69),
+    [sym_let] = STATE(69),
+    [sym_letrec] = STATE(69),
+    [sym_type_alias] = STATE(69),
+    [sym_import] = STATE(69),
+    [sym_fix] = STATE(69),
+    [sym_ascribe] = STATE(69),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(613),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(615),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(613),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(613),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [161] = {
+    [sym__expr] = STATE(25),
+    [sym_bool] = STATE(25),
+    [sym_succ] = STATE(25),
+    [sym_pred] = STATE(25),
+    [sym_iszero] = STATE(25),
+    [sym_string] = STATE(25),
+    [sym_concat] = STATE(25),
+    [sym_length] = STATE(25),
+    [sym_eq] = STATE(25),
+    [sym_not] = STATE(25),
+    [sym_and] = STATE(25),
+    [sym_or] = STATE(25),
+    [sym_equal] = STATE(25),
+    [sym_tuple] = STATE(25),
+    [sym_record] = STATE(25),
+    [sym_projection] = STATE(25),
+    [sym_inl] = STATE(25),
+    [sym_inr] = STATE(25),
+    [sym_variant] = STATE(25),
+    [sym_case] = STATE(25),
+    [sym_cons] = STATE(25),
+    [sym_isnil] = STATE(25),
+    [sym_head] = STATE(25),
+    [sym_tail] = STATE(25),
+    [sym_list] = STATE(25),
+    [sym_ref] = STATE(25),
+    [sym_deref] = STATE(25),
+    [sym_assign] = STATE(25),
+    [sym_raise] = STATE(25),
+    [sym_try] = STATE(25),
+    [sym_type_abs] = STATE(25),
+    [sym_type_app] = STATE(25),
+    [sym_pack] = STATE(25),
+    [sym_unpack] = STATE(25),
+    [sym_def] = STATE(25),
+    [sym_call] = STATE(25),
+    [sym_ifElse] = STATE(25),
+    [sym_let] = STATE(25),
+    [sym_letrec] = STATE(25),
+    [sym_type_alias] = STATE(25),
+    [sym_import] = STATE(25),
+    [sym_fix] = STATE(25),
+    [sym_ascribe] = STATE(25),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(617),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(619),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(617),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(617),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [162] = {
+    [sym__expr] = STATE(71),
+    [sym_bool] = STATE(71),
+    [sym_succ] = STATE(71),
+    [sym_pred] = STATE(71),
+    [sym_iszero] = STATE(71),
+    [sym_string] = STATE(71),
+    [sym_concat] = STATE(71),
+    [sym_length] = STATE(71),
+    [sym_eq] = STATE(71),
+    [sym_not] = STATE(71),
+    [sym_and] = STATE(71),
+    [sym_or] = STATE(71),
+    [sym_equal] = STATE(71),
+    [sym_tuple] = STATE(71),
+    [sym_record] = STATE(71),
+    [sym_projection] = STATE(71),
+    [sym_inl] = STATE(71),
+    [sym_inr] = STATE(71),
+    [sym_variant] = STATE(71),
+    [sym_case] = STATE(71),
+    [sym_cons] = STATE(71),
+    [sym_isnil] = STATE(71),
+    [sym_head] = STATE(71),
+    [sym_tail] = STATE(71),
+    [sym_list] = STATE(71),
+    [sym_ref] = STATE(71),
+    [sym_deref] = STATE(71),
+    [sym_assign] = STATE(71),
+    [sym_raise] = STATE(71),
+    [sym_try] = STATE(71),
+    [sym_type_abs] = STATE(71),
+    [sym_type_app] = STATE(71),
+    [sym_pack] = STATE(71),
+    [sym_unpack] = STATE(71),
+    [sym_def] = STATE(71),
+    [sym_call] = STATE(71),
+    [sym_ifElse] = STATE(71),
+    [sym_let] = STATE(71),
+    [sym_letrec] = STATE(71),
+    [sym_type_alias] = STATE(71),
+    [sym_import] = STATE(71),
+    [sym_fix] = STATE(71),
+    [sym_ascribe] = STATE(71),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(621),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(623),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(621),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(621),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [163] = {
+    [sym__expr] = STATE(72),
+    [sym_bool] = STATE(72),
+    [sym_succ] = STATE(72),
+    [sym_pred] = STATE(72),
+    [sym_iszero] = STATE(72),
+    [sym_string] = STATE(72),
+    [sym_concat] = STATE(72),
+    [sym_length] = STATE(72),
+    [sym_eq] = STATE(72),
+    [sym_not] = STATE(72),
+    [sym_and] = STATE(72),
+    [sym_or] = STATE(72),
+    [sym_equal] = STATE(72),
+    [sym_tuple] = STATE(72),
+    [sym_record] = STATE(72),
+    [sym_projection] = STATE(72),
+    [sym_inl] = STATE(72),
+    [sym_inr] = STATE(72),
+    [sym_variant] = STATE(72),
+    [sym_case] = STATE(72),
+    [sym_cons] = STATE(72),
+    [sym_isnil] = STATE(72),
+    [sym_head] = STATE(72),
+    [sym_tail] = STATE(72),
+    [sym_list] = STATE(72),
+    [sym_ref] = STATE(72),
+    [sym_deref] = STATE(72),
+    [sym_assign] = STATE(72),
+    [sym_raise] = STATE(72),
+    [sym_try] = STATE(72),
+    [sym_type_abs] = STATE(72),
+    [sym_type_app] = STATE(72),
+    [sym_pack] = STATE(72),
+    [sym_unpack] = STATE(72),
+    [sym_def] = STATE(72),
+    [sym_call] = STATE(72),
+    [sym_ifElse] = STATE(72),
+    [sym_let] = STATE(72),
+    [sym_letrec] = STATE(72),
+    [sym_type_alias] = STATE(72),
+    [sym_import] = STATE(72),
+    [sym_fix] = STATE(72),
+    [sym_ascribe] = STATE(72),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(477),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(479),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(477),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(477),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [164] = {
+    [sym__expr] = STATE(73),
+    [sym_bool] = STATE(73),
+    [sym_succ] = STATE(73),
+    [sym_pred] = STATE(73),
+    [sym_iszero] = STATE(73),
+    [sym_string] = STATE(73),
+    [sym_concat] = STATE(73),
+    [sym_length] = STATE(73),
+    [sym_eq] = STATE(73),
+    [sym_not] = STATE(73),
+    [sym_and] = STATE(73),
+    [sym_or] = STATE(73),
+    [sym_equal] = STATE(73),
+    [sym_tuple] = STATE(73),
+    [sym_record] = STATE(73),
+    [sym_projection] = STATE(73),
+    [sym_inl] = STATE(73),
+    [sym_inr] = STATE(73),
+    [sym_variant] = STATE(73),
+    [sym_case] = STATE(73),
+    [sym_cons] = STATE(73),
+    [sym_isnil] = STATE(73),
+    [sym_head] = STATE(73),
+    [sym_tail] = STATE(73),
+    [sym_list] = STATE(73),
+    [sym_ref] = STATE(73),
+    [sym_deref] = STATE(73),
+    [sym_assign] = STATE(73),
+    [sym_raise] = STATE(73),
+    [sym_try] = STATE(73),
+    [sym_type_abs] = STATE(73),
+    [sym_type_app] = STATE(73),
+    [sym_pack] = STATE(73),
+    [sym_unpack] = STATE(73),
+    [sym_def] = STATE(73),
+    [sym_call] = STATE(73),
+    [sym_ifElse] = STATE(73),
+    [sym_let] = STATE(73),
+    [sym_letrec] = STATE(73),
+    [sym_type_alias] = STATE(73),
+    [sym_import] = STATE(73),
+    [sym_fix] = STATE(73),
+    [sym_ascribe] = STATE(73),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(625),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(627),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(625),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(625),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [165] = {
+    [sym__expr] = STATE(56),
+    [sym_bool] = STATE(56),
+    [sym_succ] = STATE(56),
+    [sym_pred] = STATE(56),
+    [sym_iszero] = STATE(56),
+    [sym_string] = STATE(56),
+    [sym_concat] = STATE(56),
+    [sym_length] = STATE(56),
+    [sym_eq] = STATE(56),
+    [sym_not] = STATE(56),
+    [sym_and] = STATE(56),
+    [sym_or] = STATE(56),
+    [sym_equal] = STATE(56),
+    [sym_tuple] = STATE(56),
+    [sym_record] = STATE(56),
+    [sym_projection] = STATE(56),
+    [sym_inl] = STATE(56),
+    [sym_inr] = STATE(56),
+    [sym_variant] = STATE(56),
+    [sym_case] = STATE(56),
+    [sym_cons] = STATE(56),
+    [sym_isnil] = STATE(56),
+    [sym_head] = STATE(56),
+    [sym_tail] = STATE(56),
+    [sym_list] = STATE(56),
+    [sym_ref] = STATE(56),
+    [sym_deref] = STATE(56),
+    [sym_assign] = STATE(56),
+    [sym_raise] = STATE(56),
+    [sym_try] = STATE(56),
+    [sym_type_abs] = STATE(56),
+    [sym_type_app] = STATE(56),
+    [sym_pack] = STATE(56),
+    [sym_unpack] = STATE(56),
+    [sym_def] = STATE(56),
+    [sym_call] = STATE(56),
+    [sym_ifElse] = STATE(56),
+    [sym_let] = STATE(56),
+    [sym_letrec] = STATE(56),
+    [sym_type_alias] = STATE(56),
+    [sym_import] = STATE(56),
+    [sym_fix] = STATE(56),
+    [sym_ascribe] = STATE(56),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(629),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(631),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(629),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(629),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [166] = {
+    [sym__expr] = STATE(58),
+    [sym_bool] = STATE(58),
+    [sym_succ] = STATE(58),
+    [sym_pred] = STATE(58),
+    [sym_iszero] = STATE(58),
+    [sym_string] = STATE(58),
+    [sym_concat] = STATE(58),
+    [sym_length] = STATE(58),
+    [sym_eq] = STATE(58),
+    [sym_not] = STATE(58),
+    [sym_and] = STATE(58),
+    [sym_or] = STATE(58),
+    [sym_equal] = STATE(58),
+    [sym_tuple] = STATE(58),
+    [sym_record] = STATE(58),
+    [sym_projection] = STATE(58),
+    [sym_inl] = STATE(58),
+    [sym_inr] = STATE(58),
+    [sym_variant] = STATE(58),
+    [sym_case] = STATE(58),
+    [sym_cons] = STATE(58),
+    [sym_isnil] = STATE(58),
+    [sym_head] = STATE(58),
+    [sym_tail] = STATE(58),
+    [sym_list] = STATE(58),
+    [sym_ref] = STATE(58),
+    [sym_deref] = STATE(58),
+    [sym_assign] = STATE(58),
+    [sym_raise] = STATE(58),
+    [sym_try] = STATE(58),
+    [sym_type_abs] = STATE(58),
+    [sym_type_app] = STATE(58),
+    [sym_pack] = STATE(58),
+    [sym_unpack] = STATE(58),
+    [sym_def] = STATE(58),
+    [sym_call] = STATE(58),
+    [sym_ifElse] = STATE(58),
+    [sym_let] = STATE(58),
+    [sym_letrec] = STATE(58),
+    [sym_type_alias] = STATE(58),
+    [sym_import] = STATE(58),
+    [sym_fix] = STATE(58),
+    [sym_ascribe] = STATE(58),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(633),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(635),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(633),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(633),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [167] = {
+    [sym__expr] = STATE(84),
+    [sym_bool] = STATE(84),
+    [sym_succ] = STATE(84),
+    [sym_pred] = STATE(84),
+    [sym_iszero] = STATE(84),
+    [sym_string] = STATE(84),
+    [sym_concat] = STATE(84),
+    [sym_length] = STATE(84),
+    [sym_eq] = STATE(84),
+    [sym_not] = STATE(84),
+    [sym_and] = STATE(84),
+    [sym_or] = STATE(84),
+    [sym_equal] = STATE(84),
+    [sym_tuple] = STATE(84),
+    [sym_record] = STATE(84),
+    [sym_projection] = STATE(84),
+    [sym_inl] = STATE(84),
+    [sym_inr] = STATE(84),
+    [sym_variant] = STATE(84),
+    [sym_case] = STATE(84),
+    [sym_cons] = STATE(84),
+    [sym_isnil] = STATE(84),
+    [sym_head] = STATE(84),
+    [sym_tail] = STATE(84),
+    [sym_list] = STATE(84),
+    [sym_ref] = STATE(84),
+    [sym_deref] = STATE(84),
+    [sym_assign] = STATE(84),
+    [sym_raise] = STATE(84),
+    [sym_try] = STATE(84),
+    [sym_type_abs] = STATE(84),
+    [sym_type_app] = STATE(84),
+    [sym_pack] = STATE(84),
+    [sym_unpack] = STATE(84),
+    [sym_def] = STATE(84),
+    [sym_call] = STATE(84),
+    [sym_ifElse] = STATE(84),
+    [sym_let] = STATE(84),
+    [sym_letrec] = STATE(84),
+    [sym_type_alias] = STATE(84),
+    [sym_import] = STATE(84),
+    [sym_fix] = STATE(84),
+    [sym_ascribe] = STATE(84),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(637),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(639),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(637),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(637),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [168] = {
+    [sym__expr] = STATE(22),
+    [sym_bool] = STATE(22),
+    [sym_succ] = STATE(22),
+    [sym_pred] = STATE(22),
+    [sym_iszero] = STATE(22),
+    [sym_string] = STATE(22),
+    [sym_concat] = STATE(22),
+    [sym_length] = STATE(22),
+    [sym_eq] = STATE(22),
+    [sym_not] = STATE(22),
+    [sym_and] = STATE(22),
+    [sym_or] = STATE(22),
+    [sym_equal] = STATE(22),
+    [sym_tuple] = STATE(22),
+    [sym_record] = STATE(22),
+    [sym_projection] = STATE(22),
+    [sym_inl] = STATE(22),
+    [sym_inr] = STATE(22),
+    [sym_variant] = STATE(22),
+    [sym_case] = STATE(22),
+    [sym_cons] = STATE(22),
+    [sym_isnil] = STATE(22),
+    [sym_head] = STATE(22),
+    [sym_tail] = STATE(22),
+    [sym_list] = STATE(22),
+    [sym_ref] = STATE(22),
+    [sym_deref] = STATE(22),
+    [sym_assign] = STATE(22),
+    [sym_raise] = STATE(22),
+    [sym_try] = STATE(22),
+    [sym_type_abs] = STATE(22),
+    [sym_type_app] = STATE(22),
+    [sym_pack] = STATE(22),
+    [sym_unpack] = STATE(22),
+    [sym_def] = STATE(22),
+    [sym_call] = STATE(22),
+    [sym_ifElse] = STATE(22),
+    [sym_let] = STATE(22),
+    [sym_letrec] = STATE(22),
+    [sym_type_alias] = STATE(22),
+    [sym_import] = STATE(22),
+    [sym_fix] = STATE(22),
+    [sym_ascribe] = STATE(22),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(641),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(643),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(641),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(641),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [169] = {
+    [sym__expr] = STATE(26),
+    [sym_bool] = STATE(26),
+    [sym_succ] = STATE(26),
+    [sym_pred] = STATE(26),
+    [sym_iszero] = STATE(26),
+    [sym_string] = STATE(26),
+    [sym_concat] = STATE(26),
+    [sym_length] = STATE(26),
+    [sym_eq] = STATE(26),
+    [sym_not] = STATE(26),
+    [sym_and] = STATE(26),
+    [sym_or] = STATE(26),
+    [sym_equal] = STATE(26),
+    [sym_tuple] = STATE(26),
+    [sym_record] = STATE(26),
+    [sym_projection] = STATE(26),
+    [sym_inl] = STATE(26),
+    [sym_inr] = STATE(26),
+    [sym_variant] = STATE(26),
+    [sym_case] = STATE(26),
+    [sym_cons] = STATE(26),
+    [sym_isnil] = STATE(26),
+    [sym_head] = STATE(26),
+    [sym_tail] = STATE(26),
+    [sym_list] = STATE(26),
+    [sym_ref] = STATE(26),
+    [sym_deref] = STATE(26),
+    [sym_assign] = STATE(26),
+    [sym_raise] = STATE(26),
+    [sym_try] = STATE(26),
+    [sym_type_abs] = STATE(26),
+    [sym_type_app] = STATE(26),
+    [sym_pack] = STATE(26),
+    [sym_unpack] = STATE(26),
+    [sym_def] = STATE(26),
+    [sym_call] = STATE(26),
+    [sym_ifElse] = STATE(26),
+    [sym_let] = STATE(26),
+    [sym_letrec] = STATE(26),
+    [sym_type_alias] = STATE(26),
+    [sym_import] = STATE(26),
+    [sym_fix] = STATE(26),
+    [sym_ascribe] = STATE(26),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(645),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(647),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(645),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(645),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [170] = {
+    [sym__expr] = STATE(24),
+    [sym_bool] = STATE(24),
+    [sym_succ] = STATE(24),
+    [sym_pred] = STATE(24),
+    [sym_iszero] = STATE(24),
+    [sym_string] = STATE(24),
+    [sym_concat] = STATE(24),
+    [sym_length] = STATE(24),
+    [sym_eq] = STATE(24),
+    [sym_not] = STATE(24),
+    [sym_and] = STATE(24),
+    [sym_or] = STATE(24),
+    [sym_equal] = STATE(24),
+    [sym_tuple] = STATE(24),
+    [sym_record] = STATE(24),
+    [sym_projection] = STATE(24),
+    [sym_inl] = STATE(24),
+    [sym_inr] = STATE(24),
+    [sym_variant] = STATE(24),
+    [sym_case] = STATE(24),
+    [sym_cons] = STATE(24),
+    [sym_isnil] = STATE(24),
+    [sym_head] = STATE(24),
+    [sym_tail] = STATE(24),
+    [sym_list] = STATE(24),
+    [sym_ref] = STATE(24),
+    [sym_deref] = STATE(24),
+    [sym_assign] = STATE(24),
+    [sym_raise] = STATE(24),
+    [sym_try] = STATE(24),
+    [sym_type_abs] = STATE(24),
+    [sym_type_app] = STATE(24),
+    [sym_pack] = STATE(24),
+    [sym_unpack] = STATE(24),
+    [sym_def] = STATE(24),
+    [sym_call] = STATE(24),
+    [sym_ifElse] = STATE(24),
+    [sym_let] = STATE(24),
+    [sym_letrec] = STATE(24),
+    [sym_type_alias] = STATE(24),
+    [sym_import] = STATE(24),
+    [sym_fix] = STATE(24),
+    [sym_ascribe] = STATE(24),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(649),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(651),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(649),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(649),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [171] = {
+    [sym__expr] = STATE(59),
+    [sym_bool] = STATE(59),
+    [sym_succ] = STATE(59),
+    [sym_pred] = STATE(59),
+    [sym_iszero] = STATE(59),
+    [sym_string] = STATE(59),
+    [sym_concat] = STATE(59),
+    [sym_length] = STATE(59),
+    [sym_eq] = STATE(59),
+    [sym_not] = STATE(59),
+    [sym_and] = STATE(59),
+    [sym_or] = STATE(59),
+    [sym_equal] = STATE(59),
+    [sym_tuple] = STATE(59),
+    [sym_record] = STATE(59),
+    [sym_projection] = STATE(59),
+    [sym_inl] = STATE(59),
+    [sym_inr] = STATE(59),
+    [sym_variant] = STATE(59),
+    [sym_case] = STATE(59),
+    [sym_cons] = STATE(59),
+    [sym_isnil] = STATE(59),
+    [sym_head] = STATE(59),
+    [sym_tail] = STATE(59),
+    [sym_list] = STATE(59),
+    [sym_ref] = STATE(59),
+    [sym_deref] = STATE(59),
+    [sym_assign] = STATE(59),
+    [sym_raise] = STATE(59),
+    [sym_try] = STATE(59),
+    [sym_type_abs] = STATE(59),
+    [sym_type_app] = STATE(59),
+    [sym_pack] = STATE(59),
+    [sym_unpack] = STATE(59),
+    [sym_def] = STATE(59),
+    [sym_call] = STATE(59),
+    [sym_ifElse] = STATE(59),
+    [sym_let] = STATE(59),
+    [sym_letrec] = STATE(59),
+    [sym_type_alias] = STATE(59),
+    [sym_import] = STATE(59),
+    [sym_fix] = STATE(59),
+    [sym_ascribe] = STATE(59),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(653),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(655),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(653),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(653),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [172] = {
+    [sym__expr] = STATE(27),
+    [sym_bool] = STATE(27),
+    [sym_succ] = STATE(27),
+    [sym_pred] = STATE(27),
+    [sym_iszero] = STATE(27),
+    [sym_string] = STATE(27),
+    [sym_concat] = STATE(27),
+    [sym_length] = STATE(27),
+    [sym_eq] = STATE(27),
+    [sym_not] = STATE(27),
+    [sym_and] = STATE(27),
+    [sym_or] = STATE(27),
+    [sym_equal] = STATE(27),
+    [sym_tuple] = STATE(27),
+    [sym_record] = STATE(27),
+    [sym_projection] = STATE(27),
+    [sym_inl] = STATE(27),
+    [sym_inr] = STATE(27),
+    [sym_variant] = STATE(27),
+    [sym_case] = STATE(27),
+    [sym_cons] = STATE(27),
+    [sym_isnil] = STATE(27),
+    [sym_head] = STATE(27),
+    [sym_tail] = STATE(27),
+    [sym_list] = STATE(27),
+    [sym_ref] = STATE(27),
+    [sym_deref] = STATE(27),
+    [sym_assign] = STATE(27),
+    [sym_raise] = STATE(27),
+    [sym_try] = STATE(27),
+    [sym_type_abs] = STATE(27),
+    [sym_type_app] = STATE(27),
+    [sym_pack] = STATE(27),
+    [sym_unpack] = STATE(27),
+    [sym_def] = STATE(27),
+    [sym_call] = STATE(27),
+    [sym_ifElse] = STATE(27),
+    [sym_let] = STATE(27),
+    [sym_letrec] = STATE(27),
+    [sym_type_alias] = STATE(27),
+    [sym_import] = STATE(27),
+    [sym_fix] = STATE(27),
+    [sym_ascribe] = STATE(27),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(657),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(659),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(657),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(657),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [173] = {
+    [sym__expr] = STATE(60),
+    [sym_bool] = STATE(60),
+    [sym_succ] = STATE(60),
+    [sym_pred] = STATE(60),
+    [sym_iszero] = STATE(60),
+    [sym_string] = STATE(60),
+    [sym_concat] = STATE(60),
+    [sym_length] = STATE(60),
+    [sym_eq] = STATE(60),
+    [sym_not] = STATE(60),
+    [sym_and] = STATE(60),
+    [sym_or] = STATE(60),
+    [sym_equal] = STATE(60),
+    [sym_tuple] = STATE(60),
+    [sym_record] = STATE(60),
+    [sym_projection] = STATE(60),
+    [sym_inl] = STATE(60),
+    [sym_inr] = STATE(60),
+    [sym_variant] = STATE(60),
+    [sym_case] = STATE(60),
+    [sym_cons] = STATE(60),
+    [sym_isnil] = STATE(60),
+    [sym_head] = STATE(60),
+    [sym_tail] = STATE(60),
+    [sym_list] = STATE(60),
+    [sym_ref] = STATE(60),
+    [sym_deref] = STATE(60),
+    [sym_assign] = STATE(60),
+    [sym_raise] = STATE(60),
+    [sym_try] = STATE(60),
+    [sym_type_abs] = STATE(60),
+    [sym_type_app] = STATE(60),
+    [sym_pack] = STATE(60),
+    [sym_unpack] = STATE(60),
+    [sym_def] = STATE(60),
+    [sym_call] = STATE(60),
+    [sym_ifElse] = STATE(60),
+    [sym_let] = STATE(60),
+    [sym_letrec] = STATE(60),
+    [sym_type_alias] = STATE(60),
+    [sym_import] = STATE(60),
+    [sym_fix] = STATE(60),
+    [sym_ascribe] = STATE(60),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(661),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(663),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(661),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(661),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [174] = {
+    [sym__expr] = STATE(61),
+    [sym_bool] = STATE(61),
+    [sym_succ] = STATE(61),
+    [sym_pred] = STATE(61),
+    [sym_iszero] = STATE(61),
+    [sym_string] = STATE(61),
+    [sym_concat] = STATE(61),
+    [sym_length] = STATE(61),
+    [sym_eq] = STATE(61),
+    [sym_not] = STATE(61),
+    [sym_and] = STATE(61),
+    [sym_or] = STATE(61),
+    [sym_equal] = STATE(61),
+    [sym_tuple] = STATE(61),
+    [sym_record] = STATE(61),
+    [sym_projection] = STATE(61),
+    [sym_inl] = STATE(61),
+    [sym_inr] = STATE(61),
+    [sym_variant] = STATE(61),
+    [sym_case] = STATE(61),
+    [sym_cons] = STATE(61),
+    [sym_isnil] = STATE(61),
+    [sym_head] = STATE(61),
+    [sym_tail] = STATE(61),
+    [sym_list] = STATE(61),
+    [sym_ref] = STATE(61),
+    [sym_deref] = STATE(61),
+    [sym_assign] = STATE(61),
+    [sym_raise] = STATE(61),
+    [sym_try] = STATE(61),
+    [sym_type_abs] = STATE(61),
+    [sym_type_app] = STATE(61),
+    [sym_pack] = STATE(61),
+    [sym_unpack] = STATE(61),
+    [sym_def] = STATE(61),
+    [sym_call] = STATE(61),
+    [sym_ifElse] = STATE(61),
+    [sym_let] = STATE(61),
+    [sym_letrec] = STATE(61),
+    [sym_type_alias] = STATE(61),
+    [sym_import] = STATE(61),
+    [sym_fix] = STATE(61),
+    [sym_ascribe] = STATE(61),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(665),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(667),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(665),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(665),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [175] = {
+    [sym__expr] = STATE(57),
+    [sym_bool] = STATE(57),
+    [sym_succ] = STATE(57),
+    [sym_pred] = STATE(57),
+    [sym_iszero] = STATE(57),
+    [sym_string] = STATE(57),
+    [sym_concat] = STATE(57),
+    [sym_length] = STATE(57),
+    [sym_eq] = STATE(57),
+    [sym_not] = STATE(57),
+    [sym_and] = STATE(57),
+    [sym_or] = STATE(57),
+    [sym_equal] = STATE(57),
+    [sym_tuple] = STATE(57),
+    [sym_record] = STATE(57),
+    [sym_projection] = STATE(57),
+    [sym_inl] = STATE(57),
+    [sym_inr] = STATE(57),
+    [sym_variant] = STATE(57),
+    [sym_case] = STATE(57),
+    [sym_cons] = STATE(57),
+    [sym_isnil] = STATE(57),
+    [sym_head] = STATE(57),
+    [sym_tail] = STATE(57),
+    [sym_list] = STATE(57),
+    [sym_ref] = STATE(57),
+    [sym_deref] = STATE(57),
+    [sym_assign] = STATE(57),
+    [sym_raise] = STATE(57),
+    [sym_try] = STATE(57),
+    [sym_type_abs] = STATE(57),
+    [sym_type_app] = STATE(57),
+    [sym_pack] = STATE(57),
+    [sym_unpack] = STATE(57),
+    [sym_def] = STATE(57),
+    [sym_call] = STATE(57),
+    [sym_ifElse] = STATE(57),
+    [sym_let] = STATE(57),
+    [sym_letrec] = STATE(57),
+    [sym_type_alias] = STATE(57),
+    [sym_import] = STATE(57),
+    [sym_fix] = STATE(57),
+    [sym_ascribe] = STATE(57),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(485),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(487),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(485),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(485),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [176] = {
+    [sym__expr] = STATE(17),
+    [sym_bool] = STATE(17),
+    [sym_succ] = STATE(17),
+    [sym_pred] = STATE(17),
+    [sym_iszero] = STATE(17),
+    [sym_string] = STATE(17),
+    [sym_concat] = STATE(17),
+    [sym_length] = STATE(17),
+    [sym_eq] = STATE(17),
+    [sym_not] = STATE(17),
+    [sym_and] = STATE(17),
+    [sym_or] = STATE(17),
+    [sym_equal] = STATE(17),
+    [sym_tuple] = STATE(17),
+    [sym_record] = STATE(17),
+    [sym_projection] = STATE(17),
+    [sym_inl] = STATE(17),
+    [sym_inr] = STATE(17),
+    [sym_variant] = STATE(17),
+    [sym_case] = STATE(17),
+    [sym_cons] = STATE(17),
+    [sym_isnil] = STATE(17),
+    [sym_head] = STATE(17),
+    [sym_tail] = STATE(17),
+    [sym_list] = STATE(17),
+    [sym_ref] = STATE(17),
+    [sym_deref] = STATE(17),
+    [sym_assign] = STATE(17),
+    [sym_raise] = STATE(17),
+    [sym_try] = STATE(17),
+    [sym_type_abs] = STATE(17),
+    [sym_type_app] = STATE(17),
+    [sym_pack] = STATE(17),
+    [sym_unpack] = STATE(17),
+    [sym_def] = STATE(17),
+    [sym_call] = STATE(17),
+    [sym_ifElse] = STATE(17),
+    [sym_let] = STATE(17),
+    [sym_letrec] = STATE(17),
+    [sym_type_alias] = STATE(17),
+    [sym_import] = STATE(17),
+    [sym_fix] = STATE(17),
+    [sym_ascribe] = STATE(17),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(569),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(571),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(569),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(569),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [177] = {
+    [sym__expr] = STATE(20),
+    [sym_bool] = STATE(20),
+    [sym_succ] = STATE(20),
+    [sym_pred] = STATE(20),
+    [sym_iszero] = STATE(20),
+    [sym_string] = STATE(20),
+    [sym_concat] = STATE(20),
+    [sym_length] = STATE(20),
+    [sym_eq] = STATE(20),
+    [sym_not] = STATE(20),
+    [sym_and] = STATE(20),
+    [sym_or] = STATE(20),
+    [sym_equal] = STATE(20),
+    [sym_tuple] = STATE(20),
+    [sym_record] = STATE(20),
+    [sym_projection] = STATE(20),
+    [sym_inl] = STATE(20),
+    [sym_inr] = STATE(20),
+    [sym_variant] = STATE(20),
+    [sym_case] = STATE(20),
+    [sym_cons] = STATE(20),
+    [sym_isnil] = STATE(20),
+    [sym_head] = STATE(20),
+    [sym_tail] = STATE(20),
+    [sym_list] = STATE(20),
+    [sym_ref] = STATE(20),
+    [sym_deref] = STATE(20),
+    [sym_assign] = STATE(20),
+    [sym_raise] = STATE(20),
+    [sym_try] = STATE(20),
+    [sym_type_abs] = STATE(20),
+    [sym_type_app] = STATE(20),
+    [sym_pack] = STATE(20),
+    [sym_unpack] = STATE(20),
+    [sym_def] = STATE(20),
+    [sym_call] = STATE(20),
+    [sym_ifElse] = STATE(20),
+    [sym_let] = STATE(20),
+    [sym_letrec] = STATE(20),
+    [sym_type_alias] = STATE(20),
+    [sym_import] = STATE(20),
+    [sym_fix] = STATE(20),
+    [sym_ascribe] = STATE(20),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(669),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(671),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(669),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(669),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [178] = {
+    [sym__expr] = STATE(3),
+    [sym_bool] = STATE(3),
+    [sym_succ] = STATE(3),
+    [sym_pred] = STATE(3),
+    [sym_iszero] = STATE(3),
+    [sym_string] = STATE(3),
+    [sym_concat] = STATE(3),
+    [sym_length] = STATE(3),
+    [sym_eq] = STATE(3),
+    [sym_not] = STATE(3),
+    [sym_and] = STATE(3),
+    [sym_or] = STATE(3),
+    [sym_equal] = STATE(3),
+    [sym_tuple] = STATE(3),
+    [sym_record] = STATE(3),
+    [sym_projection] = STATE(3),
+    [sym_inl] = STATE(3),
+    [sym_inr] = STATE(3),
+    [sym_variant] = STATE(3),
+    [sym_case] = STATE(3),
+    [sym_cons] = STATE(3),
+    [sym_isnil] = STATE(3),
+    [sym_head] = STATE(3),
+    [sym_tail] = STATE(3),
+    [sym_list] = STATE(3),
+    [sym_ref] = STATE(3),
+    [sym_deref] = STATE(3),
+    [sym_assign] = STATE(3),
+    [sym_raise] = STATE(3),
+    [sym_try] = STATE(3),
+    [sym_type_abs] = STATE(3),
+    [sym_type_app] = STATE(3),
+    [sym_pack] = STATE(3),
+    [sym_unpack] = STATE(3),
+    [sym_def] = STATE(3),
+    [sym_call] = STATE(3),
+    [sym_ifElse] = STATE(3),
+    [sym_let] = STATE(3),
+    [sym_letrec] = STATE(3),
+    [sym_type_alias] = STATE(3),
+    [sym_import] = STATE(3),
+    [sym_fix] = STATE(3),
+    [sym_ascribe] = STATE(3),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(673),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(675),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(673),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(673),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [179] = {
+    [sym__expr] = STATE(94),
+    [sym_bool] = STATE(94),
+    [sym_succ] = STATE(94),
+    [sym_pred] = STATE(94),
+    [sym_iszero] = STATE(94),
+    [sym_string] = STATE(94),
+    [sym_concat] = STATE(94),
+    [sym_length] = STATE(94),
+    [sym_eq] = STATE(94),
+    [sym_not] = STATE(94),
+    [sym_and] = STATE(94),
+    [sym_or] = STATE(94),
+    [sym_equal] = STATE(94),
+    [sym_tuple] = STATE(94),
+    [sym_record] = STATE(94),
+    [sym_projection] = STATE(94),
+    [sym_inl] = STATE(94),
+    [sym_inr] = STATE(94),
+    [sym_variant] = STATE(94),
+    [sym_case] = STATE(94),
+    [sym_cons] = STATE(94),
+    [sym_isnil] = STATE(94),
+    [sym_head] = STATE(94),
+    [sym_tail] = STATE(94),
+    [sym_list] = STATE(94),
+    [sym_ref] = STATE(94),
+    [sym_deref] = STATE(94),
+    [sym_assign] = STATE(94),
+    [sym_raise] = STATE(94),
+    [sym_try] = STATE(94),
+    [sym_type_abs] = STATE(94),
+    [sym_type_app] = STATE(94),
+    [sym_pack] = STATE(94),
+    [sym_unpack] = STATE(94),
+    [sym_def] = STATE(94),
+    [sym_call] = STATE(94),
+    [sym_ifElse] = STATE(94),
+    [sym_let] = STATE(94),
+    [sym_letrec] = STATE(94),
+    [sym_type_alias] = STATE(94),
+    [sym_import] = STATE(94),
+    [sym_fix] = STATE(94),
+    [sym_ascribe] = STATE(94),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(677),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(679),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(677),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(677),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [180] = {
+    [sym__expr] = STATE(4),
+    [sym_bool] = STATE(4),
+    [sym_succ] = STATE(4),
+    [sym_pred] = STATE(4),
+    [sym_iszero] = STATE(4),
+    [sym_string] = STATE(4),
+    [sym_concat] = STATE(4),
+    [sym_length] = STATE(4),
+    [sym_eq] = STATE(4),
+    [sym_not] = STATE(4),
+    [sym_and] = STATE(4),
+    [sym_or] = STATE(4),
+    [sym_equal] = STATE(4),
+    [sym_tuple] = STATE(4),
+    [sym_record] = STATE(4),
+    [sym_projection] = STATE(4),
+    [sym_inl] = STATE(4),
+    [sym_inr] = STATE(4),
+    [sym_variant] = STATE(4),
+    [sym_case] = STATE(4),
+    [sym_cons] = STATE(4),
+    [sym_isnil] = STATE(4),
+    [sym_head] = STATE(4),
+    [sym_tail] = STATE(4),
+    [sym_list] = STATE(4),
+    [sym_ref] = STATE(4),
+    [sym_deref] = STATE(4),
+    [sym_assign] = STATE(4),
+    [sym_raise] = STATE(4),
+    [sym_try] = STATE(4),
+    [sym_type_abs] = STATE(4),
+    [sym_type_app] = STATE(4),
+    [sym_pack] = STATE(4),
+    [sym_unpack] = STATE(4),
+    [sym_def] = STATE(4),
+    [sym_call] = STATE(4),
+    [sym_ifElse] = STATE(4),
+    [sym_let] = STATE(4),
+    [sym_letrec] = STATE(4),
+    [sym_type_alias] = STATE(4),
+    [sym_import] = STATE(4),
+    [sym_fix] = STATE(4),
+    [sym_ascribe] = STATE(4),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(509),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(511),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(509),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(509),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [181] = {
+    [sym__expr] = STATE(96),
+    [sym_bool] = STATE(96),
+    [sym_succ] = STATE(96),
+    [sym_pred] = STATE(96),
+    [sym_iszero] = STATE(96),
+    [sym_string] = STATE(96),
+    [sym_concat] = STATE(96),
+    [sym_length] = STATE(96),
+    [sym_eq] = STATE(96),
+    [sym_not] = STATE(96),
+    [sym_and] = STATE(96),
+    [sym_or] = STATE(96),
+    [sym_equal] = STATE(96),
+    [sym_tuple] = STATE(96),
+    [sym_record] = STATE(96),
+    [sym_projection] = STATE(96),
+    [sym_inl] = STATE(96),
+    [sym_inr] = STATE(96),
+    [sym_variant] = STATE(96),
+    [sym_case] = STATE(96),
+    [sym_cons] = STATE(96),
+    [sym_isnil] = STATE(96),
+    [sym_head] = STATE(96),
+    [sym_tail] = STATE(96),
+    [sym_list] = STATE(96),
+    [sym_ref] = STATE(96),
+    [sym_deref] = STATE(96),
+    [sym_assign] = STATE(96),
+    [sym_raise] = STATE(96),
+    [sym_try] = STATE(96),
+    [sym_type_abs] = STATE(96),
+    [sym_type_app] = STATE(96),
+    [sym_pack] = STATE(96),
+    [sym_unpack] = STATE(96),
+    [sym_def] = STATE(96),
+    [sym_call] = STATE(96),
+    [sym_ifElse] = STATE(96),
+    [sym_let] = STATE(96),
+    [sym_letrec] = STATE(96),
+    [sym_type_alias] = STATE(96),
+    [sym_import] = STATE(96),
+    [sym_fix] = STATE(96),
+    [sym_ascribe] = STATE(96),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(681),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(683),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(681),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(681),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [182] = {
+    [sym__expr] = STATE(5),
+    [sym_bool] = STATE(5),
+    [sym_succ] = STATE(5),
+    [sym_pred] = STATE(5),
+    [sym_iszero] = STATE(5),
+    [sym_string] = STATE(5),
+    [sym_concat] = STATE(5),
+    [sym_length] = STATE(5),
+    [sym_eq] = STATE(5),
+    [sym_not] = STATE(5),
+    [sym_and] = STATE(5),
+    [sym_or] = STATE(5),
+    [sym_equal] = STATE(5),
+    [sym_tuple] = STATE(5),
+    [sym_record] = STATE(5),
+    [sym_projection] = STATE(5),
+    [sym_inl] = STATE(5),
+    [sym_inr] = STATE(5),
+    [sym_variant] = STATE(5),
+    [sym_case] = STATE(5),
+    [sym_cons] = STATE(5),
+    [sym_isnil] = STATE(5),
+    [sym_head] = STATE(5),
+    [sym_tail] = STATE(5),
+    [sym_list] = STATE(5),
+    [sym_ref] = STATE(5),
+    [sym_deref] = STATE(5),
+    [sym_assign] = STATE(5),
+    [sym_raise] = STATE(5),
+    [sym_try] = STATE(5),
+    [sym_type_abs] = STATE(5),
+    [sym_type_app] = STATE(5),
+    [sym_pack] = STATE(5),
+    [sym_unpack] = STATE(5),
+    [sym_def] = STATE(5),
+    [sym_call] = STATE(5),
+    [sym_ifElse] = STATE(5),
+    [sym_let] = STATE(5),
+    [sym_letrec] = STATE(5),
+    [sym_type_alias] = STATE(5),
+    [sym_import] = STATE(5),
+    [sym_fix] = STATE(5),
+    [sym_ascribe] = STATE(5),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(517),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(519),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(517),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(517),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [183] = {
+    [sym__expr] = STATE(6),
+    [sym_bool] = STATE(6),
+    [sym_succ] = STATE(6),
+    [sym_pred] = STATE(6),
+    [sym_iszero] = STATE(6),
+    [sym_string] = STATE(6),
+    [sym_concat] = STATE(6),
+    [sym_length] = STATE(6),
+    [sym_eq] = STATE(6),
+    [sym_not] = STATE(6),
+    [sym_and] = STATE(6),
+    [sym_or] = STATE(6),
+    [sym_equal] = STATE(6),
+    [sym_tuple] = STATE(6),
+    [sym_record] = STATE(6),
+    [sym_projection] = STATE(6),
+    [sym_inl] = STATE(6),
+    [sym_inr] = STATE(6),
+    [sym_variant] = STATE(6),
+    [sym_case] = STATE(6),
+    [sym_cons] = STATE(6),
+    [sym_isnil] = STATE(6),
+    [sym_head] = STATE(6),
+    [sym_tail] = STATE(6),
+    [sym_list] = STATE(6),
+    [sym_ref] = STATE(6),
+    [sym_deref] = STATE(6),
+    [sym_assign] = STATE(6),
+    [sym_raise] = STATE(6),
+    [sym_try] = STATE(6),
+    [sym_type_abs] = STATE(6),
+    [sym_type_app] = STATE(6),
+    [sym_pack] = STATE(6),
+    [sym_unpack] = STATE(6),
+    [sym_def] = STATE(6),
+    [sym_call] = STATE(6),
+    [sym_ifElse] = STATE(6),
+    [sym_let] = STATE(6),
+    [sym_letrec] = STATE(6),
+    [sym_type_alias] = STATE(6),
+    [sym_import] = STATE(6),
+    [sym_fix] = STATE(6),
+    [sym_ascribe] = STATE(6),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(533),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(535),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(533),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(533),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [184] = {
+    [sym__expr] = STATE(7),
+    [sym_bool] = STATE(7),
+    [sym_succ] = STATE(7),
+    [sym_pred] = STATE(7),
+    [sym_iszero] = STATE(7),
+    [sym_string] = STATE(7),
+    [sym_concat] = STATE(7),
+    [sym_length] = STATE(7),
+    [sym_eq] = STATE(7),
+    [sym_not] = STATE(7),
+    [sym_and] = STATE(7),
+    [sym_or] = STATE(7),
+    [sym_equal] = STATE(7),
+    [sym_tuple] = STATE(7),
+    [sym_record] = STATE(7),
+    [sym_projection] = STATE(7),
+    [sym_inl] = STATE(7),
+    [sym_inr] = STATE(7),
+    [sym_variant] = STATE(7),
+    [sym_case] = STATE(7),
+    [sym_cons] = STATE(7),
+    [sym_isnil] = STATE(7),
+    [sym_head] = STATE(7),
+    [sym_tail] = STATE(7),
+    [sym_list] = STATE(7),
+    [sym_ref] = STATE(7),
+    [sym_deref] = STATE(7),
+    [sym_assign] = STATE(7),
+    [sym_raise] = STATE(7),
+    [sym_try] = STATE(7),
+    [sym_type_abs] = STATE(7),
+    [sym_type_app] = STATE(7),
+    [sym_pack] = STATE(7),
+    [sym_unpack] = STATE(7),
+    [sym_def] = STATE(7),
+    [sym_call] = STATE(7),
+    [sym_ifElse] = STATE(7),
+    [sym_let] = STATE(7),
+    [sym_letrec] = STATE(7),
+    [sym_type_alias] = STATE(7),
+    [sym_import] = STATE(7),
+    [sym_fix] = STATE(7),
+    [sym_ascribe] = STATE(7),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(537),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(539),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(537),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(537),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [185] = {
+    [sym__expr] = STATE(93),
+    [sym_bool] = STATE(93),
+    [sym_succ] = STATE(93),
+    [sym_pred] = STATE(93),
+    [sym_iszero] = STATE(93),
+    [sym_string] = STATE(93),
+    [sym_concat] = STATE(93),
+    [sym_length] = STATE(93),
+    [sym_eq] = STATE(93),
+    [sym_not] = STATE(93),
+    [sym_and] = STATE(93),
+    [sym_or] = STATE(93),
+    [sym_equal] = STATE(93),
+    [sym_tuple] = STATE(93),
+    [sym_record] = STATE(93),
+    [sym_projection] = STATE(93),
+    [sym_inl] = STATE(93),
+    [sym_inr] = STATE(93),
+    [sym_variant] = STATE(93),
+    [sym_case] = STATE(93),
+    [sym_cons] = STATE(93),
+    [sym_isnil] = STATE(93),
+    [sym_head] = STATE(93),
+    [sym_tail] = STATE(93),
+    [sym_list] = STATE(93),
+    [sym_ref] = STATE(93),
+    [sym_deref] = STATE(93),
+    [sym_assign] = STATE(93),
+    [sym_raise] = STATE(93),
+    [sym_try] = STATE(93),
+    [sym_type_abs] = STATE(93),
+    [sym_type_app] = STATE(93),
+    [sym_pack] = STATE(93),
+    [sym_unpack] = STATE(93),
+    [sym_def] = STATE(93),
+    [sym_call] = STATE(93),
+    [sym_ifElse] = STATE(93),
+    [sym_let] = STATE(93),
+    [sym_letrec] = STATE(93),
+    [sym_type_alias] = STATE(93),
+    [sym_import] = STATE(93),
+    [sym_fix] = STATE(93),
+    [sym_ascribe] = STATE(93),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(685),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(687),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(685),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(685),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [186] = {
+    [sym__expr] = STATE(8),
+    [sym_bool] = STATE(8),
+    [sym_succ] = STATE(8),
+    [sym_pred] = STATE(8),
+    [sym_iszero] = STATE(8),
+    [sym_string] = STATE(8),
+    [sym_concat] = STATE(8),
+    [sym_length] = STATE(8),
+    [sym_eq] = STATE(8),
+    [sym_not] = STATE(8),
+    [sym_and] = STATE(8),
+    [sym_or] = STATE(8),
+    [sym_equal] = STATE(8),
+    [sym_tuple] = STATE(8),
+    [sym_record] = STATE(8),
+    [sym_projection] = STATE(8),
+    [sym_inl] = STATE(8),
+    [sym_inr] = STATE(8),
+    [sym_variant] = STATE(8),
+    [sym_case] = STATE(8),
+    [sym_cons] = STATE(8),
+    [sym_isnil] = STATE(8),
+    [sym_head] = STATE(8),
+    [sym_tail] = STATE(8),
+    [sym_list] = STATE(8),
+    [sym_ref] = STATE(8),
+    [sym_deref] = STATE(8),
+    [sym_assign] = STATE(8),
+    [sym_raise] = STATE(8),
+    [sym_try] = STATE(8),
+    [sym_type_abs] = STATE(8),
+    [sym_type_app] = STATE(8),
+    [sym_pack] = STATE(8),
+    [sym_unpack] = STATE(8),
+    [sym_def] = STATE(8),
+    [sym_call] = STATE(8),
+    [sym_ifElse] = STATE(8),
+    [sym_let] = STATE(8),
+    [sym_letrec] = STATE(8),
+    [sym_type_alias] = STATE(8),
+    [sym_import] = STATE(8),
+    [sym_fix] = STATE(8),
+    [sym_ascribe] = STATE(8),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(549),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(551),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(549),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(549),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [187] = {
+    [sym__expr] = STATE(9),
+    [sym_bool] = STATE(9),
+    [sym_succ] = STATE(9),
+    [sym_pred] = STATE(9),
+    [sym_iszero] = STATE(9),
+    [sym_string] = STATE(9),
+    [sym_concat] = STATE(9),
+    [sym_length] = STATE(9),
+    [sym_eq] = STATE(9),
+    [sym_not] = STATE(9),
+    [sym_and] = STATE(9),
+    [sym_or] = STATE(9),
+    [sym_equal] = STATE(9),
+    [sym_tuple] = STATE(9),
+    [sym_record] = STATE(9),
+    [sym_projection] = STATE(9),
+    [sym_inl] = STATE(9),
+    [sym_inr] = STATE(9),
+    [sym_variant] = STATE(9),
+    [sym_case] = STATE(9),
+    [sym_cons] = STATE(9),
+    [sym_isnil] = STATE(9),
+    [sym_head] = STATE(9),
+    [sym_tail] = STATE(9),
+    [sym_list] = STATE(9),
+    [sym_ref] = STATE(9),
+    [sym_deref] = STATE(9),
+    [sym_assign] = STATE(9),
+    [sym_raise] = STATE(9),
+    [sym_try] = STATE(9),
+    [sym_type_abs] = STATE(9),
+    [sym_type_app] = STATE(9),
+    [sym_pack] = STATE(9),
+    [sym_unpack] = STATE(9),
+    [sym_def] = STATE(9),
+    [sym_call] = STATE(9),
+    [sym_ifElse] = STATE(9),
+    [sym_let] = STATE(9),
+    [sym_letrec] = STATE(9),
+    [sym_type_alias] = STATE(9),
+    [sym_import] = STATE(9),
+    [sym_fix] = STATE(9),
+    [sym_ascribe] = STATE(9),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(553),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(555),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(553),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(553),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [188] = {
+    [sym__expr] = STATE(10),
+    [sym_bool] = STATE(10),
+    [sym_succ] = STATE(10),
+    [sym_pred] = STATE(10),
+    [sym_iszero] = STATE(10),
+    [sym_string] = STATE(10),
+    [sym_concat] = STATE(10),
+    [sym_length] = STATE(10),
+    [sym_eq] = STATE(10),
+    [sym_not] = STATE(10),
+    [sym_and] = STATE(10),
+    [sym_or] = STATE(10),
+    [sym_equal] = STATE(10),
+    [sym_tuple] = STATE(10),
+    [sym_record] = STATE(10),
+    [sym_projection] = STATE(10),
+    [sym_inl] = STATE(10),
+    [sym_inr] = STATE(10),
+    [sym_variant] = STATE(10),
+    [sym_case] = STATE(10),
+    [sym_cons] = STATE(10),
+    [sym_isnil] = STATE(10),
+    [sym_head] = STATE(10),
+    [sym_tail] = STATE(10),
+    [sym_list] = STATE(10),
+    [sym_ref] = STATE(10),
+    [sym_deref] = STATE(10),
+    [sym_assign] = STATE(10),
+    [sym_raise] = STATE(10),
+    [sym_try] = STATE(10),
+    [sym_type_abs] = STATE(10),
+    [sym_type_app] = STATE(10),
+    [sym_pack] = STATE(10),
+    [sym_unpack] = STATE(10),
+    [sym_def] = STATE(10),
+    [sym_call] = STATE(10),
+    [sym_ifElse] = STATE(10),
+    [sym_let] = STATE(10),
+    [sym_letrec] = STATE(10),
+    [sym_type_alias] = STATE(10),
+    [sym_import] = STATE(10),
+    [sym_fix] = STATE(10),
+    [sym_ascribe] = STATE(10),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(565),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(567),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(565),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(565),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [189] = {
+    [sym__expr] = STATE(11),
+    [sym_bool] = STATE(11),
+    [sym_succ] = STATE(11),
+    [sym_pred] = STATE(11),
+    [sym_iszero] = STATE(11),
+    [sym_string] = STATE(11),
+    [sym_concat] = STATE(11),
+    [sym_length] = STATE(11),
+    [sym_eq] = STATE(11),
+    [sym_not] = STATE(11),
+    [sym_and] = STATE(11),
+    [sym_or] = STATE(11),
+    [sym_equal] = STATE(11),
+    [sym_tuple] = STATE(11),
+    [sym_record] = STATE(11),
+    [sym_projection] = STATE(11),
+    [sym_inl] = STATE(11),
+    [sym_inr] = STATE(11),
+    [sym_variant] = STATE(11),
+    [sym_case] = STATE(11),
+    [sym_cons] = STATE(11),
+    [sym_isnil] = STATE(11),
+    [sym_head] = STATE(11),
+    [sym_tail] = STATE(11),
+    [sym_list] = STATE(11),
+    [sym_ref] = STATE(11),
+    [sym_deref] = STATE(11),
+    [sym_assign] = STATE(11),
+    [sym_raise] = STATE(11),
+    [sym_try] = STATE(11),
+    [sym_type_abs] = STATE(11),
+    [sym_type_app] = STATE(11),
+    [sym_pack] = STATE(11),
+    [sym_unpack] = STATE(11),
+    [sym_def] = STATE(11),
+    [sym_call] = STATE(11),
+    [sym_ifElse] = STATE(11),
+    [sym_let] = STATE(11),
+    [sym_letrec] = STATE(11),
+    [sym_type_alias] = STATE(11),
+    [sym_import] = STATE(11),
+    [sym_fix] = STATE(11),
+    [sym_ascribe] = STATE(11),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(577),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(579),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(577),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(577),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [190] = {
+    [sym__expr] = STATE(12),
+    [sym_bool] = STATE(12),
+    [sym_succ] = STATE(12),
+    [sym_pred] = STATE(12),
+    [sym_iszero] = STATE(12),
+    [sym_string] = STATE(12),
+    [sym_concat] = STATE(12),
+    [sym_length] = STATE(12),
+    [sym_eq] = STATE(12),
+    [sym_not] = STATE(12),
+    [sym_and] = STATE(12),
+    [sym_or] = STATE(12),
+    [sym_equal] = STATE(12),
+    [sym_tuple] = STATE(12),
+    [sym_record] = STATE(12),
+    [sym_projection] = STATE(12),
+    [sym_inl] = STATE(12),
+    [sym_inr] = STATE(12),
+    [sym_variant] = STATE(12),
+    [sym_case] = STATE(12),
+    [sym_cons] = STATE(12),
+    [sym_isnil] = STATE(12),
+    [sym_head] = STATE(12),
+    [sym_tail] = STATE(12),
+    [sym_list] = STATE(12),
+    [sym_ref] = STATE(12),
+    [sym_deref] = STATE(12),
+    [sym_assign] = STATE(12),
+    [sym_raise] = STATE(12),
+    [sym_try] = STATE(12),
+    [sym_type_abs] = STATE(12),
+    [sym_type_app] = STATE(12),
+    [sym_pack] = STATE(12),
+    [sym_unpack] = STATE(12),
+    [sym_def] = STATE(12),
+    [sym_call] = STATE(12),
+    [sym_ifElse] = STATE(12),
+    [sym_let] = STATE(12),
+    [sym_letrec] = STATE(12),
+    [sym_type_alias] = STATE(12),
+    [sym_import] = STATE(12),
+    [sym_fix] = STATE(12),
+    [sym_ascribe] = STATE(12),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(581),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(583),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(581),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(581),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [191] = {
+    [sym__expr] = STATE(13),
+    [sym_bool] = STATE(13),
+    [sym_succ] = STATE(13),
+    [sym_pred] = STATE(13),
+    [sym_iszero] = STATE(13),
+    [sym_string] = STATE(13),
+    [sym_concat] = STATE(13),
+    [sym_length] = STATE(13),
+    [sym_eq] = STATE(13),
+    [sym_not] = STATE(13),
+    [sym_and] = STATE(13),
+    [sym_or] = STATE(13),
+    [sym_equal] = STATE(13),
+    [sym_tuple] = STATE(13),
+    [sym_record] = STATE(13),
+    [sym_projection] = STATE(13),
+    [sym_inl] = STATE(13),
+    [sym_inr] = STATE(13),
+    [sym_variant] = STATE(13),
+    [sym_case] = STATE(13),
+    [sym_cons] = STATE(13),
+    [sym_isnil] = STATE(13),
+    [sym_head] = STATE(13),
+    [sym_tail] = STATE(13),
+    [sym_list] = STATE(13),
+    [sym_ref] = STATE(13),
+    [sym_deref] = STATE(13),
+    [sym_assign] = STATE(13),
+    [sym_raise] = STATE(13),
+    [sym_try] = STATE(13),
+    [sym_type_abs] = STATE(13),
+    [sym_type_app] = STATE(13),
+    [sym_pack] = STATE(13),
+    [sym_unpack] = STATE(13),
+    [sym_def] = STATE(13),
+    [sym_call] = STATE(13),
+    [sym_ifElse] = STATE(13),
+    [sym_let] = STATE(13),
+    [sym_letrec] = STATE(13),
+    [sym_type_alias] = STATE(13),
+    [sym_import] = STATE(13),
+    [sym_fix] = STATE(13),
+    [sym_ascribe] = STATE(13),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(589),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(591),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(589),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(589),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [192] = {
+    [sym__expr] = STATE(14),
+    [sym_bool] = STATE(14),
+    [sym_succ] = STATE(14),
+    [sym_pred] = STATE(14),
+    [sym_iszero] = STATE(14),
+    [sym_string] = STATE(14),
+    [sym_concat] = STATE(14),
+    [sym_length] = STATE(14),
+    [sym_eq] = STATE(14),
+    [sym_not] = STATE(14),
+    [sym_and] = STATE(14),
+    [sym_or] = STATE(14),
+    [sym_equal] = STATE(14),
+    [sym_tuple] = STATE(14),
+    [sym_record] = STATE(14),
+    [sym_projection] = STATE(14),
+    [sym_inl] = STATE(14),
+    [sym_inr] = STATE(14),
+    [sym_variant] = STATE(14),
+    [sym_case] = STATE(14),
+    [sym_cons] = STATE(14),
+    [sym_isnil] = STATE(14),
+    [sym_head] = STATE(14),
+    [sym_tail] = STATE(14),
+    [sym_list] = STATE(14),
+    [sym_ref] = STATE(14),
+    [sym_deref] = STATE(14),
+    [sym_assign] = STATE(14),
+    [sym_raise] = STATE(14),
+    [sym_try] = STATE(14),
+    [sym_type_abs] = STATE(14),
+    [sym_type_app] = STATE(14),
+    [sym_pack] = STATE(14),
+    [sym_unpack] = STATE(14),
+    [sym_def] = STATE(14),
+    [sym_call] = STATE(14),
+    [sym_ifElse] = STATE(14),
+    [sym_let] = STATE(14),
+    [sym_letrec] = STATE(14),
+    [sym_type_alias] = STATE(14),
+    [sym_import] = STATE(14),
+    [sym_fix] = STATE(14),
+    [sym_ascribe] = STATE(14),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(605),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(607),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(605),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(605),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [193] = {
+    [sym__expr] = STATE(14),
+    [sym_bool] = STATE(14),
+    [sym_succ] = STATE(14),
+    [sym_pred] = STATE(14),
+    [sym_iszero] = STATE(14),
+    [sym_string] = STATE(14),
+    [sym_concat] = STATE(14),
+    [sym_length] = STATE(14),
+    [sym_eq] = STATE(14),
+    [sym_not] = STATE(14),
+    [sym_and] = STATE(14),
+    [sym_or] = STATE(14),
+    [sym_equal] = STATE(14),
+    [sym_tuple] = STATE(14),
+    [sym_record] = STATE(14),
+    [sym_projection] = STATE(14),
+    [sym_inl] = STATE(14),
+    [sym_inr] = STATE(14),
+    [sym_variant] = STATE(14),
+    [sym_case] = STATE(14),
+    [sym_cons] = STATE(14),
+    [sym_isnil] = STATE(14),
+    [sym_head] = STATE(14),
+    [sym_tail] = STATE(14),
+    [sym_list] = STATE(14),
+    [sym_ref] = STATE(14),
+    [sym_deref] = STATE(14),
+    [sym_assign] = STATE(14),
+    [sym_raise] = STATE(14),
+    [sym_try] = STATE(14),
+    [sym_type_abs] = STATE(14),
+    [sym_type_app] = STATE(14),
+    [sym_pack] = STATE(14),
+    [sym_unpack] = STATE(14),
+    [sym_def] = STATE(14),
+    [sym_call] = STATE(14),
+    [sym_ifElse] = STATE(14),
+    [sym_let] = STATE(14),
+    [sym_letrec] = STATE(14),
+    [sym_type_alias] = STATE(14),
+    [sym_import] = STATE(14),
+    [sym_fix] = STATE(14),
+    [sym_ascribe] = STATE(14),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(605),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(607),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(605),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(605),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [194] = {
+    [sym__expr] = STATE(47),
+    [sym_bool] = STATE(47),
+    [sym_succ] = STATE(47),
+    [sym_pred] = STATE(47),
+    [sym_iszero] = STATE(47),
+    [sym_string] = STATE(47),
+    [sym_concat] = STATE(47),
+    [sym_length] = STATE(47),
+    [sym_eq] = STATE(47),
+    [sym_not] = STATE(47),
+    [sym_and] = STATE(47),
+    [sym_or] = STATE(47),
+    [sym_equal] = STATE(47),
+    [sym_tuple] = STATE(47),
+    [sym_record] = STATE(47),
+    [sym_projection] = STATE(47),
+    [sym_inl] = STATE(47),
+    [sym_inr] = STATE(47),
+    [sym_variant] = STATE(47),
+    [sym_case] = STATE(47),
+    [sym_cons] = STATE(47),
+    [sym_isnil] = STATE(47),
+    [sym_head] = STATE(47),
+    [sym_tail] = STATE(47),
+    [sym_list] = STATE(47),
+    [sym_ref] = STATE(47),
+    [sym_deref] = STATE(47),
+    [sym_assign] = STATE(47),
+    [sym_raise] = STATE(47),
+    [sym_try] = STATE(47),
+    [sym_type_abs] = STATE(47),
+    [sym_type_app] = STATE(47),
+    [sym_pack] = STATE(47),
+    [sym_unpack] = STATE(47),
+    [sym_def] = STATE(47),
+    [sym_call] = STATE(47),
+    [sym_ifElse] = STATE(47),
+    [sym_let] = STATE(47),
+    [sym_letrec] = STATE(47),
+    [sym_type_alias] = STATE(47),
+    [sym_import] = STATE(47),
+    [sym_fix] = STATE(47),
+    [sym_ascribe] = STATE(47),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(689),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(691),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(689),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(689),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [195] = {
+    [sym__expr] = STATE(44),
+    [sym_bool] = STATE(44),
+    [sym_succ] = STATE(44),
+    [sym_pred] = STATE(44),
+    [sym_iszero] = STATE(44),
+    [sym_string] = STATE(44),
+    [sym_concat] = STATE(44),
+    [sym_length] = STATE(44),
+    [sym_eq] = STATE(44),
+    [sym_not] = STATE(44),
+    [sym_and] = STATE(44),
+    [sym_or] = STATE(44),
+    [sym_equal] = STATE(44),
+    [sym_tuple] = STATE(44),
+    [sym_record] = STATE(44),
+    [sym_projection] = STATE(44),
+    [sym_inl] = STATE(44),
+    [sym_inr] = STATE(44),
+    [sym_variant] = STATE(44),
+    [sym_case] = STATE(44),
+    [sym_cons] = STATE(44),
+    [sym_isnil] = STATE(44),
+    [sym_head] = STATE(44),
+    [sym_tail] = STATE(44),
+    [sym_list] = STATE(44),
+    [sym_ref] = STATE(44),
+    [sym_deref] = STATE(44),
+    [sym_assign] = STATE(44),
+    [sym_raise] = STATE(44),
+    [sym_try] = STATE(44),
+    [sym_type_abs] = STATE(44),
+    [sym_type_app] = STATE(44),
+    [sym_pack] = STATE(44),
+    [sym_unpack] = STATE(44),
+    [sym_def] = STATE(44),
+    [sym_call] = STATE(44),
+    [sym_ifElse] = STATE(44),
+    [sym_let] = STATE(44),
+    [sym_letrec] = STATE(44),
+    [sym_type_alias] = STATE(44),
+    [sym_import] = STATE(44),
+    [sym_fix] = STATE(44),
+    [sym_ascribe] = STATE(44),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(693),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(695),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(693),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(693),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [196] = {
+    [sym__expr] = STATE(48),
+    [sym_bool] = STATE(48),
+    [sym_succ] = STATE(48),
+    [sym_pred] = STATE(48),
+    [sym_iszero] = STATE(48),
+    [sym_string] = STATE(48),
+    [sym_concat] = STATE(48),
+    [sym_length] = STATE(48),
+    [sym_eq] = STATE(48),
+    [sym_not] = STATE(48),
+    [sym_and] = STATE(48),
+    [sym_or] = STATE(48),
+    [sym_equal] = STATE(48),
+    [sym_tuple] = STATE(48),
+    [sym_record] = STATE(48),
+    [sym_projection] = STATE(48),
+    [sym_inl] = STATE(48),
+    [sym_inr] = STATE(48),
+    [sym_variant] = STATE(48),
+    [sym_case] = STATE(48),
+    [sym_cons] = STATE(48),
+    [sym_isnil] = STATE(48),
+    [sym_head] = STATE(48),
+    [sym_tail] = STATE(48),
+    [sym_list] = STATE(48),
+    [sym_ref] = STATE(48),
+    [sym_deref] = STATE(48),
+    [sym_assign] = STATE(48),
+    [sym_raise] = STATE(48),
+    [sym_try] = STATE(48),
+    [sym_type_abs] = STATE(48),
+    [sym_type_app] = STATE(48),
+    [sym_pack] = STATE(48),
+    [sym_unpack] = STATE(48),
+    [sym_def] = STATE(48),
+    [sym_call] = STATE(48),
+    [sym_ifElse] = STATE(48),
+    [sym_let] = STATE(48),
+    [sym_letrec] = STATE(48),
+    [sym_type_alias] = STATE(48),
+    [sym_import] = STATE(48),
+    [sym_fix] = STATE(48),
+    [sym_ascribe] = STATE(48),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(697),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(699),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(697),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(697),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [197] = {
+    [sym__expr] = STATE(43),
+    [sym_bool] = STATE(43),
+    [sym_succ] = STATE(43),
+    [sym_pred] = STATE(43),
+    [sym_iszero] = STATE(43),
+    [sym_string] = STATE(43),
+    [sym_concat] = STATE(43),
+    [sym_length] = STATE(43),
+    [sym_eq] = STATE(43),
+    [sym_not] = STATE(43),
+    [sym_and] = STATE(43),
+    [sym_or] = STATE(43),
+    [sym_equal] = STATE(43),
+    [sym_tuple] = STATE(43),
+    [sym_record] = STATE(43),
+    [sym_projection] = STATE(43),
+    [sym_inl] = STATE(43),
+    [sym_inr] = STATE(43),
+    [sym_variant] = STATE(43),
+    [sym_case] = STATE(43),
+    [sym_cons] = STATE(43),
+    [sym_isnil] = STATE(43),
+    [sym_head] = STATE(43),
+    [sym_tail] = STATE(43),
+    [sym_list] = STATE(43),
+    [sym_ref] = STATE(43),
+    [sym_deref] = STATE(43),
+    [sym_assign] = STATE(43),
+    [sym_raise] = STATE(43),
+    [sym_try] = STATE(43),
+    [sym_type_abs] = STATE(43),
+    [sym_type_app] = STATE(43),
+    [sym_pack] = STATE(43),
+    [sym_unpack] = STATE(43),
+    [sym_def] = STATE(43),
+    [sym_call] = STATE(43),
+    [sym_ifElse] = STATE(43),
+    [sym_let] = STATE(43),
+    [sym_letrec] = STATE(43),
+    [sym_type_alias] = STATE(43),
+    [sym_import] = STATE(43),
+    [sym_fix] = STATE(43),
+    [sym_ascribe] = STATE(43),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(701),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(703),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(701),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(701),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [198] = {
+    [sym__expr] = STATE(20),
+    [sym_bool] = STATE(20),
+    [sym_succ] = STATE(20),
+    [sym_pred] = STATE(20),
+    [sym_iszero] = STATE(20),
+    [sym_string] = STATE(20),
+    [sym_concat] = STATE(20),
+    [sym_length] = STATE(20),
+    [sym_eq] = STATE(20),
+    [sym_not] = STATE(20),
+    [sym_and] = STATE(20),
+    [sym_or] = STATE(20),
+    [sym_equal] = STATE(20),
+    [sym_tuple] = STATE(20),
+    [sym_record] = STATE(20),
+    [sym_projection] = STATE(20),
+    [sym_inl] = STATE(20),
+    [sym_inr] = STATE(20),
+    [sym_variant] = STATE(20),
+    [sym_case] = STATE(20),
+    [sym_cons] = STATE(20),
+    [sym_isnil] = STATE(20),
+    [sym_head] = STATE(20),
+    [sym_tail] = STATE(20),
+    [sym_list] = STATE(20),
+    [sym_ref] = STATE(20),
+    [sym_deref] = STATE(20),
+    [sym_assign] = STATE(20),
+    [sym_raise] = STATE(20),
+    [sym_try] = STATE(20),
+    [sym_type_abs] = STATE(20),
+    [sym_type_app] = STATE(20),
+    [sym_pack] = STATE(20),
+    [sym_unpack] = STATE(20),
+    [sym_def] = STATE(20),
+    [sym_call] = STATE(20),
+    [sym_ifElse] = STATE(20),
+    [sym_let] = STATE(20),
+    [sym_letrec] = STATE(20),
+    [sym_type_alias] = STATE(20),
+    [sym_import] = STATE(20),
+    [sym_fix] = STATE(20),
+    [sym_ascribe] = STATE(20),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(669),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(671),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(669),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(669),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [199] = {
+    [sym__expr] = STATE(68),
+    [sym_bool] = STATE(68),
+    [sym_succ] = STATE(68),
+    [sym_pred] = STATE(68),
+    [sym_iszero] = STATE(68),
+    [sym_string] = STATE(68),
+    [sym_concat] = STATE(68),
+    [sym_length] = STATE(68),
+    [sym_eq] = STATE(68),
+    [sym_not] = STATE(68),
+    [sym_and] = STATE(68),
+    [sym_or] = STATE(68),
+    [sym_equal] = STATE(68),
+    [sym_tuple] = STATE(68),
+    [sym_record] = STATE(68),
+    [sym_projection] = STATE(68),
+    [sym_inl] = STATE(68),
+    [sym_inr] = STATE(68),
+    [sym_variant] = STATE(68),
+    [sym_case] = STATE(68),
+    [sym_cons] = STATE(68),
+    [sym_isnil] = STATE(68),
+    [sym_head] = STATE(68),
+    [sym_tail] = STATE(68),
+    [sym_list] = STATE(68),
+    [sym_ref] = STATE(68),
+    [sym_deref] = STATE(68),
+    [sym_assign] = STATE(68),
+    [sym_raise] = STATE(68),
+    [sym_try] = STATE(68),
+    [sym_type_abs] = STATE(68),
+    [sym_type_app] = STATE(68),
+    [sym_pack] = STATE(68),
+    [sym_unpack] = STATE(68),
+    [sym_def] = STATE(68),
+    [sym_call] = STATE(68),
+    [sym_ifElse] = STATE(68),
+    [sym_let] = STATE(68),
+    [sym_letrec] = STATE(68),
+    [sym_type_alias] = STATE(68),
+    [sym_import] = STATE(68),
+    [sym_fix] = STATE(68),
+    [sym_ascribe] = STATE(68),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(471),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(473),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(471),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(471),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [200] = {
+    [sym__expr] = STATE(50),
+    [sym_bool] = STATE(50),
+    [sym_succ] = STATE(50),
+    [sym_pred] = STATE(50),
+    [sym_iszero] = STATE(50),
+    [sym_string] = STATE(50),
+    [sym_concat] = STATE(50),
+    [sym_length] = STATE(50),
+    [sym_eq] = STATE(50),
+    [sym_not] = STATE(50),
+    [sym_and] = STATE(50),
+    [sym_or] = STATE(50),
+    [sym_equal] = STATE(50),
+    [sym_tuple] = STATE(50),
+    [sym_record] = STATE(50),
+    [sym_projection] = STATE(50),
+    [sym_inl] = STATE(50),
+    [sym_inr] = STATE(50),
+    [sym_variant] = STATE(50),
+    [sym_case] = STATE(50),
+    [sym_cons] = STATE(50),
+    [sym_isnil] = STATE(50),
+    [sym_head] = STATE(50),
+    [sym_tail] = STATE(50),
+    [sym_list] = STATE(50),
+    [sym_ref] = STATE(50),
+    [sym_deref] = STATE(50),
+    [sym_assign] = STATE(50),
+    [sym_raise] = STATE(50),
+    [sym_try] = STATE(50),
+    [sym_type_abs] = STATE(50),
+    [sym_type_app] = STATE(50),
+    [sym_pack] = STATE(50),
+    [sym_unpack] = STATE(50),
+    [sym_def] = STATE(50),
+    [sym_call] = STATE(50),
+    [sym_ifElse] = STATE(50),
+    [sym_let] = STATE(50),
+    [sym_letrec] = STATE(50),
+    [sym_type_alias] = STATE(50),
+    [sym_import] = STATE(50),
+    [sym_fix] = STATE(50),
+    [sym_ascribe] = STATE(50),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(705),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(707),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(705),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(705),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [201] = {
+    [sym__expr] = STATE(49),
+    [sym_bool] = STATE(49),
+    [sym_succ] = STATE(49),
+    [sym_pred] = STATE(49),
+    [sym_iszero] = STATE(49),
+    [sym_string] = STATE(49),
+    [sym_concat] = STATE(49),
+    [sym_length] = STATE(49),
+    [sym_eq] = STATE(49),
+    [sym_not] = STATE(49),
+    [sym_and] = STATE(49),
+    [sym_or] = STATE(49),
+    [sym_equal] = STATE(49),
+    [sym_tuple] = STATE(49),
+    [sym_record] = STATE(49),
+    [sym_projection] = STATE(49),
+    [sym_inl] = STATE(49),
+    [sym_inr] = STATE(49),
+    [sym_variant] = STATE(49),
+    [sym_case] = STATE(49),
+    [sym_cons] = STATE(49),
+    [sym_isnil] = STATE(49),
+    [sym_head] = STATE(49),
+    [sym_tail] = STATE(49),
+    [sym_list] = STATE(49),
+    [sym_ref] = STATE(49),
+    [sym_deref] = STATE(49),
+    [sym_assign] = STATE(49),
+    [sym_raise] = STATE(49),
+    [sym_try] = STATE(49),
+    [sym_type_abs] = STATE(49),
+    [sym_type_app] = STATE(49),
+    [sym_pack] = STATE(49),
+    [sym_unpack] = STATE(49),
+    [sym_def] = STATE(49),
+    [sym_call] = STATE(49),
+    [sym_ifElse] = STATE(49),
+    [sym_let] = STATE(49),
+    [sym_letrec] = STATE(49),
+    [sym_type_alias] = STATE(49),
+    [sym_import] = STATE(49),
+    [sym_fix] = STATE(49),
+    [sym_ascribe] = STATE(49),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(709),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(711),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(709),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(709),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [202] = {
+    [sym__expr] = STATE(51),
+    [sym_bool] = STATE(51),
+    [sym_succ] = STATE(51),
+    [sym_pred] = STATE(51),
+    [sym_iszero] = STATE(51),
+    [sym_string] = STATE(51),
+    [sym_concat] = STATE(51),
+    [sym_length] = STATE(51),
+    [sym_eq] = STATE(51),
+    [sym_not] = STATE(51),
+    [sym_and] = STATE(51),
+    [sym_or] = STATE(51),
+    [sym_equal] = STATE(51),
+    [sym_tuple] = STATE(51),
+    [sym_record] = STATE(51),
+    [sym_projection] = STATE(51),
+    [sym_inl] = STATE(51),
+    [sym_inr] = STATE(51),
+    [sym_variant] = STATE(51),
+    [sym_case] = STATE(51),
+    [sym_cons] = STATE(51),
+    [sym_isnil] = STATE(51),
+    [sym_head] = STATE(51),
+    [sym_tail] = STATE(51),
+    [sym_list] = STATE(51),
+    [sym_ref] = STATE(51),
+    [sym_deref] = STATE(51),
+    [sym_assign] = STATE(51),
+    [sym_raise] = STATE(51),
+    [sym_try] = STATE(51),
+    [sym_type_abs] = STATE(51),
+    [sym_type_app] = STATE(51),
+    [sym_pack] = STATE(51),
+    [sym_unpack] = STATE(51),
+    [sym_def] = STATE(51),
+    [sym_call] = STATE(51),
+    [sym_ifElse] = STATE(51),
+    [sym_let] = STATE(51),
+    [sym_letrec] = STATE(51),
+    [sym_type_alias] = STATE(51),
+    [sym_import] = STATE(51),
+    [sym_fix] = STATE(51),
+    [sym_ascribe] = STATE(51),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(713),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(715),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(713),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(713),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [203] = {
+    [sym__expr] = STATE(52),
+    [sym_bool] = STATE(52),
+    [sym_succ] = STATE(52),
+    [sym_pred] = STATE(52),
+    [sym_iszero] = STATE(52),
+    [sym_string] = STATE(52),
+    [sym_concat] = STATE(52),
+    [sym_length] = STATE(52),
+    [sym_eq] = STATE(52),
+    [sym_not] = STATE(52),
+    [sym_and] = STATE(52),
+    [sym_or] = STATE(52),
+    [sym_equal] = STATE(52),
+    [sym_tuple] = STATE(52),
+    [sym_record] = STATE(52),
+    [sym_projection] = STATE(52),
+    [sym_inl] = STATE(52),
+    [sym_inr] = STATE(52),
+    [sym_variant] = STATE(52),
+    [sym_case] = STATE(52),
+    [sym_cons] = STATE(52),
+    [sym_isnil] = STATE(52),
+    [sym_head] = STATE(52),
+    [sym_tail] = STATE(52),
+    [sym_list] = STATE(52),
+    [sym_ref] = STATE(52),
+    [sym_deref] = STATE(52),
+    [sym_assign] = STATE(52),
+    [sym_raise] = STATE(52),
+    [sym_try] = STATE(52),
+    [sym_type_abs] = STATE(52),
+    [sym_type_app] = STATE(52),
+    [sym_pack] = STATE(52),
+    [sym_unpack] = STATE(52),
+    [sym_def] = STATE(52),
+    [sym_call] = STATE(52),
+    [sym_ifElse] = STATE(52),
+    [sym_let] = STATE(52),
+    [sym_letrec] = STATE(52),
+    [sym_type_alias] = STATE(52),
+    [sym_import] = STATE(52),
+    [sym_fix] = STATE(52),
+    [sym_ascribe] = STATE(52),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(717),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(719),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(717),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(717),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [204] = {
+    [sym__expr] = STATE(3),
+    [sym_bool] = STATE(3),
+    [sym_succ] = STATE(3),
+    [sym_pred] = STATE(3),
+    [sym_iszero] = STATE(3),
+    [sym_string] = STATE(3),
+    [sym_concat] = STATE(3),
+    [sym_length] = STATE(3),
+    [sym_eq] = STATE(3),
+    [sym_not] = STATE(3),
+    [sym_and] = STATE(3),
+    [sym_or] = STATE(3),
+    [sym_equal] = STATE(3),
+    [sym_tuple] = STATE(3),
+    [sym_record] = STATE(3),
+    [sym_projection] = STATE(3),
+    [sym_inl] = STATE(3),
+    [sym_inr] = STATE(3),
+    [sym_variant] = STATE(3),
+    [sym_case] = STATE(3),
+    [sym_cons] = STATE(3),
+    [sym_isnil] = STATE(3),
+    [sym_head] = STATE(3),
+    [sym_tail] = STATE(3),
+    [sym_list] = STATE(3),
+    [sym_ref] = STATE(3),
+    [sym_deref] = STATE(3),
+    [sym_assign] = STATE(3),
+    [sym_raise] = STATE(3),
+    [sym_try] = STATE(3),
+    [sym_type_abs] = STATE(3),
+    [sym_type_app] = STATE(3),
+    [sym_pack] = STATE(3),
+    [sym_unpack] = STATE(3),
+    [sym_def] = STATE(3),
+    [sym_call] = STATE(3),
+    [sym_ifElse] = STATE(3),
+    [sym_let] = STATE(3),
+    [sym_letrec] = STATE(3),
+    [sym_type_alias] = STATE(3),
+    [sym_import] = STATE(3),
+    [sym_fix] = STATE(3),
+    [sym_ascribe] = STATE(3),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(673),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(675),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(673),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(673),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [205] = {
+    [sym__expr] = STATE(28),
+    [sym_bool] = STATE(28),
+    [sym_succ] = STATE(28),
+    [sym_pred] = STATE(28),
+    [sym_iszero] = STATE(28),
+    [sym_string] = STATE(28),
+    [sym_concat] = STATE(28),
+    [sym_length] = STATE(28),
+    [sym_eq] = STATE(28),
+    [sym_not] = STATE(28),
+    [sym_and] = STATE(28),
+    [sym_or] = STATE(28),
+    [sym_equal] = STATE(28),
+    [sym_tuple] = STATE(28),
+    [sym_record] = STATE(28),
+    [sym_projection] = STATE(28),
+    [sym_inl] = STATE(28),
+    [sym_inr] = STATE(28),
+    [sym_variant] = STATE(28),
+    [sym_case] = STATE(28),
+    [sym_cons] = STATE(28),
+    [sym_isnil] = STATE(28),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(28),
+    [sym_list] = STATE(28),
+    [sym_ref] = STATE(28),
+    [sym_deref] = STATE(28),
+    [sym_assign] = STATE(28),
+    [sym_raise] = STATE(28),
+    [sym_try] = STATE(28),
+    [sym_type_abs] = STATE(28),
+    [sym_type_app] = STATE(28),
+    [sym_pack] = STATE(28),
+    [sym_unpack] = STATE(28),
+    [sym_def] = STATE(28),
+    [sym_call] = STATE(28),
+    [sym_ifElse] = STATE(28),
+    [sym_let] = STATE(28),
+    [sym_letrec] = STATE(28),
+    [sym_type_alias] = STATE(28),
+    [sym_import] = STATE(28),
+    [sym_fix] = STATE(28),
+    [sym_ascribe] = STATE(28),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(721),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(723),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(721),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(721),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [206] = {
+    [sym__expr] = STATE(63),
+    [sym_bool] = STATE(63),
+    [sym_succ] = STATE(63),
+    [sym_pred] = STATE(63),
+    [sym_iszero] = STATE(63),
+    [sym_string] = STATE(63),
+    [sym_concat] = STATE(63),
+    [sym_length] = STATE(63),
+    [sym_eq] = STATE(63),
+    [sym_not] = STATE(63),
+    [sym_and] = STATE(63),
+    [sym_or] = STATE(63),
+    [sym_equal] = STATE(63),
+    [sym_tuple] = STATE(63),
+    [sym_record] = STATE(63),
+    [sym_projection] = STATE(63),
+    [sym_inl] = STATE(63),
+    [sym_inr] = STATE(63),
+    [sym_variant] = STATE(63),
+    [sym_case] = STATE(63),
+    [sym_cons] = STATE(63),
+    [sym_isnil] = STATE(63),
+    [sym_head] = STATE(63),
+    [sym_tail] = STATE(63),
+    [sym_list] = STATE(63),
+    [sym_ref] = STATE(63),
+    [sym_deref] = STATE(63),
+    [sym_assign] = STATE(63),
+    [sym_raise] = STATE(63),
+    [sym_try] = STATE(63),
+    [sym_type_abs] = STATE(63),
+    [sym_type_app] = STATE(63),
+    [sym_pack] = STATE(63),
+    [sym_unpack] = STATE(63),
+    [sym_def] = STATE(63),
+    [sym_call] = STATE(63),
+    [sym_ifElse] = STATE(63),
+    [sym_let] = STATE(63),
+    [sym_letrec] = STATE(63),
+    [sym_type_alias] = STATE(63),
+    [sym_import] = STATE(63),
+    [sym_fix] = STATE(63),
+    [sym_ascribe] = STATE(63),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(725),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(727),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(725),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(725),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+  [207] = {
+    [sym__expr] = STATE(53),
+    [sym_bool] = STATE(53),
+    [sym_succ] = STATE(53),
+    [sym_pred] = STATE(53),
+    [sym_iszero] = STATE(53),
+    [sym_string] = STATE(53),
+    [sym_concat] = STATE(53),
+    [sym_length] = STATE(53),
+    [sym_eq] = STATE(53),
+    [sym_not] = STATE(53),
+    [sym_and] = STATE(53),
+    [sym_or] = STATE(53),
+    [sym_equal] = STATE(53),
+    [sym_tuple] = STATE(53),
+    [sym_record] = STATE(53),
+    [sym_projection] = STATE(53),
+    [sym_inl] = STATE(53),
+    [sym_inr] = STATE(53),
+    [sym_variant] = STATE(53),
+    [sym_case] = STATE(53),
+    [sym_cons] = STATE(53),
+    [sym_isnil] = STATE(53),
+    [sym_head] = STATE(53),
+    [sym_tail] = STATE(53),
+    [sym_list] = STATE(53),
+    [sym_ref] = STATE(53),
+    [sym_deref] = STATE(53),
+    [sym_assign] = STATE(53),
+    [sym_raise] = STATE(53),
+    [sym_try] = STATE(53),
+    [sym_type_abs] = STATE(53),
+    [sym_type_app] = STATE(53),
+    [sym_pack] = STATE(53),
+    [sym_unpack] = STATE(53),
+    [sym_def] = STATE(53),
+    [sym_call] = STATE(53),
+    [sym_ifElse] = STATE(53),
+    [sym_let] = STATE(53),
+    [sym_letrec] = STATE(53),
+    [sym_type_alias] = STATE(53),
+    [sym_import] = STATE(53),
+    [sym_fix] = STATE(53),
+    [sym_ascribe] = STATE(53),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(729),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(731),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(729),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(729),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [208] = {
+    [sym__expr] = STATE(40),
+    [sym_bool] = STATE(40),
+    [sym_succ] = STATE(40),
+    [sym_pred] = STATE(40),
+    [sym_iszero] = STATE(40),
+    [sym_string] = STATE(40),
+    [sym_concat] = STATE(40),
+    [sym_length] = STATE(40),
+    [sym_eq] = STATE(40),
+    [sym_not] = STATE(40),
+    [sym_and] = STATE(40),
+    [sym_or] = STATE(40),
+    [sym_equal] = STATE(40),
+    [sym_tuple] = STATE(40),
+    [sym_record] = STATE(40),
+    [sym_projection] = STATE(40),
+    [sym_inl] = STATE(40),
+    [sym_inr] = STATE(40),
+    [sym_variant] = STATE(40),
+    [sym_case] = STATE(40),
+    [sym_cons] = STATE(40),
+    [sym_isnil] = STATE(40),
+    [sym_head] = STATE(40),
+    [sym_tail] = STATE(40),
+    [sym_list] = STATE(40),
+    [sym_ref] = STATE(40),
+    [sym_deref] = STATE(40),
+    [sym_assign] = STATE(40),
+    [sym_raise] = STATE(40),
+    [sym_try] = STATE(40),
+    [sym_type_abs] = STATE(40),
+    [sym_type_app] = STATE(40),
+    [sym_pack] = STATE(40),
+    [sym_unpack] = STATE(40),
+    [sym_def] = STATE(40),
+    [sym_call] = STATE(40),
+    [sym_ifElse] = STATE(40),
+    [sym_let] = STATE(40),
+    [sym_letrec] = STATE(40),
+    [sym_type_alias] = STATE(40),
+    [sym_import] = STATE(40),
+    [sym_fix] = STATE(40),
+    [sym_ascribe] = STATE(40),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(733),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(735),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(733),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(733),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [209] = {
+    [sym__expr] = STATE(41),
+    [sym_bool] = STATE(41),
+    [sym_succ] = STATE(41),
+    [sym_pred] = STATE(41),
+    [sym_iszero] = STATE(41),
+    [sym_string] = STATE(41),
+    [sym_concat] = STATE(41),
+    [sym_length] = STATE(41),
+    [sym_eq] = STATE(41),
+    [sym_not] = STATE(41),
+    [sym_and] = STATE(41),
+    [sym_or] = STATE(41),
+    [sym_equal] = STATE(41),
+    [sym_tuple] = STATE(41),
+    [sym_record] = STATE(41),
+    [sym_projection] = STATE(41),
+    [sym_inl] = STATE(41),
+    [sym_inr] = STATE(41),
+    [sym_variant] = STATE(41),
+    [sym_case] = STATE(41),
+    [sym_cons] = STATE(41),
+    [sym_isnil] = STATE(41),
+    [sym_head] = STATE(41),
+    [sym_tail] = STATE(41),
+    [sym_list] = STATE(41),
+    [sym_ref] = STATE(41),
+    [sym_deref] = STATE(41),
+    [sym_assign] = STATE(41),
+    [sym_raise] = STATE(41),
+    [sym_try] = STATE(41),
+    [sym_type_abs] = STATE(41),
+    [sym_type_app] = STATE(41),
+    [sym_pack] = STATE(41),
+    [sym_unpack] = STATE(41),
+    [sym_def] = STATE(41),
+    [sym_call] = STATE(41),
+    [sym_ifElse] = STATE(41),
+    [sym_let] = STATE(41),
+    [sym_letrec] = STATE(41),
+    [sym_type_alias] = STATE(41),
+    [sym_import] = STATE(41),
+    [sym_fix] = STATE(41),
+    [sym_ascribe] = STATE(41),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(737),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(739),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(737),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(737),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [210] = {
+    [sym__expr] = STATE(17),
+    [sym_bool] = STATE(17),
+    [sym_succ] = STATE(17),
+    [sym_pred] = STATE(17),
+    [sym_iszero] = STATE(17),
+    [sym_string] = STATE(17),
+    [sym_concat] = STATE(17),
+    [sym_length] = STATE(17),
+    [sym_eq] = STATE(17),
+    [sym_not] = STATE(17),
+    [sym_and] = STATE(17),
+    [sym_or] = STATE(17),
+    [sym_equal] = STATE(17),
+    [sym_tuple] = STATE(17),
+    [sym_record] = STATE(17),
+    [sym_projection] = STATE(17),
+    [sym_inl] = STATE(17),
+    [sym_inr] = STATE(17),
+    [sym_variant] = STATE(17),
+    [sym_case] = STATE(17),
+    [sym_cons] = STATE(17),
+    [sym_isnil] = STATE(17),
+    [sym_head] = STATE(17),
+    [sym_tail] = STATE(17),
+    [sym_list] = STATE(17),
+    [sym_ref] = STATE(17),
+    [sym_deref] = STATE(17),
+    [sym_assign] = STATE(17),
+    [sym_raise] = STATE(17),
+    [sym_try] = STATE(17),
+    [sym_type_abs] = STATE(17),
+    [sym_type_app] = STATE(17),
+    [sym_pack] = STATE(17),
+    [sym_unpack] = STATE(17),
+    [sym_def] = STATE(17),
+    [sym_call] = STATE(17),
+    [sym_ifElse] = STATE(17),
+    [sym_let] = STATE(17),
+    [sym_letrec] = STATE(17),
+    [sym_type_alias] = STATE(17),
+    [sym_import] = STATE(17),
+    [sym_fix] = STATE(17),
+    [sym_ascribe] = STATE(17),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(569),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(571),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(569),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(569),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [211] = {
+    [sym__expr] = STATE(20),
+    [sym_bool] = STATE(20),
+    [sym_succ] = STATE(20),
+    [sym_pred] = STATE(20),
+    [sym_iszero] = STATE(20),
+    [sym_string] = STATE(20),
+    [sym_concat] = STATE(20),
+    [sym_length] = STATE(20),
+    [sym_eq] = STATE(20),
+    [sym_not] = STATE(20),
+    [sym_and] = STATE(20),
+    [sym_or] = STATE(20),
+    [sym_equal] = STATE(20),
+    [sym_tuple] = STATE(20),
+    [sym_record] = STATE(20),
+    [sym_projection] = STATE(20),
+    [sym_inl] = STATE(20),
+    [sym_inr] = STATE(20),
+    [sym_variant] = STATE(20),
+    [sym_case] = STATE(20),
+    [sym_cons] = STATE(20),
+    [sym_isnil] = STATE(20),
+    [sym_head] = STATE(20),
+    [sym_tail] = STATE(20),
+    [sym_list] = STATE(20),
+    [sym_ref] = STATE(20),
+    [sym_deref] = STATE(20),
+    [sym_assign] = STATE(20),
+    [sym_raise] = STATE(20),
+    [sym_try] = STATE(20),
+    [sym_type_abs] = STATE(20),
+    [sym_type_app] = STATE(20),
+    [sym_pack] = STATE(20),
+    [sym_unpack] = STATE(20),
+    [sym_def] = STATE(20),
+    [sym_call] = STATE(20),
+    [sym_ifElse] = STATE(20),
+    [sym_let] = STATE(20),
+    [sym_letrec] = STATE(20),
+    [sym_type_alias] = STATE(20),
+    [sym_import] = STATE(20),
+    [sym_fix] = STATE(20),
+    [sym_ascribe] = STATE(20),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(669),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(671),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(669),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(669),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [212] = {
+    [sym__expr] = STATE(3),
+    [sym_bool] = STATE(3),
+    [sym_succ] = STATE(3),
+    [sym_pred] = STATE(3),
+    [sym_iszero] = STATE(3),
+    [sym_string] = STATE(3),
+    [sym_concat] = STATE(3),
+    [sym_length] = STATE(3),
+    [sym_eq] = STATE(3),
+    [sym_not] = STATE(3),
+    [sym_and] = STATE(3),
+    [sym_or] = STATE(3),
+    [sym_equal] = STATE(3),
+    [sym_tuple] = STATE(3),
+    [sym_record] = STATE(3),
+    [sym_projection] = STATE(3),
+    [sym_inl] = STATE(3),
+    [sym_inr] = STATE(3),
+    [sym_variant] = STATE(3),
+    [sym_case] = STATE(3),
+    [sym_cons] = STATE(3),
+    [sym_isnil] = STATE(3),
+    [sym_head] = STATE(3),
+    [sym_tail] = STATE(3),
+    [sym_list] = STATE(3),
+    [sym_ref] = STATE(3),
+    [sym_deref] = STATE(3),
+    [sym_assign] = STATE(3),
+    [sym_raise] = STATE(3),
+    [sym_try] = STATE(3),
+    [sym_type_abs] = STATE(3),
+    [sym_type_app] = STATE(3),
+    [sym_pack] = STATE(3),
+    [sym_unpack] = STATE(3),
+    [sym_def] = STATE(3),
+    [sym_call] = STATE(3),
+    [sym_ifElse] = STATE(3),
+    [sym_let] = STATE(3),
+    [sym_letrec] = STATE(3),
+    [sym_type_alias] = STATE(3),
+    [sym_import] = STATE(3),
+    [sym_fix] = STATE(3),
+    [sym_ascribe] = STATE(3),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(673),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(675),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(673),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(673),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [213] = {
+    [sym__expr] = STATE(101),
+    [sym_bool] = STATE(101),
+    [sym_succ] = STATE(101),
+    [sym_pred] = STATE(101),
+    [sym_iszero] = STATE(101),
+    [sym_string] = STATE(101),
+    [sym_concat] = STATE(101),
+    [sym_length] = STATE(101),
+    [sym_eq] = STATE(101),
+    [sym_not] = STATE(101),
+    [sym_and] = STATE(101),
+    [sym_or] = STATE(101),
+    [sym_equal] = STATE(101),
+    [sym_tuple] = STATE(101),
+    [sym_record] = STATE(101),
+    [sym_projection] = STATE(101),
+    [sym_inl] = STATE(101),
+    [sym_inr] = STATE(101),
+    [sym_variant] = STATE(101),
+    [sym_case] = STATE(101),
+    [sym_cons] = STATE(101),
+    [sym_isnil] = STATE(101),
+    [sym_head] = STATE(101),
+    [sym_tail] = STATE(101),
+    [sym_list] = STATE(101),
+    [sym_ref] = STATE(101),
+    [sym_deref] = STATE(101),
+    [sym_assign] = STATE(101),
+    [sym_raise] = STATE(101),
+    [sym_try] = STATE(101),
+    [sym_type_abs] = STATE(101),
+    [sym_type_app] = STATE(101),
+    [sym_pack] = STATE(101),
+    [sym_unpack] = STATE(101),
+    [sym_def] = STATE(101),
+    [sym_call] = STATE(101),
+    [sym_ifElse] = STATE(101),
+    [sym_let] = STATE(101),
+    [sym_letrec] = STATE(101),
+    [sym_type_alias] = STATE(101),
+    [sym_import] = STATE(101),
+    [sym_fix] = STATE(101),
+    [sym_ascribe] = STATE(101),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(741),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(743),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(741),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(741),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [214] = {
+    [sym__expr] = STATE(4),
+    [sym_bool] = STATE(4),
+    [sym_succ] = STATE(4),
+    [sym_pred] = STATE(4),
+    [sym_iszero] = STATE(4),
+    [sym_string] = STATE(4),
+    [sym_concat] = STATE(4),
+    [sym_length] = STATE(4),
+    [sym_eq] = STATE(4),
+    [sym_not] = STATE(4),
+    [sym_and] = STATE(4),
+    [sym_or] = STATE(4),
+    [sym_equal] = STATE(4),
+    [sym_tuple] = STATE(4),
+    [sym_record] = STATE(4),
+    [sym_projection] = STATE(4),
+    [sym_inl] = STATE(4),
+    [sym_inr] = STATE(4),
+    [sym_variant] = STATE(4),
+    [sym_case] = STATE(4),
+    [sym_cons] = STATE(4),
+    [sym_isnil] = STATE(4),
+    [sym_head] = STATE(4),
+    [sym_tail] = STATE(4),
+    [sym_list] = STATE(4),
+    [sym_ref] = STATE(4),
+    [sym_deref] = STATE(4),
+    [sym_assign] = STATE(4),
+    [sym_raise] = STATE(4),
+    [sym_try] = STATE(4),
+    [sym_type_abs] = STATE(4),
+    [sym_type_app] = STATE(4),
+    [sym_pack] = STATE(4),
+    [sym_unpack] = STATE(4),
+    [sym_def] = STATE(4),
+    [sym_call] = STATE(4),
+    [sym_ifElse] = STATE(4),
+    [sym_let] = STATE(4),
+    [sym_letrec] = STATE(4),
+    [sym_type_alias] = STATE(4),
+    [sym_import] = STATE(4),
+    [sym_fix] = STATE(4),
+    [sym_ascribe] = STATE(4),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(509),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(511),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(509),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(509),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [215] = {
+    [sym__expr] = STATE(99),
+    [sym_bool] = STATE(99),
+    [sym_succ] = STATE(99),
+    [sym_pred] = STATE(99),
+    [sym_iszero] = STATE(99),
+    [sym_string] = STATE(99),
+    [sym_concat] = STATE(99),
+    [sym_length] = STATE(99),
+    [sym_eq] = STATE(99),
+    [sym_not] = STATE(99),
+    [sym_and] = STATE(99),
+    [sym_or] = STATE(99),
+    [sym_equal] = STATE(99),
+    [sym_tuple] = STATE(99),
+    [sym_record] = STATE(99),
+    [sym_projection] = STATE(99),
+    [sym_inl] = STATE(99),
+    [sym_inr] = STATE(99),
+    [sym_variant] = STATE(99),
+    [sym_case] = STATE(99),
+    [sym_cons] = STATE(99),
+    [sym_isnil] = STATE(99),
+    [sym_head] = STATE(99),
+    [sym_tail] = STATE(99),
+    [sym_list] = STATE(99),
+    [sym_ref] = STATE(99),
+    [sym_deref] = STATE(99),
+    [sym_assign] = STATE(99),
+    [sym_raise] = STATE(99),
+    [sym_try] = STATE(99),
+    [sym_type_abs] = STATE(99),
+    [sym_type_app] = STATE(99),
+    [sym_pack] = STATE(99),
+    [sym_unpack] = STATE(99),
+    [sym_def] = STATE(99),
+    [sym_call] = STATE(99),
+    [sym_ifElse] = STATE(99),
+    [sym_let] = STATE(99),
+    [sym_letrec] = STATE(99),
+    [sym_type_alias] = STATE(99),
+    [sym_import] = STATE(99),
+    [sym_fix] = STATE(99),
+    [sym_ascribe] = STATE(99),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(745),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(747),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(745),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(745),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [216] = {
+    [sym__expr] = STATE(5),
+    [sym_bool] = STATE(5),
+    [sym_succ] = STATE(5),
+    [sym_pred] = STATE(5),
+    [sym_iszero] = STATE(5),
+    [sym_string] = STATE(5),
+    [sym_concat] = STATE(5),
+    [sym_length] = STATE(5),
+    [sym_eq] = STATE(5),
+    [sym_not] = STATE(5),
+    [sym_and] = STATE(5),
+    [sym_or] = STATE(5),
+    [sym_equal] = STATE(5),
+    [sym_tuple] = STATE(5),
+    [sym_record] = STATE(5),
+    [sym_projection] = STATE(5),
+    [sym_inl] = STATE(5),
+    [sym_inr] = STATE(5),
+    [sym_variant] = STATE(5),
+    [sym_case] = STATE(5),
+    [sym_cons] = STATE(5),
+    [sym_isnil] = STATE(5),
+    [sym_head] = STATE(5),
+    [sym_tail] = STATE(5),
+    [sym_list] = STATE(5),
+    [sym_ref] = STATE(5),
+    [sym_deref] = STATE(5),
+    [sym_assign] = STATE(5),
+    [sym_raise] = STATE(5),
+    [sym_try] = STATE(5),
+    [sym_type_abs] = STATE(5),
+    [sym_type_app] = STATE(5),
+    [sym_pack] = STATE(5),
+    [sym_unpack] = STATE(5),
+    [sym_def] = STATE(5),
+    [sym_call] = STATE(5),
+    [sym_ifElse] = STATE(5),
+    [sym_let] = STATE(5),
+    [sym_letrec] = STATE(5),
+    [sym_type_alias] = STATE(5),
+    [sym_import] = STATE(5),
+    [sym_fix] = STATE(5),
+    [sym_ascribe] = STATE(5),
+    [sym_ident] = ACTIONS(311),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(517),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(519),
+    [anon_sym_succ] = ACTIONS(297),
+    [anon_sym_pred] = ACTIONS(299),
+    [anon_sym_iszero] = ACTIONS(301),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(303),
+    [anon_sym_length] = ACTIONS(305),
+    [anon_sym_eq] = ACTIONS(307),
+    [anon_sym_not] = ACTIONS(309),
+    [sym_wildcard] = ACTIONS(311),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(313),
+    [anon_sym_inr] = ACTIONS(315),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(317),
+    [sym_nil] = ACTIONS(517),
+    [anon_sym_cons] = ACTIONS(319),
+    [anon_sym_isnil] = ACTIONS(321),
+    [anon_sym_head] = ACTIONS(323),
+    [anon_sym_tail] = ACTIONS(325),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(329),
+    [anon_sym_BANG] = ACTIONS(331),
+    [sym_error] = ACTIONS(517),
+    [anon_sym_raise] = ACTIONS(333),
+    [anon_sym_try] = ACTIONS(335),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(337),
+    [anon_sym_let] = ACTIONS(339),
+    [anon_sym_if] = ACTIONS(341),
+    [anon_sym_letrec] = ACTIONS(343),
+    [anon_sym_type] = ACTIONS(345),
+    [anon_sym_import] = ACTIONS(347),
+    [anon_sym_fix] = ACTIONS(349),
+  },
+  [217] = {
+    [sym__expr] = STATE(80),
+    [sym_bool] = STATE(80),
+    [sym_succ] = STATE(80),
+    [sym_pred] = STATE(80),
+    [sym_iszero] = STATE(80),
+    [sym_string] = STATE(80),
+    [sym_concat] = STATE(80),
+    [sym_length] = STATE(80),
+    [sym_eq] = STATE(80),
+    [sym_not] = STATE(80),
+    [sym_and] = STATE(80),
+    [sym_or] = STATE(80),
+    [sym_equal] = STATE(80),
+    [sym_tuple] = STATE(80),
+    [sym_record] = STATE(80),
+    [sym_projection] = STATE(80),
+    [sym_inl] = STATE(80),
+    [sym_inr] = STATE(80),
+    [sym_variant] = STATE(80),
+    [sym_case] = STATE(80),
+    [sym_cons] = STATE(80),
+    [sym_isnil] = STATE(80),
+    [sym_head] = STATE(80),
+    [sym_tail] = STATE(80),
+    [sym_list] = STATE(80),
+    [sym_ref] = STATE(80),
+    [sym_deref] = STATE(80),
+    [sym_assign] = STATE(80),
+    [sym_raise] = STATE(80),
+    [sym_try] = STATE(80),
+    [sym_type_abs] = STATE(80),
+    [sym_type_app] = STATE(80),
+    [sym_pack] = STATE(80),
+    [sym_unpack] = STATE(80),
+    [sym_def] = STATE(80),
+    [sym_call] = STATE(80),
+    [sym_ifElse] = STATE(80),
+    [sym_let] = STATE(80),
+    [sym_letrec] = STATE(80),
+    [sym_type_alias] = STATE(80),
+    [sym_import] = STATE(80),
+    [sym_fix] = STATE(80),
+    [sym_ascribe] = STATE(80),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(749),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(751),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(749),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(749),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [218] = {
+    [sym__expr] = STATE(82),
+    [sym_bool] = STATE(82),
+    [sym_succ] = STATE(82),
+    [sym_pred] = STATE(82),
+    [sym_iszero] = STATE(82),
+    [sym_string] = STATE(82),
+    [sym_concat] = STATE(82),
+    [sym_length] = STATE(82),
+    [sym_eq] = STATE(82),
+    [sym_not] = STATE(82),
+    [sym_and] = STATE(82),
+    [sym_or] = STATE(82),
+    [sym_equal] = STATE(82),
+    [sym_tuple] = STATE(82),
+    [sym_record] = STATE(82),
+    [sym_projection] = STATE(82),
+    [sym_inl] = STATE(82),
+    [sym_inr] = STATE(82),
+    [sym_variant] = STATE(82),
+    [sym_case] = STATE(82),
+    [sym_cons] = STATE(82),
+    [sym_isnil] = STATE(82),
+    [sym_head] = STATE(82),
+    [sym_tail] = STATE(82),
+    [sym_list] = STATE(82),
+    [sym_ref] = STATE(82),
+    [sym_deref] = STATE(82),
+    [sym_assign] = STATE(82),
+    [sym_raise] = STATE(82),
+    [sym_try] = STATE(82),
+    [sym_type_abs] = STATE(82),
+    [sym_type_app] = STATE(82),
+    [sym_pack] = STATE(82),
+    [sym_unpack] = STATE(82),
+    [sym_def] = STATE(82),
+    [sym_call] = STATE(82),
+    [sym_ifElse] = STATE(82),
+    [sym_let] = STATE(82),
+    [sym_letrec] = STATE(82),
+    [sym_type_alias] = STATE(82),
+    [sym_import] = STATE(82),
+    [sym_fix] = STATE(82),
+    [sym_ascribe] = STATE(82),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(753),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(755),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(753),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(753),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [219] = {
+    [sym__expr] = STATE(79),
+    [sym_bool] = STATE(79),
+    [sym_succ] = STATE(79),
+    [sym_pred] = STATE(79),
+    [sym_iszero] = STATE(79),
+    [sym_string] = STATE(79),
+    [sym_concat] = STATE(79),
+    [sym_length] = STATE(79),
+    [sym_eq] = STATE(79),
+    [sym_not] = STATE(79),
+    [sym_and] = STATE(79),
+    [sym_or] = STATE(79),
+    [sym_equal] = STATE(79),
+    [sym_tuple] = STATE(79),
+    [sym_record] = STATE(79),
+    [sym_projection] = STATE(79),
+    [sym_inl] = STATE(79),
+    [sym_inr] = STATE(79),
+    [sym_variant] = STATE(79),
+    [sym_case] = STATE(79),
+    [sym_cons] = STATE(79),
+    [sym_isnil] = STATE(79),
+    [sym_head] = STATE(79),
+    [sym_tail] = STATE(79),
+    [sym_list] = STATE(79),
+    [sym_ref] = STATE(79),
+    [sym_deref] = STATE(79),
+    [sym_assign] = STATE(79),
+    [sym_raise] = STATE(79),
+    [sym_try] = STATE(79),
+    [sym_type_abs] = STATE(79),
+    [sym_type_app] = STATE(79),
+    [sym_pack] = STATE(79),
+    [sym_unpack] = STATE(79),
+    [sym_def] = STATE(79),
+    [sym_call] = STATE(79),
+    [sym_ifElse] = STATE(79),
+    [sym_let] = STATE(79),
+    [sym_letrec] = STATE(79),
+    [sym_type_alias] = STATE(79),
+    [sym_import] = STATE(79),
+    [sym_fix] = STATE(79),
+    [sym_ascribe] = STATE(79),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(757),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(759),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(757),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(757),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [220] = {
+    [sym__expr] = STATE(85),
+    [sym_bool] = STATE(85),
+    [sym_succ] = STATE(85),
+    [sym_pred] = STATE(85),
+    [sym_iszero] = STATE(85),
+    [sym_string] = STATE(85),
+    [sym_concat] = STATE(85),
+    [sym_length] = STATE(85),
+    [sym_eq] = STATE(85),
+    [sym_not] = STATE(85),
+    [sym_and] = STATE(85),
+    [sym_or] = STATE(85),
+    [sym_equal] = STATE(85),
+    [sym_tuple] = STATE(85),
+    [sym_record] = STATE(85),
+    [sym_projection] = STATE(85),
+    [sym_inl] = STATE(85),
+    [sym_inr] = STATE(85),
+    [sym_variant] = STATE(85),
+    [sym_case] = STATE(85),
+    [sym_cons] = STATE(85),
+    [sym_isnil] = STATE(85),
+    [sym_head] = STATE(85),
+    [sym_tail] = STATE(85),
+    [sym_list] = STATE(85),
+    [sym_ref] = STATE(85),
+    [sym_deref] = STATE(85),
+    [sym_assign] = STATE(85),
+    [sym_raise] = STATE(85),
+    [sym_try] = STATE(85),
+    [sym_type_abs] = STATE(85),
+    [sym_type_app] = STATE(85),
+    [sym_pack] = STATE(85),
+    [sym_unpack] = STATE(85),
+    [sym_def] = STATE(85),
+    [sym_call] = STATE(85),
+    [sym_ifElse] = STATE(85),
+    [sym_let] = STATE(85),
+    [sym_letrec] = STATE(85),
+    [sym_type_alias] = STATE(85),
+    [sym_import] = STATE(85),
+    [sym_fix] = STATE(85),
+    [sym_ascribe] = STATE(85),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(761),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(763),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(761),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(761),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [221] = {
+    [sym__expr] = STATE(86),
+    [sym_bool] = STATE(86),
+    [sym_succ] = STATE(86),
+    [sym_pred] = STATE(86),
+    [sym_iszero] = STATE(86),
+    [sym_string] = STATE(86),
+    [sym_concat] = STATE(86),
+    [sym_length] = STATE(86),
+    [sym_eq] = STATE(86),
+    [sym_not] = STATE(86),
+    [sym_and] = STATE(86),
+    [sym_or] = STATE(86),
+    [sym_equal] = STATE(86),
+    [sym_tuple] = STATE(86),
+    [sym_record] = STATE(86),
+    [sym_projection] = STATE(86),
+    [sym_inl] = STATE(86),
+    [sym_inr] = STATE(86),
+    [sym_variant] = STATE(86),
+    [sym_case] = STATE(86),
+    [sym_cons] = STATE(86),
+    [sym_isnil] = STATE(86),
+    [sym_head] = STATE(86),
+    [sym_tail] = STATE(86),
+    [sym_list] = STATE(86),
+    [sym_ref] = STATE(86),
+    [sym_deref] = STATE(86),
+    [sym_assign] = STATE(86),
+    [sym_raise] = STATE(86),
+    [sym_try] = STATE(86),
+    [sym_type_abs] = STATE(86),
+    [sym_type_app] = STATE(86),
+    [sym_pack] = STATE(86),
+    [sym_unpack] = STATE(86),
+    [sym_def] = STATE(86),
+    [sym_call] = STATE(86),
+    [sym_ifElse] = STATE(86),
+    [sym_let] = STATE(86),
+    [sym_letrec] = STATE(86),
+    [sym_type_alias] = STATE(86),
+    [sym_import] = STATE(86),
+    [sym_fix] = STATE(86),
+    [sym_ascribe] = STATE(86),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(765),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(767),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(765),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(765),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [222] = {
+    [sym__expr] = STATE(87),
+    [sym_bool] = STATE(87),
+    [sym_succ] = STATE(87),
+    [sym_pred] = STATE(87),
+    [sym_iszero] = STATE(87),
+    [sym_string] = STATE(87),
+    [sym_concat] = STATE(87),
+    [sym_length] = STATE(87),
+    [sym_eq] = STATE(87),
+    [sym_not] = STATE(87),
+    [sym_and] = STATE(87),
+    [sym_or] = STATE(87),
+    [sym_equal] = STATE(87),
+    [sym_tuple] = STATE(87),
+    [sym_record] = STATE(87),
+    [sym_projection] = STATE(87),
+    [sym_inl] = STATE(87),
+    [sym_inr] = STATE(87),
+    [sym_variant] = STATE(87),
+    [sym_case] = STATE(87),
+    [sym_cons] = STATE(87),
+    [sym_isnil] = STATE(87),
+    [sym_head] = STATE(87),
+    [sym_tail] = STATE(87),
+    [sym_list] = STATE(87),
+    [sym_ref] = STATE(87),
+    [sym_deref] = STATE(87),
+    [sym_assign] = STATE(87),
+    [sym_raise] = STATE(87),
+    [sym_try] = STATE(87),
+    [sym_type_abs] = STATE(87),
+    [sym_type_app] = STATE(87),
+    [sym_pack] = STATE(87),
+    [sym_unpack] = STATE(87),
+    [sym_def] = STATE(87),
+    [sym_call] = STATE(87),
+    [sym_ifElse] = STATE(87),
+    [sym_let] = STATE(87),
+    [sym_letrec] = STATE(87),
+    [sym_type_alias] = STATE(87),
+    [sym_import] = STATE(87),
+    [sym_fix] = STATE(87),
+    [sym_ascribe] = STATE(87),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(769),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(771),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(769),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(769),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [223] = {
+    [sym__expr] = STATE(75),
+    [sym_bool] = STATE(75),
+    [sym_succ] = STATE(75),
+    [sym_pred] = STATE(75),
+    [sym_iszero] = STATE(75),
+    [sym_string] = STATE(75),
+    [sym_concat] = STATE(75),
+    [sym_length] = STATE(75),
+    [sym_eq] = STATE(75),
+    [sym_not] = STATE(75),
+    [sym_and] = STATE(75),
+    [sym_or] = STATE(75),
+    [sym_equal] = STATE(75),
+    [sym_tuple] = STATE(75),
+    [sym_record] = STATE(75),
+    [sym_projection] = STATE(75),
+    [sym_inl] = STATE(75),
+    [sym_inr] = STATE(75),
+    [sym_variant] = STATE(75),
+    [sym_case] = STATE(75),
+    [sym_cons] = STATE(75),
+    [sym_isnil] = STATE(75),
+    [sym_head] = STATE(75),
+    [sym_tail] = STATE(75),
+    [sym_list] = STATE(75),
+    [sym_ref] = STATE(75),
+    [sym_deref] = STATE(75),
+    [sym_assign] = STATE(75),
+    [sym_raise] = STATE(75),
+    [sym_try] = STATE(75),
+    [sym_type_abs] = STATE(75),
+    [sym_type_app] = STATE(75),
+    [sym_pack] = STATE(75),
+    [sym_unpack] = STATE(75),
+    [sym_def] = STATE(75),
+    [sym_call] = STATE(75),
+    [sym_ifElse] = STATE(75),
+    [sym_let] = STATE(75),
+    [sym_letrec] = STATE(75),
+    [sym_type_alias] = STATE(75),
+    [sym_import] = STATE(75),
+    [sym_fix] = STATE(75),
+    [sym_ascribe] = STATE(75),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(773),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(775),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(773),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(773),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [224] = {
+    [sym__expr] = STATE(74),
+    [sym_bool] = STATE(74),
+    [sym_succ] = STATE(74),
+    [sym_pred] = STATE(74),
+    [sym_iszero] = STATE(74),
+    [sym_string] = STATE(74),
+    [sym_concat] = STATE(74),
+    [sym_length] = STATE(74),
+    [sym_eq] = STATE(74),
+    [sym_not] = STATE(74),
+    [sym_and] = STATE(74),
+    [sym_or] = STATE(74),
+    [sym_equal] = STATE(74),
+    [sym_tuple] = STATE(74),
+    [sym_record] = STATE(74),
+    [sym_projection] = STATE(74),
+    [sym_inl] = STATE(74),
+    [sym_inr] = STATE(74),
+    [sym_variant] = STATE(74),
+    [sym_case] = STATE(74),
+    [sym_cons] = STATE(74),
+    [sym_isnil] = STATE(74),
+    [sym_head] = STATE(74),
+    [sym_tail] = STATE(74),
+    [sym_list] = STATE(74),
+    [sym_ref] = STATE(74),
+    [sym_deref] = STATE(74),
+    [sym_assign] = STATE(74),
+    [sym_raise] = STATE(74),
+    [sym_try] = STATE(74),
+    [sym_type_abs] = STATE(74),
+    [sym_type_app] = STATE(74),
+    [sym_pack] = STATE(74),
+    [sym_unpack] = STATE(74),
+    [sym_def] = STATE(74),
+    [sym_call] = STATE(74),
+    [sym_ifElse] = STATE(74),
+    [sym_let] = STATE(74),
+    [sym_letrec] = STATE(74),
+    [sym_type_alias] = STATE(74),
+    [sym_import] = STATE(74),
+    [sym_fix] = STATE(74),
+    [sym_ascribe] = STATE(74),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(777),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(779),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(777),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(777),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [225] = {
+    [sym__expr] = STATE(76),
+    [sym_bool] = STATE(76),
+    [sym_succ] = STATE(76),
+    [sym_pred] = STATE(76),
+    [sym_iszero] = STATE(76),
+    [sym_string] = STATE(76),
+    [sym_concat] = STATE(76),
+    [sym_length] = STATE(76),
+    [sym_eq] = STATE(76),
+    [sym_not] = STATE(76),
+    [sym_and] = STATE(76),
+    [sym_or] = STATE(76),
+    [sym_equal] = STATE(76),
+    [sym_tuple] = STATE(76),
+    [sym_record] = STATE(76),
+    [sym_projection] = STATE(76),
+    [sym_inl] = STATE(76),
+    [sym_inr] = STATE(76),
+    [sym_variant] = STATE(76),
+    [sym_case] = STATE(76),
+    [sym_cons] = STATE(76),
+    [sym_isnil] = STATE(76),
+    [sym_head] = STATE(76),
+    [sym_tail] = STATE(76),
+    [sym_list] = STATE(76),
+    [sym_ref] = STATE(76),
+    [sym_deref] = STATE(76),
+    [sym_assign] = STATE(76),
+    [sym_raise] = STATE(76),
+    [sym_try] = STATE(76),
+    [sym_type_abs] = STATE(76),
+    [sym_type_app] = STATE(76),
+    [sym_pack] = STATE(76),
+    [sym_unpack] = STATE(76),
+    [sym_def] = STATE(76),
+    [sym_call] = STATE(76),
+    [sym_ifElse] = STATE(76),
+    [sym_let] = STATE(76),
+    [sym_letrec] = STATE(76),
+    [sym_type_alias] = STATE(76),
+    [sym_import] = STATE(76),
+    [sym_fix] = STATE(76),
+    [sym_ascribe] = STATE(76),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(781),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(783),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(781),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(781),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [226] = {
+    [sym__expr] = STATE(90),
+    [sym_bool] = STATE(90),
+    [sym_succ] = STATE(90),
+    [sym_pred] = STATE(90),
+    [sym_iszero] = STATE(90),
+    [sym_string] = STATE(90),
+    [sym_concat] = STATE(90),
+    [sym_length] = STATE(90),
+    [sym_eq] = STATE(90),
+    [sym_not] = STATE(90),
+    [sym_and] = STATE(90),
+    [sym_or] = STATE(90),
+    [sym_equal] = STATE(90),
+    [sym_tuple] = STATE(90),
+    [sym_record] = STATE(90),
+    [sym_projection] = STATE(90),
+    [sym_inl] = STATE(90),
+    [sym_inr] = STATE(90),
+    [sym_variant] = STATE(90),
+    [sym_case] = STATE(90),
+    [sym_cons] = STATE(90),
+    [sym_isnil] = STATE(90),
+    [sym_head] = STATE(90),
+    [sym_tail] = STATE(90),
+    [sym_list] = STATE(90),
+    [sym_ref] = STATE(90),
+    [sym_deref] = STATE(90),
+    [sym_assign] = STATE(90),
+    [sym_raise] = STATE(90),
+    [sym_try] = STATE(90),
+    [sym_type_abs] = STATE(90),
+    [sym_type_app] = STATE(90),
+    [sym_pack] = STATE(90),
+    [sym_unpack] = STATE(90),
+    [sym_def] = STATE(90),
+    [sym_call] = STATE(90),
+    [sym_ifElse] = STATE(90),
+    [sym_let] = STATE(90),
+    [sym_letrec] = STATE(90),
+    [sym_type_alias] = STATE(90),
+    [sym_import] = STATE(90),
+    [sym_fix] = STATE(90),
+    [sym_ascribe] = STATE(90),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(785),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(787),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(785),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(785),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [227] = {
+    [sym__expr] = STATE(95),
+    [sym_bool] = STATE(95),
+    [sym_succ] = STATE(95),
+    [sym_pred] = STATE(95),
+    [sym_iszero] = STATE(95),
+    [sym_string] = STATE(95),
+    [sym_concat] = STATE(95),
+    [sym_length] = STATE(95),
+    [sym_eq] = STATE(95),
+    [sym_not] = STATE(95),
+    [sym_and] = STATE(95),
+    [sym_or] = STATE(95),
+    [sym_equal] = STATE(95),
+    [sym_tuple] = STATE(95),
+    [sym_record] = STATE(95),
+    [sym_projection] = STATE(95),
+    [sym_inl] = STATE(95),
+    [sym_inr] = STATE(95),
+    [sym_variant] = STATE(95),
+    [sym_case] = STATE(95),
+    [sym_cons] = STATE(95),
+    [sym_isnil] = STATE(95),
+    [sym_head] = STATE(95),
+    [sym_tail] = STATE(95),
+    [sym_list] = STATE(95),
+    [sym_ref] = STATE(95),
+    [sym_deref] = STATE(95),
+    [sym_assign] = STATE(95),
+    [sym_raise] = STATE(95),
+    [sym_try] = STATE(95),
+    [sym_type_abs] = STATE(95),
+    [sym_type_app] = STATE(95),
+    [sym_pack] = STATE(95),
+    [sym_unpack] = STATE(95),
+    [sym_def] = STATE(95),
+    [sym_call] = STATE(95),
+    [sym_ifElse] = STATE(95),
+    [sym_let] = STATE(95),
+    [sym_letrec] = STATE(95),
+    [sym_type_alias] = STATE(95),
+    [sym_import] = STATE(95),
+    [sym_fix] = STATE(95),
+    [sym_ascribe] = STATE(95),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(789),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(791),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(789),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(789),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [228] = {
+    [sym__expr] = STATE(91),
+    [sym_bool] = STATE(91),
+    [sym_succ] = STATE(91),
+    [sym_pred] = STATE(91),
+    [sym_iszero] = STATE(91),
+    [sym_string] = STATE(91),
+    [sym_concat] = STATE(91),
+    [sym_length] = STATE(91),
+    [sym_eq] = STATE(91),
+    [sym_not] = STATE(91),
+    [sym_and] = STATE(91),
+    [sym_or] = STATE(91),
+    [sym_equal] = STATE(91),
+    [sym_tuple] = STATE(91),
+    [sym_record] = STATE(91),
+    [sym_projection] = STATE(91),
+    [sym_inl] = STATE(91),
+    [sym_inr] = STATE(91),
+    [sym_variant] = STATE(91),
+    [sym_case] = STATE(91),
+    [sym_cons] = STATE(91),
+    [sym_isnil] = STATE(91),
+    [sym_head] = STATE(91),
+    [sym_tail] = STATE(91),
+    [sym_list] = STATE(91),
+    [sym_ref] = STATE(91),
+    [sym_deref] = STATE(91),
+    [sym_assign] = STATE(91),
+    [sym_raise] = STATE(91),
+    [sym_try] = STATE(91),
+    [sym_type_abs] = STATE(91),
+    [sym_type_app] = STATE(91),
+    [sym_pack] = STATE(91),
+    [sym_unpack] = STATE(91),
+    [sym_def] = STATE(91),
+    [sym_call] = STATE(91),
+    [sym_ifElse] = STATE(91),
+    [sym_let] = STATE(91),
+    [sym_letrec] = STATE(91),
+    [sym_type_alias] = STATE(91),
+    [sym_import] = STATE(91),
+    [sym_fix] = STATE(91),
+    [sym_ascribe] = STATE(91),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(793),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(795),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(793),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(793),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [229] = {
+    [sym__expr] = STATE(77),
+    [sym_bool] = STATE(77),
+    [sym_succ] = STATE(77),
+    [sym_pred] = STATE(77),
+    [sym_iszero] = STATE(77),
+    [sym_string] = STATE(77),
+    [sym_concat] = STATE(77),
+    [sym_length] = STATE(77),
+    [sym_eq] = STATE(77),
+    [sym_not] = STATE(77),
+    [sym_and] = STATE(77),
+    [sym_or] = STATE(77),
+    [sym_equal] = STATE(77),
+    [sym_tuple] = STATE(77),
+    [sym_record] = STATE(77),
+    [sym_projection] = STATE(77),
+    [sym_inl] = STATE(77),
+    [sym_inr] = STATE(77),
+    [sym_variant] = STATE(77),
+    [sym_case] = STATE(77),
+    [sym_cons] = STATE(77),
+    [sym_isnil] = STATE(77),
+    [sym_head] = STATE(77),
+    [sym_tail] = STATE(77),
+    [sym_list] = STATE(77),
+    [sym_ref] = STATE(77),
+    [sym_deref] = STATE(77),
+    [sym_assign] = STATE(77),
+    [sym_raise] = STATE(77),
+    [sym_try] = STATE(77),
+    [sym_type_abs] = STATE(77),
+    [sym_type_app] = STATE(77),
+    [sym_pack] = STATE(77),
+    [sym_unpack] = STATE(77),
+    [sym_def] = STATE(77),
+    [sym_call] = STATE(77),
+    [sym_ifElse] = STATE(77),
+    [sym_let] = STATE(77),
+    [sym_letrec] = STATE(77),
+    [sym_type_alias] = STATE(77),
+    [sym_import] = STATE(77),
+    [sym_fix] = STATE(77),
+    [sym_ascribe] = STATE(77),
+    [sym_ident] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(797),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(799),
+    [anon_sym_succ] = ACTIONS(15),
+    [anon_sym_pred] = ACTIONS(17),
+    [anon_sym_iszero] = ACTIONS(19),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(23),
+    [anon_sym_length] = ACTIONS(25),
+    [anon_sym_eq] = ACTIONS(27),
+    [anon_sym_not] = ACTIONS(29),
+    [sym_wildcard] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(33),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(39),
+    [sym_nil] = ACTIONS(797),
+    [anon_sym_cons] = ACTIONS(41),
+    [anon_sym_isnil] = ACTIONS(43),
+    [anon_sym_head] = ACTIONS(45),
+    [anon_sym_tail] = ACTIONS(47),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(51),
+    [anon_sym_BANG] = ACTIONS(53),
+    [sym_error] = ACTIONS(797),
+    [anon_sym_raise] = ACTIONS(55),
+    [anon_sym_try] = ACTIONS(57),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(59),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(63),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(71),
+  },
+  [230] = {
+    [sym__expr] = STATE(70),
+    [sym_bool] = STATE(70),
+    [sym_succ] = STATE(70),
+    [sym_pred] = STATE(70),
+    [sym_iszero] = STATE(70),
+    [sym_string] = STATE(70),
+    [sym_concat] = STATE(70),
+    [sym_length] = STATE(70),
+    [sym_eq] = STATE(70),
+    [sym_not] = STATE(70),
+    [sym_and] = STATE(70),
+    [sym_or] = STATE(70),
+    [sym_equal] = STATE(70),
+    [sym_tuple] = STATE(70),
+    [sym_record] = STATE(70),
+    [sym_projection] = STATE(70),
+    [sym_inl] = STATE(70),
+    [sym_inr] = STATE(70),
+    [sym_variant] = STATE(70),
+    [sym_case] = STATE(70),
+    [sym_cons] = STATE(70),
+    [sym_isnil] = STATE(70),
+    [sym_head] = STATE(70),
+    [sym_tail] = STATE(70),
+    [sym_list] = STATE(70),
+    [sym_ref] = STATE(70),
+    [sym_deref] = STATE(70),
+    [sym_assign] = STATE(70),
+    [sym_raise] = STATE(70),
+    [sym_try] = STATE(70),
+    [sym_type_abs] = STATE(70),
+    [sym_type_app] = STATE(70),
+    [sym_pack] = STATE(70),
+    [sym_unpack] = STATE(70),
+    [sym_def] = STATE(70),
+    [sym_call] = STATE(70),
+    [sym_ifElse] = STATE(70),
+    [sym_let] = STATE(70),
+    [sym_letrec] = STATE(70),
+    [sym_type_alias] = STATE(70),
+    [sym_import] = STATE(70),
+    [sym_fix] = STATE(70),
+    [sym_ascribe] = STATE(70),
+    [sym_ident] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [sym_unit] = ACTIONS(801),
+    [anon_sym_true] = ACTIONS(11),
+    [anon_sym_false] = ACTIONS(11),
+    [sym_nat] = ACTIONS(803),
+    [anon_sym_succ] = ACTIONS(147),
+    [anon_sym_pred] = ACTIONS(149),
+    [anon_sym_iszero] = ACTIONS(151),
+    [anon_sym_DQUOTE] = ACTIONS(21),
+    [anon_sym_concat] = ACTIONS(153),
+    [anon_sym_length] = ACTIONS(155),
+    [anon_sym_eq] = ACTIONS(157),
+    [anon_sym_not] = ACTIONS(159),
+    [sym_wildcard] = ACTIONS(161),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(165),
+    [anon_sym_inr] = ACTIONS(167),
+    [anon_sym_LT] = ACTIONS(37),
+    [anon_sym_case] = ACTIONS(171),
+    [sym_nil] = ACTIONS(801),
+    [anon_sym_cons] = ACTIONS(173),
+    [anon_sym_isnil] = ACTIONS(175),
+    [anon_sym_head] = ACTIONS(177),
+    [anon_sym_tail] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(49),
+    [anon_sym_ref] = ACTIONS(181),
+    [anon_sym_BANG] = ACTIONS(183),
+    [sym_error] = ACTIONS(801),
+    [anon_sym_raise] = ACTIONS(185),
+    [anon_sym_try] = ACTIONS(187),
+    [anon_sym_SLASH_BSLASH] = ACTIONS(189),
+    [anon_sym_let] = ACTIONS(61),
+    [anon_sym_if] = ACTIONS(193),
+    [anon_sym_letrec] = ACTIONS(65),
+    [anon_sym_type] = ACTIONS(67),
+    [anon_sym_import] = ACTIONS(69),
+    [anon_sym_fix] = ACTIONS(195),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(805), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(807), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [69] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(809), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(811), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [138] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(813), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(815), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [207] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(817), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(819), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [276] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(821), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(823), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [345] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(825), 21,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(827), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [418] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(833), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(835), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [487] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(837), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(839), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [556] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(841), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(843), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [625] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(845), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(847), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [694] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(858), 1,
+      anon_sym_COLON,
+    STATE(345), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(856), 3,
+      anon_sym_EQ_GT,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(849), 19,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(854), 35,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [771] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(860), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(862), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [840] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(864), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(866), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [909] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(868), 21,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(870), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [982] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(872), 21,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(874), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1055] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(876), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(878), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1124] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(856), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(880), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1193] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(882), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(884), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1262] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(886), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(888), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1331] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(890), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(892), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1400] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(894), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(896), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1469] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(898), 23,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_EQ_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(900), 38,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_EQ,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1538] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(902), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(904), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1609] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(906), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(908), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1680] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(858), 1,
+      anon_sym_COLON,
+    STATE(345), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(849), 19,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(854), 35,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1752] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(910), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(912), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1817] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(914), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(916), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1882] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(918), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(920), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [1947] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(922), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(924), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2012] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(926), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(928), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2077] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(930), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(932), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2142] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(934), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(936), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2207] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(938), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(940), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2272] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(942), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(944), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2337] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(950), 1,
+      anon_sym_PIPE,
+    STATE(277), 1,
+      aux_sym_case_repeat1,
+    ACTIONS(946), 19,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(948), 36,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2406] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(952), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(954), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2471] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(922), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(924), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2536] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(956), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(958), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2601] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(960), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(962), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2666] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(964), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(966), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2731] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(950), 1,
+      anon_sym_PIPE,
+    STATE(265), 1,
+      aux_sym_case_repeat1,
+    ACTIONS(968), 19,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(970), 36,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2800] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(972), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(974), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2865] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(976), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(978), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2930] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(980), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(982), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [2995] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(984), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(986), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3060] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(988), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(990), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3125] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(996), 1,
+      anon_sym_PIPE,
+    STATE(277), 1,
+      aux_sym_case_repeat1,
+    ACTIONS(992), 19,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(994), 36,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3194] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(999), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1001), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3259] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1003), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1005), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3324] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1007), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1009), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3389] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1011), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1013), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3454] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1015), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1017), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3519] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(934), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(936), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3584] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1019), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1021), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3649] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1023), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1025), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3714] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1027), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1029), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3779] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1031), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1033), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3844] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1035), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1037), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3909] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1039), 20,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(1041), 37,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_of,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_with,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_in,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [3974] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1043), 1,
+      anon_sym_COLON,
+    STATE(341), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(856), 3,
+      anon_sym_EQ_GT,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(849), 16,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(854), 30,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4043] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(858), 1,
+      anon_sym_COLON,
+    ACTIONS(1045), 1,
+      anon_sym_RPAREN,
+    STATE(345), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(856), 2,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(849), 15,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(854), 29,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4112] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1048), 1,
+      anon_sym_COLON,
+    STATE(343), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(856), 3,
+      anon_sym_EQ_GT,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(849), 15,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+    ACTIONS(854), 29,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4179] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1048), 1,
+      anon_sym_COLON,
+    STATE(343), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(1045), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+    ACTIONS(856), 3,
+      anon_sym_EQ_GT,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(849), 13,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+    ACTIONS(854), 29,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4248] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1050), 1,
+      anon_sym_EQ,
+    ACTIONS(1052), 1,
+      anon_sym_COLON,
+    STATE(343), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(856), 2,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(1045), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+    ACTIONS(849), 13,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+    ACTIONS(854), 29,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4319] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1043), 1,
+      anon_sym_COLON,
+    ACTIONS(1045), 1,
+      anon_sym_RBRACK,
+    STATE(341), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(856), 2,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+    ACTIONS(849), 14,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+    ACTIONS(854), 29,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4387] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1043), 1,
+      anon_sym_COLON,
+    STATE(341), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(849), 16,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(854), 30,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      anon_sym_PIPE,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4451] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1054), 1,
+      anon_sym_PIPE,
+    STATE(299), 1,
+      aux_sym_case_repeat1,
+    ACTIONS(968), 16,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(970), 31,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4512] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1048), 1,
+      anon_sym_COLON,
+    ACTIONS(1050), 1,
+      anon_sym_EQ,
+    STATE(343), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(849), 15,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+    ACTIONS(854), 29,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4577] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1054), 1,
+      anon_sym_PIPE,
+    STATE(300), 1,
+      aux_sym_case_repeat1,
+    ACTIONS(946), 16,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(948), 31,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4638] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1056), 1,
+      anon_sym_PIPE,
+    STATE(300), 1,
+      aux_sym_case_repeat1,
+    ACTIONS(992), 16,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+      anon_sym_SEMI,
+    ACTIONS(994), 31,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      sym_ident,
+      sym_wildcard,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4699] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1048), 1,
+      anon_sym_COLON,
+    STATE(344), 1,
+      aux_sym_def_repeat1,
+    ACTIONS(851), 2,
+      sym_ident,
+      sym_wildcard,
+    ACTIONS(849), 15,
+      anon_sym_LPAREN,
+      sym_nat,
+      anon_sym_DQUOTE,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_EQ_EQ,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_LBRACK,
+      anon_sym_BANG,
+      anon_sym_COLON_EQ,
+      anon_sym_SLASH_BSLASH,
+    ACTIONS(854), 29,
+      sym_unit,
+      anon_sym_true,
+      anon_sym_false,
+      anon_sym_succ,
+      anon_sym_pred,
+      anon_sym_iszero,
+      anon_sym_concat,
+      anon_sym_length,
+      anon_sym_eq,
+      anon_sym_not,
+      anon_sym_inl,
+      anon_sym_inr,
+      anon_sym_case,
+      sym_nil,
+      anon_sym_cons,
+      anon_sym_isnil,
+      anon_sym_head,
+      anon_sym_tail,
+      anon_sym_ref,
+      sym_error,
+      anon_sym_raise,
+      anon_sym_try,
+      anon_sym_as,
+      anon_sym_let,
+      anon_sym_if,
+      anon_sym_letrec,
+      anon_sym_type,
+      anon_sym_import,
+      anon_sym_fix,
+  [4761] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(205), 1,
+      anon_sym_Some,
+    ACTIONS(1059), 1,
+      sym_ident,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    STATE(398), 1,
+      sym_record_field_type,
+    STATE(331), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [4808] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    ACTIONS(1069), 1,
+      anon_sym_RBRACE,
+    STATE(336), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [4852] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    ACTIONS(1071), 1,
+      anon_sym_RBRACE,
+    STATE(336), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [4896] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(334), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [4937] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(253), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [4978] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(372), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5019] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(336), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5060] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(246), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5101] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(362), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5142] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(391), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5183] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(254), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5224] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(248), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5265] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(236), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5306] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(234), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5347] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(382), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5388] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(393), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5429] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(390), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5470] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(244), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5511] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(245), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5552] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(197), 1,
+      anon_sym_List,
+    ACTIONS(199), 1,
+      anon_sym_Ref,
+    ACTIONS(201), 1,
+      anon_sym_Rec,
+    ACTIONS(203), 1,
+      anon_sym_All,
+    ACTIONS(1061), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1063), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1065), 1,
+      anon_sym_LT,
+    ACTIONS(1067), 1,
+      sym_ident,
+    STATE(369), 11,
+      sym__type,
+      sym_fn_type,
+      sym_sum_type,
+      sym_list_type,
+      sym_ref_type,
+      sym_rec_type,
+      sym_forall_type,
+      sym_some_type,
+      sym_product_type,
+      sym_record_type,
+      sym_variant_type,
+  [5593] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1073), 1,
+      sym_ident,
+    ACTIONS(1075), 1,
+      sym_wildcard,
+    ACTIONS(1077), 1,
+      sym__type_ident,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    STATE(361), 1,
+      sym_record_field_pattern,
+    STATE(354), 3,
+      sym__pattern,
+      sym_tuple_pattern,
+      sym_record_pattern,
+  [5617] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1073), 1,
+      sym_ident,
+    ACTIONS(1075), 1,
+      sym_wildcard,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1081), 1,
+      sym__type_ident,
+    STATE(361), 1,
+      sym_record_field_pattern,
+    STATE(354), 3,
+      sym__pattern,
+      sym_tuple_pattern,
+      sym_record_pattern,
+  [5641] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1083), 1,
+      sym_ident,
+    ACTIONS(1085), 1,
+      sym_wildcard,
+    ACTIONS(1087), 1,
+      anon_sym_RBRACE,
+    STATE(402), 3,
+      sym__pattern,
+      sym_tuple_pattern,
+      sym_record_pattern,
+  [5662] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1075), 1,
+      sym_wildcard,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1089), 1,
+      sym_ident,
+    STATE(361), 1,
+      sym_record_field_pattern,
+    STATE(354), 3,
+      sym__pattern,
+      sym_tuple_pattern,
+      sym_record_pattern,
+  [5683] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1083), 1,
+      sym_ident,
+    ACTIONS(1085), 1,
+      sym_wildcard,
+    ACTIONS(1091), 1,
+      anon_sym_RBRACE,
+    STATE(402), 3,
+      sym__pattern,
+      sym_tuple_pattern,
+      sym_record_pattern,
+  [5704] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1093), 1,
+      sym_ident,
+    ACTIONS(1095), 1,
+      sym_wildcard,
+    ACTIONS(1097), 1,
+      anon_sym_LBRACE,
+    STATE(463), 3,
+      sym__pattern,
+      sym_tuple_pattern,
+      sym_record_pattern,
+  [5722] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1083), 1,
+      sym_ident,
+    ACTIONS(1085), 1,
+      sym_wildcard,
+    STATE(402), 3,
+      sym__pattern,
+      sym_tuple_pattern,
+      sym_record_pattern,
+  [5740] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1099), 1,
+      sym_ident,
+    ACTIONS(1101), 1,
+      sym_wildcard,
+    STATE(401), 3,
+      sym__pattern,
+      sym_tuple_pattern,
+      sym_record_pattern,
+  [5758] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1103), 1,
+      sym_ident,
+    ACTIONS(1105), 1,
+      sym_wildcard,
+    ACTIONS(1107), 1,
+      anon_sym_LBRACE,
+    STATE(449), 3,
+      sym__pattern,
+      sym_tuple_pattern,
+      sym_record_pattern,
+  [5776] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1109), 1,
+      anon_sym_COMMA,
+    ACTIONS(1111), 1,
+      anon_sym_RBRACE,
+    STATE(352), 1,
+      aux_sym_product_type_repeat1,
+  [5795] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1115), 1,
+      anon_sym_EQ_GT,
+    ACTIONS(1113), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [5812] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1117), 1,
+      anon_sym_COLON,
+    ACTIONS(856), 4,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_DASH_GT,
+      anon_sym_PLUS,
+  [5825] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1113), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_GT,
+  [5840] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1119), 1,
+      anon_sym_inl,
+    ACTIONS(1121), 1,
+      anon_sym_LT,
+    STATE(297), 1,
+      sym_variant_arm,
+    STATE(464), 1,
+      sym_inl_arm,
+  [5856] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1123), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [5870] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1119), 1,
+      anon_sym_inl,
+    ACTIONS(1125), 1,
+      anon_sym_LT,
+    STATE(271), 1,
+      sym_variant_arm,
+    STATE(453), 1,
+      sym_inl_arm,
+  [5886] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1119), 1,
+      anon_sym_inl,
+    ACTIONS(1125), 1,
+      anon_sym_LT,
+    STATE(271), 1,
+      sym_variant_arm,
+    STATE(425), 1,
+      sym_inl_arm,
+  [5902] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1127), 1,
+      anon_sym_COMMA,
+    STATE(339), 1,
+      aux_sym_record_type_repeat1,
+    ACTIONS(1130), 2,
+      anon_sym_RBRACE,
+      anon_sym_GT,
+  [5916] = 4,
+    ACTIONS(1132), 1,
+      sym_comment,
+    ACTIONS(1134), 1,
+      anon_sym_DQUOTE2,
+    STATE(347), 1,
+      aux_sym_string_repeat1,
+    ACTIONS(1136), 2,
+      sym_string_content,
+      sym_escape_sequence,
+  [5930] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1138), 1,
+      sym_ident,
+    ACTIONS(1140), 1,
+      sym_wildcard,
+    ACTIONS(1142), 1,
+      anon_sym_COLON,
+    STATE(342), 1,
+      aux_sym_def_repeat1,
+  [5946] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1144), 1,
+      sym_ident,
+    ACTIONS(1147), 1,
+      sym_wildcard,
+    ACTIONS(1150), 1,
+      anon_sym_COLON,
+    STATE(342), 1,
+      aux_sym_def_repeat1,
+  [5962] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1138), 1,
+      sym_ident,
+    ACTIONS(1140), 1,
+      sym_wildcard,
+    ACTIONS(1152), 1,
+      anon_sym_COLON,
+    STATE(342), 1,
+      aux_sym_def_repeat1,
+  [5978] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1138), 1,
+      sym_ident,
+    ACTIONS(1140), 1,
+      sym_wildcard,
+    ACTIONS(1154), 1,
+      anon_sym_COLON,
+    STATE(342), 1,
+      aux_sym_def_repeat1,
+  [5994] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1138), 1,
+      sym_ident,
+    ACTIONS(1140), 1,
+      sym_wildcard,
+    ACTIONS(1156), 1,
+      anon_sym_COLON,
+    STATE(342), 1,
+      aux_sym_def_repeat1,
+  [6010] = 4,
+    ACTIONS(1132), 1,
+      sym_comment,
+    ACTIONS(1158), 1,
+      anon_sym_DQUOTE2,
+    STATE(340), 1,
+      aux_sym_string_repeat1,
+    ACTIONS(1160), 2,
+      sym_string_content,
+      sym_escape_sequence,
+  [6024] = 4,
+    ACTIONS(1132), 1,
+      sym_comment,
+    ACTIONS(1162), 1,
+      anon_sym_DQUOTE2,
+    STATE(347), 1,
+      aux_sym_string_repeat1,
+    ACTIONS(1164), 2,
+      sym_string_content,
+      sym_escape_sequence,
+  [6038] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1167), 1,
+      anon_sym_COMMA,
+    ACTIONS(1169), 1,
+      anon_sym_RBRACK,
+    STATE(366), 1,
+      aux_sym_tuple_repeat1,
+  [6051] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      sym_ident,
+    ACTIONS(1173), 1,
+      anon_sym_GT,
+    STATE(395), 1,
+      sym_record_field_type,
+  [6064] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1175), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1177), 1,
+      anon_sym_COMMA,
+    STATE(368), 1,
+      aux_sym_tuple_repeat1,
+  [6077] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1179), 1,
+      anon_sym_COMMA,
+    ACTIONS(1181), 1,
+      anon_sym_GT,
+    STATE(360), 1,
+      aux_sym_record_type_repeat1,
+  [6090] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_COMMA,
+    ACTIONS(1185), 1,
+      anon_sym_RBRACE,
+    STATE(392), 1,
+      aux_sym_product_type_repeat1,
+  [6103] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      sym_ident,
+    ACTIONS(1187), 1,
+      anon_sym_RBRACE,
+    STATE(395), 1,
+      sym_record_field_type,
+  [6116] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1189), 1,
+      anon_sym_COMMA,
+    ACTIONS(1191), 1,
+      anon_sym_RBRACE,
+    STATE(385), 1,
+      aux_sym_tuple_pattern_repeat1,
+  [6129] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1193), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+  [6138] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1195), 1,
+      anon_sym_COMMA,
+    ACTIONS(1197), 1,
+      anon_sym_RBRACE,
+    STATE(339), 1,
+      aux_sym_record_type_repeat1,
+  [6151] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1199), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+  [6160] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      sym_ident,
+    ACTIONS(1201), 1,
+      anon_sym_GT,
+    STATE(395), 1,
+      sym_record_field_type,
+  [6173] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1203), 1,
+      sym_ident,
+    ACTIONS(1205), 1,
+      anon_sym_RBRACE,
+    STATE(418), 1,
+      sym_record_field,
+  [6186] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1207), 1,
+      anon_sym_COMMA,
+    ACTIONS(1209), 1,
+      anon_sym_GT,
+    STATE(339), 1,
+      aux_sym_record_type_repeat1,
+  [6199] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1211), 1,
+      anon_sym_COMMA,
+    ACTIONS(1213), 1,
+      anon_sym_RBRACE,
+    STATE(388), 1,
+      aux_sym_record_pattern_repeat1,
+  [6212] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1215), 1,
+      anon_sym_RBRACE,
+  [6225] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1217), 1,
+      anon_sym_COMMA,
+    ACTIONS(1220), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_record_repeat1,
+  [6238] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1115), 1,
+      anon_sym_EQ_GT,
+  [6251] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1222), 1,
+      anon_sym_RBRACK,
+  [6264] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1224), 1,
+      anon_sym_COMMA,
+    ACTIONS(1227), 1,
+      anon_sym_RBRACK,
+    STATE(366), 1,
+      aux_sym_tuple_repeat1,
+  [6277] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1229), 1,
+      anon_sym_EQ_GT,
+  [6290] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1227), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1231), 1,
+      anon_sym_COMMA,
+    STATE(368), 1,
+      aux_sym_tuple_repeat1,
+  [6303] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1234), 1,
+      anon_sym_RPAREN,
+  [6316] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1236), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+  [6325] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1240), 1,
+      sym_wildcard,
+    ACTIONS(1238), 2,
+      sym_ident,
+      anon_sym_COLON,
+  [6336] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1242), 1,
+      anon_sym_COMMA,
+  [6349] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1244), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+  [6358] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1246), 1,
+      anon_sym_COMMA,
+    ACTIONS(1249), 1,
+      anon_sym_RBRACE,
+    STATE(374), 1,
+      aux_sym_tuple_pattern_repeat1,
+  [6371] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1251), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+  [6380] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1253), 1,
+      sym_ident,
+    ACTIONS(1255), 1,
+      anon_sym_RBRACE,
+    STATE(405), 1,
+      sym_record_field_pattern,
+  [6393] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1257), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+  [6402] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1259), 1,
+      anon_sym_COMMA,
+    ACTIONS(1262), 1,
+      anon_sym_RBRACE,
+    STATE(378), 1,
+      aux_sym_record_pattern_repeat1,
+  [6415] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1264), 1,
+      anon_sym_COMMA,
+    ACTIONS(1266), 1,
+      anon_sym_RBRACE,
+    STATE(394), 1,
+      aux_sym_tuple_repeat1,
+  [6428] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1268), 1,
+      anon_sym_EQ_GT,
+  [6441] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1203), 1,
+      sym_ident,
+    ACTIONS(1270), 1,
+      anon_sym_RBRACE,
+    STATE(418), 1,
+      sym_record_field,
+  [6454] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1272), 1,
+      anon_sym_SEMI,
+  [6467] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1274), 1,
+      anon_sym_COMMA,
+    ACTIONS(1276), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_record_repeat1,
+  [6480] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1278), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+  [6489] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1280), 1,
+      anon_sym_COMMA,
+    ACTIONS(1282), 1,
+      anon_sym_RBRACE,
+    STATE(374), 1,
+      aux_sym_tuple_pattern_repeat1,
+  [6502] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1253), 1,
+      sym_ident,
+    ACTIONS(1284), 1,
+      anon_sym_RBRACE,
+    STATE(405), 1,
+      sym_record_field_pattern,
+  [6515] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1286), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_EQ,
+  [6524] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1288), 1,
+      anon_sym_COMMA,
+    ACTIONS(1290), 1,
+      anon_sym_RBRACE,
+    STATE(378), 1,
+      aux_sym_record_pattern_repeat1,
+  [6537] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1292), 1,
+      anon_sym_COMMA,
+    ACTIONS(1294), 1,
+      anon_sym_RBRACE,
+    STATE(383), 1,
+      aux_sym_record_repeat1,
+  [6550] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1296), 1,
+      anon_sym_EQ,
+  [6563] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1298), 1,
+      anon_sym_EQ,
+  [6576] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1300), 1,
+      anon_sym_COMMA,
+    ACTIONS(1303), 1,
+      anon_sym_RBRACE,
+    STATE(392), 1,
+      aux_sym_product_type_repeat1,
+  [6589] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(831), 1,
+      anon_sym_PLUS,
+    ACTIONS(1305), 1,
+      anon_sym_SEMI,
+  [6602] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1227), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1307), 1,
+      anon_sym_COMMA,
+    STATE(394), 1,
+      aux_sym_tuple_repeat1,
+  [6615] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1310), 3,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+      anon_sym_GT,
+  [6624] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      sym_ident,
+    ACTIONS(1312), 1,
+      anon_sym_RBRACE,
+    STATE(395), 1,
+      sym_record_field_type,
+  [6637] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1316), 1,
+      anon_sym_EQ,
+    ACTIONS(1314), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [6648] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1318), 1,
+      anon_sym_COMMA,
+    ACTIONS(1320), 1,
+      anon_sym_RBRACE,
+    STATE(356), 1,
+      aux_sym_record_type_repeat1,
+  [6661] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1125), 1,
+      anon_sym_LT,
+    STATE(276), 1,
+      sym_variant_arm,
+  [6671] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1117), 1,
+      anon_sym_COLON,
+    ACTIONS(1322), 1,
+      anon_sym_EQ,
+  [6681] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1324), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [6689] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1326), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [6697] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      sym_ident,
+    STATE(351), 1,
+      sym_record_field_type,
+  [6707] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1328), 1,
+      sym_ident,
+    ACTIONS(1330), 1,
+      sym_wildcard,
+  [6717] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1332), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [6725] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      sym_ident,
+    STATE(395), 1,
+      sym_record_field_type,
+  [6735] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1334), 1,
+      sym_ident,
+    ACTIONS(1336), 1,
+      sym_wildcard,
+  [6745] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1338), 1,
+      sym_ident,
+    ACTIONS(1340), 1,
+      sym_wildcard,
+  [6755] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1342), 1,
+      sym_ident,
+    ACTIONS(1344), 1,
+      sym_wildcard,
+  [6765] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1346), 1,
+      sym_ident,
+    ACTIONS(1348), 1,
+      sym_wildcard,
+  [6775] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1203), 1,
+      sym_ident,
+    STATE(418), 1,
+      sym_record_field,
+  [6785] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1350), 1,
+      anon_sym_inr,
+    STATE(275), 1,
+      sym_inr_arm,
+  [6795] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1253), 1,
+      sym_ident,
+    STATE(405), 1,
+      sym_record_field_pattern,
+  [6805] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1352), 1,
+      sym_ident,
+    ACTIONS(1354), 1,
+      sym_wildcard,
+  [6815] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1356), 1,
+      sym_ident,
+    STATE(351), 1,
+      sym_record_field_type,
+  [6825] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1358), 1,
+      anon_sym_inr,
+    STATE(275), 1,
+      sym_inr_arm,
+  [6835] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1360), 1,
+      sym_ident,
+    ACTIONS(1362), 1,
+      sym_wildcard,
+  [6845] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1364), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [6853] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1366), 1,
+      sym_ident,
+    ACTIONS(1368), 1,
+      sym_nat,
+  [6863] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1370), 1,
+      sym_ident,
+    ACTIONS(1372), 1,
+      sym_wildcard,
+  [6873] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1121), 1,
+      anon_sym_LT,
+    STATE(276), 1,
+      sym_variant_arm,
+  [6883] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1374), 1,
+      anon_sym_inr,
+    STATE(275), 1,
+      sym_inr_arm,
+  [6893] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1376), 1,
+      anon_sym_SEMI,
+  [6900] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1378), 1,
+      anon_sym_DOT,
+  [6907] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1380), 1,
+      anon_sym_PIPE,
+  [6914] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1382), 1,
+      anon_sym_EQ,
+  [6921] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1384), 1,
+      anon_sym_GT,
+  [6928] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1386), 1,
+      anon_sym_COMMA,
+  [6935] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1388), 1,
+      anon_sym_EQ_GT,
+  [6942] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1390), 1,
+      anon_sym_SEMI,
+  [6949] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1392), 1,
+      anon_sym_EQ,
+  [6956] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1394), 1,
+      anon_sym_DOT,
+  [6963] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1396), 1,
+      anon_sym_EQ_GT,
+  [6970] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1398), 1,
+      anon_sym_EQ_GT,
+  [6977] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1400), 1,
+      anon_sym_DOT,
+  [6984] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1402), 1,
+      sym_ident,
+  [6991] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1404), 1,
+      sym_ident,
+  [6998] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1406), 1,
+      anon_sym_RBRACE,
+  [7005] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1408), 1,
+      sym_ident,
+  [7012] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1410), 1,
+      anon_sym_RPAREN,
+  [7019] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1412), 1,
+      anon_sym_as,
+  [7026] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1414), 1,
+      sym_ident,
+  [7033] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1322), 1,
+      anon_sym_EQ,
+  [7040] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1416), 1,
+      anon_sym_DOT,
+  [7047] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1316), 1,
+      anon_sym_EQ,
+  [7054] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1418), 1,
+      anon_sym_GT,
+  [7061] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1420), 1,
+      ts_builtin_sym_end,
+  [7068] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1422), 1,
+      ts_builtin_sym_end,
+  [7075] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1424), 1,
+      anon_sym_EQ,
+  [7082] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1426), 1,
+      sym_ident,
+  [7089] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1428), 1,
+      sym_ident,
+  [7096] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1430), 1,
+      anon_sym_as,
+  [7103] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1432), 1,
+      anon_sym_PIPE,
+  [7110] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1434), 1,
+      anon_sym_EQ_GT,
+  [7117] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1436), 1,
+      anon_sym_EQ_GT,
+  [7124] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1438), 1,
+      anon_sym_DOT,
+  [7131] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1440), 1,
+      anon_sym_EQ,
+  [7138] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1442), 1,
+      anon_sym_EQ_GT,
+  [7145] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1444), 1,
+      sym_ident,
+  [7152] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1446), 1,
+      sym_ident,
+  [7159] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1448), 1,
+      sym_path,
+  [7166] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1450), 1,
+      anon_sym_EQ,
+  [7173] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1452), 1,
+      anon_sym_EQ,
+  [7180] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1454), 1,
+      anon_sym_PIPE,
+  [7187] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1456), 1,
+      anon_sym_EQ,
+  [7194] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1458), 1,
+      anon_sym_RBRACE,
+  [7201] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1460), 1,
+      anon_sym_EQ,
+  [7208] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1462), 1,
+      anon_sym_COLON,
+  [7215] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1464), 1,
+      sym_ident,
+  [7222] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1466), 1,
+      sym_ident,
+  [7229] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1468), 1,
+      anon_sym_as,
+  [7236] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1470), 1,
+      sym_ident,
+  [7243] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1472), 1,
+      sym_ident,
+  [7250] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1474), 1,
+      anon_sym_COLON,
+  [7257] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1476), 1,
+      sym_ident,
+  [7264] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1478), 1,
+      sym_ident,
+  [7271] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1480), 1,
+      sym_ident,
+  [7278] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1482), 1,
+      anon_sym_COMMA,
+  [7285] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1117), 1,
+      anon_sym_COLON,
+  [7292] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1484), 1,
+      sym_path,
+  [7299] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1486), 1,
+      anon_sym_COMMA,
+  [7306] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(59), 1,
+    ACTIONS(1488), 1,
       anon_sym_EQ,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
-  [SMALL_STATE(21)] = 0,
-  [SMALL_STATE(22)] = 7,
-  [SMALL_STATE(23)] = 14,
+  [SMALL_STATE(231)] = 0,
+  [SMALL_STATE(232)] = 69,
+  [SMALL_STATE(233)] = 138,
+  [SMALL_STATE(234)] = 207,
+  [SMALL_STATE(235)] = 276,
+  [SMALL_STATE(236)] = 345,
+  [SMALL_STATE(237)] = 418,
+  [SMALL_STATE(238)] = 487,
+  [SMALL_STATE(239)] = 556,
+  [SMALL_STATE(240)] = 625,
+  [SMALL_STATE(241)] = 694,
+  [SMALL_STATE(242)] = 771,
+  [SMALL_STATE(243)] = 840,
+  [SMALL_STATE(244)] = 909,
+  [SMALL_STATE(245)] = 982,
+  [SMALL_STATE(246)] = 1055,
+  [SMALL_STATE(247)] = 1124,
+  [SMALL_STATE(248)] = 1193,
+  [SMALL_STATE(249)] = 1262,
+  [SMALL_STATE(250)] = 1331,
+  [SMALL_STATE(251)] = 1400,
+  [SMALL_STATE(252)] = 1469,
+  [SMALL_STATE(253)] = 1538,
+  [SMALL_STATE(254)] = 1609,
+  [SMALL_STATE(255)] = 1680,
+  [SMALL_STATE(256)] = 1752,
+  [SMALL_STATE(257)] = 1817,
+  [SMALL_STATE(258)] = 1882,
+  [SMALL_STATE(259)] = 1947,
+  [SMALL_STATE(260)] = 2012,
+  [SMALL_STATE(261)] = 2077,
+  [SMALL_STATE(262)] = 2142,
+  [SMALL_STATE(263)] = 2207,
+  [SMALL_STATE(264)] = 2272,
+  [SMALL_STATE(265)] = 2337,
+  [SMALL_STATE(266)] = 2406,
+  [SMALL_STATE(267)] = 2471,
+  [SMALL_STATE(268)] = 2536,
+  [SMALL_STATE(269)] = 2601,
+  [SMALL_STATE(270)] = 2666,
+  [SMALL_STATE(271)] = 2731,
+  [SMALL_STATE(272)] = 2800,
+  [SMALL_STATE(273)] = 2865,
+  [SMALL_STATE(274)] = 2930,
+  [SMALL_STATE(275)] = 2995,
+  [SMALL_STATE(276)] = 3060,
+  [SMALL_STATE(277)] = 3125,
+  [SMALL_STATE(278)] = 3194,
+  [SMALL_STATE(279)] = 3259,
+  [SMALL_STATE(280)] = 3324,
+  [SMALL_STATE(281)] = 3389,
+  [SMALL_STATE(282)] = 3454,
+  [SMALL_STATE(283)] = 3519,
+  [SMALL_STATE(284)] = 3584,
+  [SMALL_STATE(285)] = 3649,
+  [SMALL_STATE(286)] = 3714,
+  [SMALL_STATE(287)] = 3779,
+  [SMALL_STATE(288)] = 3844,
+  [SMALL_STATE(289)] = 3909,
+  [SMALL_STATE(290)] = 3974,
+  [SMALL_STATE(291)] = 4043,
+  [SMALL_STATE(292)] = 4112,
+  [SMALL_STATE(293)] = 4179,
+  [SMALL_STATE(294)] = 4248,
+  [SMALL_STATE(295)] = 4319,
+  [SMALL_STATE(296)] = 4387,
+  [SMALL_STATE(297)] = 4451,
+  [SMALL_STATE(298)] = 4512,
+  [SMALL_STATE(299)] = 4577,
+  [SMALL_STATE(300)] = 4638,
+  [SMALL_STATE(301)] = 4699,
+  [SMALL_STATE(302)] = 4761,
+  [SMALL_STATE(303)] = 4808,
+  [SMALL_STATE(304)] = 4852,
+  [SMALL_STATE(305)] = 4896,
+  [SMALL_STATE(306)] = 4937,
+  [SMALL_STATE(307)] = 4978,
+  [SMALL_STATE(308)] = 5019,
+  [SMALL_STATE(309)] = 5060,
+  [SMALL_STATE(310)] = 5101,
+  [SMALL_STATE(311)] = 5142,
+  [SMALL_STATE(312)] = 5183,
+  [SMALL_STATE(313)] = 5224,
+  [SMALL_STATE(314)] = 5265,
+  [SMALL_STATE(315)] = 5306,
+  [SMALL_STATE(316)] = 5347,
+  [SMALL_STATE(317)] = 5388,
+  [SMALL_STATE(318)] = 5429,
+  [SMALL_STATE(319)] = 5470,
+  [SMALL_STATE(320)] = 5511,
+  [SMALL_STATE(321)] = 5552,
+  [SMALL_STATE(322)] = 5593,
+  [SMALL_STATE(323)] = 5617,
+  [SMALL_STATE(324)] = 5641,
+  [SMALL_STATE(325)] = 5662,
+  [SMALL_STATE(326)] = 5683,
+  [SMALL_STATE(327)] = 5704,
+  [SMALL_STATE(328)] = 5722,
+  [SMALL_STATE(329)] = 5740,
+  [SMALL_STATE(330)] = 5758,
+  [SMALL_STATE(331)] = 5776,
+  [SMALL_STATE(332)] = 5795,
+  [SMALL_STATE(333)] = 5812,
+  [SMALL_STATE(334)] = 5825,
+  [SMALL_STATE(335)] = 5840,
+  [SMALL_STATE(336)] = 5856,
+  [SMALL_STATE(337)] = 5870,
+  [SMALL_STATE(338)] = 5886,
+  [SMALL_STATE(339)] = 5902,
+  [SMALL_STATE(340)] = 5916,
+  [SMALL_STATE(341)] = 5930,
+  [SMALL_STATE(342)] = 5946,
+  [SMALL_STATE(343)] = 5962,
+  [SMALL_STATE(344)] = 5978,
+  [SMALL_STATE(345)] = 5994,
+  [SMALL_STATE(346)] = 6010,
+  [SMALL_STATE(347)] = 6024,
+  [SMALL_STATE(348)] = 6038,
+  [SMALL_STATE(349)] = 6051,
+  [SMALL_STATE(350)] = 6064,
+  [SMALL_STATE(351)] = 6077,
+  [SMALL_STATE(352)] = 6090,
+  [SMALL_STATE(353)] = 6103,
+  [SMALL_STATE(354)] = 6116,
+  [SMALL_STATE(355)] = 6129,
+  [SMALL_STATE(356)] = 6138,
+  [SMALL_STATE(357)] = 6151,
+  [SMALL_STATE(358)] = 6160,
+  [SMALL_STATE(359)] = 6173,
+  [SMALL_STATE(360)] = 6186,
+  [SMALL_STATE(361)] = 6199,
+  [SMALL_STATE(362)] = 6212,
+  [SMALL_STATE(363)] = 6225,
+  [SMALL_STATE(364)] = 6238,
+  [SMALL_STATE(365)] = 6251,
+  [SMALL_STATE(366)] = 6264,
+  [SMALL_STATE(367)] = 6277,
+  [SMALL_STATE(368)] = 6290,
+  [SMALL_STATE(369)] = 6303,
+  [SMALL_STATE(370)] = 6316,
+  [SMALL_STATE(371)] = 6325,
+  [SMALL_STATE(372)] = 6336,
+  [SMALL_STATE(373)] = 6349,
+  [SMALL_STATE(374)] = 6358,
+  [SMALL_STATE(375)] = 6371,
+  [SMALL_STATE(376)] = 6380,
+  [SMALL_STATE(377)] = 6393,
+  [SMALL_STATE(378)] = 6402,
+  [SMALL_STATE(379)] = 6415,
+  [SMALL_STATE(380)] = 6428,
+  [SMALL_STATE(381)] = 6441,
+  [SMALL_STATE(382)] = 6454,
+  [SMALL_STATE(383)] = 6467,
+  [SMALL_STATE(384)] = 6480,
+  [SMALL_STATE(385)] = 6489,
+  [SMALL_STATE(386)] = 6502,
+  [SMALL_STATE(387)] = 6515,
+  [SMALL_STATE(388)] = 6524,
+  [SMALL_STATE(389)] = 6537,
+  [SMALL_STATE(390)] = 6550,
+  [SMALL_STATE(391)] = 6563,
+  [SMALL_STATE(392)] = 6576,
+  [SMALL_STATE(393)] = 6589,
+  [SMALL_STATE(394)] = 6602,
+  [SMALL_STATE(395)] = 6615,
+  [SMALL_STATE(396)] = 6624,
+  [SMALL_STATE(397)] = 6637,
+  [SMALL_STATE(398)] = 6648,
+  [SMALL_STATE(399)] = 6661,
+  [SMALL_STATE(400)] = 6671,
+  [SMALL_STATE(401)] = 6681,
+  [SMALL_STATE(402)] = 6689,
+  [SMALL_STATE(403)] = 6697,
+  [SMALL_STATE(404)] = 6707,
+  [SMALL_STATE(405)] = 6717,
+  [SMALL_STATE(406)] = 6725,
+  [SMALL_STATE(407)] = 6735,
+  [SMALL_STATE(408)] = 6745,
+  [SMALL_STATE(409)] = 6755,
+  [SMALL_STATE(410)] = 6765,
+  [SMALL_STATE(411)] = 6775,
+  [SMALL_STATE(412)] = 6785,
+  [SMALL_STATE(413)] = 6795,
+  [SMALL_STATE(414)] = 6805,
+  [SMALL_STATE(415)] = 6815,
+  [SMALL_STATE(416)] = 6825,
+  [SMALL_STATE(417)] = 6835,
+  [SMALL_STATE(418)] = 6845,
+  [SMALL_STATE(419)] = 6853,
+  [SMALL_STATE(420)] = 6863,
+  [SMALL_STATE(421)] = 6873,
+  [SMALL_STATE(422)] = 6883,
+  [SMALL_STATE(423)] = 6893,
+  [SMALL_STATE(424)] = 6900,
+  [SMALL_STATE(425)] = 6907,
+  [SMALL_STATE(426)] = 6914,
+  [SMALL_STATE(427)] = 6921,
+  [SMALL_STATE(428)] = 6928,
+  [SMALL_STATE(429)] = 6935,
+  [SMALL_STATE(430)] = 6942,
+  [SMALL_STATE(431)] = 6949,
+  [SMALL_STATE(432)] = 6956,
+  [SMALL_STATE(433)] = 6963,
+  [SMALL_STATE(434)] = 6970,
+  [SMALL_STATE(435)] = 6977,
+  [SMALL_STATE(436)] = 6984,
+  [SMALL_STATE(437)] = 6991,
+  [SMALL_STATE(438)] = 6998,
+  [SMALL_STATE(439)] = 7005,
+  [SMALL_STATE(440)] = 7012,
+  [SMALL_STATE(441)] = 7019,
+  [SMALL_STATE(442)] = 7026,
+  [SMALL_STATE(443)] = 7033,
+  [SMALL_STATE(444)] = 7040,
+  [SMALL_STATE(445)] = 7047,
+  [SMALL_STATE(446)] = 7054,
+  [SMALL_STATE(447)] = 7061,
+  [SMALL_STATE(448)] = 7068,
+  [SMALL_STATE(449)] = 7075,
+  [SMALL_STATE(450)] = 7082,
+  [SMALL_STATE(451)] = 7089,
+  [SMALL_STATE(452)] = 7096,
+  [SMALL_STATE(453)] = 7103,
+  [SMALL_STATE(454)] = 7110,
+  [SMALL_STATE(455)] = 7117,
+  [SMALL_STATE(456)] = 7124,
+  [SMALL_STATE(457)] = 7131,
+  [SMALL_STATE(458)] = 7138,
+  [SMALL_STATE(459)] = 7145,
+  [SMALL_STATE(460)] = 7152,
+  [SMALL_STATE(461)] = 7159,
+  [SMALL_STATE(462)] = 7166,
+  [SMALL_STATE(463)] = 7173,
+  [SMALL_STATE(464)] = 7180,
+  [SMALL_STATE(465)] = 7187,
+  [SMALL_STATE(466)] = 7194,
+  [SMALL_STATE(467)] = 7201,
+  [SMALL_STATE(468)] = 7208,
+  [SMALL_STATE(469)] = 7215,
+  [SMALL_STATE(470)] = 7222,
+  [SMALL_STATE(471)] = 7229,
+  [SMALL_STATE(472)] = 7236,
+  [SMALL_STATE(473)] = 7243,
+  [SMALL_STATE(474)] = 7250,
+  [SMALL_STATE(475)] = 7257,
+  [SMALL_STATE(476)] = 7264,
+  [SMALL_STATE(477)] = 7271,
+  [SMALL_STATE(478)] = 7278,
+  [SMALL_STATE(479)] = 7285,
+  [SMALL_STATE(480)] = 7292,
+  [SMALL_STATE(481)] = 7299,
+  [SMALL_STATE(482)] = 7306,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
-  [5] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
-  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(11),
-  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
-  [15] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_def, 3, 0, 2),
-  [17] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_def, 3, 0, 2),
-  [19] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call, 2, 0, 1),
-  [21] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call, 2, 0, 1),
-  [23] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let, 6, 0, 4),
-  [25] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let, 6, 0, 4),
-  [27] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ifElse, 6, 0, 3),
-  [29] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ifElse, 6, 0, 3),
-  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(15),
-  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(18),
-  [37] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
-  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
-  [41] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expr, 1, 0, 0),
-  [43] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expr, 1, 0, 0),
-  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [47] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expr, 3, 0, 0),
-  [49] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expr, 3, 0, 0),
-  [51] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bool, 1, 0, 0),
-  [53] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bool, 1, 0, 0),
-  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [57] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [5] = {.entry = {.count = 1, .reusable = false}}, SHIFT(255),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(29),
+  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(286),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(141),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(198),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(204),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(227),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(126),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(127),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(128),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(132),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(133),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(450),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(134),
+  [41] = {.entry = {.count = 1, .reusable = false}}, SHIFT(135),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(137),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(140),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(143),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [55] = {.entry = {.count = 1, .reusable = false}}, SHIFT(146),
+  [57] = {.entry = {.count = 1, .reusable = false}}, SHIFT(148),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(451),
+  [61] = {.entry = {.count = 1, .reusable = false}}, SHIFT(327),
+  [63] = {.entry = {.count = 1, .reusable = false}}, SHIFT(167),
+  [65] = {.entry = {.count = 1, .reusable = false}}, SHIFT(473),
+  [67] = {.entry = {.count = 1, .reusable = false}}, SHIFT(477),
+  [69] = {.entry = {.count = 1, .reusable = false}}, SHIFT(480),
+  [71] = {.entry = {.count = 1, .reusable = false}}, SHIFT(193),
+  [73] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_eq, 3, 0, 4),
+  [75] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_eq, 3, 0, 4),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(419),
+  [79] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_iszero, 2, 0, 1),
+  [81] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_iszero, 2, 0, 1),
+  [83] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_length, 2, 0, 1),
+  [85] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_length, 2, 0, 1),
+  [87] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_not, 2, 0, 1),
+  [89] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_not, 2, 0, 1),
+  [91] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inl, 2, 0, 1),
+  [93] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_inl, 2, 0, 1),
+  [95] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inr, 2, 0, 1),
+  [97] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_inr, 2, 0, 1),
+  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_isnil, 2, 0, 1),
+  [101] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_isnil, 2, 0, 1),
+  [103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_head, 2, 0, 1),
+  [105] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_head, 2, 0, 1),
+  [107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tail, 2, 0, 1),
+  [109] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tail, 2, 0, 1),
+  [111] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ref, 2, 0, 1),
+  [113] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ref, 2, 0, 1),
+  [115] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_deref, 2, 0, 1),
+  [117] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_deref, 2, 0, 1),
+  [119] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raise, 2, 0, 1),
+  [121] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_raise, 2, 0, 1),
+  [123] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fix, 2, 0, 1),
+  [125] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fix, 2, 0, 1),
+  [127] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call, 2, 0, 3),
+  [129] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call, 2, 0, 3),
+  [131] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_concat, 3, 0, 4),
+  [133] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_concat, 3, 0, 4),
+  [135] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_succ, 2, 0, 1),
+  [137] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_succ, 2, 0, 1),
+  [139] = {.entry = {.count = 1, .reusable = false}}, SHIFT(294),
+  [141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [143] = {.entry = {.count = 1, .reusable = false}}, SHIFT(54),
+  [145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [147] = {.entry = {.count = 1, .reusable = false}}, SHIFT(176),
+  [149] = {.entry = {.count = 1, .reusable = false}}, SHIFT(177),
+  [151] = {.entry = {.count = 1, .reusable = false}}, SHIFT(178),
+  [153] = {.entry = {.count = 1, .reusable = false}}, SHIFT(179),
+  [155] = {.entry = {.count = 1, .reusable = false}}, SHIFT(180),
+  [157] = {.entry = {.count = 1, .reusable = false}}, SHIFT(181),
+  [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(182),
+  [161] = {.entry = {.count = 1, .reusable = false}}, SHIFT(301),
+  [163] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [165] = {.entry = {.count = 1, .reusable = false}}, SHIFT(150),
+  [167] = {.entry = {.count = 1, .reusable = false}}, SHIFT(151),
+  [169] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
+  [171] = {.entry = {.count = 1, .reusable = false}}, SHIFT(228),
+  [173] = {.entry = {.count = 1, .reusable = false}}, SHIFT(185),
+  [175] = {.entry = {.count = 1, .reusable = false}}, SHIFT(152),
+  [177] = {.entry = {.count = 1, .reusable = false}}, SHIFT(153),
+  [179] = {.entry = {.count = 1, .reusable = false}}, SHIFT(154),
+  [181] = {.entry = {.count = 1, .reusable = false}}, SHIFT(155),
+  [183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [185] = {.entry = {.count = 1, .reusable = false}}, SHIFT(157),
+  [187] = {.entry = {.count = 1, .reusable = false}}, SHIFT(217),
+  [189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(472),
+  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [193] = {.entry = {.count = 1, .reusable = false}}, SHIFT(225),
+  [195] = {.entry = {.count = 1, .reusable = false}}, SHIFT(158),
+  [197] = {.entry = {.count = 1, .reusable = false}}, SHIFT(309),
+  [199] = {.entry = {.count = 1, .reusable = false}}, SHIFT(313),
+  [201] = {.entry = {.count = 1, .reusable = false}}, SHIFT(459),
+  [203] = {.entry = {.count = 1, .reusable = false}}, SHIFT(469),
+  [205] = {.entry = {.count = 1, .reusable = false}}, SHIFT(436),
+  [207] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cons, 3, 0, 10),
+  [209] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cons, 3, 0, 10),
+  [211] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pred, 2, 0, 1),
+  [213] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pred, 2, 0, 1),
+  [215] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_abs, 4, 0, 26),
+  [217] = {.entry = {.count = 1, .reusable = false}}, SHIFT(15),
+  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [223] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [227] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_abs, 4, 0, 26),
+  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [233] = {.entry = {.count = 1, .reusable = false}}, SHIFT(312),
+  [235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_and, 3, 0, 11),
+  [237] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_and, 3, 0, 11),
+  [239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_def, 3, 0, 6),
+  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_def, 3, 0, 6),
+  [243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_equal, 3, 0, 11),
+  [245] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_equal, 3, 0, 11),
+  [247] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_try, 4, 0, 25),
+  [249] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_try, 4, 0, 25),
+  [251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_or, 3, 0, 11),
+  [253] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_or, 3, 0, 11),
+  [255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assign, 3, 0, 14),
+  [257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_assign, 3, 0, 14),
+  [259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_def, 4, 1, 20),
+  [261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_def, 4, 1, 20),
+  [263] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__block, 1, 0, 0),
+  [265] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__block, 1, 0, 0),
+  [267] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [269] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ifElse, 6, 0, 38),
+  [271] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ifElse, 6, 0, 38),
+  [273] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_def, 5, 0, 27),
+  [275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_def, 5, 0, 27),
+  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variant_arm, 7, 0, 45),
+  [279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variant_arm, 7, 0, 45),
+  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inr_arm, 4, 0, 41),
+  [283] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_inr_arm, 4, 0, 41),
+  [285] = {.entry = {.count = 1, .reusable = false}}, SHIFT(291),
+  [287] = {.entry = {.count = 1, .reusable = false}}, SHIFT(42),
+  [289] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [291] = {.entry = {.count = 1, .reusable = false}}, SHIFT(295),
+  [293] = {.entry = {.count = 1, .reusable = false}}, SHIFT(55),
+  [295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [297] = {.entry = {.count = 1, .reusable = false}}, SHIFT(210),
+  [299] = {.entry = {.count = 1, .reusable = false}}, SHIFT(211),
+  [301] = {.entry = {.count = 1, .reusable = false}}, SHIFT(212),
+  [303] = {.entry = {.count = 1, .reusable = false}}, SHIFT(213),
+  [305] = {.entry = {.count = 1, .reusable = false}}, SHIFT(214),
+  [307] = {.entry = {.count = 1, .reusable = false}}, SHIFT(215),
+  [309] = {.entry = {.count = 1, .reusable = false}}, SHIFT(216),
+  [311] = {.entry = {.count = 1, .reusable = false}}, SHIFT(296),
+  [313] = {.entry = {.count = 1, .reusable = false}}, SHIFT(183),
+  [315] = {.entry = {.count = 1, .reusable = false}}, SHIFT(184),
+  [317] = {.entry = {.count = 1, .reusable = false}}, SHIFT(229),
+  [319] = {.entry = {.count = 1, .reusable = false}}, SHIFT(122),
+  [321] = {.entry = {.count = 1, .reusable = false}}, SHIFT(186),
+  [323] = {.entry = {.count = 1, .reusable = false}}, SHIFT(187),
+  [325] = {.entry = {.count = 1, .reusable = false}}, SHIFT(188),
+  [327] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [329] = {.entry = {.count = 1, .reusable = false}}, SHIFT(189),
+  [331] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [333] = {.entry = {.count = 1, .reusable = false}}, SHIFT(191),
+  [335] = {.entry = {.count = 1, .reusable = false}}, SHIFT(220),
+  [337] = {.entry = {.count = 1, .reusable = true}}, SHIFT(437),
+  [339] = {.entry = {.count = 1, .reusable = false}}, SHIFT(330),
+  [341] = {.entry = {.count = 1, .reusable = false}}, SHIFT(226),
+  [343] = {.entry = {.count = 1, .reusable = false}}, SHIFT(476),
+  [345] = {.entry = {.count = 1, .reusable = false}}, SHIFT(460),
+  [347] = {.entry = {.count = 1, .reusable = false}}, SHIFT(461),
+  [349] = {.entry = {.count = 1, .reusable = false}}, SHIFT(192),
+  [351] = {.entry = {.count = 1, .reusable = false}}, SHIFT(241),
+  [353] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
+  [355] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [357] = {.entry = {.count = 1, .reusable = false}}, SHIFT(293),
+  [359] = {.entry = {.count = 1, .reusable = false}}, SHIFT(66),
+  [361] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [363] = {.entry = {.count = 1, .reusable = false}}, SHIFT(292),
+  [365] = {.entry = {.count = 1, .reusable = false}}, SHIFT(290),
+  [367] = {.entry = {.count = 1, .reusable = false}}, SHIFT(46),
+  [369] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [371] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [373] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [377] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [379] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [381] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [383] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [385] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [387] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [389] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [391] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [393] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [395] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
+  [399] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_tuple_repeat1, 2, 0, 8),
+  [401] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_field, 3, 0, 21),
+  [403] = {.entry = {.count = 1, .reusable = false}}, SHIFT(106),
+  [405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [407] = {.entry = {.count = 1, .reusable = false}}, SHIFT(105),
+  [409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [411] = {.entry = {.count = 1, .reusable = false}}, SHIFT(110),
+  [413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [415] = {.entry = {.count = 1, .reusable = false}}, SHIFT(111),
+  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [419] = {.entry = {.count = 1, .reusable = false}}, SHIFT(218),
+  [421] = {.entry = {.count = 1, .reusable = false}}, SHIFT(335),
+  [423] = {.entry = {.count = 1, .reusable = true}}, SHIFT(471),
+  [425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [427] = {.entry = {.count = 1, .reusable = false}}, SHIFT(165),
+  [429] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_inl_arm, 4, 0, 41),
+  [431] = {.entry = {.count = 1, .reusable = false}}, SHIFT(173),
+  [433] = {.entry = {.count = 1, .reusable = false}}, SHIFT(161),
+  [435] = {.entry = {.count = 1, .reusable = false}}, SHIFT(125),
+  [437] = {.entry = {.count = 1, .reusable = false}}, SHIFT(201),
+  [439] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [441] = {.entry = {.count = 1, .reusable = false}}, SHIFT(207),
+  [443] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [445] = {.entry = {.count = 1, .reusable = false}}, SHIFT(138),
+  [447] = {.entry = {.count = 1, .reusable = false}}, SHIFT(222),
+  [449] = {.entry = {.count = 1, .reusable = false}}, SHIFT(337),
+  [451] = {.entry = {.count = 1, .reusable = false}}, SHIFT(338),
+  [453] = {.entry = {.count = 1, .reusable = false}}, SHIFT(19),
+  [455] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [457] = {.entry = {.count = 1, .reusable = false}}, SHIFT(16),
+  [459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [461] = {.entry = {.count = 1, .reusable = false}}, SHIFT(2),
+  [463] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [465] = {.entry = {.count = 1, .reusable = false}}, SHIFT(45),
+  [467] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [469] = {.entry = {.count = 1, .reusable = false}}, SHIFT(298),
+  [471] = {.entry = {.count = 1, .reusable = false}}, SHIFT(68),
+  [473] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [475] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
+  [477] = {.entry = {.count = 1, .reusable = false}}, SHIFT(72),
+  [479] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [481] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [483] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
+  [485] = {.entry = {.count = 1, .reusable = false}}, SHIFT(57),
+  [487] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [489] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
+  [491] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [493] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
+  [495] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [497] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
+  [499] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [501] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
+  [503] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [505] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
+  [507] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [509] = {.entry = {.count = 1, .reusable = false}}, SHIFT(4),
+  [511] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [513] = {.entry = {.count = 1, .reusable = false}}, SHIFT(97),
+  [515] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [517] = {.entry = {.count = 1, .reusable = false}}, SHIFT(5),
+  [519] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [521] = {.entry = {.count = 1, .reusable = false}}, SHIFT(31),
+  [523] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [525] = {.entry = {.count = 1, .reusable = false}}, SHIFT(62),
+  [527] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [529] = {.entry = {.count = 1, .reusable = false}}, SHIFT(78),
+  [531] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [533] = {.entry = {.count = 1, .reusable = false}}, SHIFT(6),
+  [535] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [537] = {.entry = {.count = 1, .reusable = false}}, SHIFT(7),
+  [539] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [541] = {.entry = {.count = 1, .reusable = false}}, SHIFT(92),
+  [543] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [545] = {.entry = {.count = 1, .reusable = false}}, SHIFT(100),
+  [547] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [549] = {.entry = {.count = 1, .reusable = false}}, SHIFT(8),
+  [551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [553] = {.entry = {.count = 1, .reusable = false}}, SHIFT(9),
+  [555] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [557] = {.entry = {.count = 1, .reusable = false}}, SHIFT(30),
+  [559] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [561] = {.entry = {.count = 1, .reusable = false}}, SHIFT(64),
+  [563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [565] = {.entry = {.count = 1, .reusable = false}}, SHIFT(10),
+  [567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [569] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
+  [571] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [573] = {.entry = {.count = 1, .reusable = false}}, SHIFT(81),
+  [575] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [577] = {.entry = {.count = 1, .reusable = false}}, SHIFT(11),
+  [579] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [581] = {.entry = {.count = 1, .reusable = false}}, SHIFT(12),
+  [583] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [585] = {.entry = {.count = 1, .reusable = false}}, SHIFT(65),
+  [587] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [589] = {.entry = {.count = 1, .reusable = false}}, SHIFT(13),
+  [591] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [593] = {.entry = {.count = 1, .reusable = false}}, SHIFT(33),
+  [595] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [597] = {.entry = {.count = 1, .reusable = false}}, SHIFT(83),
+  [599] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [601] = {.entry = {.count = 1, .reusable = false}}, SHIFT(32),
+  [603] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [605] = {.entry = {.count = 1, .reusable = false}}, SHIFT(14),
+  [607] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [609] = {.entry = {.count = 1, .reusable = false}}, SHIFT(67),
+  [611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [613] = {.entry = {.count = 1, .reusable = false}}, SHIFT(69),
+  [615] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [617] = {.entry = {.count = 1, .reusable = false}}, SHIFT(25),
+  [619] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [621] = {.entry = {.count = 1, .reusable = false}}, SHIFT(71),
+  [623] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [625] = {.entry = {.count = 1, .reusable = false}}, SHIFT(73),
+  [627] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [629] = {.entry = {.count = 1, .reusable = false}}, SHIFT(56),
+  [631] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [633] = {.entry = {.count = 1, .reusable = false}}, SHIFT(58),
+  [635] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [637] = {.entry = {.count = 1, .reusable = false}}, SHIFT(84),
+  [639] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [641] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
+  [643] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [645] = {.entry = {.count = 1, .reusable = false}}, SHIFT(26),
+  [647] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [649] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
+  [651] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [653] = {.entry = {.count = 1, .reusable = false}}, SHIFT(59),
+  [655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [657] = {.entry = {.count = 1, .reusable = false}}, SHIFT(27),
+  [659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [661] = {.entry = {.count = 1, .reusable = false}}, SHIFT(60),
+  [663] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [665] = {.entry = {.count = 1, .reusable = false}}, SHIFT(61),
+  [667] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [669] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
+  [671] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [673] = {.entry = {.count = 1, .reusable = false}}, SHIFT(3),
+  [675] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [677] = {.entry = {.count = 1, .reusable = false}}, SHIFT(94),
+  [679] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [681] = {.entry = {.count = 1, .reusable = false}}, SHIFT(96),
+  [683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [685] = {.entry = {.count = 1, .reusable = false}}, SHIFT(93),
+  [687] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [689] = {.entry = {.count = 1, .reusable = false}}, SHIFT(47),
+  [691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [693] = {.entry = {.count = 1, .reusable = false}}, SHIFT(44),
+  [695] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [697] = {.entry = {.count = 1, .reusable = false}}, SHIFT(48),
+  [699] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [701] = {.entry = {.count = 1, .reusable = false}}, SHIFT(43),
+  [703] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [705] = {.entry = {.count = 1, .reusable = false}}, SHIFT(50),
+  [707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [709] = {.entry = {.count = 1, .reusable = false}}, SHIFT(49),
+  [711] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [713] = {.entry = {.count = 1, .reusable = false}}, SHIFT(51),
+  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [717] = {.entry = {.count = 1, .reusable = false}}, SHIFT(52),
+  [719] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [721] = {.entry = {.count = 1, .reusable = false}}, SHIFT(28),
+  [723] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [725] = {.entry = {.count = 1, .reusable = false}}, SHIFT(63),
+  [727] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [729] = {.entry = {.count = 1, .reusable = false}}, SHIFT(53),
+  [731] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [733] = {.entry = {.count = 1, .reusable = false}}, SHIFT(40),
+  [735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [737] = {.entry = {.count = 1, .reusable = false}}, SHIFT(41),
+  [739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [741] = {.entry = {.count = 1, .reusable = false}}, SHIFT(101),
+  [743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [745] = {.entry = {.count = 1, .reusable = false}}, SHIFT(99),
+  [747] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [749] = {.entry = {.count = 1, .reusable = false}}, SHIFT(80),
+  [751] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [753] = {.entry = {.count = 1, .reusable = false}}, SHIFT(82),
+  [755] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [757] = {.entry = {.count = 1, .reusable = false}}, SHIFT(79),
+  [759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [761] = {.entry = {.count = 1, .reusable = false}}, SHIFT(85),
+  [763] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [765] = {.entry = {.count = 1, .reusable = false}}, SHIFT(86),
+  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [769] = {.entry = {.count = 1, .reusable = false}}, SHIFT(87),
+  [771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [773] = {.entry = {.count = 1, .reusable = false}}, SHIFT(75),
+  [775] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [777] = {.entry = {.count = 1, .reusable = false}}, SHIFT(74),
+  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [781] = {.entry = {.count = 1, .reusable = false}}, SHIFT(76),
+  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [785] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
+  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [789] = {.entry = {.count = 1, .reusable = false}}, SHIFT(95),
+  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [793] = {.entry = {.count = 1, .reusable = false}}, SHIFT(91),
+  [795] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [797] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
+  [799] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [801] = {.entry = {.count = 1, .reusable = false}}, SHIFT(70),
+  [803] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [805] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_type, 3, 0, 9),
+  [807] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_record_type, 3, 0, 9),
+  [809] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type, 3, 0, 0),
+  [811] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__type, 3, 0, 0),
+  [813] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_product_type, 3, 0, 8),
+  [815] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_product_type, 3, 0, 8),
+  [817] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sum_type, 3, 0, 11),
+  [819] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_sum_type, 3, 0, 11),
+  [821] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variant_type, 3, 0, 9),
+  [823] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variant_type, 3, 0, 9),
+  [825] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fn_type, 3, 0, 28),
+  [827] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fn_type, 3, 0, 28),
+  [829] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [831] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [833] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_product_type, 4, 0, 8),
+  [835] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_product_type, 4, 0, 8),
+  [837] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_product_type, 4, 0, 17),
+  [839] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_product_type, 4, 0, 17),
+  [841] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_type, 4, 0, 9),
+  [843] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_record_type, 4, 0, 9),
+  [845] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_type, 4, 0, 22),
+  [847] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_record_type, 4, 0, 22),
+  [849] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expr, 1, 0, 0),
+  [851] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym__expr, 1, 0, 0), SHIFT(371),
+  [854] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expr, 1, 0, 0),
+  [856] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__type, 1, 0, 5),
+  [858] = {.entry = {.count = 1, .reusable = false}}, SHIFT(36),
+  [860] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variant_type, 4, 0, 9),
+  [862] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variant_type, 4, 0, 9),
+  [864] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variant_type, 4, 0, 22),
+  [866] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variant_type, 4, 0, 22),
+  [868] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rec_type, 4, 0, 33),
+  [870] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_rec_type, 4, 0, 33),
+  [872] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forall_type, 4, 0, 33),
+  [874] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forall_type, 4, 0, 33),
+  [876] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_type, 2, 0, 8),
+  [878] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_type, 2, 0, 8),
+  [880] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__type, 1, 0, 5),
+  [882] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ref_type, 2, 0, 19),
+  [884] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ref_type, 2, 0, 19),
+  [886] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_product_type, 5, 0, 17),
+  [888] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_product_type, 5, 0, 17),
+  [890] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_type, 5, 0, 22),
+  [892] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_record_type, 5, 0, 22),
+  [894] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variant_type, 5, 0, 22),
+  [896] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variant_type, 5, 0, 22),
+  [898] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_some_type, 6, 0, 42),
+  [900] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_some_type, 6, 0, 42),
+  [902] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pack, 8, 0, 43),
+  [904] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pack, 8, 0, 43),
+  [906] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ascribe, 3, 0, 15),
+  [908] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ascribe, 3, 0, 15),
+  [910] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unpack, 10, 0, 46),
+  [912] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unpack, 10, 0, 46),
+  [914] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple, 4, 0, 8),
+  [916] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple, 4, 0, 8),
+  [918] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_projection, 3, 0, 12),
+  [920] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_projection, 3, 0, 12),
+  [922] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple, 4, 0, 17),
+  [924] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple, 4, 0, 17),
+  [926] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record, 4, 0, 9),
+  [928] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_record, 4, 0, 9),
+  [930] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sequence, 3, 0, 16),
+  [932] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_sequence, 3, 0, 16),
+  [934] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple, 5, 0, 17),
+  [936] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple, 5, 0, 17),
+  [938] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record, 5, 0, 22),
+  [940] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_record, 5, 0, 22),
+  [942] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variant, 5, 0, 29),
+  [944] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variant, 5, 0, 29),
+  [946] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case, 5, 0, 30),
+  [948] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_case, 5, 0, 30),
+  [950] = {.entry = {.count = 1, .reusable = false}}, SHIFT(399),
+  [952] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 5, 0, 17),
+  [954] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 5, 0, 17),
+  [956] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record, 4, 0, 22),
+  [958] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_record, 4, 0, 22),
+  [960] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expr, 3, 0, 0),
+  [962] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expr, 3, 0, 0),
+  [964] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 0),
+  [966] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 0),
+  [968] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case, 4, 0, 24),
+  [970] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_case, 4, 0, 24),
+  [972] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 3, 0, 8),
+  [974] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 3, 0, 8),
+  [976] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 4, 0, 17),
+  [978] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 4, 0, 17),
+  [980] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_projection, 3, 0, 13),
+  [982] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_projection, 3, 0, 13),
+  [984] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case, 6, 0, 34),
+  [986] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_case, 6, 0, 34),
+  [988] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_case_repeat1, 2, 0, 35),
+  [990] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_case_repeat1, 2, 0, 35),
+  [992] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_case_repeat1, 2, 0, 36),
+  [994] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_case_repeat1, 2, 0, 36),
+  [996] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_case_repeat1, 2, 0, 36), SHIFT_REPEAT(399),
+  [999] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let, 6, 0, 37),
+  [1001] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let, 6, 0, 37),
+  [1003] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 2, 0, 0),
+  [1005] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 2, 0, 0),
+  [1007] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_app, 4, 1, 15),
+  [1009] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_app, 4, 1, 15),
+  [1011] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_alias, 6, 0, 39),
+  [1013] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_alias, 6, 0, 39),
+  [1015] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import, 6, 0, 40),
+  [1017] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import, 6, 0, 40),
+  [1019] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple, 3, 0, 8),
+  [1021] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple, 3, 0, 8),
+  [1023] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record, 3, 0, 9),
+  [1025] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_record, 3, 0, 9),
+  [1027] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bool, 1, 0, 0),
+  [1029] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bool, 1, 0, 0),
+  [1031] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
+  [1033] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
+  [1035] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_letrec, 8, 0, 44),
+  [1037] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_letrec, 8, 0, 44),
+  [1039] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 4, 0, 8),
+  [1041] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 4, 0, 8),
+  [1043] = {.entry = {.count = 1, .reusable = false}}, SHIFT(39),
+  [1045] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__expr, 1, 0, 0), REDUCE(sym__type, 1, 0, 5),
+  [1048] = {.entry = {.count = 1, .reusable = false}}, SHIFT(38),
+  [1050] = {.entry = {.count = 1, .reusable = false}}, SHIFT(206),
+  [1052] = {.entry = {.count = 1, .reusable = false}}, SHIFT(37),
+  [1054] = {.entry = {.count = 1, .reusable = false}}, SHIFT(421),
+  [1056] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_case_repeat1, 2, 0, 36), SHIFT_REPEAT(421),
+  [1059] = {.entry = {.count = 1, .reusable = false}}, SHIFT(333),
+  [1061] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [1063] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [1065] = {.entry = {.count = 1, .reusable = true}}, SHIFT(403),
+  [1067] = {.entry = {.count = 1, .reusable = false}}, SHIFT(247),
+  [1069] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [1071] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [1073] = {.entry = {.count = 1, .reusable = false}}, SHIFT(397),
+  [1075] = {.entry = {.count = 1, .reusable = false}}, SHIFT(354),
+  [1077] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
+  [1079] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [1081] = {.entry = {.count = 1, .reusable = true}}, SHIFT(478),
+  [1083] = {.entry = {.count = 1, .reusable = true}}, SHIFT(402),
+  [1085] = {.entry = {.count = 1, .reusable = false}}, SHIFT(402),
+  [1087] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [1089] = {.entry = {.count = 1, .reusable = true}}, SHIFT(397),
+  [1091] = {.entry = {.count = 1, .reusable = true}}, SHIFT(370),
+  [1093] = {.entry = {.count = 1, .reusable = true}}, SHIFT(463),
+  [1095] = {.entry = {.count = 1, .reusable = false}}, SHIFT(463),
+  [1097] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [1099] = {.entry = {.count = 1, .reusable = true}}, SHIFT(401),
+  [1101] = {.entry = {.count = 1, .reusable = false}}, SHIFT(401),
+  [1103] = {.entry = {.count = 1, .reusable = true}}, SHIFT(449),
+  [1105] = {.entry = {.count = 1, .reusable = false}}, SHIFT(449),
+  [1107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [1109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
+  [1111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [1113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_field_type, 3, 0, 32),
+  [1115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [1117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [1119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(407),
+  [1121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(470),
+  [1123] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_product_type_repeat1, 2, 0, 8),
+  [1125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(475),
+  [1127] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_record_type_repeat1, 2, 0, 23), SHIFT_REPEAT(406),
+  [1130] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_record_type_repeat1, 2, 0, 23),
+  [1132] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
+  [1134] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [1136] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [1138] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
+  [1140] = {.entry = {.count = 1, .reusable = false}}, SHIFT(371),
+  [1142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [1144] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_def_repeat1, 2, 0, 7), SHIFT_REPEAT(371),
+  [1147] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_def_repeat1, 2, 0, 7), SHIFT_REPEAT(371),
+  [1150] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_def_repeat1, 2, 0, 7),
+  [1152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [1154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [1156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [1158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
+  [1160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [1162] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0),
+  [1164] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(347),
+  [1167] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [1169] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
+  [1171] = {.entry = {.count = 1, .reusable = true}}, SHIFT(479),
+  [1173] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [1175] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
+  [1177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [1179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [1181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [1183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [1185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [1187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [1189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [1191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [1193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_pattern, 5, 0, 17),
+  [1195] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [1197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [1199] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_pattern, 5, 0, 22),
+  [1201] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [1203] = {.entry = {.count = 1, .reusable = true}}, SHIFT(431),
+  [1205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [1207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [1209] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [1211] = {.entry = {.count = 1, .reusable = true}}, SHIFT(386),
+  [1213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
+  [1215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [1217] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_record_repeat1, 2, 0, 23), SHIFT_REPEAT(411),
+  [1220] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_record_repeat1, 2, 0, 23),
+  [1222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
+  [1224] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_tuple_repeat1, 2, 0, 18), SHIFT_REPEAT(199),
+  [1227] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_tuple_repeat1, 2, 0, 18),
+  [1229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [1231] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_tuple_repeat1, 2, 0, 18), SHIFT_REPEAT(175),
+  [1234] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [1236] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_pattern, 4, 0, 8),
+  [1238] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_def_repeat1, 1, 0, 2),
+  [1240] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_def_repeat1, 1, 0, 2),
+  [1242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [1244] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_pattern, 4, 0, 17),
+  [1246] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_tuple_pattern_repeat1, 2, 0, 18), SHIFT_REPEAT(328),
+  [1249] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_tuple_pattern_repeat1, 2, 0, 18),
+  [1251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_pattern, 4, 0, 9),
+  [1253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(445),
+  [1255] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [1257] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_pattern, 4, 0, 22),
+  [1259] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_record_pattern_repeat1, 2, 0, 23), SHIFT_REPEAT(413),
+  [1262] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_record_pattern_repeat1, 2, 0, 23),
+  [1264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [1266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [1268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [1270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [1272] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [1274] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [1276] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [1278] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_pattern, 3, 0, 8),
+  [1280] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [1282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(373),
+  [1284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [1286] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_pattern, 3, 0, 9),
+  [1288] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [1290] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [1292] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
+  [1294] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [1296] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
+  [1298] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [1300] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_product_type_repeat1, 2, 0, 18), SHIFT_REPEAT(308),
+  [1303] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_product_type_repeat1, 2, 0, 18),
+  [1305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [1307] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_tuple_repeat1, 2, 0, 18), SHIFT_REPEAT(163),
+  [1310] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_record_type_repeat1, 2, 0, 9),
+  [1312] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [1314] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__pattern, 1, 0, 0),
+  [1316] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [1318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [1320] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [1322] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [1324] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_record_field_pattern, 3, 0, 31),
+  [1326] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_tuple_pattern_repeat1, 2, 0, 8),
+  [1328] = {.entry = {.count = 1, .reusable = true}}, SHIFT(427),
+  [1330] = {.entry = {.count = 1, .reusable = false}}, SHIFT(427),
+  [1332] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_record_pattern_repeat1, 2, 0, 9),
+  [1334] = {.entry = {.count = 1, .reusable = true}}, SHIFT(458),
+  [1336] = {.entry = {.count = 1, .reusable = false}}, SHIFT(458),
+  [1338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(455),
+  [1340] = {.entry = {.count = 1, .reusable = false}}, SHIFT(455),
+  [1342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
+  [1344] = {.entry = {.count = 1, .reusable = false}}, SHIFT(438),
+  [1346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(429),
+  [1348] = {.entry = {.count = 1, .reusable = false}}, SHIFT(429),
+  [1350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
+  [1352] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
+  [1354] = {.entry = {.count = 1, .reusable = false}}, SHIFT(433),
+  [1356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [1358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(414),
+  [1360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(446),
+  [1362] = {.entry = {.count = 1, .reusable = false}}, SHIFT(446),
+  [1364] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_record_repeat1, 2, 0, 9),
+  [1366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [1368] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [1370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(466),
+  [1372] = {.entry = {.count = 1, .reusable = false}}, SHIFT(466),
+  [1374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
+  [1376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [1378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [1380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(422),
+  [1382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [1384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(454),
+  [1386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(409),
+  [1388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [1390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [1392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [1394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [1396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [1398] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [1400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [1402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(481),
+  [1404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(424),
+  [1406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(462),
+  [1408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(423),
+  [1410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [1412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(439),
+  [1414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
+  [1416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [1418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(434),
+  [1420] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
+  [1422] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [1426] = {.entry = {.count = 1, .reusable = true}}, SHIFT(443),
+  [1428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
+  [1430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(442),
+  [1432] = {.entry = {.count = 1, .reusable = true}}, SHIFT(412),
+  [1434] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [1436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [1438] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [1440] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
+  [1442] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [1444] = {.entry = {.count = 1, .reusable = true}}, SHIFT(444),
+  [1446] = {.entry = {.count = 1, .reusable = true}}, SHIFT(482),
+  [1448] = {.entry = {.count = 1, .reusable = true}}, SHIFT(452),
+  [1450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [1452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [1454] = {.entry = {.count = 1, .reusable = true}}, SHIFT(416),
+  [1456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(417),
+  [1458] = {.entry = {.count = 1, .reusable = true}}, SHIFT(457),
+  [1460] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [1462] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [1464] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
+  [1466] = {.entry = {.count = 1, .reusable = true}}, SHIFT(465),
+  [1468] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [1470] = {.entry = {.count = 1, .reusable = true}}, SHIFT(456),
+  [1472] = {.entry = {.count = 1, .reusable = true}}, SHIFT(474),
+  [1474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [1476] = {.entry = {.count = 1, .reusable = true}}, SHIFT(467),
+  [1478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(468),
+  [1480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(426),
+  [1482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(420),
+  [1484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(441),
+  [1486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [1488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
 };
 
 #ifdef __cplusplus